@@ -0,0 +1,2 @@
+pub(crate) mod pg_auction_event_broadcaster;
+pub(crate) mod pg_user_event_notifier;
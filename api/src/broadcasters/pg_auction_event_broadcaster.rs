@@ -0,0 +1,86 @@
+use application::broadcasters::i_auction_event_broadcaster::dtos::AuctionEventDto;
+use application::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
+use application::broadcasters::in_process_auction_event_broadcaster::InProcessAuctionEventBroadcaster;
+use async_trait::async_trait;
+use domain::entities::auction::Auction;
+use domain::entities::auction_event::AuctionEvent;
+use domain::id::Id;
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::Receiver;
+use tracing::{error, info};
+
+const AUCTION_EVENTS_CHANNEL: &str = "auction_events";
+
+/// Publishes auction events through Postgres `NOTIFY`, so that every running instance
+/// forwards them to its own in-process subscribers.
+pub struct PgAuctionEventBroadcaster {
+    pool: PgPool,
+    local: Arc<InProcessAuctionEventBroadcaster>,
+}
+
+impl PgAuctionEventBroadcaster {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            local: Arc::new(InProcessAuctionEventBroadcaster::new()),
+        }
+    }
+
+    pub async fn listen(&self) -> anyhow::Result<()> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(AUCTION_EVENTS_CHANNEL).await?;
+
+        let local = self.local.clone();
+        tokio::spawn(async move {
+            loop {
+                // the listener reconnects by itself when the connection is lost
+                let notification = match listener.recv().await {
+                    Ok(notification) => notification,
+                    Err(e) => {
+                        error!("Failed to receive auction event: {:?}", e);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        continue;
+                    }
+                };
+
+                let event = serde_json::from_str::<AuctionEventDto>(notification.payload())
+                    .map_err(anyhow::Error::from)
+                    .and_then(AuctionEvent::try_from);
+
+                match event {
+                    Ok(event) => {
+                        if let Err(e) = local.publish(event).await {
+                            error!("Failed to forward auction event: {:?}", e);
+                        }
+                    }
+                    Err(e) => error!("Received malformed auction event: {:?}", e),
+                }
+            }
+        });
+
+        info!("Listening for auction events.");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl IAuctionEventBroadcaster for PgAuctionEventBroadcaster {
+    async fn publish(&self, event: AuctionEvent) -> anyhow::Result<()> {
+        let payload = serde_json::to_string(&AuctionEventDto::from(event))?;
+
+        sqlx::query("SELECT pg_notify($1, $2)")
+            .bind(AUCTION_EVENTS_CHANNEL)
+            .bind(payload)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    fn subscribe(&self, auction_id: Id<Auction>) -> Receiver<AuctionEvent> {
+        self.local.subscribe(auction_id)
+    }
+}
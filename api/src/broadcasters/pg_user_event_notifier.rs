@@ -0,0 +1,54 @@
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio::sync::broadcast::{channel, Receiver, Sender};
+use tracing::{error, info};
+
+const USER_EVENTS_CHANNEL: &str = "user_events";
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Tells the event feeds of this instance which users got new events, as announced on commit by
+/// the trigger on `user_events`. Feeds read the events themselves, so one listener serves them all.
+pub struct PgUserEventNotifier {
+    pool: PgPool,
+    sender: Sender<String>,
+}
+
+impl PgUserEventNotifier {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            sender: channel(CHANNEL_CAPACITY).0,
+        }
+    }
+
+    pub async fn listen(&self) -> anyhow::Result<()> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(USER_EVENTS_CHANNEL).await?;
+
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            loop {
+                // the listener reconnects by itself when the connection is lost
+                match listener.recv().await {
+                    Ok(notification) => {
+                        // an error only means that no feed is open
+                        let _ = sender.send(notification.payload().to_string());
+                    }
+                    Err(e) => {
+                        error!("Failed to receive user event notification: {:?}", e);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                    }
+                }
+            }
+        });
+
+        info!("Listening for user events.");
+        Ok(())
+    }
+
+    /// Receives the ids of users with new events.
+    pub fn subscribe(&self) -> Receiver<String> {
+        self.sender.subscribe()
+    }
+}
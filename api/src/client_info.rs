@@ -0,0 +1,31 @@
+use application::use_cases::sessions::create_session_use_case::dtos::ClientInfo;
+use http::HeaderMap;
+
+/// Reads the client's user agent and address. Requests reach us through `trusted_proxies`
+/// proxies, each appending the address it was connected from to `X-Forwarded-For`, so the client
+/// is the entry that many places from the right. Anything left of it was sent by the client and
+/// cannot be trusted.
+pub(crate) fn client_info(headers: &HeaderMap, trusted_proxies: usize) -> ClientInfo {
+    let user_agent = headers
+        .get(http::header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+
+    // a request may carry the header more than once, which counts as one list
+    let forwarded = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|ip| ip.trim().to_string())
+        .collect::<Vec<_>>();
+
+    ClientInfo {
+        user_agent,
+        ip_address: trusted_proxies
+            .checked_sub(1)
+            .and_then(|hops| forwarded.iter().rev().nth(hops))
+            .filter(|ip| !ip.is_empty())
+            .cloned(),
+    }
+}
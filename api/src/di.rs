@@ -1,88 +1,545 @@
 use std::sync::Arc;
 
+use crate::broadcasters::pg_auction_event_broadcaster::PgAuctionEventBroadcaster;
+use crate::broadcasters::pg_user_event_notifier::PgUserEventNotifier;
+use application::jobs::deliver_webhook_job_handler::DeliverWebhookJobHandler;
+use application::jobs::dispatch_webhook_event_job_handler::DispatchWebhookEventJobHandler;
+use application::jobs::job_handler_registry::JobHandlerRegistry;
+use application::jobs::send_email_job_handler::SendEmailJobHandler;
+use application::schedulers::auction_expiry_scheduler::AuctionExpiryScheduler;
+use application::signers::verification_link_signer::VerificationLinkSigner;
+use application::use_cases::account::delete_account_use_case::DeleteAccountUseCase;
+use application::use_cases::account::export_account_use_case::ExportAccountUseCase;
+use application::use_cases::admin::force_close_auction_use_case::ForceCloseAuctionUseCase;
+use application::use_cases::admin::get_users_use_case::GetUsersUseCase;
+use application::use_cases::admin::suspend_user_use_case::SuspendUserUseCase;
+use application::use_cases::api_keys::authenticate_api_key_use_case::AuthenticateApiKeyUseCase;
+use application::use_cases::api_keys::create_api_key_use_case::CreateApiKeyUseCase;
+use application::use_cases::api_keys::get_api_keys_use_case::GetApiKeysUseCase;
+use application::use_cases::api_keys::revoke_api_key_use_case::RevokeApiKeyUseCase;
 use application::use_cases::auctions::confirm_auction_use_case::ConfirmAuctionUseCase;
 use application::use_cases::auctions::create_auction_use_case::CreateAuctionUseCase;
 use application::use_cases::auctions::get_ongoing_auction_for_item_use_case::GetAuctionByItemIdUseCase;
 use application::use_cases::auctions::get_ongoing_auctions_use_case::GetAuctionsUseCase;
 use application::use_cases::auctions::handle_expired_auction_use_case::HandleExpiredAuctionUseCase;
 use application::use_cases::auctions::handle_expired_auctions_use_case::HandleExpiredAuctionsUseCase;
+use application::use_cases::auctions::run_auction_expiry_scheduler_use_case::RunAuctionExpirySchedulerUseCase;
+use application::use_cases::auctions::subscribe_to_auction_use_case::SubscribeToAuctionUseCase;
 use application::use_cases::bids::create_bid_use_case::CreateBidUseCase;
 use application::use_cases::bids::get_bids_use_case::GetBidsUseCase;
+use application::use_cases::external_identities::complete_external_login_use_case::CompleteExternalLoginUseCase;
+use application::use_cases::external_identities::link_external_identity_use_case::LinkExternalIdentityUseCase;
+use application::use_cases::external_identities::start_external_login_use_case::StartExternalLoginUseCase;
+use application::use_cases::jobs::run_jobs_use_case::RunJobsUseCase;
 use shuttle_secrets::SecretStore;
 use sqlx::PgPool;
 
+use application::use_cases::items::add_item_image_use_case::AddItemImageUseCase;
 use application::use_cases::items::create_item_use_case::CreateItemUseCase;
+use application::use_cases::items::delete_item_image_use_case::DeleteItemImageUseCase;
+use application::use_cases::items::delete_item_use_case::DeleteItemUseCase;
 use application::use_cases::items::get_item_image_use_case::GetItemImageUseCase;
 use application::use_cases::items::get_item_use_case::GetItemUseCase;
 use application::use_cases::items::get_items_use_case::GetItemsUseCase;
+use application::use_cases::items::reorder_item_images_use_case::ReorderItemImagesUseCase;
+use application::use_cases::items::update_item_use_case::UpdateItemUseCase;
+use application::use_cases::notifications::get_notifications_use_case::GetNotificationsUseCase;
+use application::use_cases::notifications::get_unread_notifications_count_use_case::GetUnreadNotificationsCountUseCase;
+use application::use_cases::notifications::mark_all_notifications_read_use_case::MarkAllNotificationsReadUseCase;
+use application::use_cases::notifications::mark_notification_read_use_case::MarkNotificationReadUseCase;
+use application::use_cases::ratings::get_user_ratings_use_case::GetUserRatingsUseCase;
+use application::use_cases::ratings::rate_counterparty_use_case::RateCounterpartyUseCase;
+use application::use_cases::sessions::check_session_use_case::CheckSessionUseCase;
+use application::use_cases::sessions::create_session_use_case::CreateSessionUseCase;
+use application::use_cases::sessions::get_sessions_use_case::GetSessionsUseCase;
+use application::use_cases::sessions::logout_use_case::LogoutUseCase;
+use application::use_cases::sessions::refresh_session_use_case::RefreshSessionUseCase;
+use application::use_cases::sessions::revoke_other_sessions_use_case::RevokeOtherSessionsUseCase;
+use application::use_cases::sessions::revoke_session_use_case::RevokeSessionUseCase;
+use application::use_cases::two_factor::confirm_two_factor_use_case::ConfirmTwoFactorUseCase;
+use application::use_cases::two_factor::disable_two_factor_use_case::DisableTwoFactorUseCase;
+use application::use_cases::two_factor::enroll_two_factor_use_case::EnrollTwoFactorUseCase;
+use application::use_cases::two_factor::verify_login_challenge_use_case::VerifyLoginChallengeUseCase;
+use application::use_cases::user::change_password_use_case::ChangePasswordUseCase;
+use application::use_cases::user::forgot_password_use_case::ForgotPasswordUseCase;
+use application::use_cases::user::get_avatar_use_case::GetAvatarUseCase;
+use application::use_cases::user::get_profile_use_case::GetProfileUseCase;
+use application::use_cases::user::get_public_profile_use_case::GetPublicProfileUseCase;
+use application::use_cases::user::get_user_events_use_case::GetUserEventsUseCase;
 use application::use_cases::user::get_user_use_case::GetUserUseCase;
 use application::use_cases::user::login_use_case::LoginUseCase;
 use application::use_cases::user::register_use_case::RegisterUseCase;
+use application::use_cases::user::resend_verification_email_use_case::ResendVerificationEmailUseCase;
+use application::use_cases::user::reset_password_use_case::ResetPasswordUseCase;
+use application::use_cases::user::update_profile_use_case::UpdateProfileUseCase;
+use application::use_cases::user::verify_email_use_case::VerifyEmailUseCase;
+use application::use_cases::webhooks::create_webhook_use_case::CreateWebhookUseCase;
+use application::use_cases::webhooks::delete_webhook_use_case::DeleteWebhookUseCase;
+use application::use_cases::webhooks::get_webhook_deliveries_use_case::GetWebhookDeliveriesUseCase;
+use application::use_cases::webhooks::get_webhooks_use_case::GetWebhooksUseCase;
+use domain::entities::account::AccountExport;
+use domain::entities::api_key::ApiKey;
 use domain::entities::auction::Auction;
+use domain::entities::audit_event::AuditEvent;
+use domain::entities::email_verification::EmailVerificationToken;
+use domain::entities::external_identity::ExternalIdentity;
 use domain::entities::item::Item;
+use domain::entities::item_image::ItemImage;
+use domain::entities::job::Job;
+use domain::entities::login_throttle::LoginThrottle;
+use domain::entities::notification::Notification;
+use domain::entities::password_reset::PasswordResetToken;
+use domain::entities::rating::Rating;
+use domain::entities::session::Session;
+use domain::entities::two_factor::TwoFactor;
 use domain::entities::user::User;
+use domain::entities::user_event::UserEvent;
+use domain::entities::user_profile::UserProfile;
+use domain::entities::webhook::WebhookSubscription;
+use infrastructure::blob_stores::file_blob_store::FileBlobStore;
+use infrastructure::blob_stores::s3_blob_store::S3BlobStore;
+use infrastructure::blob_stores::BlobStore;
+use infrastructure::identity::github_identity_provider::GithubIdentityProvider;
+use infrastructure::identity::oidc_identity_provider::OidcIdentityProvider;
+use infrastructure::identity::IdentityProvider;
+use infrastructure::mailers::file_mailer::FileMailer;
+use infrastructure::mailers::smtp_mailer::SmtpMailer;
+use infrastructure::mailers::Mailer;
 use infrastructure::repositories::DatabaseRepositoryImpl;
+use infrastructure::webhooks::http_webhook_sender::HttpWebhookSender;
+use std::path::PathBuf;
 
 pub struct Modules {
-    pub(crate) register_use_case: RegisterUseCase<DatabaseRepositoryImpl<User>>,
-    pub(crate) login_use_case: LoginUseCase<DatabaseRepositoryImpl<User>>,
+    pub(crate) register_use_case: RegisterUseCase<
+        DatabaseRepositoryImpl<User>,
+        DatabaseRepositoryImpl<EmailVerificationToken>,
+    >,
+    pub(crate) verify_email_use_case:
+        VerifyEmailUseCase<DatabaseRepositoryImpl<EmailVerificationToken>>,
+    pub(crate) resend_verification_email_use_case:
+        ResendVerificationEmailUseCase<DatabaseRepositoryImpl<EmailVerificationToken>>,
+    pub(crate) forgot_password_use_case: ForgotPasswordUseCase<
+        DatabaseRepositoryImpl<User>,
+        DatabaseRepositoryImpl<PasswordResetToken>,
+    >,
+    pub(crate) reset_password_use_case: ResetPasswordUseCase<
+        DatabaseRepositoryImpl<PasswordResetToken>,
+        DatabaseRepositoryImpl<Session>,
+    >,
+    pub(crate) change_password_use_case: ChangePasswordUseCase<DatabaseRepositoryImpl<User>>,
+    pub(crate) login_use_case: LoginUseCase<
+        DatabaseRepositoryImpl<User>,
+        DatabaseRepositoryImpl<LoginThrottle>,
+        DatabaseRepositoryImpl<AuditEvent>,
+        DatabaseRepositoryImpl<TwoFactor>,
+    >,
+    pub(crate) enroll_two_factor_use_case:
+        EnrollTwoFactorUseCase<DatabaseRepositoryImpl<TwoFactor>>,
+    pub(crate) confirm_two_factor_use_case:
+        ConfirmTwoFactorUseCase<DatabaseRepositoryImpl<TwoFactor>>,
+    pub(crate) disable_two_factor_use_case:
+        DisableTwoFactorUseCase<DatabaseRepositoryImpl<TwoFactor>>,
+    pub(crate) verify_login_challenge_use_case: VerifyLoginChallengeUseCase<
+        DatabaseRepositoryImpl<TwoFactor>,
+        DatabaseRepositoryImpl<User>,
+        DatabaseRepositoryImpl<LoginThrottle>,
+        DatabaseRepositoryImpl<AuditEvent>,
+    >,
+    pub(crate) start_external_login_use_case:
+        StartExternalLoginUseCase<DatabaseRepositoryImpl<ExternalIdentity>, IdentityProvider>,
+    pub(crate) complete_external_login_use_case: CompleteExternalLoginUseCase<
+        DatabaseRepositoryImpl<ExternalIdentity>,
+        DatabaseRepositoryImpl<User>,
+        DatabaseRepositoryImpl<TwoFactor>,
+        IdentityProvider,
+    >,
+    pub(crate) link_external_identity_use_case:
+        LinkExternalIdentityUseCase<DatabaseRepositoryImpl<ExternalIdentity>, IdentityProvider>,
     pub(crate) get_user_use_case: GetUserUseCase<DatabaseRepositoryImpl<User>>,
-    pub(crate) create_item_use_case: CreateItemUseCase<DatabaseRepositoryImpl<Item>>,
-    pub(crate) get_item_image_use_case:
-        GetItemImageUseCase<DatabaseRepositoryImpl<Item>, DatabaseRepositoryImpl<Auction>>,
+    pub(crate) create_session_use_case: CreateSessionUseCase<DatabaseRepositoryImpl<Session>>,
+    pub(crate) refresh_session_use_case:
+        RefreshSessionUseCase<DatabaseRepositoryImpl<Session>, DatabaseRepositoryImpl<User>>,
+    pub(crate) logout_use_case: LogoutUseCase<DatabaseRepositoryImpl<Session>>,
+    pub(crate) check_session_use_case: CheckSessionUseCase<DatabaseRepositoryImpl<Session>>,
+    pub(crate) get_sessions_use_case: GetSessionsUseCase<DatabaseRepositoryImpl<Session>>,
+    pub(crate) revoke_session_use_case: RevokeSessionUseCase<DatabaseRepositoryImpl<Session>>,
+    pub(crate) revoke_other_sessions_use_case:
+        RevokeOtherSessionsUseCase<DatabaseRepositoryImpl<Session>>,
+    pub(crate) create_item_use_case: CreateItemUseCase<DatabaseRepositoryImpl<Item>, BlobStore>,
+    pub(crate) get_item_image_use_case: GetItemImageUseCase<
+        DatabaseRepositoryImpl<Item>,
+        DatabaseRepositoryImpl<Auction>,
+        DatabaseRepositoryImpl<ItemImage>,
+        BlobStore,
+    >,
+    pub(crate) add_item_image_use_case: AddItemImageUseCase<
+        DatabaseRepositoryImpl<Item>,
+        DatabaseRepositoryImpl<Auction>,
+        DatabaseRepositoryImpl<ItemImage>,
+        BlobStore,
+    >,
+    pub(crate) delete_item_image_use_case: DeleteItemImageUseCase<
+        DatabaseRepositoryImpl<Item>,
+        DatabaseRepositoryImpl<Auction>,
+        DatabaseRepositoryImpl<ItemImage>,
+        BlobStore,
+    >,
+    pub(crate) reorder_item_images_use_case: ReorderItemImagesUseCase<
+        DatabaseRepositoryImpl<Item>,
+        DatabaseRepositoryImpl<Auction>,
+        DatabaseRepositoryImpl<ItemImage>,
+    >,
     pub(crate) get_items_use_case: GetItemsUseCase<DatabaseRepositoryImpl<Item>>,
     pub(crate) get_item_use_case:
         GetItemUseCase<DatabaseRepositoryImpl<Item>, DatabaseRepositoryImpl<Auction>>,
+    pub(crate) update_item_use_case: UpdateItemUseCase<
+        DatabaseRepositoryImpl<Item>,
+        DatabaseRepositoryImpl<Auction>,
+        DatabaseRepositoryImpl<ItemImage>,
+        BlobStore,
+    >,
+    pub(crate) delete_item_use_case:
+        DeleteItemUseCase<DatabaseRepositoryImpl<Item>, DatabaseRepositoryImpl<Auction>>,
     pub(crate) create_auction_use_case:
         CreateAuctionUseCase<DatabaseRepositoryImpl<Auction>, DatabaseRepositoryImpl<Item>>,
     pub(crate) get_by_item_id: GetAuctionByItemIdUseCase<DatabaseRepositoryImpl<Auction>>,
-    pub(crate) get_auctions_use_case: GetAuctionsUseCase<DatabaseRepositoryImpl<Auction>>,
-    pub(crate) get_bids_use_case: GetBidsUseCase<DatabaseRepositoryImpl<Auction>>,
-    pub(crate) create_bid_use_case: CreateBidUseCase<DatabaseRepositoryImpl<Auction>>,
-    pub(crate) handle_expired_auctions_use_case:
-        HandleExpiredAuctionsUseCase<DatabaseRepositoryImpl<Auction>, DatabaseRepositoryImpl<Item>>,
+    pub(crate) get_auctions_use_case:
+        GetAuctionsUseCase<DatabaseRepositoryImpl<Auction>, DatabaseRepositoryImpl<Rating>>,
+    pub(crate) get_bids_use_case:
+        GetBidsUseCase<DatabaseRepositoryImpl<Auction>, DatabaseRepositoryImpl<Rating>>,
+    pub(crate) create_bid_use_case:
+        CreateBidUseCase<DatabaseRepositoryImpl<Auction>, PgAuctionEventBroadcaster>,
+    pub(crate) handle_expired_auctions_use_case: HandleExpiredAuctionsUseCase<
+        DatabaseRepositoryImpl<Auction>,
+        DatabaseRepositoryImpl<UserEvent>,
+        DatabaseRepositoryImpl<Job>,
+        PgAuctionEventBroadcaster,
+    >,
+    pub(crate) run_auction_expiry_scheduler_use_case: RunAuctionExpirySchedulerUseCase<
+        DatabaseRepositoryImpl<Auction>,
+        DatabaseRepositoryImpl<UserEvent>,
+        DatabaseRepositoryImpl<Job>,
+        PgAuctionEventBroadcaster,
+    >,
     pub(crate) confirm_auction_use_case:
-        ConfirmAuctionUseCase<DatabaseRepositoryImpl<Auction>, DatabaseRepositoryImpl<Item>>,
+        ConfirmAuctionUseCase<DatabaseRepositoryImpl<Auction>, PgAuctionEventBroadcaster>,
+    pub(crate) subscribe_to_auction_use_case:
+        SubscribeToAuctionUseCase<DatabaseRepositoryImpl<Auction>, PgAuctionEventBroadcaster>,
+    pub(crate) auction_event_broadcaster: Arc<PgAuctionEventBroadcaster>,
+    pub(crate) get_user_events_use_case: GetUserEventsUseCase<DatabaseRepositoryImpl<UserEvent>>,
+    pub(crate) user_event_notifier: Arc<PgUserEventNotifier>,
+    pub(crate) get_profile_use_case: GetProfileUseCase<DatabaseRepositoryImpl<UserProfile>>,
+    pub(crate) update_profile_use_case: UpdateProfileUseCase<DatabaseRepositoryImpl<UserProfile>>,
+    pub(crate) get_public_profile_use_case: GetPublicProfileUseCase<
+        DatabaseRepositoryImpl<User>,
+        DatabaseRepositoryImpl<UserProfile>,
+        DatabaseRepositoryImpl<Auction>,
+        DatabaseRepositoryImpl<Rating>,
+    >,
+    pub(crate) get_avatar_use_case:
+        GetAvatarUseCase<DatabaseRepositoryImpl<User>, DatabaseRepositoryImpl<UserProfile>>,
+    pub(crate) get_notifications_use_case:
+        GetNotificationsUseCase<DatabaseRepositoryImpl<Notification>>,
+    pub(crate) get_unread_notifications_count_use_case:
+        GetUnreadNotificationsCountUseCase<DatabaseRepositoryImpl<Notification>>,
+    pub(crate) mark_notification_read_use_case:
+        MarkNotificationReadUseCase<DatabaseRepositoryImpl<Notification>>,
+    pub(crate) mark_all_notifications_read_use_case:
+        MarkAllNotificationsReadUseCase<DatabaseRepositoryImpl<Notification>>,
+    pub(crate) run_jobs_use_case: RunJobsUseCase<DatabaseRepositoryImpl<Job>>,
+    pub(crate) create_webhook_use_case:
+        CreateWebhookUseCase<DatabaseRepositoryImpl<WebhookSubscription>>,
+    pub(crate) get_webhooks_use_case:
+        GetWebhooksUseCase<DatabaseRepositoryImpl<WebhookSubscription>>,
+    pub(crate) delete_webhook_use_case:
+        DeleteWebhookUseCase<DatabaseRepositoryImpl<WebhookSubscription>>,
+    pub(crate) get_webhook_deliveries_use_case:
+        GetWebhookDeliveriesUseCase<DatabaseRepositoryImpl<WebhookSubscription>>,
+    pub(crate) create_api_key_use_case: CreateApiKeyUseCase<DatabaseRepositoryImpl<ApiKey>>,
+    pub(crate) get_api_keys_use_case: GetApiKeysUseCase<DatabaseRepositoryImpl<ApiKey>>,
+    pub(crate) revoke_api_key_use_case: RevokeApiKeyUseCase<DatabaseRepositoryImpl<ApiKey>>,
+    pub(crate) authenticate_api_key_use_case:
+        AuthenticateApiKeyUseCase<DatabaseRepositoryImpl<ApiKey>, DatabaseRepositoryImpl<User>>,
+    pub(crate) rate_counterparty_use_case: RateCounterpartyUseCase<DatabaseRepositoryImpl<Rating>>,
+    pub(crate) get_user_ratings_use_case:
+        GetUserRatingsUseCase<DatabaseRepositoryImpl<User>, DatabaseRepositoryImpl<Rating>>,
+    pub(crate) export_account_use_case: ExportAccountUseCase<
+        DatabaseRepositoryImpl<AccountExport>,
+        DatabaseRepositoryImpl<ItemImage>,
+        BlobStore,
+    >,
+    pub(crate) delete_account_use_case: DeleteAccountUseCase<DatabaseRepositoryImpl<AccountExport>>,
+    pub(crate) get_users_use_case: GetUsersUseCase<DatabaseRepositoryImpl<User>>,
+    pub(crate) suspend_user_use_case:
+        SuspendUserUseCase<DatabaseRepositoryImpl<User>, DatabaseRepositoryImpl<Session>>,
+    pub(crate) force_close_auction_use_case:
+        ForceCloseAuctionUseCase<DatabaseRepositoryImpl<Auction>, PgAuctionEventBroadcaster>,
 }
 
 impl Modules {
-    pub fn new(db: PgPool) -> Self {
+    pub fn new(db: PgPool, config: &Constants) -> Self {
         let user_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
 
         let item_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
 
+        let item_image_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
         let auction_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
 
-        let register_use_case = RegisterUseCase::new(user_repository.clone());
+        let user_event_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let user_profile_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let rating_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let account_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let notification_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let job_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let webhook_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let session_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let email_verification_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let password_reset_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let login_throttle_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let audit_log_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let api_key_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let two_factor_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let external_identity_repository = Arc::new(DatabaseRepositoryImpl::new(db.clone()));
+
+        let auction_event_broadcaster = Arc::new(PgAuctionEventBroadcaster::new(db.clone()));
+
+        let user_event_notifier = Arc::new(PgUserEventNotifier::new(db.clone()));
+
+        let mailer = Arc::new(match &config.smtp {
+            Some(smtp) => Mailer::Smtp(
+                SmtpMailer::new(
+                    &smtp.host,
+                    smtp.username.clone(),
+                    smtp.password.clone(),
+                    &smtp.from,
+                )
+                .expect("Invalid SMTP configuration!"),
+            ),
+            None => Mailer::File(FileMailer::new(
+                config.mail_output_dir.clone().map(PathBuf::from),
+            )),
+        });
+
+        let blob_store = Arc::new(match &config.blob_store {
+            BlobStoreConstants::S3(s3) => BlobStore::S3(S3BlobStore::new(
+                s3.endpoint.clone(),
+                s3.bucket.clone(),
+                s3.region.clone(),
+                s3.access_key.clone(),
+                s3.secret_key.clone(),
+            )),
+            BlobStoreConstants::File(blob_dir) => {
+                BlobStore::File(FileBlobStore::new(PathBuf::from(blob_dir)))
+            }
+        });
+
+        let verification_link_signer = Arc::new(VerificationLinkSigner::new(
+            config.verification_link_key.clone(),
+            format!("{}/verify-email", config.allowed_origin),
+        ));
+
+        let register_use_case = RegisterUseCase::new(
+            user_repository.clone(),
+            email_verification_repository.clone(),
+            verification_link_signer.clone(),
+        );
+
+        let verify_email_use_case = VerifyEmailUseCase::new(
+            email_verification_repository.clone(),
+            verification_link_signer.clone(),
+        );
+
+        let resend_verification_email_use_case = ResendVerificationEmailUseCase::new(
+            email_verification_repository.clone(),
+            verification_link_signer,
+        );
+
+        let forgot_password_use_case = ForgotPasswordUseCase::new(
+            user_repository.clone(),
+            password_reset_repository.clone(),
+            format!("{}/reset-password", config.allowed_origin),
+        );
+
+        let reset_password_use_case = ResetPasswordUseCase::new(
+            password_reset_repository.clone(),
+            session_repository.clone(),
+        );
+
+        let change_password_use_case = ChangePasswordUseCase::new(user_repository.clone());
+
+        let login_use_case = LoginUseCase::new(
+            user_repository.clone(),
+            login_throttle_repository.clone(),
+            audit_log_repository.clone(),
+            two_factor_repository.clone(),
+        );
+
+        let enroll_two_factor_use_case = EnrollTwoFactorUseCase::new(two_factor_repository.clone());
 
-        let login_use_case = LoginUseCase::new(user_repository.clone());
+        let confirm_two_factor_use_case =
+            ConfirmTwoFactorUseCase::new(two_factor_repository.clone());
+
+        let disable_two_factor_use_case =
+            DisableTwoFactorUseCase::new(two_factor_repository.clone());
+
+        let verify_login_challenge_use_case = VerifyLoginChallengeUseCase::new(
+            two_factor_repository.clone(),
+            user_repository.clone(),
+            login_throttle_repository.clone(),
+            audit_log_repository.clone(),
+        );
+
+        let redirect_uri =
+            |provider: &str| format!("{}/oauth/{}/callback", config.allowed_origin, provider);
+        let mut identity_providers = vec![];
+        if let Some(google) = &config.google {
+            identity_providers.push(IdentityProvider::Oidc(OidcIdentityProvider::new(
+                "google".to_string(),
+                "https://accounts.google.com".to_string(),
+                google.client_id.clone(),
+                google.client_secret.clone(),
+                redirect_uri("google"),
+            )));
+        }
+        if let Some(github) = &config.github {
+            identity_providers.push(IdentityProvider::Github(GithubIdentityProvider::new(
+                github.client_id.clone(),
+                github.client_secret.clone(),
+                redirect_uri("github"),
+            )));
+        }
+        if let Some(oidc) = &config.oidc {
+            identity_providers.push(IdentityProvider::Oidc(OidcIdentityProvider::new(
+                oidc.name.clone(),
+                oidc.issuer.clone(),
+                oidc.client.client_id.clone(),
+                oidc.client.client_secret.clone(),
+                redirect_uri(&oidc.name),
+            )));
+        }
+        let identity_providers = Arc::new(identity_providers);
+
+        let start_external_login_use_case = StartExternalLoginUseCase::new(
+            external_identity_repository.clone(),
+            identity_providers.clone(),
+        );
+
+        let complete_external_login_use_case = CompleteExternalLoginUseCase::new(
+            external_identity_repository.clone(),
+            user_repository.clone(),
+            two_factor_repository.clone(),
+            identity_providers.clone(),
+        );
+
+        let link_external_identity_use_case = LinkExternalIdentityUseCase::new(
+            external_identity_repository.clone(),
+            identity_providers,
+        );
 
         let get_user_use_case = GetUserUseCase::new(user_repository.clone());
 
+        let create_session_use_case = CreateSessionUseCase::new(session_repository.clone());
+
+        let refresh_session_use_case =
+            RefreshSessionUseCase::new(session_repository.clone(), user_repository.clone());
+
+        let logout_use_case = LogoutUseCase::new(session_repository.clone());
+
+        let check_session_use_case = CheckSessionUseCase::new(session_repository.clone());
+
+        let get_sessions_use_case = GetSessionsUseCase::new(session_repository.clone());
+
+        let revoke_session_use_case = RevokeSessionUseCase::new(session_repository.clone());
+
+        let revoke_other_sessions_use_case =
+            RevokeOtherSessionsUseCase::new(session_repository.clone());
+
         let get_items_use_case = GetItemsUseCase::new(item_repository.clone());
 
-        let create_item_use_case = CreateItemUseCase::new(item_repository.clone());
+        let create_item_use_case =
+            CreateItemUseCase::new(item_repository.clone(), blob_store.clone());
 
-        let get_item_image_use_case =
-            GetItemImageUseCase::new(item_repository.clone(), auction_repository.clone());
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            item_repository.clone(),
+            auction_repository.clone(),
+            item_image_repository.clone(),
+            blob_store.clone(),
+        );
+
+        let add_item_image_use_case = AddItemImageUseCase::new(
+            item_repository.clone(),
+            auction_repository.clone(),
+            item_image_repository.clone(),
+            blob_store.clone(),
+        );
+
+        let delete_item_image_use_case = DeleteItemImageUseCase::new(
+            item_repository.clone(),
+            auction_repository.clone(),
+            item_image_repository.clone(),
+            blob_store.clone(),
+        );
+
+        let reorder_item_images_use_case = ReorderItemImagesUseCase::new(
+            item_repository.clone(),
+            auction_repository.clone(),
+            item_image_repository.clone(),
+        );
 
         let get_item_use_case =
             GetItemUseCase::new(item_repository.clone(), auction_repository.clone());
 
-        let create_auction_use_case =
-            CreateAuctionUseCase::new(auction_repository.clone(), item_repository.clone());
+        let update_item_use_case = UpdateItemUseCase::new(
+            item_repository.clone(),
+            auction_repository.clone(),
+            item_image_repository.clone(),
+            blob_store.clone(),
+        );
+
+        let delete_item_use_case =
+            DeleteItemUseCase::new(item_repository.clone(), auction_repository.clone());
+
+        let auction_expiry_scheduler = Arc::new(AuctionExpiryScheduler::new());
+
+        let create_auction_use_case = CreateAuctionUseCase::new(
+            auction_repository.clone(),
+            item_repository.clone(),
+            auction_expiry_scheduler.clone(),
+        );
 
         let get_by_item_id = GetAuctionByItemIdUseCase::new(auction_repository.clone());
 
-        let get_auctions_use_case = GetAuctionsUseCase::new(auction_repository.clone());
+        let get_auctions_use_case =
+            GetAuctionsUseCase::new(auction_repository.clone(), rating_repository.clone());
 
-        let get_bids_use_case = GetBidsUseCase::new(auction_repository.clone());
+        let get_bids_use_case =
+            GetBidsUseCase::new(auction_repository.clone(), rating_repository.clone());
 
-        let create_bid_use_case = CreateBidUseCase::new(auction_repository.clone());
+        let create_bid_use_case = CreateBidUseCase::new(
+            auction_repository.clone(),
+            auction_event_broadcaster.clone(),
+        );
 
         let handle_expired_auction_use_case = Arc::new(HandleExpiredAuctionUseCase::new(
             auction_repository.clone(),
-            item_repository.clone(),
+            user_event_repository.clone(),
+            job_repository.clone(),
+            auction_event_broadcaster.clone(),
         ));
 
         let handle_expired_auctions_use_case = HandleExpiredAuctionsUseCase::new(
@@ -90,33 +547,227 @@ impl Modules {
             handle_expired_auction_use_case.clone(),
         );
 
-        let confirm_auction_use_case =
-            ConfirmAuctionUseCase::new(auction_repository.clone(), item_repository.clone());
+        let run_auction_expiry_scheduler_use_case = RunAuctionExpirySchedulerUseCase::new(
+            auction_repository.clone(),
+            auction_expiry_scheduler,
+            handle_expired_auction_use_case.clone(),
+        );
+
+        let confirm_auction_use_case = ConfirmAuctionUseCase::new(
+            auction_repository.clone(),
+            auction_event_broadcaster.clone(),
+        );
+
+        let get_user_events_use_case = GetUserEventsUseCase::new(user_event_repository.clone());
+
+        let get_profile_use_case = GetProfileUseCase::new(user_profile_repository.clone());
+
+        let update_profile_use_case = UpdateProfileUseCase::new(user_profile_repository.clone());
+
+        let get_public_profile_use_case = GetPublicProfileUseCase::new(
+            user_repository.clone(),
+            user_profile_repository.clone(),
+            auction_repository.clone(),
+            rating_repository.clone(),
+        );
+
+        let get_avatar_use_case =
+            GetAvatarUseCase::new(user_repository.clone(), user_profile_repository.clone());
+
+        let get_notifications_use_case =
+            GetNotificationsUseCase::new(notification_repository.clone());
+
+        let get_unread_notifications_count_use_case =
+            GetUnreadNotificationsCountUseCase::new(notification_repository.clone());
+
+        let mark_notification_read_use_case =
+            MarkNotificationReadUseCase::new(notification_repository.clone());
+
+        let mark_all_notifications_read_use_case =
+            MarkAllNotificationsReadUseCase::new(notification_repository.clone());
+
+        let job_handler_registry = Arc::new(
+            JobHandlerRegistry::new()
+                .register(SendEmailJobHandler::new(user_repository.clone(), mailer))
+                .register(DispatchWebhookEventJobHandler::new(
+                    webhook_repository.clone(),
+                    job_repository.clone(),
+                ))
+                .register(DeliverWebhookJobHandler::new(
+                    webhook_repository.clone(),
+                    Arc::new(HttpWebhookSender::new()),
+                )),
+        );
+
+        let run_jobs_use_case = RunJobsUseCase::new(job_repository.clone(), job_handler_registry);
+
+        let create_webhook_use_case = CreateWebhookUseCase::new(webhook_repository.clone());
+
+        let get_webhooks_use_case = GetWebhooksUseCase::new(webhook_repository.clone());
+
+        let delete_webhook_use_case = DeleteWebhookUseCase::new(webhook_repository.clone());
+
+        let get_webhook_deliveries_use_case =
+            GetWebhookDeliveriesUseCase::new(webhook_repository.clone());
+
+        let create_api_key_use_case = CreateApiKeyUseCase::new(api_key_repository.clone());
+
+        let get_api_keys_use_case = GetApiKeysUseCase::new(api_key_repository.clone());
+
+        let revoke_api_key_use_case = RevokeApiKeyUseCase::new(api_key_repository.clone());
+
+        let authenticate_api_key_use_case =
+            AuthenticateApiKeyUseCase::new(api_key_repository.clone(), user_repository.clone());
+
+        let rate_counterparty_use_case = RateCounterpartyUseCase::new(rating_repository.clone());
+
+        let get_user_ratings_use_case =
+            GetUserRatingsUseCase::new(user_repository.clone(), rating_repository.clone());
+
+        let export_account_use_case = ExportAccountUseCase::new(
+            account_repository.clone(),
+            item_image_repository.clone(),
+            blob_store.clone(),
+        );
+
+        let delete_account_use_case = DeleteAccountUseCase::new(account_repository.clone());
+
+        let get_users_use_case = GetUsersUseCase::new(user_repository.clone());
+
+        let suspend_user_use_case =
+            SuspendUserUseCase::new(user_repository.clone(), session_repository.clone());
+
+        let force_close_auction_use_case = ForceCloseAuctionUseCase::new(
+            auction_repository.clone(),
+            auction_event_broadcaster.clone(),
+        );
+
+        let subscribe_to_auction_use_case = SubscribeToAuctionUseCase::new(
+            auction_repository.clone(),
+            auction_event_broadcaster.clone(),
+        );
 
         Self {
             register_use_case,
+            verify_email_use_case,
+            resend_verification_email_use_case,
+            forgot_password_use_case,
+            reset_password_use_case,
+            change_password_use_case,
             login_use_case,
+            enroll_two_factor_use_case,
+            confirm_two_factor_use_case,
+            disable_two_factor_use_case,
+            verify_login_challenge_use_case,
+            start_external_login_use_case,
+            complete_external_login_use_case,
+            link_external_identity_use_case,
             get_user_use_case,
+            create_session_use_case,
+            refresh_session_use_case,
+            logout_use_case,
+            check_session_use_case,
+            get_sessions_use_case,
+            revoke_session_use_case,
+            revoke_other_sessions_use_case,
             get_items_use_case,
             create_item_use_case,
             get_item_image_use_case,
+            add_item_image_use_case,
+            delete_item_image_use_case,
+            reorder_item_images_use_case,
             get_item_use_case,
+            update_item_use_case,
+            delete_item_use_case,
             create_auction_use_case,
             get_by_item_id,
             get_auctions_use_case,
             get_bids_use_case,
             create_bid_use_case,
             handle_expired_auctions_use_case,
+            run_auction_expiry_scheduler_use_case,
             confirm_auction_use_case,
+            subscribe_to_auction_use_case,
+            auction_event_broadcaster,
+            get_user_events_use_case,
+            user_event_notifier,
+            get_profile_use_case,
+            update_profile_use_case,
+            get_public_profile_use_case,
+            get_avatar_use_case,
+            get_notifications_use_case,
+            get_unread_notifications_count_use_case,
+            mark_notification_read_use_case,
+            mark_all_notifications_read_use_case,
+            run_jobs_use_case,
+            create_webhook_use_case,
+            get_webhooks_use_case,
+            delete_webhook_use_case,
+            get_webhook_deliveries_use_case,
+            create_api_key_use_case,
+            get_api_keys_use_case,
+            revoke_api_key_use_case,
+            authenticate_api_key_use_case,
+            rate_counterparty_use_case,
+            get_user_ratings_use_case,
+            export_account_use_case,
+            delete_account_use_case,
+            get_users_use_case,
+            suspend_user_use_case,
+            force_close_auction_use_case,
         }
     }
 }
 
+pub struct SmtpConstants {
+    pub host: String,
+    pub username: String,
+    pub password: String,
+    pub from: String,
+}
+
+pub struct S3Constants {
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+pub enum BlobStoreConstants {
+    S3(S3Constants),
+    /// Only meant for development, files below the directory are lost with the container.
+    File(String),
+}
+
+pub struct OAuthClientConstants {
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+pub struct OidcConstants {
+    pub name: String,
+    pub issuer: String,
+    pub client: OAuthClientConstants,
+}
+
 pub struct Constants {
     pub jwt_key: String,
+    /// Signs the links in verification emails, kept apart from `jwt_key` so either can be rotated
+    /// on its own.
+    pub verification_link_key: String,
     pub allowed_origin: String,
     pub jwt_duration: String,
     pub finalize_auctions_cron: String,
+    pub run_jobs_cron: String,
+    /// How many proxies in front of the API append to `X-Forwarded-For`.
+    pub trusted_proxies: usize,
+    pub smtp: Option<SmtpConstants>,
+    pub mail_output_dir: Option<String>,
+    pub blob_store: BlobStoreConstants,
+    pub google: Option<OAuthClientConstants>,
+    pub github: Option<OAuthClientConstants>,
+    pub oidc: Option<OidcConstants>,
 }
 
 impl Constants {
@@ -125,6 +776,10 @@ impl Constants {
             .get("JWT_KEY")
             .expect("You need to set your JWT_KEY secret!");
 
+        let verification_link_key = secrets
+            .get("VERIFICATION_LINK_KEY")
+            .expect("You need to set your VERIFICATION_LINK_KEY secret!");
+
         let jwt_duration = secrets
             .get("JWT_DURATION_MINUTES")
             .expect("You need to set you JWT_DURATION_MINUTES secret!");
@@ -137,11 +792,110 @@ impl Constants {
             .get("FINALIZE_AUCTIONS_CRON")
             .expect("You need to set your FINALIZE_AUCTIONS_CRON secret!");
 
+        let run_jobs_cron = secrets
+            .get("RUN_JOBS_CRON")
+            .expect("You need to set your RUN_JOBS_CRON secret!");
+
+        // Shuttle puts a single proxy in front of the API
+        let trusted_proxies = secrets
+            .get("TRUSTED_PROXIES")
+            .map(|count| {
+                count
+                    .parse::<usize>()
+                    .expect("TRUSTED_PROXIES must be a number!")
+            })
+            .unwrap_or(1);
+
+        // without an SMTP host emails are written by the file mailer instead
+        let smtp = secrets
+            .get("SMTP_HOST")
+            .filter(|host| !host.is_empty())
+            .map(|host| SmtpConstants {
+                host,
+                username: secrets
+                    .get("SMTP_USERNAME")
+                    .expect("You need to set your SMTP_USERNAME secret!"),
+                password: secrets
+                    .get("SMTP_PASSWORD")
+                    .expect("You need to set your SMTP_PASSWORD secret!"),
+                from: secrets
+                    .get("MAIL_FROM")
+                    .expect("You need to set your MAIL_FROM secret!"),
+            });
+
+        let mail_output_dir = secrets.get("MAIL_OUTPUT_DIR");
+
+        // writing blobs to the local disk has to be asked for, a deployment missing its S3
+        // secrets must not start and lose every upload with the container
+        let blob_store = match secrets
+            .get("S3_ENDPOINT")
+            .filter(|endpoint| !endpoint.is_empty())
+        {
+            Some(endpoint) => BlobStoreConstants::S3(S3Constants {
+                endpoint,
+                bucket: secrets
+                    .get("S3_BUCKET")
+                    .expect("You need to set your S3_BUCKET secret!"),
+                // empty when the deploy workflow has no such secret
+                region: secrets
+                    .get("S3_REGION")
+                    .filter(|region| !region.is_empty())
+                    .unwrap_or("us-east-1".to_string()),
+                access_key: secrets
+                    .get("S3_ACCESS_KEY")
+                    .expect("You need to set your S3_ACCESS_KEY secret!"),
+                secret_key: secrets
+                    .get("S3_SECRET_KEY")
+                    .expect("You need to set your S3_SECRET_KEY secret!"),
+            }),
+            None => BlobStoreConstants::File(
+                secrets
+                    .get("BLOB_DIR")
+                    .filter(|blob_dir| !blob_dir.is_empty())
+                    .expect("You need to set your S3_ENDPOINT or BLOB_DIR secret!"),
+            ),
+        };
+
+        // an identity provider is enabled by setting its client id
+        let oauth_client = |prefix: &str| {
+            secrets
+                .get(&format!("{}_CLIENT_ID", prefix))
+                .filter(|client_id| !client_id.is_empty())
+                .map(|client_id| OAuthClientConstants {
+                    client_id,
+                    client_secret: secrets
+                        .get(&format!("{}_CLIENT_SECRET", prefix))
+                        .unwrap_or_else(|| {
+                            panic!("You need to set your {}_CLIENT_SECRET secret!", prefix)
+                        }),
+                })
+        };
+        let google = oauth_client("GOOGLE");
+        let github = oauth_client("GITHUB");
+        let oidc = oauth_client("OIDC").map(|client| OidcConstants {
+            name: secrets
+                .get("OIDC_PROVIDER_NAME")
+                .expect("You need to set your OIDC_PROVIDER_NAME secret!"),
+            issuer: secrets
+                .get("OIDC_ISSUER")
+                .expect("You need to set your OIDC_ISSUER secret!"),
+            client,
+        });
+
         Self {
             jwt_key,
+            verification_link_key,
             allowed_origin,
             jwt_duration,
             finalize_auctions_cron,
+            run_jobs_cron,
+            trusted_proxies,
+            smtp,
+            mail_output_dir,
+            blob_store,
+            google,
+            github,
+            oidc,
         }
     }
 }
@@ -154,8 +908,8 @@ pub struct AppState {
 
 impl AppState {
     pub fn new(db: PgPool, secrets: SecretStore) -> Self {
-        let modules = Arc::new(Modules::new(db));
         let config = Arc::new(Constants::new(secrets));
+        let modules = Arc::new(Modules::new(db, &config));
 
         Self { modules, config }
     }
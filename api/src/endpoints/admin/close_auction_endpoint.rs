@@ -0,0 +1,28 @@
+use crate::di::AppState;
+use application::use_cases::admin::force_close_auction_use_case::dtos::CloseAuctionRequest;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use http::StatusCode;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Path(id): Path<String>,
+    Valid(Json(request)): Valid<Json<CloseAuctionRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .force_close_auction_use_case
+        .execute(current_user, id, request)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to close auction: {:?}", e);
+            e
+        })
+}
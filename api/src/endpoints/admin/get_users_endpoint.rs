@@ -0,0 +1,17 @@
+use crate::di::AppState;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use domain::app_error::AppError;
+use tracing::error;
+
+pub async fn handle(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .get_users_use_case
+        .execute()
+        .await
+        .map_err(|e| {
+            error!("Failed to get users: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,3 @@
+pub(crate) mod close_auction_endpoint;
+pub(crate) mod get_users_endpoint;
+pub(crate) mod suspend_user_endpoint;
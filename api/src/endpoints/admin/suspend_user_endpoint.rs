@@ -0,0 +1,25 @@
+use crate::di::AppState;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use http::StatusCode;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .suspend_user_use_case
+        .execute(current_user, id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to suspend user: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,27 @@
+use crate::di::AppState;
+use application::use_cases::api_keys::create_api_key_use_case::dtos::CreateApiKeyRequest;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use http::StatusCode;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Valid(Json(request)): Valid<Json<CreateApiKeyRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .create_api_key_use_case
+        .execute(current_user, request)
+        .await
+        .map(|api_key| (StatusCode::CREATED, api_key).into_response())
+        .map_err(|e| {
+            error!("Failed to create API key: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,3 @@
+pub(crate) mod create_endpoint;
+pub(crate) mod get_all_endpoint;
+pub(crate) mod revoke_endpoint;
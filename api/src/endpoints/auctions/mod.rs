@@ -2,6 +2,8 @@ pub(crate) mod confirm_endpoint;
 pub(crate) mod create_endpoint;
 pub(crate) mod get_all_endpoint;
 pub(crate) mod get_by_item_id_endpoint;
+pub(crate) mod rate_endpoint;
+pub(crate) mod subscribe_endpoint;
 
 pub(crate) mod bids {
     pub(crate) mod create_endpoint;
@@ -0,0 +1,28 @@
+use crate::di::AppState;
+use application::use_cases::ratings::rate_counterparty_use_case::dtos::RateCounterpartyRequest;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Path(auction_id): Path<String>,
+    Valid(Json(request)): Valid<Json<RateCounterpartyRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .rate_counterparty_use_case
+        .execute(current_user, auction_id, request)
+        .await
+        .map(|_| StatusCode::CREATED.into_response())
+        .map_err(|e| {
+            error!("Failed to rate counterparty: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,65 @@
+use crate::di::AppState;
+use crate::middleware::auth_middleware::TOKEN_PROTOCOL;
+use application::broadcasters::i_auction_event_broadcaster::dtos::AuctionEventDto;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use domain::app_error::AppError;
+use domain::entities::auction_event::AuctionEvent;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::broadcast::Receiver;
+use tracing::{error, info, warn};
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Path(auction_id): Path<String>,
+    ws: WebSocketUpgrade,
+) -> Result<impl IntoResponse, AppError> {
+    let receiver = state
+        .modules
+        .subscribe_to_auction_use_case
+        .execute(auction_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to subscribe to auction: {:?}", e);
+            e
+        })?;
+
+    // browsers drop the connection unless one of the subprotocols they offered is picked
+    Ok(ws
+        .protocols([TOKEN_PROTOCOL])
+        .on_upgrade(move |socket| forward_events(socket, receiver)))
+}
+
+async fn forward_events(mut socket: WebSocket, mut receiver: Receiver<AuctionEvent>) {
+    loop {
+        tokio::select! {
+            event = receiver.recv() => match event {
+                Ok(event) => {
+                    let payload = match serde_json::to_string(&AuctionEventDto::from(event)) {
+                        Ok(payload) => payload,
+                        Err(e) => {
+                            error!("Failed to serialize auction event: {:?}", e);
+                            continue;
+                        }
+                    };
+
+                    if socket.send(Message::Text(payload)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Auction subscriber lagged behind by {} events", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                _ => {}
+            },
+        }
+    }
+
+    info!("Auction subscriber disconnected.");
+    let _ = socket.send(Message::Close(None)).await;
+}
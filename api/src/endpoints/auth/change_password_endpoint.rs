@@ -0,0 +1,27 @@
+use crate::di::AppState;
+use application::use_cases::user::change_password_use_case::dtos::ChangePasswordRequest;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Valid(Json(request)): Valid<Json<ChangePasswordRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .change_password_use_case
+        .execute(user, request)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to change password: {:?}", e);
+            e
+        })
+}
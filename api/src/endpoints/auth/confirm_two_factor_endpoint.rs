@@ -0,0 +1,25 @@
+use crate::di::AppState;
+use application::use_cases::two_factor::confirm_two_factor_use_case::dtos::ConfirmTwoFactorRequest;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Valid(Json(request)): Valid<Json<ConfirmTwoFactorRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .confirm_two_factor_use_case
+        .execute(user, request)
+        .await
+        .map_err(|e| {
+            error!("Failed to confirm two-factor: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,23 @@
+use crate::di::AppState;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(provider): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .start_external_login_use_case
+        .execute(provider, Some(user))
+        .await
+        .map_err(|e| {
+            error!("Failed to start linking external identity: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,28 @@
+use crate::di::AppState;
+use application::use_cases::external_identities::start_external_login_use_case::dtos::ExternalCallbackRequest;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(provider): Path<String>,
+    Valid(Json(request)): Valid<Json<ExternalCallbackRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .link_external_identity_use_case
+        .execute(user, provider, request)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to link external identity: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,20 @@
+use crate::di::AppState;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use domain::app_error::AppError;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .start_external_login_use_case
+        .execute(provider, None)
+        .await
+        .map_err(|e| {
+            error!("Failed to start external login: {:?}", e);
+            e
+        })
+}
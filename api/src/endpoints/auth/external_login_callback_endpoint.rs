@@ -0,0 +1,26 @@
+use crate::client_info::client_info;
+use crate::di::AppState;
+use crate::endpoints::auth::tokens::login_response;
+use application::use_cases::external_identities::start_external_login_use_case::dtos::ExternalCallbackRequest;
+use axum::extract::{Path, State};
+use axum::response::Response;
+use axum::Json;
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use http::HeaderMap;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    headers: HeaderMap,
+    Valid(Json(request)): Valid<Json<ExternalCallbackRequest>>,
+) -> Result<Response, AppError> {
+    let client = client_info(&headers, state.config.trusted_proxies);
+    let outcome = state
+        .modules
+        .complete_external_login_use_case
+        .execute(provider, request)
+        .await?;
+
+    login_response(&state, outcome, client).await
+}
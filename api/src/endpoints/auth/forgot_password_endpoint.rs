@@ -0,0 +1,25 @@
+use crate::di::AppState;
+use application::use_cases::user::forgot_password_use_case::dtos::ForgotPasswordRequest;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Valid(Json(request)): Valid<Json<ForgotPasswordRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .forgot_password_use_case
+        .execute(request)
+        .await
+        .map(|_| StatusCode::ACCEPTED)
+        .map_err(|e| {
+            error!("Failed to issue password reset token: {:?}", e);
+            e
+        })
+}
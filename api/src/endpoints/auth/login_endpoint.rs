@@ -1,50 +1,25 @@
+use crate::client_info::client_info;
 use crate::di::AppState;
+use crate::endpoints::auth::tokens::login_response;
 use application::use_cases::user::login_use_case::dtos::LoginRequest;
 use axum::extract::State;
-use axum::response::{IntoResponse, Response};
+use axum::response::Response;
 use axum::Json;
 use axum_valid::Valid;
-use chrono::{Duration, Utc};
 use domain::app_error::AppError;
-use domain::entities::token_claims::TokenClaims;
-use http::HeaderValue;
-use jsonwebtoken::{encode, EncodingKey, Header};
-use shuttle_runtime::__internals::serde_json::json;
+use http::HeaderMap;
 
 pub async fn handle(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Valid(Json(request)): Valid<Json<LoginRequest>>,
-) -> Result<impl IntoResponse, AppError> {
-    let response = state
+) -> Result<Response, AppError> {
+    let client = client_info(&headers, state.config.trusted_proxies);
+    let outcome = state
         .modules
         .login_use_case
-        .execute(request)
-        .await
-        .map(|user| {
-            let exp = (Utc::now() + Duration::minutes(state.config.jwt_duration.parse().unwrap()))
-                .timestamp() as usize;
-            let claims: TokenClaims = TokenClaims {
-                sub: user.id.to_string(),
-                username: user.name,
-                exp,
-            };
+        .execute(request, client.ip_address.clone())
+        .await?;
 
-            let token = encode(
-                &Header::default(),
-                &claims,
-                &EncodingKey::from_secret(state.config.jwt_key.as_ref()),
-            )
-            .map_err(|_| AppError::InvalidJwt())?;
-
-            let mut response = Response::new(json!({}).to_string());
-            response.headers_mut().insert(
-                "Authorization",
-                HeaderValue::from_str(format!("Bearer {}", &token).as_str())
-                    .map_err(|_| AppError::InvalidJwt())?,
-            );
-
-            Ok(response)
-        })?;
-
-    response
+    login_response(&state, outcome, client).await
 }
@@ -0,0 +1,24 @@
+use crate::di::AppState;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::session::Session;
+use http::StatusCode;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(session): Extension<Session>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .logout_use_case
+        .execute(session)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to log out: {:?}", e);
+            e
+        })
+}
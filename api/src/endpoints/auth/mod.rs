@@ -1,2 +1,18 @@
+pub(crate) mod change_password_endpoint;
+pub(crate) mod confirm_two_factor_endpoint;
+pub(crate) mod disable_two_factor_endpoint;
+pub(crate) mod enroll_two_factor_endpoint;
+pub(crate) mod external_link_authorize_endpoint;
+pub(crate) mod external_link_callback_endpoint;
+pub(crate) mod external_login_authorize_endpoint;
+pub(crate) mod external_login_callback_endpoint;
+pub(crate) mod forgot_password_endpoint;
 pub(crate) mod login_endpoint;
+pub(crate) mod logout_endpoint;
+pub(crate) mod refresh_endpoint;
 pub(crate) mod register_endpoint;
+pub(crate) mod resend_verification_email_endpoint;
+pub(crate) mod reset_password_endpoint;
+mod tokens;
+pub(crate) mod verify_email_endpoint;
+pub(crate) mod verify_login_challenge_endpoint;
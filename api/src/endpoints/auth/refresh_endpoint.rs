@@ -0,0 +1,20 @@
+use crate::di::AppState;
+use crate::endpoints::auth::tokens::session_response;
+use application::use_cases::sessions::refresh_session_use_case::dtos::RefreshSessionRequest;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+use domain::app_error::AppError;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Json(request): Json<RefreshSessionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let (user, session) = state
+        .modules
+        .refresh_session_use_case
+        .execute(request)
+        .await?;
+
+    session_response(&state.config, user, session)
+}
@@ -0,0 +1,24 @@
+use crate::di::AppState;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use http::StatusCode;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .resend_verification_email_use_case
+        .execute(user)
+        .await
+        .map(|_| StatusCode::ACCEPTED)
+        .map_err(|e| {
+            error!("Failed to resend verification email: {:?}", e);
+            e
+        })
+}
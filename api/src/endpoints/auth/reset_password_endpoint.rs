@@ -0,0 +1,25 @@
+use crate::di::AppState;
+use application::use_cases::user::reset_password_use_case::dtos::ResetPasswordRequest;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Valid(Json(request)): Valid<Json<ResetPasswordRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .reset_password_use_case
+        .execute(request)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to reset password: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,67 @@
+use crate::di::{AppState, Constants};
+use application::use_cases::sessions::create_session_use_case::dtos::{ClientInfo, IssuedSession};
+use application::use_cases::user::login_use_case::dtos::LoginOutcome;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{Duration, Utc};
+use domain::app_error::AppError;
+use domain::entities::token_claims::TokenClaims;
+use domain::entities::user::User;
+use http::HeaderValue;
+use jsonwebtoken::{encode, EncodingKey, Header};
+use shuttle_runtime::__internals::serde_json::json;
+
+/// Returns the access token in the `Authorization` header and the refresh token in the body.
+pub(crate) fn session_response(
+    config: &Constants,
+    user: User,
+    session: IssuedSession,
+) -> Result<Response, AppError> {
+    let exp =
+        (Utc::now() + Duration::minutes(config.jwt_duration.parse().unwrap())).timestamp() as usize;
+    let claims: TokenClaims = TokenClaims {
+        sub: user.id.to_string(),
+        username: user.name,
+        sid: session.session_id,
+        role: user.role,
+        exp,
+    };
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(config.jwt_key.as_ref()),
+    )
+    .map_err(|_| AppError::InvalidJwt())?;
+
+    let mut response = Response::new(json!({ "refresh_token": session.refresh_token }).to_string());
+    response.headers_mut().insert(
+        "Authorization",
+        HeaderValue::from_str(format!("Bearer {}", &token).as_str())
+            .map_err(|_| AppError::InvalidJwt())?,
+    );
+
+    Ok(response)
+}
+
+/// Starts a session for a signed in user, or answers `202 Accepted` with a challenge token when
+/// the user still has to give their second factor, see `verify_login_challenge_endpoint`.
+pub(crate) async fn login_response(
+    state: &AppState,
+    outcome: LoginOutcome,
+    client: ClientInfo,
+) -> Result<Response, AppError> {
+    let user = match outcome {
+        LoginOutcome::SignedIn(user) => user,
+        LoginOutcome::ChallengeRequired(challenge) => {
+            return Ok((StatusCode::ACCEPTED, challenge).into_response());
+        }
+    };
+    let session = state
+        .modules
+        .create_session_use_case
+        .execute(&user, client)
+        .await?;
+
+    session_response(&state.config, user, session)
+}
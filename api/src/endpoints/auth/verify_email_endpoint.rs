@@ -0,0 +1,24 @@
+use crate::di::AppState;
+use application::use_cases::user::verify_email_use_case::dtos::VerifyEmailRequest;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+use domain::app_error::AppError;
+use http::StatusCode;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Json(request): Json<VerifyEmailRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .verify_email_use_case
+        .execute(request)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to verify email: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,30 @@
+use crate::client_info::client_info;
+use crate::di::AppState;
+use crate::endpoints::auth::tokens::session_response;
+use application::use_cases::two_factor::verify_login_challenge_use_case::dtos::VerifyLoginChallengeRequest;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use http::HeaderMap;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Valid(Json(request)): Valid<Json<VerifyLoginChallengeRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    let client = client_info(&headers, state.config.trusted_proxies);
+    let user = state
+        .modules
+        .verify_login_challenge_use_case
+        .execute(request, client.ip_address.clone())
+        .await?;
+    let session = state
+        .modules
+        .create_session_use_case
+        .execute(&user, client)
+        .await?;
+
+    session_response(&state.config, user, session)
+}
@@ -0,0 +1,26 @@
+use crate::di::AppState;
+use application::use_cases::items::add_item_image_use_case::dtos::AddItemImageRequest;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Extension;
+use axum_typed_multipart::TypedMultipart;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Path(id): Path<String>,
+    TypedMultipart(request): TypedMultipart<AddItemImageRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .add_item_image_use_case
+        .execute(current_user, id, request)
+        .await
+        .map_err(|e| {
+            error!("Failed to add item image: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,33 @@
+use crate::di::AppState;
+use application::use_cases::items::get_item_image_use_case::dtos::GetItemImageRequest;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap};
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Path((id, position)): Path<(String, i32)>,
+    Query(request): Query<GetItemImageRequest>,
+    headers: HeaderMap,
+    Extension(current_user): Extension<User>,
+) -> Result<impl IntoResponse, AppError> {
+    let if_none_match = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    state
+        .modules
+        .get_item_image_use_case
+        .execute(current_user, id, position, request, if_none_match)
+        .await
+        .map(|image| image.into_response())
+        .map_err(|e| {
+            error!("Failed to get item image: {:?}", e);
+            e
+        })
+}
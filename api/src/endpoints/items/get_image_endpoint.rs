@@ -1,6 +1,7 @@
 use crate::di::AppState;
-use axum::body::Bytes;
-use axum::extract::{Path, State};
+use application::use_cases::items::get_item_image_use_case::dtos::GetItemImageRequest;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap};
 use axum::response::IntoResponse;
 use axum::Extension;
 use domain::app_error::AppError;
@@ -9,18 +10,21 @@ use domain::entities::user::User;
 pub async fn handle(
     State(state): State<AppState>,
     Path(id): Path<String>,
+    Query(request): Query<GetItemImageRequest>,
+    headers: HeaderMap,
     Extension(current_user): Extension<User>,
 ) -> Result<impl IntoResponse, AppError> {
+    let if_none_match = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
     let response = state
         .modules
         .get_item_image_use_case
-        .execute(current_user, id)
+        .execute(current_user, id, 0, request, if_none_match)
         .await
-        .map(|picture_data| {
-            let picture_data: Bytes = picture_data.into();
-
-            Ok(([("content-type", "image/png")], picture_data).into_response())
-        })?;
+        .map(|image| Ok(image.into_response()))?;
 
     response
 }
@@ -1,4 +1,10 @@
+pub(crate) mod add_image_endpoint;
 pub(crate) mod create_endpoint;
+pub(crate) mod delete_endpoint;
+pub(crate) mod delete_image_endpoint;
+pub(crate) mod get_gallery_image_endpoint;
 pub(crate) mod get_image_endpoint;
 pub(crate) mod get_item_endpoint;
 pub(crate) mod get_items_endpoint;
+pub(crate) mod reorder_images_endpoint;
+pub(crate) mod update_endpoint;
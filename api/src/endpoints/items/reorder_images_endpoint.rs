@@ -0,0 +1,28 @@
+use crate::di::AppState;
+use application::use_cases::items::reorder_item_images_use_case::dtos::ReorderItemImagesRequest;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Path(id): Path<String>,
+    Valid(Json(request)): Valid<Json<ReorderItemImagesRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .reorder_item_images_use_case
+        .execute(current_user, id, request)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to reorder item images: {:?}", e);
+            e
+        })
+}
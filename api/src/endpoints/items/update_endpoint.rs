@@ -0,0 +1,29 @@
+use crate::di::AppState;
+use application::use_cases::items::update_item_use_case::dtos::UpdateItemRequest;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Extension;
+use axum_typed_multipart::TypedMultipart;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+use validator::Validate;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Path(id): Path<String>,
+    TypedMultipart(request): TypedMultipart<UpdateItemRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    request.validate()?;
+
+    state
+        .modules
+        .update_item_use_case
+        .execute(current_user, id, request)
+        .await
+        .map_err(|e| {
+            error!("Failed to update item: {:?}", e);
+            e
+        })
+}
@@ -1,8 +1,14 @@
 use serde::Deserialize;
 
+pub(crate) mod admin;
+pub(crate) mod api_keys;
 pub(crate) mod auctions;
 pub(crate) mod auth;
 pub(crate) mod items;
+pub(crate) mod notifications;
+pub(crate) mod sessions;
+pub(crate) mod users;
+pub(crate) mod webhooks;
 
 #[derive(Deserialize)]
 pub struct QueryFilterParamDto {
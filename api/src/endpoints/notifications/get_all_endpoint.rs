@@ -0,0 +1,25 @@
+use crate::di::AppState;
+use application::use_cases::notifications::get_notifications_use_case::dtos::GetNotificationsRequest;
+use axum::extract::{Query, State};
+use axum::response::IntoResponse;
+use axum::Extension;
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Valid(Query(request)): Valid<Query<GetNotificationsRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .get_notifications_use_case
+        .execute(current_user, request)
+        .await
+        .map_err(|e| {
+            error!("Failed to get notifications: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,24 @@
+use crate::di::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .mark_all_notifications_read_use_case
+        .execute(current_user)
+        .await
+        .map(|_| StatusCode::OK.into_response())
+        .map_err(|e| {
+            error!("Failed to mark notifications as read: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,25 @@
+use crate::di::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .mark_notification_read_use_case
+        .execute(current_user, id)
+        .await
+        .map(|_| StatusCode::OK.into_response())
+        .map_err(|e| {
+            error!("Failed to mark notification as read: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,4 @@
+pub(crate) mod get_all_endpoint;
+pub(crate) mod mark_all_read_endpoint;
+pub(crate) mod mark_read_endpoint;
+pub(crate) mod unread_count_endpoint;
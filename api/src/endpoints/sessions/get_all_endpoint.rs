@@ -0,0 +1,22 @@
+use crate::di::AppState;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::session::Session;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(session): Extension<Session>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .get_sessions_use_case
+        .execute(session)
+        .await
+        .map_err(|e| {
+            error!("Failed to get sessions: {:?}", e);
+            e
+        })
+}
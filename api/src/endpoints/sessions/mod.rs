@@ -0,0 +1,3 @@
+pub(crate) mod get_all_endpoint;
+pub(crate) mod revoke_endpoint;
+pub(crate) mod revoke_others_endpoint;
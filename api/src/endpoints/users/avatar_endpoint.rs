@@ -0,0 +1,20 @@
+use crate::di::AppState;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use domain::app_error::AppError;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Path(username): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .get_avatar_use_case
+        .execute(username)
+        .await
+        .map_err(|e| {
+            error!("Failed to get avatar: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,27 @@
+use crate::di::AppState;
+use application::use_cases::account::delete_account_use_case::dtos::DeleteAccountRequest;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use http::StatusCode;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Valid(Json(request)): Valid<Json<DeleteAccountRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .delete_account_use_case
+        .execute(current_user, request)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| {
+            error!("Failed to delete account: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,127 @@
+use crate::di::AppState;
+use application::use_cases::user::get_user_events_use_case::dtos::UserEventDto;
+use application::use_cases::user::get_user_events_use_case::PAGE_SIZE;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::entities::user_event::UserEventCursor;
+use futures::stream;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::broadcast::Receiver;
+use tracing::error;
+
+/// How soon to look again while events wait for older transactions to end.
+const HELD_BACK_RETRY: Duration = Duration::from_secs(1);
+/// Catches up on notifications missed while the listener reconnected.
+const RESYNC_INTERVAL: Duration = Duration::from_secs(30);
+
+struct Feed {
+    state: AppState,
+    current_user: User,
+    cursor: UserEventCursor,
+    pending: VecDeque<UserEventDto>,
+    notifications: Receiver<String>,
+    /// Whether to read again right away instead of waiting for a notification.
+    stale: bool,
+    held_back: bool,
+}
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.parse::<UserEventCursor>().ok());
+
+    // subscribed before the first read, so nothing committed in between goes unnoticed
+    let notifications = state.modules.user_event_notifier.subscribe();
+
+    let page = state
+        .modules
+        .get_user_events_use_case
+        .execute(current_user.clone(), last_event_id)
+        .await?;
+
+    let feed = Feed {
+        stale: page.events.len() as i64 == PAGE_SIZE,
+        state,
+        current_user,
+        cursor: page.cursor,
+        pending: VecDeque::from(page.events),
+        notifications,
+        held_back: page.held_back,
+    };
+    let stream = stream::unfold(feed, |mut feed| async move {
+        loop {
+            if let Some(event) = feed.pending.pop_front() {
+                match Event::default()
+                    .id(event.cursor.to_string())
+                    .event(event.kind.clone())
+                    .json_data(&event)
+                {
+                    Ok(sse_event) => return Some((Ok::<Event, Infallible>(sse_event), feed)),
+                    Err(e) => {
+                        error!("Failed to serialize user event: {:?}", e);
+                        continue;
+                    }
+                }
+            }
+
+            if !feed.stale {
+                let timeout = match feed.held_back {
+                    true => HELD_BACK_RETRY,
+                    false => RESYNC_INTERVAL,
+                };
+                let user_id = feed.current_user.id.to_string();
+                wait_for_events(&mut feed.notifications, &user_id, timeout).await;
+            }
+
+            match feed
+                .state
+                .modules
+                .get_user_events_use_case
+                .execute(feed.current_user.clone(), Some(feed.cursor))
+                .await
+            {
+                Ok(page) => {
+                    feed.stale = page.events.len() as i64 == PAGE_SIZE;
+                    feed.held_back = page.held_back;
+                    feed.cursor = page.cursor;
+                    feed.pending.extend(page.events);
+                }
+                Err(e) => {
+                    error!("Failed to read user events: {:?}", e);
+                    feed.stale = false;
+                }
+            }
+        }
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// Returns once events of the user may have been committed, or after `timeout` at the latest.
+async fn wait_for_events(notifications: &mut Receiver<String>, user_id: &str, timeout: Duration) {
+    let _ = tokio::time::timeout(timeout, async {
+        loop {
+            match notifications.recv().await {
+                Ok(notified_user_id) if notified_user_id == user_id => return,
+                Ok(_) => continue,
+                // the skipped notifications may have been for this user
+                Err(RecvError::Lagged(_)) => return,
+                Err(RecvError::Closed) => std::future::pending::<()>().await,
+            }
+        }
+    })
+    .await;
+}
@@ -0,0 +1,22 @@
+use crate::di::AppState;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .export_account_use_case
+        .execute(current_user)
+        .await
+        .map_err(|e| {
+            error!("Failed to export account: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,8 @@
+pub(crate) mod avatar_endpoint;
+pub(crate) mod delete_me_endpoint;
+pub(crate) mod events_endpoint;
+pub(crate) mod export_endpoint;
+pub(crate) mod get_me_endpoint;
+pub(crate) mod public_profile_endpoint;
+pub(crate) mod ratings_endpoint;
+pub(crate) mod update_me_endpoint;
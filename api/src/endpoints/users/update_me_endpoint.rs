@@ -0,0 +1,28 @@
+use crate::di::AppState;
+use application::use_cases::user::update_profile_use_case::dtos::UpdateProfileRequest;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Extension;
+use axum_typed_multipart::TypedMultipart;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+use validator::Validate;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    TypedMultipart(request): TypedMultipart<UpdateProfileRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    request.validate()?;
+
+    state
+        .modules
+        .update_profile_use_case
+        .execute(current_user, request)
+        .await
+        .map_err(|e| {
+            error!("Failed to update profile: {:?}", e);
+            e
+        })
+}
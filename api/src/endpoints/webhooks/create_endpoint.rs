@@ -0,0 +1,27 @@
+use crate::di::AppState;
+use application::use_cases::webhooks::create_webhook_use_case::dtos::CreateWebhookRequest;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use axum_valid::Valid;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use http::StatusCode;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Valid(Json(request)): Valid<Json<CreateWebhookRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .create_webhook_use_case
+        .execute(current_user, request)
+        .await
+        .map(|webhook| (StatusCode::CREATED, webhook).into_response())
+        .map_err(|e| {
+            error!("Failed to create webhook: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,23 @@
+use crate::di::AppState;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Extension;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use tracing::error;
+
+pub async fn handle(
+    State(state): State<AppState>,
+    Extension(current_user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .modules
+        .get_webhook_deliveries_use_case
+        .execute(current_user, id)
+        .await
+        .map_err(|e| {
+            error!("Failed to get webhook deliveries: {:?}", e);
+            e
+        })
+}
@@ -0,0 +1,4 @@
+pub(crate) mod create_endpoint;
+pub(crate) mod delete_endpoint;
+pub(crate) mod deliveries_endpoint;
+pub(crate) mod get_all_endpoint;
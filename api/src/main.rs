@@ -1,3 +1,5 @@
+mod broadcasters;
+mod client_info;
 mod di;
 mod endpoints;
 mod middleware;
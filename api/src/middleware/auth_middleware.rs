@@ -1,14 +1,25 @@
+use crate::client_info::client_info;
 use crate::di::AppState;
+use crate::middleware::scope_middleware::RequiredScope;
 use axum::extract::{Request, State};
+use axum::http::header::{AUTHORIZATION, SEC_WEBSOCKET_PROTOCOL};
+use axum::http::HeaderValue;
 use axum::middleware::Next;
 use axum::response::IntoResponse;
 use domain::app_error::AppError;
 use domain::app_error::AppError::InvalidJwt;
+use domain::entities::session::Session;
 use domain::entities::token_claims::TokenClaims;
 use domain::entities::user::User;
 use jsonwebtoken::decode;
 use tracing::{error, info};
 
+pub(crate) const API_KEY_HEADER: &str = "x-api-key";
+/// Subprotocol offered right before the session token on WebSocket handshakes.
+pub(crate) const TOKEN_PROTOCOL: &str = "bearer";
+
+/// Accepts a session token as `Authorization: Bearer <jwt>` or an API key in `X-Api-Key`. API keys
+/// only pass on routes marked with a `RequiredScope` the key has.
 pub async fn auth(
     State(state): State<AppState>,
     mut req: Request,
@@ -16,6 +27,36 @@ pub async fn auth(
 ) -> Result<impl IntoResponse, AppError> {
     info!("Authentication data check.");
 
+    let api_key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|header| header.to_str().ok())
+        .map(str::to_owned);
+
+    if let Some(api_key) = api_key {
+        let required_scope = req
+            .extensions()
+            .get::<RequiredScope>()
+            .map(|required_scope| required_scope.0);
+
+        return match state
+            .modules
+            .authenticate_api_key_use_case
+            .execute(&api_key, required_scope)
+            .await
+        {
+            Ok((current_user, api_key)) => {
+                req.extensions_mut().insert(current_user);
+                req.extensions_mut().insert(api_key);
+                Ok(next.run(req).await)
+            }
+            Err(err) => {
+                error!("Error authorizing API key: {:?}", err);
+                Err(err)
+            }
+        };
+    }
+
     let auth_header = req
         .headers()
         .get(http::header::AUTHORIZATION)
@@ -33,9 +74,12 @@ pub async fn auth(
         None => return Err(InvalidJwt()),
     };
 
-    match authorize_current_user(auth_header, &state).await {
-        Ok(current_user) => {
+    let ip_address = client_info(req.headers(), state.config.trusted_proxies).ip_address;
+
+    match authorize_current_user(auth_header, ip_address, &state).await {
+        Ok((current_user, session)) => {
             req.extensions_mut().insert(current_user);
+            req.extensions_mut().insert(session);
             Ok(next.run(req).await)
         }
         Err(err) => {
@@ -45,7 +89,39 @@ pub async fn auth(
     }
 }
 
-async fn authorize_current_user(auth_token: &str, state: &AppState) -> Result<User, AppError> {
+/// Browsers cannot set headers on a WebSocket handshake, so they offer the session token as a
+/// subprotocol instead, `new WebSocket(url, ["bearer", token])`, which is then checked as if it was
+/// sent as `Authorization: Bearer <jwt>`.
+pub async fn websocket_auth(
+    State(state): State<AppState>,
+    mut req: Request,
+    next: Next,
+) -> Result<impl IntoResponse, AppError> {
+    let token = req
+        .headers()
+        .get_all(SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|header| header.to_str().ok())
+        .flat_map(|header| header.split(','))
+        .map(str::trim)
+        .skip_while(|protocol| *protocol != TOKEN_PROTOCOL)
+        .nth(1)
+        .and_then(|token| HeaderValue::from_str(&format!("Bearer {}", token)).ok());
+
+    if let Some(token) = token {
+        if !req.headers().contains_key(AUTHORIZATION) {
+            req.headers_mut().insert(AUTHORIZATION, token);
+        }
+    }
+
+    auth(State(state), req, next).await
+}
+
+async fn authorize_current_user(
+    auth_token: &str,
+    ip_address: Option<String>,
+    state: &AppState,
+) -> Result<(User, Session), AppError> {
     let claims = decode::<TokenClaims>(
         auth_token,
         &jsonwebtoken::DecodingKey::from_secret(state.config.jwt_key.as_ref()),
@@ -54,11 +130,21 @@ async fn authorize_current_user(auth_token: &str, state: &AppState) -> Result<Us
 
     match claims {
         Ok(claims) => {
+            // rejects tokens of sessions that were logged out or revoked
+            let session = state
+                .modules
+                .check_session_use_case
+                .execute(claims.claims.sid, ip_address)
+                .await?;
             let user_id = claims.claims.sub;
             let user = state.modules.get_user_use_case.execute(user_id).await?;
+            if user.is_suspended() {
+                error!("User {} is suspended.", user.id);
+                return Err(AppError::AccountSuspended());
+            }
 
             info!("User authorized: {}", user.name);
-            Ok(user)
+            Ok((user, session))
         }
         Err(err) => {
             error!("Error decoding token: {:?}", err);
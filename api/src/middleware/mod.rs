@@ -1 +1,3 @@
 pub(crate) mod auth_middleware;
+pub(crate) mod role_middleware;
+pub(crate) mod scope_middleware;
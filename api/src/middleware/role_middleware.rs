@@ -0,0 +1,34 @@
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use domain::app_error::AppError;
+use domain::entities::user::{Role, User};
+use tracing::error;
+
+/// Lets the request through when the user put in place by `auth` has at least `role`, so it has
+/// to be layered inside `auth`.
+pub async fn require_role(
+    role: Role,
+    req: Request,
+    next: Next,
+) -> Result<impl IntoResponse, AppError> {
+    let Some(user) = req.extensions().get::<User>() else {
+        error!("Role check ran without an authenticated user.");
+        return Err(AppError::InvalidJwt());
+    };
+
+    if !user.has_role(role) {
+        error!("User {} lacks the {:?} role.", user.id, role);
+        return Err(AppError::InsufficientRole(role.into()));
+    }
+
+    Ok(next.run(req).await)
+}
+
+pub async fn moderator(req: Request, next: Next) -> Result<impl IntoResponse, AppError> {
+    require_role(Role::Moderator, req, next).await
+}
+
+pub async fn admin(req: Request, next: Next) -> Result<impl IntoResponse, AppError> {
+    require_role(Role::Admin, req, next).await
+}
@@ -0,0 +1,35 @@
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use domain::app_error::AppError;
+use domain::entities::api_key::ApiKeyScope;
+
+/// The scope an API key needs for the route. Routes without it only accept a session token.
+#[derive(Debug, Clone, Copy)]
+pub struct RequiredScope(pub ApiKeyScope);
+
+/// Marks the route with `scope` for `auth` to check, so it has to be layered outside `auth`.
+pub async fn require_scope(
+    scope: ApiKeyScope,
+    mut req: Request,
+    next: Next,
+) -> Result<impl IntoResponse, AppError> {
+    req.extensions_mut().insert(RequiredScope(scope));
+    Ok(next.run(req).await)
+}
+
+pub async fn read(req: Request, next: Next) -> Result<impl IntoResponse, AppError> {
+    require_scope(ApiKeyScope::Read, req, next).await
+}
+
+pub async fn items_write(req: Request, next: Next) -> Result<impl IntoResponse, AppError> {
+    require_scope(ApiKeyScope::ItemsWrite, req, next).await
+}
+
+pub async fn auctions_write(req: Request, next: Next) -> Result<impl IntoResponse, AppError> {
+    require_scope(ApiKeyScope::AuctionsWrite, req, next).await
+}
+
+pub async fn bids_write(req: Request, next: Next) -> Result<impl IntoResponse, AppError> {
+    require_scope(ApiKeyScope::BidsWrite, req, next).await
+}
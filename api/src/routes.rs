@@ -1,12 +1,14 @@
 use crate::di::AppState;
 use crate::endpoints;
-use crate::middleware::auth_middleware::auth;
+use crate::middleware::auth_middleware::{auth, websocket_auth, API_KEY_HEADER};
+use crate::middleware::role_middleware::{admin, moderator};
+use crate::middleware::scope_middleware::{auctions_write, bids_write, items_write, read};
 use axum::http::header::{
     ACCEPT, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_REQUEST_HEADERS,
     ACCESS_CONTROL_REQUEST_METHOD, AUTHORIZATION, CONTENT_TYPE, ORIGIN,
 };
-use axum::http::{HeaderValue, Method};
-use axum::routing::{get, post};
+use axum::http::{HeaderName, HeaderValue, Method};
+use axum::routing::{delete, get, patch, post};
 use axum::{middleware, Router};
 use shuttle_secrets::SecretStore;
 use sqlx::PgPool;
@@ -24,6 +26,7 @@ pub async fn init_router(db: PgPool, secrets: SecretStore) -> Router {
             Method::GET,
             Method::POST,
             Method::PUT,
+            Method::PATCH,
             Method::DELETE,
             Method::OPTIONS,
         ])
@@ -35,6 +38,7 @@ pub async fn init_router(db: PgPool, secrets: SecretStore) -> Router {
             ACCESS_CONTROL_REQUEST_METHOD,
             CONTENT_TYPE,
             ACCESS_CONTROL_ALLOW_HEADERS,
+            HeaderName::from_static(API_KEY_HEADER),
         ])
         .expose_headers(vec![
             ORIGIN,
@@ -53,45 +57,173 @@ pub async fn init_router(db: PgPool, secrets: SecretStore) -> Router {
                 .unwrap(),
         );
 
+    match app_state.modules.auction_event_broadcaster.listen().await {
+        Ok(_) => info!("Auction event listener started."),
+        Err(e) => error!("Error. Auction event listener failed to start: {:?}", e),
+    }
+
+    match app_state.modules.user_event_notifier.listen().await {
+        Ok(_) => info!("User event listener started."),
+        Err(e) => error!("Error. User event listener failed to start: {:?}", e),
+    }
+
     match init_job_scheduler(app_state.clone()).await {
         Ok(_) => info!("Job scheduler started."),
         Err(e) => error!("Error. Job scheduler failed to start: {:?}", e),
     }
 
+    let app_state_for_expiry_scheduler = app_state.clone();
+    tokio::spawn(async move {
+        if let Err(e) = app_state_for_expiry_scheduler
+            .modules
+            .run_auction_expiry_scheduler_use_case
+            .execute()
+            .await
+        {
+            error!("Error. Auction expiry scheduler failed to start: {:?}", e);
+        }
+    });
+
     let auth_router = Router::new()
         .route(
             "/register",
             post(endpoints::auth::register_endpoint::handle),
         )
-        .route("/login", post(endpoints::auth::login_endpoint::handle));
+        .route("/login", post(endpoints::auth::login_endpoint::handle))
+        .route(
+            "/login/2fa",
+            post(endpoints::auth::verify_login_challenge_endpoint::handle),
+        )
+        .route("/refresh", post(endpoints::auth::refresh_endpoint::handle))
+        .route(
+            "/logout",
+            post(endpoints::auth::logout_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/verify",
+            post(endpoints::auth::verify_email_endpoint::handle),
+        )
+        .route(
+            "/verify/resend",
+            post(endpoints::auth::resend_verification_email_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/password/forgot",
+            post(endpoints::auth::forgot_password_endpoint::handle),
+        )
+        .route(
+            "/password/reset",
+            post(endpoints::auth::reset_password_endpoint::handle),
+        )
+        .route(
+            "/password/change",
+            post(endpoints::auth::change_password_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/external/:provider/authorize",
+            post(endpoints::auth::external_login_authorize_endpoint::handle),
+        )
+        .route(
+            "/external/:provider/callback",
+            post(endpoints::auth::external_login_callback_endpoint::handle),
+        )
+        .route(
+            "/external/:provider/link/authorize",
+            post(endpoints::auth::external_link_authorize_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/external/:provider/link",
+            post(endpoints::auth::external_link_callback_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/2fa/enroll",
+            post(endpoints::auth::enroll_two_factor_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/2fa/confirm",
+            post(endpoints::auth::confirm_two_factor_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/2fa/disable",
+            post(endpoints::auth::disable_two_factor_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        );
 
     let item_router = Router::new()
         .route(
             "/create",
             post(endpoints::items::create_endpoint::handle)
-                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(items_write)),
         )
         .route(
             "/:id/image",
             get(endpoints::items::get_image_endpoint::handle)
-                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/:id/images",
+            post(endpoints::items::add_image_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(items_write)),
+        )
+        .route(
+            "/:id/images",
+            patch(endpoints::items::reorder_images_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(items_write)),
+        )
+        .route(
+            "/:id/images/:n",
+            get(endpoints::items::get_gallery_image_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/:id/images/:n",
+            delete(endpoints::items::delete_image_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(items_write)),
         )
         .route(
             "/:id",
             get(endpoints::items::get_item_endpoint::handle)
-                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/:id",
+            patch(endpoints::items::update_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(items_write)),
+        )
+        .route(
+            "/:id",
+            delete(endpoints::items::delete_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(items_write)),
         )
         .route(
             "/all",
             get(endpoints::items::get_items_endpoint::handle)
-                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
         );
 
     let auction_router = Router::new()
         .route(
             "/create",
             post(endpoints::auctions::create_endpoint::handle)
-                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(auctions_write)),
         )
         .route(
             "/:item_id",
@@ -101,16 +233,174 @@ pub async fn init_router(db: PgPool, secrets: SecretStore) -> Router {
         .route(
             "/:auction_id/bids/create",
             post(endpoints::auctions::bids::create_endpoint::handle)
-                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(bids_write)),
         )
         .route(
             "/:auction_id/bids/all",
             get(endpoints::auctions::bids::get_all_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/:auction_id/events",
+            get(endpoints::auctions::subscribe_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(
+                    app_state.clone(),
+                    websocket_auth,
+                ))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/:auction_id/rating",
+            post(endpoints::auctions::rate_endpoint::handle)
                 .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
         )
         .route(
             "/:auction_id/confirm",
             post(endpoints::auctions::confirm_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(auctions_write)),
+        );
+
+    let notification_router = Router::new()
+        .route(
+            "/all",
+            get(endpoints::notifications::get_all_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/unread_count",
+            get(endpoints::notifications::unread_count_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/read_all",
+            post(endpoints::notifications::mark_all_read_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/:id/read",
+            post(endpoints::notifications::mark_read_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        );
+
+    let session_router = Router::new()
+        .route(
+            "/all",
+            get(endpoints::sessions::get_all_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/revoke-others",
+            post(endpoints::sessions::revoke_others_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/:id",
+            delete(endpoints::sessions::revoke_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        );
+
+    let webhook_router = Router::new()
+        .route(
+            "/create",
+            post(endpoints::webhooks::create_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/all",
+            get(endpoints::webhooks::get_all_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/:id",
+            delete(endpoints::webhooks::delete_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/:id/deliveries",
+            get(endpoints::webhooks::deliveries_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        );
+
+    let api_key_router = Router::new()
+        .route(
+            "/create",
+            post(endpoints::api_keys::create_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/all",
+            get(endpoints::api_keys::get_all_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/:id",
+            delete(endpoints::api_keys::revoke_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        );
+
+    let user_router = Router::new()
+        .route(
+            "/me",
+            get(endpoints::users::get_me_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/me",
+            patch(endpoints::users::update_me_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/me",
+            delete(endpoints::users::delete_me_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/me/export",
+            get(endpoints::users::export_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/me/events",
+            get(endpoints::users::events_endpoint::handle)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth))
+                .route_layer(middleware::from_fn(read)),
+        )
+        .route(
+            "/:username",
+            get(endpoints::users::public_profile_endpoint::handle),
+        )
+        .route(
+            "/:username/avatar",
+            get(endpoints::users::avatar_endpoint::handle),
+        )
+        .route(
+            "/:username/ratings",
+            get(endpoints::users::ratings_endpoint::handle),
+        );
+
+    let admin_router = Router::new()
+        .route(
+            "/users",
+            get(endpoints::admin::get_users_endpoint::handle)
+                .route_layer(middleware::from_fn(admin))
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/users/:id/suspend",
+            post(endpoints::admin::suspend_user_endpoint::handle)
+                .route_layer(middleware::from_fn(admin))
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
+        )
+        .route(
+            "/auctions/:id/close",
+            post(endpoints::admin::close_auction_endpoint::handle)
+                .route_layer(middleware::from_fn(moderator))
                 .route_layer(middleware::from_fn_with_state(app_state.clone(), auth)),
         );
 
@@ -118,6 +408,12 @@ pub async fn init_router(db: PgPool, secrets: SecretStore) -> Router {
         .nest("/auth", auth_router)
         .nest("/items", item_router)
         .nest("/auctions", auction_router)
+        .nest("/users", user_router)
+        .nest("/notifications", notification_router)
+        .nest("/sessions", session_router)
+        .nest("/webhooks", webhook_router)
+        .nest("/api-keys", api_key_router)
+        .nest("/admin", admin_router)
         .with_state(app_state)
         .layer(cors)
 }
@@ -145,7 +441,12 @@ pub async fn init_job_scheduler(app_state: AppState) -> Result<(), JobSchedulerE
                         .execute()
                         .await
                     {
-                        Ok(_) => info!("Expired auctions job succeeded."),
+                        Ok(summary) => info!(
+                            "Expired auctions job finished. Succeeded: {}, failed: {}, quarantined: {}.",
+                            summary.succeeded.len(),
+                            summary.failed.len(),
+                            summary.quarantined.len()
+                        ),
                         Err(e) => error!("Error. Expired auctions job failed: {:?}", e),
                     }
                 })
@@ -153,6 +454,22 @@ pub async fn init_job_scheduler(app_state: AppState) -> Result<(), JobSchedulerE
         )?)
         .await?;
 
+    let app_state_clone_for_jobs = app_state_clone.clone();
+    scheduler
+        .add(Job::new_async(
+            app_state.config.run_jobs_cron.as_str(),
+            move |_, _| {
+                let app_state_clone = app_state_clone_for_jobs.clone();
+                Box::pin(async move {
+                    match app_state_clone.modules.run_jobs_use_case.execute().await {
+                        Ok(_) => {}
+                        Err(e) => error!("Error. Run jobs job failed: {:?}", e),
+                    }
+                })
+            },
+        )?)
+        .await?;
+
     scheduler.start().await?;
     Ok(())
 }
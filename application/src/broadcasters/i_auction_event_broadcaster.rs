@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use domain::entities::auction::Auction;
+use domain::entities::auction_event::AuctionEvent;
+use domain::id::Id;
+use mockall::automock;
+use tokio::sync::broadcast::Receiver;
+
+/// Fans auction events out to every client watching a given auction.
+#[automock]
+#[async_trait]
+pub trait IAuctionEventBroadcaster {
+    async fn publish(&self, event: AuctionEvent) -> anyhow::Result<()>;
+    fn subscribe(&self, auction_id: Id<Auction>) -> Receiver<AuctionEvent>;
+}
+
+pub mod dtos {
+    use domain::entities::auction_event::AuctionEvent;
+    use domain::id::Id;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    pub enum AuctionEventDto {
+        BidPlaced {
+            auction_id: String,
+            bid_id: String,
+            user_id: String,
+            username: String,
+            value: f32,
+        },
+        Finalized {
+            auction_id: String,
+            winner_id: Option<String>,
+            winning_bid: Option<f32>,
+        },
+    }
+
+    impl From<AuctionEvent> for AuctionEventDto {
+        fn from(event: AuctionEvent) -> Self {
+            match event {
+                AuctionEvent::BidPlaced {
+                    auction_id,
+                    bid_id,
+                    user_id,
+                    username,
+                    value,
+                } => AuctionEventDto::BidPlaced {
+                    auction_id: auction_id.to_string(),
+                    bid_id: bid_id.to_string(),
+                    user_id: user_id.to_string(),
+                    username,
+                    value,
+                },
+                AuctionEvent::Finalized {
+                    auction_id,
+                    winner_id,
+                    winning_bid,
+                } => AuctionEventDto::Finalized {
+                    auction_id: auction_id.to_string(),
+                    winner_id: winner_id.map(|id| id.to_string()),
+                    winning_bid,
+                },
+            }
+        }
+    }
+
+    impl TryFrom<AuctionEventDto> for AuctionEvent {
+        type Error = anyhow::Error;
+
+        fn try_from(dto: AuctionEventDto) -> Result<Self, Self::Error> {
+            Ok(match dto {
+                AuctionEventDto::BidPlaced {
+                    auction_id,
+                    bid_id,
+                    user_id,
+                    username,
+                    value,
+                } => AuctionEvent::BidPlaced {
+                    auction_id: Id::try_from(auction_id)?,
+                    bid_id: Id::try_from(bid_id)?,
+                    user_id: Id::try_from(user_id)?,
+                    username,
+                    value,
+                },
+                AuctionEventDto::Finalized {
+                    auction_id,
+                    winner_id,
+                    winning_bid,
+                } => AuctionEvent::Finalized {
+                    auction_id: Id::try_from(auction_id)?,
+                    winner_id: winner_id.map(Id::try_from).transpose()?,
+                    winning_bid,
+                },
+            })
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
+use async_trait::async_trait;
+use domain::entities::auction::Auction;
+use domain::entities::auction_event::AuctionEvent;
+use domain::id::Id;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokio::sync::broadcast::{channel, Receiver, Sender};
+use uuid::Uuid;
+
+const CHANNEL_CAPACITY: usize = 64;
+
+/// Keeps one broadcast channel per watched auction inside the current process.
+pub struct InProcessAuctionEventBroadcaster {
+    channels: Mutex<HashMap<Uuid, Sender<AuctionEvent>>>,
+}
+
+impl InProcessAuctionEventBroadcaster {
+    pub fn new() -> Self {
+        Self {
+            channels: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for InProcessAuctionEventBroadcaster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl IAuctionEventBroadcaster for InProcessAuctionEventBroadcaster {
+    async fn publish(&self, event: AuctionEvent) -> anyhow::Result<()> {
+        let auction_id = event.auction_id().value;
+        let is_final = event.is_final();
+        let mut channels = self.channels.lock().unwrap();
+
+        if let Some(sender) = channels.get(&auction_id) {
+            // an error only means that nobody is listening anymore
+            let _ = sender.send(event);
+
+            // dropping the sender closes the channel for subscribers once they drain it
+            if is_final || sender.receiver_count() == 0 {
+                channels.remove(&auction_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn subscribe(&self, auction_id: Id<Auction>) -> Receiver<AuctionEvent> {
+        let mut channels = self.channels.lock().unwrap();
+
+        // auctions whose subscribers all left may never see another event to drop them on publish
+        channels.retain(|_, sender| sender.receiver_count() > 0);
+
+        channels
+            .entry(auction_id.value)
+            .or_insert_with(|| channel(CHANNEL_CAPACITY).0)
+            .subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::bid::Bid;
+    use domain::entities::user::User;
+    use tokio::sync::broadcast::error::RecvError;
+
+    #[tokio::test]
+    async fn given_subscriber_when_publishing_bid_placed_then_subscriber_receives_event() {
+        // Arrange
+        let broadcaster = InProcessAuctionEventBroadcaster::new();
+        let auction_id = Id::<Auction>::gen();
+        let mut receiver = broadcaster.subscribe(auction_id.clone());
+
+        // Act
+        let result = broadcaster
+            .publish(AuctionEvent::BidPlaced {
+                auction_id: auction_id.clone(),
+                bid_id: Id::<Bid>::gen(),
+                user_id: Id::<User>::gen(),
+                username: "username".to_string(),
+                value: 10.0,
+            })
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        match receiver.recv().await {
+            Ok(AuctionEvent::BidPlaced { value, .. }) => assert_eq!(value, 10.0),
+            _ => panic!("Test failed"),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_subscriber_when_publishing_finalized_then_channel_is_closed_after_event() {
+        // Arrange
+        let broadcaster = InProcessAuctionEventBroadcaster::new();
+        let auction_id = Id::<Auction>::gen();
+        let mut receiver = broadcaster.subscribe(auction_id.clone());
+
+        // Act
+        broadcaster
+            .publish(AuctionEvent::Finalized {
+                auction_id: auction_id.clone(),
+                winner_id: None,
+                winning_bid: None,
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(matches!(
+            receiver.recv().await,
+            Ok(AuctionEvent::Finalized { .. })
+        ));
+        assert!(matches!(receiver.recv().await, Err(RecvError::Closed)));
+    }
+
+    #[tokio::test]
+    async fn given_subscriber_gone_when_subscribing_to_other_auction_then_channel_is_dropped() {
+        // Arrange
+        let broadcaster = InProcessAuctionEventBroadcaster::new();
+        let left_auction_id = Id::<Auction>::gen();
+        drop(broadcaster.subscribe(left_auction_id.clone()));
+
+        // Act
+        let _receiver = broadcaster.subscribe(Id::<Auction>::gen());
+
+        // Assert
+        let channels = broadcaster.channels.lock().unwrap();
+        assert_eq!(channels.len(), 1);
+        assert!(!channels.contains_key(&left_auction_id.value));
+    }
+
+    #[tokio::test]
+    async fn given_subscriber_of_other_auction_when_publishing_then_event_is_not_received() {
+        // Arrange
+        let broadcaster = InProcessAuctionEventBroadcaster::new();
+        let mut receiver = broadcaster.subscribe(Id::<Auction>::gen());
+
+        // Act
+        broadcaster
+            .publish(AuctionEvent::Finalized {
+                auction_id: Id::<Auction>::gen(),
+                winner_id: None,
+                winning_bid: None,
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod i_auction_event_broadcaster;
+pub mod in_process_auction_event_broadcaster;
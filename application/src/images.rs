@@ -0,0 +1,338 @@
+//! Checks uploaded item images and avatars and derives what is stored from them. Uploads are
+//! decoded and encoded again, which drops EXIF and any other metadata, and item image thumbnails
+//! are cut from the result.
+use domain::app_error::AppError;
+use domain::entities::item::Item;
+use domain::entities::item_image::{ItemImage, THUMBNAIL_SIZES};
+use domain::id::Id;
+use domain::interfaces::i_blob_store::IBlobStore;
+use domain::interfaces::i_item_image_repository::IItemImageRepository;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageError, ImageReader};
+use std::io::Cursor;
+use tracing::error;
+
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+const MAX_IMAGE_DIMENSION: u32 = 8192;
+const JPEG_QUALITY: u8 = 90;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ImageFormat {
+    Jpeg,
+    Png,
+    WebP,
+}
+
+impl ImageFormat {
+    /// Goes by the magic bytes, the file name and content type sent along are not trusted.
+    fn sniff(data: &[u8]) -> Option<Self> {
+        match data {
+            [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpeg),
+            [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some(Self::Png),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(Self::WebP),
+            _ => None,
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::WebP => "image/webp",
+        }
+    }
+
+    fn codec(self) -> image::ImageFormat {
+        match self {
+            Self::Jpeg => image::ImageFormat::Jpeg,
+            Self::Png => image::ImageFormat::Png,
+            Self::WebP => image::ImageFormat::WebP,
+        }
+    }
+
+    /// WebP is written losslessly, the encoder has no lossy mode.
+    fn encode(self, image: &DynamicImage) -> Result<Vec<u8>, ImageError> {
+        // the encoders take 8 bit samples, and JPEG has no alpha channel
+        let image = match (self, image.color().has_alpha()) {
+            (Self::Jpeg, _) | (_, false) => DynamicImage::ImageRgb8(image.to_rgb8()),
+            (_, true) => DynamicImage::ImageRgba8(image.to_rgba8()),
+        };
+
+        let mut data = Vec::new();
+        match self {
+            Self::Jpeg => {
+                image.write_with_encoder(JpegEncoder::new_with_quality(&mut data, JPEG_QUALITY))?
+            }
+            Self::Png => image.write_with_encoder(PngEncoder::new(&mut data))?,
+            Self::WebP => image.write_with_encoder(WebPEncoder::new_lossless(&mut data))?,
+        }
+
+        Ok(data)
+    }
+}
+
+/// Validates an upload and puts it into the blob store, together with its thumbnails. Returns the
+/// rows to save, the image at `position` first. Nothing is left in the store when this fails.
+pub(crate) async fn store_item_images<B: IBlobStore>(
+    blob_store: &B,
+    item_id: Id<Item>,
+    position: i32,
+    data: Vec<u8>,
+) -> Result<Vec<ItemImage>, AppError> {
+    // decoding and encoding keep a core busy for a while
+    let prepared = tokio::task::spawn_blocking(move || prepare(item_id, position, &data))
+        .await
+        .map_err(|e| {
+            error!("Failed to process image: {:?}", e);
+            AppError::InternalServerError()
+        })??;
+
+    let mut images: Vec<ItemImage> = Vec::new();
+    for (image, data) in prepared {
+        let stored = blob_store
+            .put(image.blob_key.clone(), image.content_type.clone(), data)
+            .await;
+        if let Err(e) = stored {
+            error!("Failed to store image: {:?}", e);
+            let keys = images.iter().map(|image| image.blob_key.clone()).collect();
+            discard_blobs(blob_store, keys).await;
+            return Err(AppError::FailedToUpdateItemImages());
+        }
+        images.push(image);
+    }
+
+    Ok(images)
+}
+
+/// Validates an uploaded avatar and returns it encoded again, along with its content type.
+pub(crate) async fn prepare_avatar(data: Vec<u8>) -> Result<(Vec<u8>, String), AppError> {
+    tokio::task::spawn_blocking(move || reencode(&data))
+        .await
+        .map_err(|e| {
+            error!("Failed to process avatar: {:?}", e);
+            AppError::InternalServerError()
+        })?
+        .map(|(format, data)| (data, format.content_type().to_string()))
+}
+
+/// Reads the data of an image from the blob store, or from the table for images saved before the
+/// blob store that `migrate_blobs` has not moved yet.
+pub(crate) async fn load_image_data<B: IBlobStore, R: IItemImageRepository>(
+    blob_store: &B,
+    item_image_repository: &R,
+    image: &ItemImage,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    if let Some(data) = blob_store.get(image.blob_key.clone()).await? {
+        return Ok(Some(data));
+    }
+
+    match item_image_repository
+        .find_legacy_data(image.id.clone())
+        .await?
+    {
+        Some(data) => Ok(Some(data)),
+        // moved out between the two reads
+        None => blob_store.get(image.blob_key.clone()).await,
+    }
+}
+
+/// Removes blobs whose rows are gone or were never saved. Failures only leave garbage behind, so
+/// they are logged rather than reported.
+pub(crate) async fn discard_blobs<B: IBlobStore>(blob_store: &B, keys: Vec<String>) {
+    for key in keys {
+        if let Err(e) = blob_store.delete(key.clone()).await {
+            error!("Failed to delete blob {}: {:?}", key, e);
+        }
+    }
+}
+
+fn decode(data: &[u8]) -> Result<(ImageFormat, DynamicImage), AppError> {
+    if data.len() > MAX_IMAGE_BYTES {
+        return Err(AppError::ImageTooLarge(MAX_IMAGE_BYTES));
+    }
+    let format = ImageFormat::sniff(data).ok_or(AppError::UnsupportedImageType())?;
+
+    let mut decoder = ImageReader::with_format(Cursor::new(data), format.codec())
+        .into_decoder()
+        .map_err(decoding_error)?;
+    let (width, height) = decoder.dimensions();
+    if width > MAX_IMAGE_DIMENSION || height > MAX_IMAGE_DIMENSION {
+        return Err(AppError::ImageDimensionsTooLarge(MAX_IMAGE_DIMENSION));
+    }
+    // applied to the pixels, as the EXIF it comes from is not written back
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut decoded = DynamicImage::from_decoder(decoder).map_err(decoding_error)?;
+    decoded.apply_orientation(orientation);
+
+    Ok((format, decoded))
+}
+
+fn reencode(data: &[u8]) -> Result<(ImageFormat, Vec<u8>), AppError> {
+    let (format, decoded) = decode(data)?;
+    let data = format.encode(&decoded).map_err(encoding_error)?;
+
+    Ok((format, data))
+}
+
+fn prepare(
+    item_id: Id<Item>,
+    position: i32,
+    data: &[u8],
+) -> Result<Vec<(ItemImage, Vec<u8>)>, AppError> {
+    let (format, decoded) = decode(data)?;
+
+    let image = ItemImage::new(item_id, position, format.content_type().to_string());
+    let data = format.encode(&decoded).map_err(encoding_error)?;
+
+    let mut thumbnails = Vec::new();
+    for size in THUMBNAIL_SIZES {
+        // images that already fit are not blown up
+        let thumbnail_data = match decoded.width().max(decoded.height()) <= size as u32 {
+            true => data.clone(),
+            false => format
+                .encode(&decoded.thumbnail(size as u32, size as u32))
+                .map_err(encoding_error)?,
+        };
+        thumbnails.push((image.thumbnail(size), thumbnail_data));
+    }
+
+    Ok(std::iter::once((image, data)).chain(thumbnails).collect())
+}
+
+fn decoding_error(e: ImageError) -> AppError {
+    error!("Failed to decode image: {:?}", e);
+    match e {
+        ImageError::Limits(_) => AppError::ImageDimensionsTooLarge(MAX_IMAGE_DIMENSION),
+        _ => AppError::InvalidImage(),
+    }
+}
+
+fn encoding_error(e: ImageError) -> AppError {
+    error!("Failed to encode image: {:?}", e);
+    AppError::InternalServerError()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use domain::interfaces::i_blob_store::MockIBlobStore;
+    use image::{Rgb, RgbImage};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(width, height, Rgb([200, 0, 0])));
+        ImageFormat::Png.encode(&image).unwrap()
+    }
+
+    #[test]
+    fn given_file_that_is_no_image_when_preparing_then_it_is_rejected() {
+        // Act
+        let result = prepare(Id::gen(), 0, b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>");
+
+        // Assert
+        assert!(matches!(result, Err(AppError::UnsupportedImageType())));
+    }
+
+    #[test]
+    fn given_image_wider_than_the_limit_when_preparing_then_it_is_rejected() {
+        // Act
+        let result = prepare(Id::gen(), 0, &png(MAX_IMAGE_DIMENSION + 1, 1));
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(AppError::ImageDimensionsTooLarge(MAX_IMAGE_DIMENSION))
+        ));
+    }
+
+    #[test]
+    fn given_png_when_preparing_then_thumbnails_fit_their_size() {
+        // Act
+        let images = prepare(Id::gen(), 2, &png(1000, 500)).unwrap();
+
+        // Assert
+        assert_eq!(images.len(), 1 + THUMBNAIL_SIZES.len());
+        assert_eq!(images[0].0.thumbnail_size, None);
+        for ((image, data), size) in images[1..].iter().zip(THUMBNAIL_SIZES) {
+            let decoded = image::load_from_memory(data).unwrap();
+            assert_eq!(image.thumbnail_size, Some(size));
+            assert_eq!(image.position, 2);
+            assert_eq!(image.content_type, "image/png");
+            assert_eq!(
+                (decoded.width(), decoded.height()),
+                (size as u32, size as u32 / 2)
+            );
+        }
+    }
+
+    #[test]
+    fn given_jpeg_with_exif_when_preparing_then_metadata_is_dropped() {
+        // Arrange
+        let jpeg = ImageFormat::Jpeg
+            .encode(&DynamicImage::ImageRgb8(RgbImage::new(8, 8)))
+            .unwrap();
+        let exif = b"Exif\0\0II*\0\x08\0\0\0\0\0GPS 52.5200 13.4050";
+        let mut data = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        data.extend(((exif.len() + 2) as u16).to_be_bytes());
+        data.extend(exif);
+        data.extend(&jpeg[2..]);
+
+        // Act
+        let images = prepare(Id::gen(), 0, &data).unwrap();
+
+        // Assert
+        assert_eq!(images[0].0.content_type, "image/jpeg");
+        assert!(images
+            .iter()
+            .all(|(_, data)| !data.windows(4).any(|window| window == b"Exif")));
+    }
+
+    #[tokio::test]
+    async fn given_html_disguised_as_avatar_when_preparing_then_it_is_rejected() {
+        // Act
+        let result = prepare_avatar(b"<html><script>alert(1)</script></html>".to_vec()).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::UnsupportedImageType())));
+    }
+
+    #[tokio::test]
+    async fn given_png_avatar_when_preparing_then_it_is_reencoded_with_its_content_type() {
+        // Arrange
+        let mut data = png(4, 4);
+        // trailing bytes survive neither decoding nor encoding
+        data.extend(b"<script>alert(1)</script>");
+
+        // Act
+        let (avatar, content_type) = prepare_avatar(data).await.unwrap();
+
+        // Assert
+        assert_eq!(content_type, "image/png");
+        assert!(!avatar.windows(8).any(|window| window == b"<script>"));
+        assert_eq!(image::load_from_memory(&avatar).unwrap().width(), 4);
+    }
+
+    #[tokio::test]
+    async fn given_blob_store_failing_midway_when_storing_then_stored_blobs_are_discarded() {
+        // Arrange
+        let mut blob_store = MockIBlobStore::new();
+        let mut puts = 0;
+        blob_store.expect_put().returning(move |_, _, _| {
+            puts += 1;
+            match puts {
+                1 => Ok(()),
+                _ => Err(anyhow!("unavailable")),
+            }
+        });
+        blob_store.expect_delete().times(1).returning(|_| Ok(()));
+
+        // Act
+        let result = store_item_images(&blob_store, Id::gen(), 0, png(10, 10)).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::FailedToUpdateItemImages())));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::jobs::i_job_handler::IJobHandler;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::Utc;
+use domain::entities::job::Job;
+use domain::entities::webhook::{WebhookDelivery, WebhookMessage};
+use domain::interfaces::i_webhook_repository::IWebhookRepository;
+use domain::interfaces::i_webhook_sender::IWebhookSender;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// Hex encoded HMAC-SHA256 of `message` keyed with the subscription secret.
+pub fn sign(secret: &str, message: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(message.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Posts a webhook message to its subscription and records the attempt in the delivery log.
+pub struct DeliverWebhookJobHandler<R: IWebhookRepository, S: IWebhookSender> {
+    webhook_repository: Arc<R>,
+    webhook_sender: Arc<S>,
+}
+
+impl<R: IWebhookRepository, S: IWebhookSender> DeliverWebhookJobHandler<R, S> {
+    pub fn new(webhook_repository: Arc<R>, webhook_sender: Arc<S>) -> Self {
+        Self {
+            webhook_repository,
+            webhook_sender,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: IWebhookRepository + Send + Sync, S: IWebhookSender + Send + Sync> IJobHandler
+    for DeliverWebhookJobHandler<R, S>
+{
+    fn kind(&self) -> &'static str {
+        WebhookMessage::JOB_KIND
+    }
+
+    async fn handle(&self, job: &Job) -> anyhow::Result<()> {
+        let message = WebhookMessage::try_from(job)?;
+        let subscription = match self
+            .webhook_repository
+            .find_by_id(message.subscription_id.clone())
+            .await?
+        {
+            Some(subscription) => subscription,
+            None => {
+                info!(
+                    "Dropping webhook {} for deleted subscription",
+                    message.event_id
+                );
+                return Ok(());
+            }
+        };
+
+        // the timestamp is signed together with the body so receivers can reject replays
+        let timestamp = Utc::now().timestamp();
+        let signature = sign(
+            &subscription.secret,
+            &format!("{}.{}", timestamp, message.body),
+        );
+        let headers = vec![
+            ("X-Webhook-Id".to_string(), message.event_id.clone()),
+            (
+                "X-Webhook-Event".to_string(),
+                String::from(message.event_type),
+            ),
+            ("X-Webhook-Timestamp".to_string(), timestamp.to_string()),
+            (
+                "X-Webhook-Signature".to_string(),
+                format!("sha256={}", signature),
+            ),
+        ];
+
+        let (status_code, result) = match self
+            .webhook_sender
+            .send(subscription.url, headers, message.body)
+            .await
+        {
+            Ok(status_code) if (200..300).contains(&status_code) => (Some(status_code), Ok(())),
+            Ok(status_code) => (
+                Some(status_code),
+                Err(anyhow!("Receiver responded with status {}", status_code)),
+            ),
+            Err(e) => (None, Err(e)),
+        };
+
+        if let Err(e) = self
+            .webhook_repository
+            .insert_delivery(WebhookDelivery::new(
+                subscription.id,
+                message.event_id,
+                message.event_type,
+                job.attempts,
+                status_code.map(i32::from),
+                result.as_ref().err().map(|e| e.to_string()),
+            ))
+            .await
+        {
+            error!("Failed to record webhook delivery: {:?}", e);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::user::User;
+    use domain::entities::webhook::{
+        WebhookEvent, WebhookEventType, WebhookMessage, WebhookSubscription,
+    };
+    use domain::id::Id;
+    use domain::interfaces::i_webhook_repository::MockIWebhookRepository;
+    use domain::interfaces::i_webhook_sender::MockIWebhookSender;
+    use serde_json::json;
+
+    fn subscription() -> WebhookSubscription {
+        WebhookSubscription::new(
+            Id::<User>::gen(),
+            "http://localhost/webhook".to_string(),
+            vec![WebhookEventType::BidPlaced],
+            "secret".to_string(),
+        )
+    }
+
+    fn delivery_job(subscription: &WebhookSubscription) -> Job {
+        let event = WebhookEvent {
+            event_type: WebhookEventType::BidPlaced,
+            user_ids: vec![subscription.user_id.clone()],
+            data: json!({ "value": 5.0 }),
+        };
+        let mut job =
+            WebhookMessage::new(subscription.id.clone(), "event".to_string(), &event).into_job();
+        job.attempts = 1;
+        job
+    }
+
+    fn webhook_repository_returning(
+        subscription: WebhookSubscription,
+        succeeded: bool,
+    ) -> MockIWebhookRepository {
+        let mut webhook_repository = MockIWebhookRepository::new();
+        webhook_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(subscription.clone())));
+        webhook_repository
+            .expect_insert_delivery()
+            .withf(move |delivery| delivery.succeeded == succeeded && delivery.attempt == 1)
+            .times(1)
+            .returning(|delivery| Ok(Some(delivery)));
+        webhook_repository
+    }
+
+    #[test]
+    fn given_secret_and_message_when_signing_then_hmac_sha256_is_returned() {
+        // Act
+        let signature = sign("key", "The quick brown fox jumps over the lazy dog");
+
+        // Assert
+        assert_eq!(
+            signature,
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[tokio::test]
+    async fn given_accepting_receiver_when_handling_then_signed_delivery_is_logged_as_succeeded() {
+        // Arrange
+        let subscription = subscription();
+        let job = delivery_job(&subscription);
+
+        let mut webhook_sender = MockIWebhookSender::new();
+        webhook_sender
+            .expect_send()
+            .withf(|url, headers, body| {
+                let header = |name: &str| {
+                    headers
+                        .iter()
+                        .find(|(header, _)| header == name)
+                        .map(|(_, value)| value.clone())
+                        .unwrap_or_default()
+                };
+                let expected = format!(
+                    "sha256={}",
+                    sign(
+                        "secret",
+                        &format!("{}.{}", header("X-Webhook-Timestamp"), body)
+                    )
+                );
+                url == "http://localhost/webhook"
+                    && header("X-Webhook-Event") == "bid_placed"
+                    && header("X-Webhook-Signature") == expected
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(204));
+
+        let handler = DeliverWebhookJobHandler::new(
+            Arc::new(webhook_repository_returning(subscription, true)),
+            Arc::new(webhook_sender),
+        );
+
+        // Act
+        let result = handler.handle(&job).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_failing_receiver_when_handling_then_delivery_is_logged_and_job_fails() {
+        // Arrange
+        let subscription = subscription();
+        let job = delivery_job(&subscription);
+
+        let mut webhook_sender = MockIWebhookSender::new();
+        webhook_sender.expect_send().returning(|_, _, _| Ok(500));
+
+        let handler = DeliverWebhookJobHandler::new(
+            Arc::new(webhook_repository_returning(subscription, false)),
+            Arc::new(webhook_sender),
+        );
+
+        // Act
+        let result = handler.handle(&job).await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,50 @@
+use crate::jobs::i_job_handler::IJobHandler;
+use async_trait::async_trait;
+use domain::entities::job::Job;
+use domain::entities::webhook::{WebhookEvent, WebhookMessage};
+use domain::interfaces::i_job_repository::IJobRepository;
+use domain::interfaces::i_webhook_repository::IWebhookRepository;
+use std::sync::Arc;
+
+/// Turns a webhook event into one delivery job per matching subscription.
+pub struct DispatchWebhookEventJobHandler<R: IWebhookRepository, J: IJobRepository> {
+    webhook_repository: Arc<R>,
+    job_repository: Arc<J>,
+}
+
+impl<R: IWebhookRepository, J: IJobRepository> DispatchWebhookEventJobHandler<R, J> {
+    pub fn new(webhook_repository: Arc<R>, job_repository: Arc<J>) -> Self {
+        Self {
+            webhook_repository,
+            job_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: IWebhookRepository + Send + Sync, J: IJobRepository + Send + Sync> IJobHandler
+    for DispatchWebhookEventJobHandler<R, J>
+{
+    fn kind(&self) -> &'static str {
+        WebhookEvent::JOB_KIND
+    }
+
+    async fn handle(&self, job: &Job) -> anyhow::Result<()> {
+        let event = WebhookEvent::try_from(job)?;
+        let subscriptions = self
+            .webhook_repository
+            .find_subscribed(event.user_ids.clone(), event.event_type)
+            .await?;
+
+        // the event id is the dispatch job id, so receivers can deduplicate if this job is retried
+        for subscription in subscriptions {
+            self.job_repository
+                .enqueue(
+                    WebhookMessage::new(subscription.id, job.id.to_string(), &event).into_job(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
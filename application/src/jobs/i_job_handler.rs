@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use domain::entities::job::Job;
+use mockall::automock;
+
+/// Runs jobs of a single kind; an error schedules a retry until the job's attempts run out.
+#[automock]
+#[async_trait]
+pub trait IJobHandler: Send + Sync {
+    fn kind(&self) -> &'static str;
+    async fn handle(&self, job: &Job) -> anyhow::Result<()>;
+}
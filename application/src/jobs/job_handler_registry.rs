@@ -0,0 +1,28 @@
+use crate::jobs::i_job_handler::IJobHandler;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Maps job kinds to their handlers; workers only claim kinds that are registered here.
+#[derive(Default)]
+pub struct JobHandlerRegistry {
+    handlers: HashMap<&'static str, Arc<dyn IJobHandler>>,
+}
+
+impl JobHandlerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<H: IJobHandler + 'static>(mut self, handler: H) -> Self {
+        self.handlers.insert(handler.kind(), Arc::new(handler));
+        self
+    }
+
+    pub fn kinds(&self) -> Vec<String> {
+        self.handlers.keys().map(|kind| kind.to_string()).collect()
+    }
+
+    pub fn get(&self, kind: &str) -> Option<Arc<dyn IJobHandler>> {
+        self.handlers.get(kind).cloned()
+    }
+}
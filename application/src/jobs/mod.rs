@@ -0,0 +1,5 @@
+pub mod deliver_webhook_job_handler;
+pub mod dispatch_webhook_event_job_handler;
+pub mod i_job_handler;
+pub mod job_handler_registry;
+pub mod send_email_job_handler;
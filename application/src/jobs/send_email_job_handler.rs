@@ -0,0 +1,83 @@
+use crate::jobs::i_job_handler::IJobHandler;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::email::Email;
+use domain::entities::job::Job;
+use domain::interfaces::i_mailer::IMailer;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+
+pub struct SendEmailJobHandler<R: IUserRepository, M: IMailer> {
+    user_repository: Arc<R>,
+    mailer: Arc<M>,
+}
+
+impl<R: IUserRepository, M: IMailer> SendEmailJobHandler<R, M> {
+    pub fn new(user_repository: Arc<R>, mailer: Arc<M>) -> Self {
+        Self {
+            user_repository,
+            mailer,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: IUserRepository + Send + Sync, M: IMailer + Send + Sync> IJobHandler
+    for SendEmailJobHandler<R, M>
+{
+    fn kind(&self) -> &'static str {
+        Email::JOB_KIND
+    }
+
+    async fn handle(&self, job: &Job) -> anyhow::Result<()> {
+        let email = Email::try_from(job)?;
+        let recipient = self
+            .user_repository
+            .find(email.recipient_id)
+            .await?
+            .ok_or_else(|| anyhow!("Recipient not found"))?;
+
+        self.mailer
+            .send(recipient.email, email.subject, email.body)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::user::User;
+    use domain::interfaces::i_mailer::MockIMailer;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+
+    #[tokio::test]
+    async fn given_email_job_when_handling_then_email_is_sent_to_recipient_address() {
+        // Arrange
+        let user = User::new(
+            "username".to_string(),
+            "user@example.com".to_string(),
+            "hashed_password".to_string(),
+        );
+        let job = Email::registration(&user).into_job();
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(user.clone())));
+
+        let mut mailer = MockIMailer::new();
+        mailer
+            .expect_send()
+            .withf(|to, subject, _| to == "user@example.com" && subject == "Welcome to RainbowBid")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let handler = SendEmailJobHandler::new(Arc::new(user_repository), Arc::new(mailer));
+
+        // Act
+        let result = handler.handle(&job).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
@@ -1 +1,9 @@
+pub mod broadcasters;
+mod images;
+pub mod jobs;
+mod pkce;
+pub mod schedulers;
+pub mod signers;
+mod totp;
 pub mod use_cases;
+mod validators;
@@ -0,0 +1,31 @@
+//! Proof Key for Code Exchange (RFC 7636), which binds an authorization code to the party that
+//! asked for it.
+use data_encoding::BASE64URL_NOPAD;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// A random verifier of 43 characters, the shortest one allowed.
+pub(crate) fn generate_code_verifier() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    BASE64URL_NOPAD.encode(&bytes)
+}
+
+/// The `S256` challenge sent along with the authorization request.
+pub(crate) fn code_challenge(code_verifier: &str) -> String {
+    BASE64URL_NOPAD.encode(&Sha256::digest(code_verifier.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_rfc_example_verifier_when_computing_challenge_then_it_matches() {
+        assert_eq!(
+            code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+        assert_eq!(generate_code_verifier().len(), 43);
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use domain::entities::auction::Auction;
+use domain::id::Id;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::Mutex;
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+/// Keeps the end dates of upcoming auctions in a priority queue and hands each auction out as
+/// soon as it ends. Scheduling an auction again with a later end date extends it; the stale
+/// entry still fires, so whoever finalizes must check the end date itself.
+#[derive(Default)]
+pub struct AuctionExpiryScheduler {
+    queue: Mutex<BinaryHeap<Reverse<(DateTime<Utc>, Uuid)>>>,
+    changed: Notify,
+}
+
+impl AuctionExpiryScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn schedule(&self, auction_id: Id<Auction>, end_date: DateTime<Utc>) {
+        self.queue
+            .lock()
+            .unwrap()
+            .push(Reverse((end_date, auction_id.value)));
+        // wakes `next_due` in case the new end date comes before the one it is sleeping until
+        self.changed.notify_one();
+    }
+
+    /// Waits until the earliest scheduled auction has ended and removes it from the queue.
+    pub async fn next_due(&self) -> Id<Auction> {
+        loop {
+            let next_end_date = self
+                .queue
+                .lock()
+                .unwrap()
+                .peek()
+                .map(|Reverse((end_date, _))| *end_date);
+
+            match next_end_date {
+                Some(end_date) => match (end_date - Utc::now()).to_std() {
+                    Ok(wait) if !wait.is_zero() => {
+                        tokio::select! {
+                            _ = tokio::time::sleep(wait) => {}
+                            _ = self.changed.notified() => {}
+                        }
+                    }
+                    _ => {
+                        if let Some(Reverse((_, auction_id))) = self.queue.lock().unwrap().pop() {
+                            return Id::new(auction_id);
+                        }
+                    }
+                },
+                None => self.changed.notified().await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn given_ended_auctions_when_waiting_then_they_are_due_in_end_date_order() {
+        // Arrange
+        let scheduler = AuctionExpiryScheduler::new();
+        let later = Id::<Auction>::gen();
+        let earlier = Id::<Auction>::gen();
+        scheduler.schedule(later.clone(), Utc::now() - chrono::Duration::seconds(1));
+        scheduler.schedule(earlier.clone(), Utc::now() - chrono::Duration::seconds(10));
+
+        // Act
+        let first = scheduler.next_due().await;
+        let second = scheduler.next_due().await;
+
+        // Assert
+        assert_eq!(first.value, earlier.value);
+        assert_eq!(second.value, later.value);
+    }
+
+    #[tokio::test]
+    async fn given_waiting_for_distant_auction_when_earlier_one_is_scheduled_then_it_is_due_first()
+    {
+        // Arrange
+        let scheduler = Arc::new(AuctionExpiryScheduler::new());
+        scheduler.schedule(Id::gen(), Utc::now() + chrono::Duration::hours(1));
+        let waiter = tokio::spawn({
+            let scheduler = scheduler.clone();
+            async move { scheduler.next_due().await }
+        });
+        let soon = Id::<Auction>::gen();
+
+        // Act
+        scheduler.schedule(
+            soon.clone(),
+            Utc::now() + chrono::Duration::milliseconds(50),
+        );
+        let due = tokio::time::timeout(Duration::from_secs(1), waiter).await;
+
+        // Assert
+        assert_eq!(due.unwrap().unwrap().value, soon.value);
+    }
+}
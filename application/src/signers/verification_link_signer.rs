@@ -0,0 +1,107 @@
+use chrono::{Duration, Utc};
+use domain::entities::email::Email;
+use domain::entities::email_verification::EmailVerificationToken;
+use domain::entities::user::User;
+use domain::id::Id;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+const TOKEN_LIFETIME_HOURS: i64 = 24;
+
+/// Builds and checks the links sent in verification emails. A link carries the token id and an
+/// HMAC of it, so a guessed id is rejected before the database is queried.
+pub struct VerificationLinkSigner {
+    key: String,
+    url: String,
+}
+
+impl VerificationLinkSigner {
+    pub fn new(key: String, url: String) -> Self {
+        Self { key, url }
+    }
+
+    /// Creates a fresh token for `user` along with the email carrying its link.
+    pub fn issue(&self, user: &User) -> (EmailVerificationToken, Email) {
+        let token = EmailVerificationToken::new(
+            user.id.clone(),
+            Utc::now() + Duration::hours(TOKEN_LIFETIME_HOURS),
+        );
+        let link = format!("{}?token={}.{}", self.url, token.id, self.sign(&token.id));
+        let email = Email::email_verification(user, link);
+
+        (token, email)
+    }
+
+    /// Returns the token id when the signature matches it.
+    pub fn verify(&self, token: &str) -> Option<Id<EmailVerificationToken>> {
+        let (id, signature) = token.split_once('.')?;
+        let id = Id::<EmailVerificationToken>::try_from(id.to_string()).ok()?;
+        let signature = hex::decode(signature).ok()?;
+
+        self.mac(&id).verify_slice(&signature).ok()?;
+        Some(id)
+    }
+
+    fn sign(&self, id: &Id<EmailVerificationToken>) -> String {
+        hex::encode(self.mac(id).finalize().into_bytes())
+    }
+
+    fn mac(&self, id: &Id<EmailVerificationToken>) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.key.as_bytes())
+            .expect("HMAC accepts keys of any size");
+        mac.update(id.to_string().as_bytes());
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> VerificationLinkSigner {
+        VerificationLinkSigner::new(
+            "key".to_string(),
+            "http://localhost/verify-email".to_string(),
+        )
+    }
+
+    #[test]
+    fn given_issued_link_when_verifying_then_token_id_is_returned() {
+        // Arrange
+        let signer = signer();
+        let user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let (token, email) = signer.issue(&user);
+        let link_token = email
+            .body
+            .split("?token=")
+            .nth(1)
+            .unwrap()
+            .split_whitespace()
+            .next()
+            .unwrap();
+
+        // Act
+        let result = signer.verify(link_token);
+
+        // Assert
+        assert_eq!(result.map(|id| id.value), Some(token.id.value));
+    }
+
+    #[test]
+    fn given_tampered_signature_when_verifying_then_none_is_returned() {
+        // Arrange
+        let signer = signer();
+        let id = Id::<EmailVerificationToken>::gen();
+        let token = format!("{}.{}", id, "00".repeat(32));
+
+        // Act
+        let result = signer.verify(&token);
+
+        // Assert
+        assert!(result.is_none());
+    }
+}
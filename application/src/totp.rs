@@ -0,0 +1,152 @@
+//! Time-based one-time passwords as described by RFC 6238, with the parameters every
+//! authenticator app understands: HMAC-SHA1, 6 digits and 30 second steps.
+use chrono::Utc;
+use data_encoding::BASE32_NOPAD;
+use domain::entities::two_factor::TwoFactor;
+use domain::interfaces::i_two_factor_repository::ITwoFactorRepository;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+const ISSUER: &str = "RainbowBid";
+const DIGITS: u32 = 6;
+const STEP_SECONDS: i64 = 30;
+/// Codes of the neighbouring steps are accepted too, to forgive clock drift.
+const ALLOWED_DRIFT_STEPS: i64 = 1;
+const RECOVERY_CODE_COUNT: usize = 10;
+
+/// A random 160 bit secret, base32 encoded.
+pub(crate) fn generate_secret() -> String {
+    let mut bytes = [0u8; 20];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    BASE32_NOPAD.encode(&bytes)
+}
+
+/// The `otpauth://` URI authenticator apps read from a QR code.
+pub(crate) fn provisioning_uri(secret: &str, account: &str) -> String {
+    format!(
+        "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={DIGITS}&period={STEP_SECONDS}",
+        issuer = ISSUER,
+        account = encode_label(account),
+    )
+}
+
+/// Returns the time step `code` is valid for at `timestamp`, if any.
+pub(crate) fn verify_code(secret: &str, code: &str, timestamp: i64) -> Option<i64> {
+    let secret = BASE32_NOPAD.decode(secret.as_bytes()).ok()?;
+    let code = code.trim().parse::<u32>().ok()?;
+    let step = timestamp / STEP_SECONDS;
+
+    (step - ALLOWED_DRIFT_STEPS..=step + ALLOWED_DRIFT_STEPS)
+        .find(|&candidate| candidate >= 0 && hotp(&secret, candidate as u64, DIGITS) == code)
+}
+
+/// Fresh recovery codes as shown to the user, e.g. `ABCD-EFGH`.
+pub(crate) fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let mut bytes = [0u8; 5];
+            rand::thread_rng().fill_bytes(&mut bytes);
+            let code = BASE32_NOPAD.encode(&bytes);
+            format!("{}-{}", &code[..4], &code[4..])
+        })
+        .collect()
+}
+
+/// Hashes a recovery code the way it is stored, ignoring case and separators.
+pub(crate) fn hash_recovery_code(code: &str) -> String {
+    let normalized = code
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_ascii_uppercase();
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+/// Accepts either a TOTP code, at most once per time step, or an unused recovery code, which is
+/// used up by it.
+pub(crate) async fn accept_code<R: ITwoFactorRepository>(
+    two_factor_repository: &R,
+    two_factor: &TwoFactor,
+    code: &str,
+) -> anyhow::Result<bool> {
+    if let Some(step) = verify_code(&two_factor.secret, code, Utc::now().timestamp()) {
+        return two_factor_repository
+            .record_used_step(two_factor.user_id.clone(), step)
+            .await;
+    }
+
+    two_factor_repository
+        .use_recovery_code(two_factor.user_id.clone(), hash_recovery_code(code))
+        .await
+}
+
+fn hotp(secret: &[u8], counter: u64, digits: u32) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let truncated = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    truncated % 10u32.pow(digits)
+}
+
+fn encode_label(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'@' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the SHA1 seed of RFC 6238, appendix B
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn given_rfc_test_vectors_when_computing_codes_then_they_match() {
+        assert_eq!(hotp(RFC_SECRET, 59 / 30, 8), 94287082);
+        assert_eq!(hotp(RFC_SECRET, 1111111109 / 30, 8), 7081804);
+        assert_eq!(hotp(RFC_SECRET, 1234567890 / 30, 8), 89005924);
+    }
+
+    #[test]
+    fn given_code_of_neighbouring_step_when_verifying_then_it_is_accepted_and_older_ones_are_not() {
+        // Arrange
+        let secret = BASE32_NOPAD.encode(RFC_SECRET);
+        let code = format!("{:06}", hotp(RFC_SECRET, 1111111109 / 30, DIGITS));
+
+        // Act & Assert
+        assert_eq!(
+            verify_code(&secret, &code, 1111111109),
+            Some(1111111109 / 30)
+        );
+        assert_eq!(
+            verify_code(&secret, &code, 1111111109 + 30),
+            Some(1111111109 / 30)
+        );
+        assert_eq!(verify_code(&secret, &code, 1111111109 + 90), None);
+        assert_eq!(verify_code(&secret, "not a code", 1111111109), None);
+    }
+
+    #[test]
+    fn given_recovery_code_with_other_formatting_when_hashing_then_hash_is_the_same() {
+        assert_eq!(
+            hash_recovery_code("ABCD-EFGH"),
+            hash_recovery_code("abcd efgh")
+        );
+    }
+}
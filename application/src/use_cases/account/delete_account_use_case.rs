@@ -0,0 +1,144 @@
+use domain::app_error::AppError;
+use domain::entities::account::AccountDeletion;
+use domain::entities::user::User;
+use domain::interfaces::i_account_repository::IAccountRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct DeleteAccountRequest {
+        /// Required unless the user only ever signed in through an identity provider.
+        pub password: Option<String>,
+    }
+}
+
+pub struct DeleteAccountUseCase<R: IAccountRepository> {
+    account_repository: Arc<R>,
+}
+
+impl<R: IAccountRepository> DeleteAccountUseCase<R> {
+    pub fn new(account_repository: Arc<R>) -> Self {
+        Self { account_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        dto: dtos::DeleteAccountRequest,
+    ) -> Result<(), AppError> {
+        info!("Deleting account of user {}", current_user.id);
+
+        if let Some(password) = current_user.password.as_deref() {
+            let matches =
+                bcrypt::verify(dto.password.unwrap_or_default(), password).map_err(|_| {
+                    error!("Failed to verify password");
+                    AppError::BadPassword()
+                })?;
+            if !matches {
+                error!("Bad password.");
+                return Err(AppError::BadPassword());
+            }
+        }
+
+        let deletion = self
+            .account_repository
+            .delete(current_user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to delete account: {:?}", e);
+                AppError::FailedToDeleteAccount()
+            })?;
+
+        match deletion {
+            AccountDeletion::Deleted => Ok(()),
+            AccountDeletion::BlockedByLiveAuctions => {
+                error!("User {} still has live auctions.", current_user.id);
+                Err(AppError::AccountHasLiveAuctions())
+            }
+            AccountDeletion::BlockedByLeadingBids => {
+                error!("User {} still holds leading bids.", current_user.id);
+                Err(AppError::AccountHasLeadingBids())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_account_repository::MockIAccountRepository;
+
+    fn user() -> User {
+        User::new(
+            "username".to_string(),
+            "email".to_string(),
+            bcrypt::hash("Password1!", 4).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn given_wrong_password_when_executing_then_account_is_not_deleted() {
+        // Arrange
+        let mut account_repository = MockIAccountRepository::new();
+        account_repository.expect_delete().times(0);
+
+        let use_case = DeleteAccountUseCase::new(Arc::new(account_repository));
+        let dto = dtos::DeleteAccountRequest {
+            password: Some("Wrong1!".to_string()),
+        };
+
+        // Act
+        let result = use_case.execute(user(), dto).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::BadPassword())));
+    }
+
+    #[tokio::test]
+    async fn given_leading_bids_when_executing_then_conflict_error_is_returned() {
+        // Arrange
+        let mut account_repository = MockIAccountRepository::new();
+        account_repository
+            .expect_delete()
+            .times(1)
+            .returning(|_| Ok(AccountDeletion::BlockedByLeadingBids));
+
+        let use_case = DeleteAccountUseCase::new(Arc::new(account_repository));
+        let dto = dtos::DeleteAccountRequest {
+            password: Some("Password1!".to_string()),
+        };
+
+        // Act
+        let result = use_case.execute(user(), dto).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::AccountHasLeadingBids())));
+    }
+
+    #[tokio::test]
+    async fn given_user_without_password_when_executing_then_account_is_deleted() {
+        // Arrange
+        let mut account_repository = MockIAccountRepository::new();
+        account_repository
+            .expect_delete()
+            .times(1)
+            .returning(|_| Ok(AccountDeletion::Deleted));
+
+        let use_case = DeleteAccountUseCase::new(Arc::new(account_repository));
+        let current_user = User {
+            password: None,
+            ..user()
+        };
+        let dto = dtos::DeleteAccountRequest { password: None };
+
+        // Act
+        let result = use_case.execute(current_user, dto).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,353 @@
+use crate::images::load_image_data;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_account_repository::IAccountRepository;
+use domain::interfaces::i_blob_store::IBlobStore;
+use domain::interfaces::i_item_image_repository::IItemImageRepository;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+pub mod dtos {
+    use crate::use_cases::user::get_user_events_use_case::dtos::UserEventDto;
+    use axum::http::{header, StatusCode};
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use data_encoding::BASE64;
+    use domain::entities::account::AccountExport;
+    use domain::entities::auction::AuctionWithItem;
+    use domain::entities::bid::Bid;
+    use domain::entities::item::Item;
+    use domain::entities::item_image::ItemImage;
+    use domain::entities::rating::{Rating, Sale};
+    use domain::entities::session::Session;
+    use domain::entities::user::{Role, User};
+    use domain::entities::user_profile::UserProfile;
+    use serde::Serialize;
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedUserDto {
+        pub id: String,
+        pub username: String,
+        pub email: String,
+        pub email_verified: bool,
+        pub role: Role,
+        pub suspended_at: Option<i64>,
+    }
+
+    impl From<User> for ExportedUserDto {
+        fn from(user: User) -> Self {
+            Self {
+                id: user.id.to_string(),
+                username: user.name,
+                email: user.email,
+                email_verified: user.email_verified,
+                role: user.role,
+                suspended_at: user.suspended_at.map(|date| date.timestamp()),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedProfileDto {
+        pub display_name: Option<String>,
+        pub bio: Option<String>,
+        pub location: Option<String>,
+        /// Base64 encoded.
+        pub avatar: Option<String>,
+        pub updated_at: i64,
+    }
+
+    impl From<UserProfile> for ExportedProfileDto {
+        fn from(profile: UserProfile) -> Self {
+            Self {
+                display_name: profile.display_name,
+                bio: profile.bio,
+                location: profile.location,
+                avatar: profile.avatar.map(|avatar| BASE64.encode(&avatar)),
+                updated_at: profile.updated_at.timestamp(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedItemDto {
+        pub id: String,
+        pub brief: String,
+        pub description: String,
+        pub category: String,
+    }
+
+    impl From<Item> for ExportedItemDto {
+        fn from(item: Item) -> Self {
+            Self {
+                id: item.id.to_string(),
+                brief: item.brief,
+                description: item.description,
+                category: item.category.into(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedItemImageDto {
+        pub item_id: String,
+        pub position: i32,
+        pub content_type: String,
+        /// Base64 encoded.
+        pub data: String,
+    }
+
+    impl From<(ItemImage, Vec<u8>)> for ExportedItemImageDto {
+        fn from((image, data): (ItemImage, Vec<u8>)) -> Self {
+            Self {
+                item_id: image.item_id.to_string(),
+                position: image.position,
+                content_type: image.content_type,
+                data: BASE64.encode(&data),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedBidDto {
+        pub id: String,
+        pub auction_id: String,
+        pub value: f32,
+    }
+
+    impl From<Bid> for ExportedBidDto {
+        fn from(bid: Bid) -> Self {
+            Self {
+                id: bid.id.to_string(),
+                auction_id: bid.auction_id.to_string(),
+                value: bid.value,
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedAuctionDto {
+        pub id: String,
+        pub item_id: String,
+        pub starting_price: f32,
+        pub end_date: i64,
+        pub strategy: String,
+    }
+
+    impl From<AuctionWithItem> for ExportedAuctionDto {
+        fn from(auction: AuctionWithItem) -> Self {
+            Self {
+                id: auction.id.to_string(),
+                item_id: auction.item_id.to_string(),
+                starting_price: auction.starting_price,
+                end_date: auction.end_date.timestamp(),
+                strategy: auction.strategy.into(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedSaleDto {
+        pub id: String,
+        pub auction_id: String,
+        pub item_id: String,
+        pub seller_id: String,
+        pub buyer_id: String,
+        pub completed_at: i64,
+    }
+
+    impl From<Sale> for ExportedSaleDto {
+        fn from(sale: Sale) -> Self {
+            Self {
+                id: sale.id.to_string(),
+                auction_id: sale.auction_id.to_string(),
+                item_id: sale.item_id.to_string(),
+                seller_id: sale.seller_id.to_string(),
+                buyer_id: sale.buyer_id.to_string(),
+                completed_at: sale.completed_at.timestamp(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedRatingDto {
+        pub id: String,
+        pub sale_id: String,
+        pub rater_id: String,
+        pub ratee_id: String,
+        pub role: String,
+        pub score: i16,
+        pub comment: Option<String>,
+        pub created_at: i64,
+    }
+
+    impl From<Rating> for ExportedRatingDto {
+        fn from(rating: Rating) -> Self {
+            Self {
+                id: rating.id.to_string(),
+                sale_id: rating.sale_id.to_string(),
+                rater_id: rating.rater_id.to_string(),
+                ratee_id: rating.ratee_id.to_string(),
+                role: rating.ratee_role.into(),
+                score: rating.score,
+                comment: rating.comment,
+                created_at: rating.created_at.timestamp(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ExportedSessionDto {
+        pub id: String,
+        pub user_agent: Option<String>,
+        pub ip_address: Option<String>,
+        pub created_at: i64,
+        pub last_seen_at: i64,
+        pub revoked_at: Option<i64>,
+    }
+
+    impl From<Session> for ExportedSessionDto {
+        fn from(session: Session) -> Self {
+            Self {
+                id: session.id.to_string(),
+                user_agent: session.user_agent,
+                ip_address: session.ip_address,
+                created_at: session.created_at.timestamp(),
+                last_seen_at: session.last_seen_at.timestamp(),
+                revoked_at: session.revoked_at.map(|date| date.timestamp()),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct AccountExportDto {
+        pub user: ExportedUserDto,
+        pub profile: Option<ExportedProfileDto>,
+        pub items: Vec<ExportedItemDto>,
+        pub item_images: Vec<ExportedItemImageDto>,
+        pub bids: Vec<ExportedBidDto>,
+        pub auctions: Vec<ExportedAuctionDto>,
+        pub sales: Vec<ExportedSaleDto>,
+        pub ratings_given: Vec<ExportedRatingDto>,
+        pub ratings_received: Vec<ExportedRatingDto>,
+        pub events: Vec<UserEventDto>,
+        pub sessions: Vec<ExportedSessionDto>,
+    }
+
+    impl From<(AccountExport, Vec<(ItemImage, Vec<u8>)>)> for AccountExportDto {
+        fn from((export, item_images): (AccountExport, Vec<(ItemImage, Vec<u8>)>)) -> Self {
+            Self {
+                user: export.user.into(),
+                profile: export.profile.map(ExportedProfileDto::from),
+                items: export
+                    .items
+                    .into_iter()
+                    .map(ExportedItemDto::from)
+                    .collect(),
+                item_images: item_images
+                    .into_iter()
+                    .map(ExportedItemImageDto::from)
+                    .collect(),
+                bids: export.bids.into_iter().map(ExportedBidDto::from).collect(),
+                auctions: export
+                    .auctions
+                    .into_iter()
+                    .map(ExportedAuctionDto::from)
+                    .collect(),
+                sales: export
+                    .sales
+                    .into_iter()
+                    .map(ExportedSaleDto::from)
+                    .collect(),
+                ratings_given: export
+                    .ratings_given
+                    .into_iter()
+                    .map(ExportedRatingDto::from)
+                    .collect(),
+                ratings_received: export
+                    .ratings_received
+                    .into_iter()
+                    .map(ExportedRatingDto::from)
+                    .collect(),
+                events: export.events.into_iter().map(UserEventDto::from).collect(),
+                sessions: export
+                    .sessions
+                    .into_iter()
+                    .map(ExportedSessionDto::from)
+                    .collect(),
+            }
+        }
+    }
+
+    impl IntoResponse for AccountExportDto {
+        fn into_response(self) -> Response {
+            (
+                StatusCode::OK,
+                [(
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"account-export.json\"",
+                )],
+                Json(self),
+            )
+                .into_response()
+        }
+    }
+}
+
+pub struct ExportAccountUseCase<R1: IAccountRepository, R2: IItemImageRepository, R3: IBlobStore> {
+    account_repository: Arc<R1>,
+    item_image_repository: Arc<R2>,
+    blob_store: Arc<R3>,
+}
+
+impl<R1: IAccountRepository, R2: IItemImageRepository, R3: IBlobStore>
+    ExportAccountUseCase<R1, R2, R3>
+{
+    pub fn new(
+        account_repository: Arc<R1>,
+        item_image_repository: Arc<R2>,
+        blob_store: Arc<R3>,
+    ) -> Self {
+        Self {
+            account_repository,
+            item_image_repository,
+            blob_store,
+        }
+    }
+
+    pub async fn execute(&self, current_user: User) -> Result<dtos::AccountExportDto, AppError> {
+        info!("Exporting account data of user {}", current_user.id);
+
+        let mut export = self
+            .account_repository
+            .find_export(current_user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to export account: {:?}", e);
+                AppError::FailedToExportAccount()
+            })?
+            .ok_or(AppError::FailedToExportAccount())?;
+
+        let mut item_images = Vec::new();
+        for image in std::mem::take(&mut export.item_images) {
+            let data = load_image_data(
+                self.blob_store.as_ref(),
+                self.item_image_repository.as_ref(),
+                &image,
+            )
+            .await
+            .map_err(|e| {
+                error!("Failed to export account: {:?}", e);
+                AppError::FailedToExportAccount()
+            })?;
+
+            match data {
+                Some(data) => item_images.push((image, data)),
+                // deleted while the export was put together
+                None => warn!("Image {} has no stored data, leaving it out", image.id),
+            }
+        }
+
+        Ok(dtos::AccountExportDto::from((export, item_images)))
+    }
+}
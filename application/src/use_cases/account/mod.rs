@@ -0,0 +1,2 @@
+pub mod delete_account_use_case;
+pub mod export_account_use_case;
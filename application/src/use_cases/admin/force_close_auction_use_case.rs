@@ -0,0 +1,188 @@
+use crate::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
+use domain::app_error::AppError;
+use domain::entities::auction::{Auction, Finalization};
+use domain::entities::auction_event::AuctionEvent;
+use domain::entities::email::Email;
+use domain::entities::user::User;
+use domain::entities::webhook::WebhookEvent;
+use domain::id::Id;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct CloseAuctionRequest {
+        #[validate(length(
+            min = 1,
+            max = 500,
+            message = "Reason must be between 1 and 500 characters"
+        ))]
+        pub reason: String,
+    }
+}
+
+/// Ends an auction early without a winner. The item stays with its owner and the bids are
+/// discarded.
+pub struct ForceCloseAuctionUseCase<R: IAuctionRepository, B: IAuctionEventBroadcaster> {
+    auction_repository: Arc<R>,
+    auction_event_broadcaster: Arc<B>,
+}
+
+impl<R: IAuctionRepository, B: IAuctionEventBroadcaster> ForceCloseAuctionUseCase<R, B> {
+    pub fn new(auction_repository: Arc<R>, auction_event_broadcaster: Arc<B>) -> Self {
+        Self {
+            auction_repository,
+            auction_event_broadcaster,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        id: String,
+        dto: dtos::CloseAuctionRequest,
+    ) -> Result<(), AppError> {
+        info!(
+            "User {} force-closes auction {}: {}",
+            current_user.id, id, dto.reason
+        );
+
+        let auction_id = Id::<Auction>::try_from(id.clone())
+            .map_err(|_| AppError::NoAuctionFoundForId(id.clone()))?;
+        let auction = self
+            .auction_repository
+            .find_by_id(auction_id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find auction: {:?}", e);
+                AppError::FailedToCloseAuction()
+            })?
+            .ok_or_else(|| AppError::NoAuctionFoundForId(id.clone()))?;
+
+        let finalization = Finalization {
+            new_owner_id: None,
+            jobs: vec![
+                WebhookEvent::auction_finalized(&auction, None).into_job(),
+                Email::auction_closed_by_moderator(&auction, &dto.reason).into_job(),
+            ],
+            notifications: vec![],
+            user_events: vec![],
+        };
+        let closed = self
+            .auction_repository
+            .finalize_auction(auction_id.clone(), Box::new(move |_| finalization))
+            .await
+            .map_err(|e| {
+                error!("Failed to close auction: {:?}", e);
+                AppError::FailedToCloseAuction()
+            })?;
+
+        // finalized in the meantime, either because it ended or by another moderator, or a bid is
+        // being placed right now
+        if closed.is_none() {
+            return Err(AppError::NoAuctionFoundForId(id));
+        }
+
+        if let Err(e) = self
+            .auction_event_broadcaster
+            .publish(AuctionEvent::Finalized {
+                auction_id,
+                winner_id: None,
+                winning_bid: None,
+            })
+            .await
+        {
+            error!("Failed to publish auction finalized event: {:?}", e);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::broadcasters::i_auction_event_broadcaster::MockIAuctionEventBroadcaster;
+    use chrono::Utc;
+    use domain::entities::auction::{AuctionStrategy, AuctionWithItem};
+    use domain::entities::item::Category;
+    use domain::entities::user::Role;
+    use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+
+    #[tokio::test]
+    async fn given_ongoing_auction_when_executing_then_auction_is_finalized_without_winner() {
+        // Arrange
+        let auction = AuctionWithItem::new(
+            Id::gen(),
+            Id::gen(),
+            1.0,
+            Utc::now() + chrono::Duration::minutes(10),
+            "brief".to_string(),
+            "description".to_string(),
+            Category::Art,
+            Id::gen(),
+            AuctionStrategy::Standard,
+        );
+        let auction_id = auction.id.to_string();
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+        auction_repository
+            .expect_finalize_auction()
+            .times(1)
+            .returning(|_, plan| {
+                let finalization = plan(&[]);
+                assert!(finalization.new_owner_id.is_none());
+                assert!(finalization
+                    .jobs
+                    .iter()
+                    .any(|job| job.kind == Email::JOB_KIND));
+                assert!(finalization
+                    .jobs
+                    .iter()
+                    .any(|job| job.kind == WebhookEvent::JOB_KIND));
+                Ok(Some(vec![]))
+            });
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster
+            .expect_publish()
+            .withf(|event| {
+                matches!(
+                    event,
+                    AuctionEvent::Finalized {
+                        winner_id: None,
+                        ..
+                    }
+                )
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = ForceCloseAuctionUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(auction_event_broadcaster),
+        );
+        let mut moderator = User::new(
+            "moderator".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        moderator.role = Role::Moderator;
+        let dto = dtos::CloseAuctionRequest {
+            reason: "Counterfeit item".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(moderator, auction_id, dto).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,68 @@
+use domain::app_error::AppError;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+use tracing::error;
+
+pub mod dtos {
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::user::{Role, User};
+    use serde::Serialize;
+
+    #[derive(Serialize, Debug)]
+    pub struct UserDto {
+        pub id: String,
+        pub username: String,
+        pub email: String,
+        pub email_verified: bool,
+        pub role: Role,
+        pub suspended_at: Option<i64>,
+    }
+
+    impl From<User> for UserDto {
+        fn from(user: User) -> Self {
+            Self {
+                id: user.id.to_string(),
+                username: user.name,
+                email: user.email,
+                email_verified: user.email_verified,
+                role: user.role,
+                suspended_at: user
+                    .suspended_at
+                    .map(|suspended_at| suspended_at.timestamp()),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct GetUsersDto {
+        pub users: Vec<UserDto>,
+    }
+
+    impl IntoResponse for GetUsersDto {
+        fn into_response(self) -> Response {
+            Json(self).into_response()
+        }
+    }
+}
+
+pub struct GetUsersUseCase<R: IUserRepository> {
+    user_repository: Arc<R>,
+}
+
+impl<R: IUserRepository> GetUsersUseCase<R> {
+    pub fn new(user_repository: Arc<R>) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(&self) -> Result<dtos::GetUsersDto, AppError> {
+        let users = self.user_repository.find_all().await.map_err(|e| {
+            error!("Failed to get users: {:?}", e);
+            AppError::FailedToGetUsers()
+        })?;
+
+        Ok(dtos::GetUsersDto {
+            users: users.into_iter().map(dtos::UserDto::from).collect(),
+        })
+    }
+}
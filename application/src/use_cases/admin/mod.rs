@@ -0,0 +1,3 @@
+pub mod force_close_auction_use_case;
+pub mod get_users_use_case;
+pub mod suspend_user_use_case;
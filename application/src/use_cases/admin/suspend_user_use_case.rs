@@ -0,0 +1,137 @@
+use domain::app_error::AppError;
+use domain::entities::user::{Role, User};
+use domain::id::Id;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct SuspendUserUseCase<R1: IUserRepository, R2: ISessionRepository> {
+    user_repository: Arc<R1>,
+    session_repository: Arc<R2>,
+}
+
+impl<R1: IUserRepository, R2: ISessionRepository> SuspendUserUseCase<R1, R2> {
+    pub fn new(user_repository: Arc<R1>, session_repository: Arc<R2>) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+        }
+    }
+
+    pub async fn execute(&self, current_user: User, id: String) -> Result<(), AppError> {
+        info!("User {} suspends user {}", current_user.id, id);
+
+        let user_id =
+            Id::<User>::try_from(id.clone()).map_err(|_| AppError::UserNotFound(id.clone()))?;
+        let user = self
+            .user_repository
+            .find(user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find user: {:?}", e);
+                AppError::FailedToSuspendUser()
+            })?
+            .ok_or_else(|| AppError::UserNotFound(id.clone()))?;
+
+        // admins are managed in the database, so one cannot lock the others out
+        if user.has_role(Role::Admin) {
+            return Err(AppError::CannotSuspendAdmin(id));
+        }
+
+        let suspended = self
+            .user_repository
+            .suspend(user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to suspend user: {:?}", e);
+                AppError::FailedToSuspendUser()
+            })?;
+        if !suspended {
+            info!("User {} is already suspended", id);
+        }
+
+        // also run for an already suspended user in case an earlier attempt stopped here
+        self.session_repository
+            .revoke_all_for_user(user.id, None)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke sessions of suspended user: {:?}", e);
+                AppError::FailedToSuspendUser()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_session_repository::MockISessionRepository;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+
+    fn user(role: Role) -> User {
+        let mut user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        user.role = role;
+        user
+    }
+
+    #[tokio::test]
+    async fn given_regular_user_when_executing_then_user_is_suspended_and_logged_out() {
+        // Arrange
+        let suspended_user = user(Role::User);
+        let suspended_user_id = suspended_user.id.clone();
+        let id = suspended_user.id.to_string();
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(suspended_user.clone())));
+        user_repository
+            .expect_suspend()
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let mut session_repository = MockISessionRepository::new();
+        session_repository
+            .expect_revoke_all_for_user()
+            .withf(move |user_id, except| *user_id == suspended_user_id && except.is_none())
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case =
+            SuspendUserUseCase::new(Arc::new(user_repository), Arc::new(session_repository));
+
+        // Act
+        let result = use_case.execute(user(Role::Admin), id).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_admin_when_executing_then_cannot_suspend_admin_error_is_returned() {
+        // Arrange
+        let admin = user(Role::Admin);
+        let id = admin.id.to_string();
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(admin.clone())));
+        user_repository.expect_suspend().times(0);
+
+        let use_case = SuspendUserUseCase::new(
+            Arc::new(user_repository),
+            Arc::new(MockISessionRepository::new()),
+        );
+
+        // Act
+        let result = use_case.execute(user(Role::Admin), id).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::CannotSuspendAdmin(_))));
+    }
+}
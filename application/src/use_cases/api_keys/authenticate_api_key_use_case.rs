@@ -0,0 +1,183 @@
+use domain::app_error::AppError;
+use domain::entities::api_key::{ApiKey, ApiKeyScope};
+use domain::entities::user::User;
+use domain::interfaces::i_api_key_repository::IApiKeyRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// API keys are random, so a fast unsalted hash is enough to look them up by.
+pub(crate) fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+pub struct AuthenticateApiKeyUseCase<R1: IApiKeyRepository, R2: IUserRepository> {
+    api_key_repository: Arc<R1>,
+    user_repository: Arc<R2>,
+}
+
+impl<R1: IApiKeyRepository, R2: IUserRepository> AuthenticateApiKeyUseCase<R1, R2> {
+    pub fn new(api_key_repository: Arc<R1>, user_repository: Arc<R2>) -> Self {
+        Self {
+            api_key_repository,
+            user_repository,
+        }
+    }
+
+    /// `required_scope` is the scope of the route, routes without one are closed to API keys.
+    pub async fn execute(
+        &self,
+        key: &str,
+        required_scope: Option<ApiKeyScope>,
+    ) -> Result<(User, ApiKey), AppError> {
+        let api_key = self
+            .api_key_repository
+            .find_by_hash(hash_api_key(key))
+            .await
+            .map_err(|e| {
+                error!("Failed to find API key: {:?}", e);
+                AppError::InvalidApiKey()
+            })?
+            .filter(|api_key| api_key.is_active())
+            .ok_or(AppError::InvalidApiKey())?;
+
+        let Some(required_scope) = required_scope else {
+            error!("API key {} used on a route without a scope.", api_key.id);
+            return Err(AppError::ApiKeyNotAllowed());
+        };
+        if !api_key.has_scope(required_scope) {
+            error!(
+                "API key {} lacks the {:?} scope.",
+                api_key.id, required_scope
+            );
+            return Err(AppError::MissingApiKeyScope(required_scope.into()));
+        }
+
+        let user = self
+            .user_repository
+            .find(api_key.user_id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find user of API key: {:?}", e);
+                AppError::InvalidApiKey()
+            })?
+            .ok_or(AppError::InvalidApiKey())?;
+        if user.is_suspended() {
+            error!("User {} is suspended.", user.id);
+            return Err(AppError::AccountSuspended());
+        }
+
+        // last use is informational, a failed update does not reject the request
+        if let Err(e) = self.api_key_repository.touch(api_key.id.clone()).await {
+            error!("Failed to update last use of API key: {:?}", e);
+        }
+
+        info!("User {} authorized with API key {}", user.name, api_key.id);
+        Ok((user, api_key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use domain::interfaces::i_api_key_repository::MockIApiKeyRepository;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+
+    const KEY: &str = "rb_0123456789abcdef";
+
+    fn user_and_api_key(scopes: Vec<ApiKeyScope>) -> (User, ApiKey) {
+        let user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let api_key = ApiKey::new(
+            user.id.clone(),
+            "bidding bot".to_string(),
+            KEY[..11].to_string(),
+            hash_api_key(KEY),
+            scopes,
+            None,
+        );
+        (user, api_key)
+    }
+
+    fn use_case(
+        api_key: ApiKey,
+        user: User,
+    ) -> AuthenticateApiKeyUseCase<MockIApiKeyRepository, MockIUserRepository> {
+        let mut api_key_repository = MockIApiKeyRepository::new();
+        api_key_repository
+            .expect_find_by_hash()
+            .returning(move |key_hash| {
+                Ok(Some(api_key.clone()).filter(|api_key| api_key.key_hash == key_hash))
+            });
+        api_key_repository.expect_touch().returning(|_| Ok(()));
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(user.clone())));
+
+        AuthenticateApiKeyUseCase::new(Arc::new(api_key_repository), Arc::new(user_repository))
+    }
+
+    #[tokio::test]
+    async fn given_key_with_required_scope_when_executing_then_owner_is_returned() {
+        // Arrange
+        let (user, api_key) = user_and_api_key(vec![ApiKeyScope::Read, ApiKeyScope::BidsWrite]);
+        let user_id = user.id.clone();
+        let use_case = use_case(api_key, user);
+
+        // Act
+        let result = use_case.execute(KEY, Some(ApiKeyScope::BidsWrite)).await;
+
+        // Assert
+        let (user, _) = result.unwrap();
+        assert_eq!(user.id, user_id);
+    }
+
+    #[tokio::test]
+    async fn given_key_without_required_scope_when_executing_then_missing_scope_is_returned() {
+        // Arrange
+        let (user, api_key) = user_and_api_key(vec![ApiKeyScope::Read]);
+        let use_case = use_case(api_key, user);
+
+        // Act
+        let result = use_case.execute(KEY, Some(ApiKeyScope::ItemsWrite)).await;
+
+        // Assert
+        assert!(
+            matches!(result, Err(AppError::MissingApiKeyScope(scope)) if scope == "items:write")
+        );
+    }
+
+    #[tokio::test]
+    async fn given_route_without_scope_when_executing_then_api_key_not_allowed_is_returned() {
+        // Arrange
+        let (user, api_key) = user_and_api_key(vec![ApiKeyScope::Read]);
+        let use_case = use_case(api_key, user);
+
+        // Act
+        let result = use_case.execute(KEY, None).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::ApiKeyNotAllowed())));
+    }
+
+    #[tokio::test]
+    async fn given_expired_key_when_executing_then_invalid_api_key_is_returned() {
+        // Arrange
+        let (user, mut api_key) = user_and_api_key(vec![ApiKeyScope::Read]);
+        api_key.expires_at = Some(Utc::now() - Duration::minutes(1));
+        let use_case = use_case(api_key, user);
+
+        // Act
+        let result = use_case.execute(KEY, Some(ApiKeyScope::Read)).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidApiKey())));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::use_cases::api_keys::authenticate_api_key_use_case::hash_api_key;
+use chrono::{Duration, Utc};
+use domain::app_error::AppError;
+use domain::entities::api_key::{ApiKey, ApiKeyScope};
+use domain::entities::user::User;
+use domain::interfaces::i_api_key_repository::IApiKeyRepository;
+use rand::RngCore;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const API_KEY_PREFIX: &str = "rb_";
+const LISTED_PREFIX_LENGTH: usize = 8;
+
+pub mod dtos {
+    use crate::use_cases::api_keys::get_api_keys_use_case::dtos::ApiKeyDto;
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use serde::{Deserialize, Serialize};
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct CreateApiKeyRequest {
+        #[validate(length(min = 1, max = 64, message = "Name must be 1 to 64 characters long"))]
+        pub name: String,
+        #[validate(length(min = 1, message = "At least one scope is required"))]
+        pub scopes: Vec<String>,
+        #[validate(range(min = 1, max = 365, message = "Expiry must be 1 to 365 days"))]
+        pub expires_in_days: Option<i64>,
+    }
+
+    /// The only response that carries the key itself.
+    #[derive(Serialize, Debug)]
+    pub struct CreatedApiKeyDto {
+        pub key: String,
+        #[serde(flatten)]
+        pub api_key: ApiKeyDto,
+    }
+
+    impl IntoResponse for CreatedApiKeyDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct CreateApiKeyUseCase<R: IApiKeyRepository> {
+    api_key_repository: Arc<R>,
+}
+
+impl<R: IApiKeyRepository> CreateApiKeyUseCase<R> {
+    pub fn new(api_key_repository: Arc<R>) -> Self {
+        Self { api_key_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        request: dtos::CreateApiKeyRequest,
+    ) -> Result<dtos::CreatedApiKeyDto, AppError> {
+        info!("Creating API key for user with id {}", current_user.id);
+
+        let mut scopes = Vec::new();
+        for scope in request.scopes {
+            let scope = ApiKeyScope::try_from(scope.clone())
+                .map_err(|_| AppError::InvalidApiKeyScope(scope))?;
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let key = format!("{}{}", API_KEY_PREFIX, hex::encode(bytes));
+
+        let api_key = ApiKey::new(
+            current_user.id,
+            request.name,
+            key[..API_KEY_PREFIX.len() + LISTED_PREFIX_LENGTH].to_string(),
+            hash_api_key(&key),
+            scopes,
+            request
+                .expires_in_days
+                .map(|days| Utc::now() + Duration::days(days)),
+        );
+
+        self.api_key_repository
+            .insert(api_key.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to create API key: {:?}", e);
+                AppError::FailedToCreateApiKey()
+            })?;
+
+        Ok(dtos::CreatedApiKeyDto {
+            key,
+            api_key: api_key.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_api_key_repository::MockIApiKeyRepository;
+
+    fn current_user() -> User {
+        User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn given_valid_request_when_executing_then_only_hash_of_returned_key_is_stored() {
+        // Arrange
+        let mut api_key_repository = MockIApiKeyRepository::new();
+        api_key_repository
+            .expect_insert()
+            .withf(|api_key| {
+                api_key.scopes == vec![ApiKeyScope::Read, ApiKeyScope::BidsWrite]
+                    && api_key.expires_at.is_some()
+                    && api_key.prefix.starts_with(API_KEY_PREFIX)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = CreateApiKeyUseCase::new(Arc::new(api_key_repository));
+        let request = dtos::CreateApiKeyRequest {
+            name: "bidding bot".to_string(),
+            scopes: vec![
+                "read".to_string(),
+                "bids:write".to_string(),
+                "read".to_string(),
+            ],
+            expires_in_days: Some(30),
+        };
+
+        // Act
+        let result = use_case.execute(current_user(), request).await;
+
+        // Assert
+        let created = result.unwrap();
+        assert!(created.key.starts_with(&created.api_key.prefix));
+        assert_eq!(created.api_key.scopes, vec!["read", "bids:write"]);
+    }
+
+    #[tokio::test]
+    async fn given_unknown_scope_when_executing_then_invalid_api_key_scope_is_returned() {
+        // Arrange
+        let mut api_key_repository = MockIApiKeyRepository::new();
+        api_key_repository.expect_insert().times(0);
+
+        let use_case = CreateApiKeyUseCase::new(Arc::new(api_key_repository));
+        let request = dtos::CreateApiKeyRequest {
+            name: "bidding bot".to_string(),
+            scopes: vec!["admin".to_string()],
+            expires_in_days: None,
+        };
+
+        // Act
+        let result = use_case.execute(current_user(), request).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidApiKeyScope(scope)) if scope == "admin"));
+    }
+}
@@ -0,0 +1,79 @@
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_api_key_repository::IApiKeyRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::api_key::ApiKey;
+    use serde::Serialize;
+
+    /// Only the prefix of the key is listed, the key itself cannot be recovered.
+    #[derive(Serialize, Debug)]
+    pub struct ApiKeyDto {
+        pub id: String,
+        pub name: String,
+        pub prefix: String,
+        pub scopes: Vec<String>,
+        pub expires_at: Option<i64>,
+        pub last_used_at: Option<i64>,
+        pub created_at: i64,
+    }
+
+    impl From<ApiKey> for ApiKeyDto {
+        fn from(api_key: ApiKey) -> Self {
+            ApiKeyDto {
+                id: api_key.id.to_string(),
+                name: api_key.name,
+                prefix: api_key.prefix,
+                scopes: api_key.scopes.into_iter().map(String::from).collect(),
+                expires_at: api_key.expires_at.map(|expires_at| expires_at.timestamp()),
+                last_used_at: api_key
+                    .last_used_at
+                    .map(|last_used_at| last_used_at.timestamp()),
+                created_at: api_key.created_at.timestamp(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct GetAllApiKeysDto {
+        pub api_keys: Vec<ApiKeyDto>,
+    }
+
+    impl IntoResponse for GetAllApiKeysDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct GetApiKeysUseCase<R: IApiKeyRepository> {
+    api_key_repository: Arc<R>,
+}
+
+impl<R: IApiKeyRepository> GetApiKeysUseCase<R> {
+    pub fn new(api_key_repository: Arc<R>) -> Self {
+        Self { api_key_repository }
+    }
+
+    pub async fn execute(&self, current_user: User) -> Result<dtos::GetAllApiKeysDto, AppError> {
+        info!("Getting API keys of user with id {}", current_user.id);
+
+        let api_keys = self
+            .api_key_repository
+            .find_all_by_user_id(current_user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to get API keys: {:?}", e);
+                AppError::FailedToGetApiKeys()
+            })?;
+
+        Ok(dtos::GetAllApiKeysDto {
+            api_keys: api_keys.into_iter().map(dtos::ApiKeyDto::from).collect(),
+        })
+    }
+}
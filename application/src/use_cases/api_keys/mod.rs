@@ -0,0 +1,4 @@
+pub mod authenticate_api_key_use_case;
+pub mod create_api_key_use_case;
+pub mod get_api_keys_use_case;
+pub mod revoke_api_key_use_case;
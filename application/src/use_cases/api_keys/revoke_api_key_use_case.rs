@@ -0,0 +1,40 @@
+use domain::app_error::AppError;
+use domain::entities::api_key::ApiKey;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_api_key_repository::IApiKeyRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct RevokeApiKeyUseCase<R: IApiKeyRepository> {
+    api_key_repository: Arc<R>,
+}
+
+impl<R: IApiKeyRepository> RevokeApiKeyUseCase<R> {
+    pub fn new(api_key_repository: Arc<R>) -> Self {
+        Self { api_key_repository }
+    }
+
+    pub async fn execute(&self, current_user: User, id: String) -> Result<(), AppError> {
+        info!("Revoking API key with id: {}", id);
+
+        let api_key_id =
+            Id::<ApiKey>::try_from(id.clone()).map_err(|_| AppError::ApiKeyNotFound(id.clone()))?;
+
+        // keys of other users are scoped out by the query and reported as missing
+        let revoked = self
+            .api_key_repository
+            .revoke(api_key_id, current_user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke API key: {:?}", e);
+                AppError::FailedToRevokeApiKey()
+            })?;
+
+        if !revoked {
+            return Err(AppError::ApiKeyNotFound(id));
+        }
+
+        Ok(())
+    }
+}
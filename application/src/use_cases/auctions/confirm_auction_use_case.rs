@@ -1,9 +1,11 @@
+use crate::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
 use domain::app_error::AppError;
-use domain::entities::auction::{Auction, AuctionStrategy};
+use domain::entities::auction::{Auction, AuctionStrategy, Finalization};
+use domain::entities::auction_event::AuctionEvent;
+use domain::entities::bid::BidWithUsername;
 use domain::entities::user::User;
 use domain::id::Id;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
-use domain::interfaces::i_item_repository::IItemRepository;
 use serde::Deserialize;
 use std::sync::Arc;
 use tracing::error;
@@ -13,16 +15,16 @@ pub struct AuctionConfirmationRequest {
     pub is_confirmed: bool,
 }
 
-pub struct ConfirmAuctionUseCase<R1: IAuctionRepository, R2: IItemRepository> {
+pub struct ConfirmAuctionUseCase<R1: IAuctionRepository, B: IAuctionEventBroadcaster> {
     auction_repository: Arc<R1>,
-    item_repository: Arc<R2>,
+    auction_event_broadcaster: Arc<B>,
 }
 
-impl<R1: IAuctionRepository, R2: IItemRepository> ConfirmAuctionUseCase<R1, R2> {
-    pub fn new(auction_repository: Arc<R1>, item_repository: Arc<R2>) -> Self {
+impl<R1: IAuctionRepository, B: IAuctionEventBroadcaster> ConfirmAuctionUseCase<R1, B> {
+    pub fn new(auction_repository: Arc<R1>, auction_event_broadcaster: Arc<B>) -> Self {
         Self {
             auction_repository,
-            item_repository,
+            auction_event_broadcaster,
         }
     }
 
@@ -66,44 +68,20 @@ impl<R1: IAuctionRepository, R2: IItemRepository> ConfirmAuctionUseCase<R1, R2>
             return Err(AppError::CannotConfirmAuctionIfUserIsNotOwner());
         }
 
-        match request.is_confirmed {
-            true => {
-                // Get all bids for auction
-                let bids = self
-                    .auction_repository
-                    .get_all_bids(auction_id.clone())
-                    .await
-                    .map_err(|_| {
-                        AppError::GetAuctionFailed(
-                            "Cannot handle expired auction for invalid auction_id".to_string(),
-                        )
-                    })?;
-
-                // If any bids, get the highest bid and update item with the new owner taken from the highest bid
-                let highest_bid = bids
-                    .iter()
-                    .max_by(|a, b| a.value.partial_cmp(&b.value).unwrap());
-
-                if let Some(highest_bid) = highest_bid {
-                    let item_id = auction.item_id.clone();
-                    let new_owner_id = highest_bid.user_id.clone();
-
-                    self.item_repository
-                        .change_owner(item_id, new_owner_id)
-                        .await
-                        .map_err(|_| {
-                            AppError::GetAuctionFailed(
-                                "Cannot handle expired auction for invalid auction_id".to_string(),
-                            )
-                        })?;
-                }
-            }
-            false => {}
-        }
-
-        // Delete auction bids
-        self.auction_repository
-            .delete_bids_for_auction(auction_id.clone())
+        // if confirmed, the highest bidder becomes the new owner of the item; emails, webhook
+        // events, notifications and user events are stored in the same transaction as the
+        // finalization
+        let is_confirmed = request.is_confirmed;
+        let plan_auction = auction.clone();
+        let bids = self
+            .auction_repository
+            .finalize_auction(
+                auction_id.clone(),
+                Box::new(move |bids| {
+                    let winner = BidWithUsername::winner(bids).filter(|_| is_confirmed);
+                    Finalization::new(&plan_auction, bids, winner)
+                }),
+            )
             .await
             .map_err(|_| {
                 AppError::GetAuctionFailed(
@@ -111,15 +89,27 @@ impl<R1: IAuctionRepository, R2: IItemRepository> ConfirmAuctionUseCase<R1, R2>
                 )
             })?;
 
-        // Delete auction
-        self.auction_repository
-            .delete_auction(auction_id.clone())
+        let Some(bids) = bids else {
+            error!(
+                "Auction with auction_id = {} is already finalized",
+                auction_id
+            );
+            return Err(AppError::AuctionConfirmationFailed());
+        };
+        let (winner_id, winning_bid) = BidWithUsername::winner(&bids)
+            .filter(|_| is_confirmed)
+            .unzip();
+        if let Err(e) = self
+            .auction_event_broadcaster
+            .publish(AuctionEvent::Finalized {
+                auction_id,
+                winner_id,
+                winning_bid,
+            })
             .await
-            .map_err(|_| {
-                AppError::GetAuctionFailed(
-                    "Cannot handle expired auction for invalid auction_id".to_string(),
-                )
-            })?;
+        {
+            error!("Failed to publish auction finalized event: {:?}", e);
+        }
 
         Ok(())
     }
@@ -1,7 +1,9 @@
+use crate::schedulers::auction_expiry_scheduler::AuctionExpiryScheduler;
 use anyhow::anyhow;
 use domain::app_error::AppError;
 use domain::entities::auction::Auction;
 use domain::entities::user::User;
+use domain::entities::webhook::WebhookEvent;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
 use domain::interfaces::i_item_repository::IItemRepository;
 use std::sync::Arc;
@@ -66,13 +68,19 @@ pub mod dtos {
 pub struct CreateAuctionUseCase<R1: IAuctionRepository, R2: IItemRepository> {
     auction_repository: Arc<R1>,
     item_repository: Arc<R2>,
+    auction_expiry_scheduler: Arc<AuctionExpiryScheduler>,
 }
 
 impl<R1: IAuctionRepository, R2: IItemRepository> CreateAuctionUseCase<R1, R2> {
-    pub fn new(auction_repository: Arc<R1>, item_repository: Arc<R2>) -> Self {
+    pub fn new(
+        auction_repository: Arc<R1>,
+        item_repository: Arc<R2>,
+        auction_expiry_scheduler: Arc<AuctionExpiryScheduler>,
+    ) -> Self {
         Self {
             auction_repository,
             item_repository,
+            auction_expiry_scheduler,
         }
     }
 
@@ -83,6 +91,10 @@ impl<R1: IAuctionRepository, R2: IItemRepository> CreateAuctionUseCase<R1, R2> {
     ) -> Result<(), AppError> {
         info!("Creating auction with item_id: {}", dto.item_id);
 
+        if !current_user.email_verified {
+            return Err(AppError::EmailNotVerified());
+        }
+
         let auction: Auction = dto.try_into()?;
 
         let item = match self.item_repository.find(auction.item_id.clone()).await {
@@ -127,8 +139,15 @@ impl<R1: IAuctionRepository, R2: IItemRepository> CreateAuctionUseCase<R1, R2> {
             ));
         }
 
-        match self.auction_repository.insert(auction).await {
-            Ok(Some(_)) => {
+        let auction_created = WebhookEvent::auction_created(&auction, current_user.id).into_job();
+        match self
+            .auction_repository
+            .insert(auction, vec![auction_created])
+            .await
+        {
+            Ok(Some(auction)) => {
+                self.auction_expiry_scheduler
+                    .schedule(auction.id, auction.end_date);
                 info!("Auction created successfully");
                 Ok(())
             }
@@ -141,26 +160,34 @@ impl<R1: IAuctionRepository, R2: IItemRepository> CreateAuctionUseCase<R1, R2> {
 
 #[cfg(test)]
 mod tests {
+    use crate::schedulers::auction_expiry_scheduler::AuctionExpiryScheduler;
     use crate::use_cases::auctions::create_auction_use_case::{dtos, CreateAuctionUseCase};
     use anyhow::anyhow;
     use chrono::Utc;
     use domain::entities::auction::{Auction, AuctionStrategy};
     use domain::entities::item::{Category, Item};
     use domain::entities::user::User;
+    use domain::entities::webhook::WebhookEvent;
     use domain::id::Id;
     use domain::interfaces::i_auction_repository::MockIAuctionRepository;
     use domain::interfaces::i_item_repository::MockIItemRepository;
     use std::sync::Arc;
     use uuid::Uuid;
 
-    #[tokio::test]
-    async fn given_valid_input_when_executing_then_auction_is_created() {
-        // Arrange
-        let current_user = User::new(
+    fn verified_user() -> User {
+        let mut user = User::new(
             "username".to_string(),
             "email".to_string(),
             "hashed_password".to_string(),
         );
+        user.email_verified = true;
+        user
+    }
+
+    #[tokio::test]
+    async fn given_valid_input_when_executing_then_auction_is_created() {
+        // Arrange
+        let current_user = verified_user();
 
         let item_id = Uuid::new_v4();
         let user_id = current_user.id.value;
@@ -176,7 +203,6 @@ mod tests {
                 Ok(Some(Item::new(
                     "brief".to_string(),
                     "description".to_string(),
-                    vec![0],
                     Id::try_from(user_id_clone.to_string()).unwrap(),
                     Category::Electronics,
                 )))
@@ -194,8 +220,12 @@ mod tests {
         let item_id_clone2 = item_id;
         auction_repository
             .expect_insert()
-            .withf(move |auction| auction.item_id.value == item_id_clone1)
-            .returning(move |_| {
+            .withf(move |auction, jobs| {
+                auction.item_id.value == item_id_clone1
+                    && jobs.len() == 1
+                    && jobs[0].kind == WebhookEvent::JOB_KIND
+            })
+            .returning(move |_, _| {
                 Ok(Some(Auction::new(
                     Id::try_from(item_id_clone2.to_string()).unwrap(),
                     0.0,
@@ -204,8 +234,11 @@ mod tests {
                 )))
             });
 
-        let use_case =
-            CreateAuctionUseCase::new(Arc::new(auction_repository), Arc::new(item_repository));
+        let use_case = CreateAuctionUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(item_repository),
+            Arc::new(AuctionExpiryScheduler::new()),
+        );
 
         let dto = dtos::CreateAuctionRequest {
             item_id: item_id.to_string(),
@@ -225,11 +258,7 @@ mod tests {
     async fn given_invalid_dto_for_non_existing_item_when_executing_then_cannot_create_auction_for_non_existing_item_is_returned(
     ) {
         // Arrange
-        let current_user = User::new(
-            "username".to_string(),
-            "email".to_string(),
-            "hashed_password".to_string(),
-        );
+        let current_user = verified_user();
 
         let item_id = Uuid::new_v4();
 
@@ -240,6 +269,7 @@ mod tests {
         let use_case = CreateAuctionUseCase::new(
             Arc::new(MockIAuctionRepository::new()),
             Arc::new(item_repository),
+            Arc::new(AuctionExpiryScheduler::new()),
         );
 
         let dto = dtos::CreateAuctionRequest {
@@ -265,11 +295,7 @@ mod tests {
     async fn given_invalid_dto_for_item_that_does_not_belong_to_current_user_when_executing_then_cannot_create_auction_for_item_that_does_not_belong_to_current_user_is_returned(
     ) {
         // Arrange
-        let current_user = User::new(
-            "username".to_string(),
-            "email".to_string(),
-            "hashed_password".to_string(),
-        );
+        let current_user = verified_user();
 
         let item_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
@@ -280,7 +306,6 @@ mod tests {
             Ok(Some(Item::new(
                 "brief".to_string(),
                 "description".to_string(),
-                vec![0],
                 Id::try_from(user_id.to_string()).unwrap(),
                 Category::Electronics,
             )))
@@ -289,6 +314,7 @@ mod tests {
         let use_case = CreateAuctionUseCase::new(
             Arc::new(MockIAuctionRepository::new()),
             Arc::new(item_repository),
+            Arc::new(AuctionExpiryScheduler::new()),
         );
 
         let dto = dtos::CreateAuctionRequest {
@@ -315,11 +341,7 @@ mod tests {
     async fn given_invalid_dto_for_item_with_ongoing_auction_when_executing_then_cannot_create_auction_for_item_with_ongoing_auction_is_returned(
     ) {
         // Arrange
-        let current_user = User::new(
-            "username".to_string(),
-            "email".to_string(),
-            "hashed_password".to_string(),
-        );
+        let current_user = verified_user();
 
         let item_id = Uuid::new_v4();
         let user_id = current_user.id.value;
@@ -335,7 +357,6 @@ mod tests {
                 Ok(Some(Item::new(
                     "brief".to_string(),
                     "description".to_string(),
-                    vec![0],
                     Id::try_from(user_id_clone.to_string()).unwrap(),
                     Category::Electronics,
                 )))
@@ -356,8 +377,11 @@ mod tests {
                 )))
             });
 
-        let use_case =
-            CreateAuctionUseCase::new(Arc::new(auction_repository), Arc::new(item_repository));
+        let use_case = CreateAuctionUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(item_repository),
+            Arc::new(AuctionExpiryScheduler::new()),
+        );
 
         let dto = dtos::CreateAuctionRequest {
             item_id: item_id.to_string(),
@@ -383,11 +407,7 @@ mod tests {
     #[tokio::test]
     async fn given_invalid_dto_when_executing_then_create_auction_failed_error_is_returned() {
         // Arrange
-        let current_user = User::new(
-            "username".to_string(),
-            "email".to_string(),
-            "hashed_password".to_string(),
-        );
+        let current_user = verified_user();
 
         let mut item_repository = MockIItemRepository::new();
 
@@ -398,6 +418,7 @@ mod tests {
         let use_case = CreateAuctionUseCase::new(
             Arc::new(MockIAuctionRepository::new()),
             Arc::new(item_repository),
+            Arc::new(AuctionExpiryScheduler::new()),
         );
 
         let dto = dtos::CreateAuctionRequest {
@@ -416,4 +437,39 @@ mod tests {
             _ => panic!("Test failed"),
         }
     }
+
+    #[tokio::test]
+    async fn given_unverified_user_when_executing_then_email_not_verified_error_is_returned() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository.expect_insert().times(0);
+
+        let use_case = CreateAuctionUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(MockIItemRepository::new()),
+            Arc::new(AuctionExpiryScheduler::new()),
+        );
+
+        let dto = dtos::CreateAuctionRequest {
+            item_id: Uuid::new_v4().to_string(),
+            starting_price: 0.0,
+            end_date: (Utc::now() + chrono::Duration::minutes(10)).timestamp(),
+            strategy: AuctionStrategy::Standard.into(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, dto).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(domain::app_error::AppError::EmailNotVerified())
+        ));
+    }
 }
@@ -1,11 +1,17 @@
+use crate::use_cases::ratings::get_user_ratings_use_case::dtos::ReputationDto;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use domain::app_error::AppError;
 use domain::entities::auction::AuctionWithItem;
 use domain::entities::item::Category;
+use domain::entities::reputation::Reputation;
+use domain::entities::user::User;
+use domain::id::Id;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_rating_repository::IRatingRepository;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{error, info};
 
@@ -15,11 +21,20 @@ pub struct GetAllDto {
 }
 
 impl GetAllDto {
-    fn from_auctions_and_items(auctions_and_items: Vec<AuctionWithItem>) -> GetAllDto {
+    fn from_auctions_and_items(
+        auctions_and_items: Vec<AuctionWithItem>,
+        reputations: HashMap<Id<User>, Reputation>,
+    ) -> GetAllDto {
         GetAllDto {
             auctions: auctions_and_items
                 .iter()
-                .map(AuctionWithItemDto::from)
+                .map(|auction| {
+                    let reputation = reputations
+                        .get(&auction.user_id)
+                        .cloned()
+                        .unwrap_or_default();
+                    AuctionWithItemDto::new(auction, reputation)
+                })
                 .collect(),
         }
     }
@@ -48,9 +63,11 @@ pub struct AuctionWithItemDto {
     pub category: String,
     pub user_id: String,
     pub strategy: String,
+    /// Reputation of the seller.
+    pub reputation: ReputationDto,
 }
 impl AuctionWithItemDto {
-    fn from(auction: &AuctionWithItem) -> Self {
+    pub(crate) fn new(auction: &AuctionWithItem, reputation: Reputation) -> Self {
         AuctionWithItemDto {
             id: auction.id.clone().to_string(),
             item_id: auction.item_id.clone().to_string(),
@@ -61,17 +78,22 @@ impl AuctionWithItemDto {
             category: auction.category.clone().into(),
             user_id: auction.user_id.clone().to_string(),
             strategy: auction.strategy.clone().into(),
+            reputation: reputation.into(),
         }
     }
 }
 
-pub struct GetAuctionsUseCase<R: IAuctionRepository> {
-    auction_repository: Arc<R>,
+pub struct GetAuctionsUseCase<R1: IAuctionRepository, R2: IRatingRepository> {
+    auction_repository: Arc<R1>,
+    rating_repository: Arc<R2>,
 }
 
-impl<R: IAuctionRepository> GetAuctionsUseCase<R> {
-    pub fn new(auction_repository: Arc<R>) -> Self {
-        Self { auction_repository }
+impl<R1: IAuctionRepository, R2: IRatingRepository> GetAuctionsUseCase<R1, R2> {
+    pub fn new(auction_repository: Arc<R1>, rating_repository: Arc<R2>) -> Self {
+        Self {
+            auction_repository,
+            rating_repository,
+        }
     }
 
     pub async fn execute(&self, category: Option<Category>) -> Result<GetAllDto, AppError> {
@@ -82,8 +104,22 @@ impl<R: IAuctionRepository> GetAuctionsUseCase<R> {
                 if auctions_and_items_result.is_empty() {
                     Ok(GetAllDto::new_empty())
                 } else {
+                    let seller_ids = auctions_and_items_result
+                        .iter()
+                        .map(|auction| auction.user_id.clone())
+                        .collect();
+                    let reputations = self
+                        .rating_repository
+                        .find_reputations(seller_ids)
+                        .await
+                        .map_err(|e| {
+                            error!("Failed to get reputations of sellers: {:?}", e);
+                            AppError::FailedToGetAuctions()
+                        })?;
+
                     Ok(GetAllDto::from_auctions_and_items(
                         auctions_and_items_result,
+                        reputations,
                     ))
                 }
             }
@@ -1,21 +1,48 @@
+use crate::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
+use chrono::Utc;
 use domain::app_error::AppError;
-use domain::entities::auction::{Auction, AuctionStrategy};
+use domain::entities::auction::{Auction, AuctionStrategy, Finalization};
+use domain::entities::auction_event::AuctionEvent;
+use domain::entities::bid::BidWithUsername;
+use domain::entities::email::Email;
+use domain::entities::user_event::{UserEvent, UserEventKind};
 use domain::id::Id;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
-use domain::interfaces::i_item_repository::IItemRepository;
+use domain::interfaces::i_job_repository::IJobRepository;
+use domain::interfaces::i_user_event_repository::IUserEventRepository;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{error, info};
 
-pub struct HandleExpiredAuctionUseCase<R1: IAuctionRepository, R2: IItemRepository> {
+pub struct HandleExpiredAuctionUseCase<
+    R1: IAuctionRepository,
+    R2: IUserEventRepository,
+    R3: IJobRepository,
+    B: IAuctionEventBroadcaster,
+> {
     auction_repository: Arc<R1>,
-    item_repository: Arc<R2>,
+    user_event_repository: Arc<R2>,
+    job_repository: Arc<R3>,
+    auction_event_broadcaster: Arc<B>,
 }
 
-impl<R1: IAuctionRepository, R2: IItemRepository> HandleExpiredAuctionUseCase<R1, R2> {
-    pub fn new(auction_repository: Arc<R1>, item_repository: Arc<R2>) -> Self {
+impl<
+        R1: IAuctionRepository,
+        R2: IUserEventRepository,
+        R3: IJobRepository,
+        B: IAuctionEventBroadcaster,
+    > HandleExpiredAuctionUseCase<R1, R2, R3, B>
+{
+    pub fn new(
+        auction_repository: Arc<R1>,
+        user_event_repository: Arc<R2>,
+        job_repository: Arc<R3>,
+        auction_event_broadcaster: Arc<B>,
+    ) -> Self {
         Self {
             auction_repository,
-            item_repository,
+            user_event_repository,
+            job_repository,
+            auction_event_broadcaster,
         }
     }
 
@@ -33,50 +60,71 @@ impl<R1: IAuctionRepository, R2: IItemRepository> HandleExpiredAuctionUseCase<R1
             .auction_repository
             .find_by_id(parsed_auction_id.clone())
             .await
-            .map_err(|_| {
-                AppError::GetAuctionFailed(
-                    "Cannot handle expired auction for invalid auction_id".to_string(),
-                )
-            })?
-            .ok_or_else(|| AppError::NoAuctionFoundForId(parsed_auction_id.value.to_string()))?;
-
-        if auction_with_item.strategy == AuctionStrategy::RequestFinalApproval {
-            return Ok(());
-        }
-
-        // Get all bids for auction
-        let bids = self
-            .auction_repository
-            .get_all_bids(parsed_auction_id.clone())
-            .await
             .map_err(|_| {
                 AppError::GetAuctionFailed(
                     "Cannot handle expired auction for invalid auction_id".to_string(),
                 )
             })?;
 
-        // If any bids, get the highest bid and update item with the new owner taken from the highest bid
-        let highest_bid = bids
-            .iter()
-            .max_by(|a, b| a.value.partial_cmp(&b.value).unwrap());
+        // a re-run on an auction that has already been finalized is a no-op
+        let Some(auction_with_item) = auction_with_item else {
+            info!("Expired auction with id: {} already handled", auction_id);
+            return Ok(());
+        };
 
-        if let Some(highest_bid) = highest_bid {
-            let item_id = auction_with_item.item_id.clone();
-            let new_owner_id = highest_bid.user_id.clone();
+        // the scheduler may still hold the old end date of an extended auction
+        if auction_with_item.end_date > Utc::now() {
+            info!("Auction with id: {} has not ended yet", auction_id);
+            return Ok(());
+        }
 
-            self.item_repository
-                .change_owner(item_id, new_owner_id)
+        if auction_with_item.strategy == AuctionStrategy::RequestFinalApproval {
+            // recorded once per auction, however often the job sees it waiting for confirmation
+            match self
+                .user_event_repository
+                .insert(UserEvent::new(
+                    auction_with_item.user_id.clone(),
+                    UserEventKind::ConfirmationRequired,
+                    Some(parsed_auction_id.clone()),
+                    Some(auction_with_item.item_id.clone()),
+                    None,
+                ))
                 .await
-                .map_err(|_| {
-                    AppError::GetAuctionFailed(
-                        "Cannot handle expired auction for invalid auction_id".to_string(),
-                    )
-                })?;
+            {
+                // only the first sighting is recorded, so the owner is emailed once
+                Ok(Some(_)) => {
+                    if let Err(e) = self
+                        .job_repository
+                        .enqueue(
+                            Email::confirmation_required(
+                                auction_with_item.user_id.clone(),
+                                &auction_with_item,
+                            )
+                            .into_job(),
+                        )
+                        .await
+                    {
+                        error!("Failed to enqueue email: {:?}", e);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => error!("Failed to record user event: {:?}", e),
+            }
+
+            return Ok(());
         }
 
-        // Delete auction bids
-        self.auction_repository
-            .delete_bids_for_auction(parsed_auction_id.clone())
+        // the highest bidder becomes the new owner of the item; emails, webhook events,
+        // notifications and user events are stored in the same transaction as the finalization
+        let plan_auction = auction_with_item.clone();
+        let bids = self
+            .auction_repository
+            .finalize_auction(
+                parsed_auction_id.clone(),
+                Box::new(move |bids| {
+                    Finalization::new(&plan_auction, bids, BidWithUsername::winner(bids))
+                }),
+            )
             .await
             .map_err(|_| {
                 AppError::GetAuctionFailed(
@@ -84,15 +132,25 @@ impl<R1: IAuctionRepository, R2: IItemRepository> HandleExpiredAuctionUseCase<R1
                 )
             })?;
 
-        // Delete auction
-        self.auction_repository
-            .delete_auction(parsed_auction_id.clone())
+        let Some(bids) = bids else {
+            info!(
+                "Expired auction with id: {} is handled by another instance",
+                auction_id
+            );
+            return Ok(());
+        };
+        let (winner_id, winning_bid) = BidWithUsername::winner(&bids).unzip();
+        if let Err(e) = self
+            .auction_event_broadcaster
+            .publish(AuctionEvent::Finalized {
+                auction_id: parsed_auction_id,
+                winner_id,
+                winning_bid,
+            })
             .await
-            .map_err(|_| {
-                AppError::GetAuctionFailed(
-                    "Cannot handle expired auction for invalid auction_id".to_string(),
-                )
-            })?;
+        {
+            error!("Failed to publish auction finalized event: {:?}", e);
+        }
 
         info!(
             "Expired auction with id: {} handled successfully",
@@ -102,3 +160,196 @@ impl<R1: IAuctionRepository, R2: IItemRepository> HandleExpiredAuctionUseCase<R1
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::broadcasters::i_auction_event_broadcaster::MockIAuctionEventBroadcaster;
+    use crate::use_cases::auctions::handle_expired_auction_use_case::HandleExpiredAuctionUseCase;
+    use chrono::Utc;
+    use domain::entities::auction::{Auction, AuctionStrategy, AuctionWithItem};
+    use domain::entities::auction_event::AuctionEvent;
+    use domain::entities::bid::BidWithUsername;
+    use domain::entities::item::Category;
+    use domain::entities::notification::NotificationKind;
+    use domain::entities::user::User;
+    use domain::entities::user_event::UserEventKind;
+    use domain::id::Id;
+    use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+    use domain::interfaces::i_job_repository::MockIJobRepository;
+    use domain::interfaces::i_user_event_repository::MockIUserEventRepository;
+    use std::sync::{Arc, Mutex};
+
+    fn expired_auction() -> AuctionWithItem {
+        AuctionWithItem::new(
+            Id::gen(),
+            Id::gen(),
+            1.0,
+            Utc::now() - chrono::Duration::minutes(10),
+            "brief".to_string(),
+            "description".to_string(),
+            Category::Art,
+            Id::gen(),
+            AuctionStrategy::Standard,
+        )
+    }
+
+    fn use_case(
+        auction_repository: MockIAuctionRepository,
+        auction_event_broadcaster: MockIAuctionEventBroadcaster,
+    ) -> HandleExpiredAuctionUseCase<
+        MockIAuctionRepository,
+        MockIUserEventRepository,
+        MockIJobRepository,
+        MockIAuctionEventBroadcaster,
+    > {
+        // user events of a finalization are only written together with it
+        HandleExpiredAuctionUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(MockIUserEventRepository::new()),
+            Arc::new(MockIJobRepository::new()),
+            Arc::new(auction_event_broadcaster),
+        )
+    }
+
+    #[tokio::test]
+    async fn given_already_finalized_auction_when_executing_then_nothing_is_done() {
+        // Arrange
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_by_id()
+            .returning(|_| Ok(None));
+        auction_repository.expect_finalize_auction().times(0);
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster.expect_publish().times(0);
+
+        let use_case = use_case(auction_repository, auction_event_broadcaster);
+
+        // Act
+        let result = use_case.execute(Id::<Auction>::gen().to_string()).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_auction_that_has_not_ended_when_executing_then_it_is_not_finalized() {
+        // Arrange
+        let mut auction = expired_auction();
+        auction.end_date = Utc::now() + chrono::Duration::minutes(10);
+        let auction_id = auction.id.clone();
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+        auction_repository.expect_finalize_auction().times(0);
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster.expect_publish().times(0);
+
+        let use_case = use_case(auction_repository, auction_event_broadcaster);
+
+        // Act
+        let result = use_case.execute(auction_id.to_string()).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_auction_claimed_by_another_instance_when_executing_then_no_side_effects_are_recorded(
+    ) {
+        // Arrange
+        let auction = expired_auction();
+        let auction_id = auction.id.clone();
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+        auction_repository
+            .expect_finalize_auction()
+            .times(1)
+            .returning(|_, _| Ok(None));
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster.expect_publish().times(0);
+
+        let use_case = use_case(auction_repository, auction_event_broadcaster);
+
+        // Act
+        let result = use_case.execute(auction_id.to_string()).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_bids_read_at_claim_when_executing_then_auction_is_finalized_for_highest_bidder()
+    {
+        // Arrange
+        let auction = expired_auction();
+        let auction_id = auction.id.clone();
+        let highest_bidder_id = Id::<User>::gen();
+        let bids = vec![
+            BidWithUsername {
+                id: Id::gen(),
+                value: 2.0,
+                auction_id: auction_id.clone(),
+                user_id: Id::gen(),
+                username: "lower".to_string(),
+            },
+            BidWithUsername {
+                id: Id::gen(),
+                value: 5.0,
+                auction_id: auction_id.clone(),
+                user_id: highest_bidder_id.clone(),
+                username: "highest".to_string(),
+            },
+        ];
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+        // bids read before the claim could miss one placed in the meantime
+        auction_repository.expect_get_all_bids().times(0);
+        let finalization = Arc::new(Mutex::new(None));
+        let planned = finalization.clone();
+        auction_repository
+            .expect_finalize_auction()
+            .times(1)
+            .returning(move |_, plan| {
+                *planned.lock().unwrap() = Some(plan(&bids));
+                Ok(Some(bids.clone()))
+            });
+
+        let winner_id = highest_bidder_id.clone();
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster
+            .expect_publish()
+            .withf(move |event| {
+                matches!(event, AuctionEvent::Finalized { winner_id: Some(id), .. } if *id == winner_id)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = use_case(auction_repository, auction_event_broadcaster);
+
+        // Act
+        let result = use_case.execute(auction_id.to_string()).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let finalization = finalization.lock().unwrap().take().unwrap();
+        assert!(finalization.new_owner_id == Some(highest_bidder_id.clone()));
+        assert!(finalization
+            .notifications
+            .iter()
+            .any(|notification| notification.kind == NotificationKind::AuctionLost));
+        assert!(finalization.user_events.iter().any(|event| {
+            event.kind == UserEventKind::AuctionWon && event.user_id == highest_bidder_id
+        }));
+    }
+}
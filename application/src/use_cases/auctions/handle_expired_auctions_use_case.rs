@@ -1,19 +1,47 @@
+use crate::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
 use crate::use_cases::auctions::handle_expired_auction_use_case::HandleExpiredAuctionUseCase;
 use domain::app_error::AppError;
+use domain::entities::auction::Auction;
+use domain::id::Id;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
-use domain::interfaces::i_item_repository::IItemRepository;
-use futures::TryStreamExt;
+use domain::interfaces::i_job_repository::IJobRepository;
+use domain::interfaces::i_user_event_repository::IUserEventRepository;
+use futures::StreamExt;
 use std::sync::Arc;
+use tracing::error;
 
-pub struct HandleExpiredAuctionsUseCase<R1: IAuctionRepository, R2: IItemRepository> {
+const CONCURRENCY: usize = 8;
+const MAX_FINALIZATION_ATTEMPTS: i32 = 5;
+
+#[derive(Debug, Default)]
+pub struct ExpiredAuctionsSummary {
+    pub succeeded: Vec<Id<Auction>>,
+    /// Failed this run and will be retried by the next one.
+    pub failed: Vec<Id<Auction>>,
+    /// Failed for the last time and are skipped until released by hand.
+    pub quarantined: Vec<Id<Auction>>,
+}
+
+pub struct HandleExpiredAuctionsUseCase<
+    R1: IAuctionRepository,
+    R2: IUserEventRepository,
+    R3: IJobRepository,
+    B: IAuctionEventBroadcaster,
+> {
     auction_repository: Arc<R1>,
-    handle_expired_auction_use_case: Arc<HandleExpiredAuctionUseCase<R1, R2>>,
+    handle_expired_auction_use_case: Arc<HandleExpiredAuctionUseCase<R1, R2, R3, B>>,
 }
 
-impl<R1: IAuctionRepository, R2: IItemRepository> HandleExpiredAuctionsUseCase<R1, R2> {
+impl<
+        R1: IAuctionRepository,
+        R2: IUserEventRepository,
+        R3: IJobRepository,
+        B: IAuctionEventBroadcaster,
+    > HandleExpiredAuctionsUseCase<R1, R2, R3, B>
+{
     pub fn new(
         auction_repository: Arc<R1>,
-        handle_expired_auction_use_case: Arc<HandleExpiredAuctionUseCase<R1, R2>>,
+        handle_expired_auction_use_case: Arc<HandleExpiredAuctionUseCase<R1, R2, R3, B>>,
     ) -> Self {
         Self {
             auction_repository,
@@ -21,7 +49,7 @@ impl<R1: IAuctionRepository, R2: IItemRepository> HandleExpiredAuctionsUseCase<R
         }
     }
 
-    pub async fn execute(&self) -> Result<(), AppError> {
+    pub async fn execute(&self) -> Result<ExpiredAuctionsSummary, AppError> {
         let expired_auctions = self
             .auction_repository
             .find_all_expired()
@@ -30,16 +58,172 @@ impl<R1: IAuctionRepository, R2: IItemRepository> HandleExpiredAuctionsUseCase<R
                 AppError::GetAuctionFailed("Cannot handle expired auctions".to_string())
             })?;
 
-        expired_auctions
-            .into_iter()
-            .map(|auction| {
-                self.handle_expired_auction_use_case
+        // a failing auction is counted against its own retries and does not abort the batch
+        let results = futures::stream::iter(expired_auctions)
+            .map(|auction| async move {
+                let result = self
+                    .handle_expired_auction_use_case
                     .execute(auction.id.value.to_string())
+                    .await;
+                (auction.id, result)
+            })
+            .buffer_unordered(CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut summary = ExpiredAuctionsSummary::default();
+        for (auction_id, result) in results {
+            let e = match result {
+                Ok(()) => {
+                    summary.succeeded.push(auction_id);
+                    continue;
+                }
+                Err(e) => e,
+            };
+
+            error!("Failed to handle expired auction {}: {:?}", auction_id, e);
+            match self
+                .auction_repository
+                .record_finalization_failure(
+                    auction_id.clone(),
+                    e.to_string(),
+                    MAX_FINALIZATION_ATTEMPTS,
+                )
+                .await
+            {
+                Ok(true) => {
+                    error!(
+                        "Expired auction {} quarantined after {} failed attempts",
+                        auction_id, MAX_FINALIZATION_ATTEMPTS
+                    );
+                    summary.quarantined.push(auction_id);
+                }
+                Ok(false) => summary.failed.push(auction_id),
+                Err(e) => {
+                    error!(
+                        "Failed to record finalization failure for auction {}: {:?}",
+                        auction_id, e
+                    );
+                    summary.failed.push(auction_id);
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::broadcasters::i_auction_event_broadcaster::MockIAuctionEventBroadcaster;
+    use anyhow::anyhow;
+    use chrono::Utc;
+    use domain::entities::auction::AuctionStrategy;
+    use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+    use domain::interfaces::i_job_repository::MockIJobRepository;
+    use domain::interfaces::i_user_event_repository::MockIUserEventRepository;
+
+    fn expired_auction() -> Auction {
+        Auction::new(
+            Id::gen(),
+            1.0,
+            Utc::now() - chrono::Duration::minutes(10),
+            AuctionStrategy::Standard,
+        )
+    }
+
+    fn ids(auction_ids: &[Id<Auction>]) -> Vec<uuid::Uuid> {
+        auction_ids.iter().map(|id| id.value).collect()
+    }
+
+    fn use_case(
+        auction_repository: MockIAuctionRepository,
+    ) -> HandleExpiredAuctionsUseCase<
+        MockIAuctionRepository,
+        MockIUserEventRepository,
+        MockIJobRepository,
+        MockIAuctionEventBroadcaster,
+    > {
+        let auction_repository = Arc::new(auction_repository);
+        let handle_expired_auction_use_case = Arc::new(HandleExpiredAuctionUseCase::new(
+            auction_repository.clone(),
+            Arc::new(MockIUserEventRepository::new()),
+            Arc::new(MockIJobRepository::new()),
+            Arc::new(MockIAuctionEventBroadcaster::new()),
+        ));
+
+        HandleExpiredAuctionsUseCase::new(auction_repository, handle_expired_auction_use_case)
+    }
+
+    #[tokio::test]
+    async fn given_one_failing_auction_when_executing_then_others_are_still_handled() {
+        // Arrange
+        let handled_auction = expired_auction();
+        let failing_auction = expired_auction();
+        let handled_auction_id = handled_auction.id.value;
+        let failing_auction_id = failing_auction.id.value;
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_all_expired()
+            .returning(move || Ok(vec![handled_auction.clone(), failing_auction.clone()]));
+        // already finalized, so handling it is a no-op
+        auction_repository
+            .expect_find_by_id()
+            .withf(move |id| id.value == handled_auction_id)
+            .returning(|_| Ok(None));
+        auction_repository
+            .expect_find_by_id()
+            .withf(move |id| id.value == failing_auction_id)
+            .returning(|_| Err(anyhow!("Connection reset")));
+        auction_repository
+            .expect_record_finalization_failure()
+            .withf(move |id, _, max_attempts| {
+                id.value == failing_auction_id && *max_attempts == MAX_FINALIZATION_ATTEMPTS
             })
-            .collect::<futures::stream::FuturesUnordered<_>>()
-            .try_collect::<Vec<_>>()
-            .await?;
+            .times(1)
+            .returning(|_, _, _| Ok(false));
+
+        let use_case = use_case(auction_repository);
+
+        // Act
+        let result = use_case.execute().await;
+
+        // Assert
+        let summary = result.unwrap();
+        assert_eq!(ids(&summary.succeeded), vec![handled_auction_id]);
+        assert_eq!(ids(&summary.failed), vec![failing_auction_id]);
+        assert!(summary.quarantined.is_empty());
+    }
+
+    #[tokio::test]
+    async fn given_auction_failing_for_the_last_time_when_executing_then_it_is_quarantined() {
+        // Arrange
+        let failing_auction = expired_auction();
+        let failing_auction_id = failing_auction.id.value;
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_all_expired()
+            .returning(move || Ok(vec![failing_auction.clone()]));
+        auction_repository
+            .expect_find_by_id()
+            .returning(|_| Err(anyhow!("Connection reset")));
+        auction_repository
+            .expect_record_finalization_failure()
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+
+        let use_case = use_case(auction_repository);
+
+        // Act
+        let result = use_case.execute().await;
 
-        Ok(())
+        // Assert
+        let summary = result.unwrap();
+        assert!(summary.succeeded.is_empty());
+        assert!(summary.failed.is_empty());
+        assert_eq!(ids(&summary.quarantined), vec![failing_auction_id]);
     }
 }
@@ -4,3 +4,5 @@ pub mod get_ongoing_auction_for_item_use_case;
 pub mod get_ongoing_auctions_use_case;
 pub mod handle_expired_auction_use_case;
 pub mod handle_expired_auctions_use_case;
+pub mod run_auction_expiry_scheduler_use_case;
+pub mod subscribe_to_auction_use_case;
@@ -0,0 +1,71 @@
+use crate::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
+use crate::schedulers::auction_expiry_scheduler::AuctionExpiryScheduler;
+use crate::use_cases::auctions::handle_expired_auction_use_case::HandleExpiredAuctionUseCase;
+use domain::app_error::AppError;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_job_repository::IJobRepository;
+use domain::interfaces::i_user_event_repository::IUserEventRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct RunAuctionExpirySchedulerUseCase<
+    R1: IAuctionRepository,
+    R2: IUserEventRepository,
+    R3: IJobRepository,
+    B: IAuctionEventBroadcaster,
+> {
+    auction_repository: Arc<R1>,
+    auction_expiry_scheduler: Arc<AuctionExpiryScheduler>,
+    handle_expired_auction_use_case: Arc<HandleExpiredAuctionUseCase<R1, R2, R3, B>>,
+}
+
+impl<
+        R1: IAuctionRepository,
+        R2: IUserEventRepository,
+        R3: IJobRepository,
+        B: IAuctionEventBroadcaster,
+    > RunAuctionExpirySchedulerUseCase<R1, R2, R3, B>
+{
+    pub fn new(
+        auction_repository: Arc<R1>,
+        auction_expiry_scheduler: Arc<AuctionExpiryScheduler>,
+        handle_expired_auction_use_case: Arc<HandleExpiredAuctionUseCase<R1, R2, R3, B>>,
+    ) -> Self {
+        Self {
+            auction_repository,
+            auction_expiry_scheduler,
+            handle_expired_auction_use_case,
+        }
+    }
+
+    /// Loads the ongoing auctions and finalizes each one as it ends. Only returns if the initial
+    /// load fails.
+    pub async fn execute(&self) -> Result<(), AppError> {
+        let ongoing_auctions = self
+            .auction_repository
+            .find_all_ongoing(None)
+            .await
+            .map_err(|_| {
+                AppError::GetAuctionFailed("Cannot schedule ongoing auctions".to_string())
+            })?;
+
+        info!("Scheduling {} ongoing auctions", ongoing_auctions.len());
+        for auction in ongoing_auctions {
+            self.auction_expiry_scheduler
+                .schedule(auction.id, auction.end_date);
+        }
+
+        loop {
+            let auction_id = self.auction_expiry_scheduler.next_due().await;
+
+            // failures are left to the cron run, which retries and quarantines
+            if let Err(e) = self
+                .handle_expired_auction_use_case
+                .execute(auction_id.value.to_string())
+                .await
+            {
+                error!("Failed to handle expired auction {}: {:?}", auction_id, e);
+            }
+        }
+    }
+}
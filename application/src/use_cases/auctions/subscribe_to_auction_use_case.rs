@@ -0,0 +1,48 @@
+use crate::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
+use domain::app_error::AppError;
+use domain::entities::auction::Auction;
+use domain::entities::auction_event::AuctionEvent;
+use domain::id::Id;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use std::sync::Arc;
+use tokio::sync::broadcast::Receiver;
+use tracing::{error, info};
+
+pub struct SubscribeToAuctionUseCase<R: IAuctionRepository, B: IAuctionEventBroadcaster> {
+    auction_repository: Arc<R>,
+    auction_event_broadcaster: Arc<B>,
+}
+
+impl<R: IAuctionRepository, B: IAuctionEventBroadcaster> SubscribeToAuctionUseCase<R, B> {
+    pub fn new(auction_repository: Arc<R>, auction_event_broadcaster: Arc<B>) -> Self {
+        Self {
+            auction_repository,
+            auction_event_broadcaster,
+        }
+    }
+
+    pub async fn execute(&self, auction_id: String) -> Result<Receiver<AuctionEvent>, AppError> {
+        info!("Subscribing to events of auction with id: {}", auction_id);
+
+        let parsed_auction_id = Id::<Auction>::try_from(auction_id.clone()).map_err(|_| {
+            error!("Failed to parse auction_id = {}", auction_id);
+            AppError::NoAuctionFoundForId(auction_id.clone())
+        })?;
+
+        match self
+            .auction_repository
+            .find_by_id(parsed_auction_id.clone())
+            .await
+        {
+            Ok(Some(_)) => Ok(self.auction_event_broadcaster.subscribe(parsed_auction_id)),
+            Ok(None) => {
+                error!("Auction not found for id {}", auction_id);
+                Err(AppError::NoAuctionFoundForId(auction_id))
+            }
+            Err(e) => {
+                error!("Failed to get auction with id {}: {:?}", auction_id, e);
+                Err(AppError::GetAuctionFailed(auction_id))
+            }
+        }
+    }
+}
@@ -1,8 +1,14 @@
+use crate::broadcasters::i_auction_event_broadcaster::IAuctionEventBroadcaster;
 use anyhow::anyhow;
 use domain::app_error::AppError;
 use domain::entities::auction::Auction;
+use domain::entities::auction_event::AuctionEvent;
 use domain::entities::bid::Bid;
+use domain::entities::email::Email;
+use domain::entities::notification::Notification;
 use domain::entities::user::User;
+use domain::entities::user_event::{UserEvent, UserEventKind};
+use domain::entities::webhook::WebhookEvent;
 use domain::id::Id;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
 use std::sync::Arc;
@@ -44,13 +50,17 @@ pub mod dtos {
     }
 }
 
-pub struct CreateBidUseCase<R: IAuctionRepository> {
-    auction_repository: Arc<R>,
+pub struct CreateBidUseCase<R1: IAuctionRepository, B: IAuctionEventBroadcaster> {
+    auction_repository: Arc<R1>,
+    auction_event_broadcaster: Arc<B>,
 }
 
-impl<R: IAuctionRepository> CreateBidUseCase<R> {
-    pub fn new(auction_repository: Arc<R>) -> Self {
-        Self { auction_repository }
+impl<R1: IAuctionRepository, B: IAuctionEventBroadcaster> CreateBidUseCase<R1, B> {
+    pub fn new(auction_repository: Arc<R1>, auction_event_broadcaster: Arc<B>) -> Self {
+        Self {
+            auction_repository,
+            auction_event_broadcaster,
+        }
     }
 
     pub async fn execute(
@@ -60,6 +70,10 @@ impl<R: IAuctionRepository> CreateBidUseCase<R> {
     ) -> Result<(), AppError> {
         info!("Creating bid for auction with id: {}", request.auction_id);
 
+        if !current_user.email_verified {
+            return Err(AppError::EmailNotVerified());
+        }
+
         // check if user is owner of the auction
         let auction_id = Id::<Auction>::try_from(request.auction_id.clone()).map_err(|_| {
             error!(
@@ -111,10 +125,11 @@ impl<R: IAuctionRepository> CreateBidUseCase<R> {
                     ));
                 }
 
-                if let Some(highest_bid) = bids
+                let highest_bid = bids
                     .iter()
-                    .max_by(|a, b| a.value.partial_cmp(&b.value).unwrap())
-                {
+                    .max_by(|a, b| a.value.partial_cmp(&b.value).unwrap());
+
+                if let Some(highest_bid) = highest_bid {
                     if request.value <= highest_bid.value {
                         return Err(AppError::BidAmountMustBeGreaterThanCurrentHighestBid(
                             request.value,
@@ -122,20 +137,71 @@ impl<R: IAuctionRepository> CreateBidUseCase<R> {
                         ));
                     }
                 }
+                let outbid_user_id = highest_bid
+                    .map(|bid| bid.user_id.clone())
+                    .filter(|user_id| *user_id != current_user.id);
 
                 let bid = Bid::try_from(request).map_err(|_| {
                     AppError::CreateBidFailed(anyhow!("Failed to create bid. Bad bid data."))
                 })?;
-                match self.auction_repository.create_bid(bid).await {
-                    Ok(Some(_)) => {
+
+                // the outbid email, notification, user events and the webhook event are stored in
+                // the same transaction as the bid
+                let mut jobs = vec![WebhookEvent::bid_placed(&auction, &bid).into_job()];
+                jobs.extend(
+                    outbid_user_id.iter().map(|user_id| {
+                        Email::outbid(user_id.clone(), &auction, bid.value).into_job()
+                    }),
+                );
+                let notifications = outbid_user_id
+                    .iter()
+                    .map(|user_id| Notification::outbid(user_id.clone(), &auction, bid.value))
+                    .collect();
+                let mut user_events = vec![UserEvent::new(
+                    auction.user_id.clone(),
+                    UserEventKind::OfferReceived,
+                    Some(bid.auction_id.clone()),
+                    Some(auction.item_id.clone()),
+                    Some(bid.value),
+                )];
+                if let Some(outbid_user_id) = outbid_user_id {
+                    user_events.push(UserEvent::new(
+                        outbid_user_id,
+                        UserEventKind::Outbid,
+                        Some(bid.auction_id.clone()),
+                        Some(auction.item_id.clone()),
+                        Some(bid.value),
+                    ));
+                }
+                match self
+                    .auction_repository
+                    .create_bid(bid, jobs, notifications, user_events)
+                    .await
+                {
+                    Ok(Some(bid)) => {
                         info!("Bid created successfully");
+
+                        // bidders watching the auction must not make the bid fail
+                        if let Err(e) = self
+                            .auction_event_broadcaster
+                            .publish(AuctionEvent::BidPlaced {
+                                auction_id: bid.auction_id,
+                                bid_id: bid.id,
+                                user_id: bid.user_id,
+                                username: current_user.name,
+                                value: bid.value,
+                            })
+                            .await
+                        {
+                            error!("Failed to publish bid placed event: {:?}", e);
+                        }
+
                         Ok(())
                     }
+                    // the auction ended or was finalized since it was read
                     Ok(None) => {
-                        error!("Failed to create bid: Bid not returned from repository");
-                        Err(AppError::CreateBidFailedInternalServerError(anyhow!(
-                            "Failed to create bid. Internal server error."
-                        )))
+                        error!("Cannot bid on expired auction.");
+                        Err(AppError::CannotBidOnExpiredAuction())
                     }
                     Err(e) => {
                         error!("Failed when creating bid in repository: {:?}", e);
@@ -154,3 +220,321 @@ impl<R: IAuctionRepository> CreateBidUseCase<R> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::broadcasters::i_auction_event_broadcaster::MockIAuctionEventBroadcaster;
+    use crate::use_cases::bids::create_bid_use_case::{dtos, CreateBidUseCase};
+    use anyhow::anyhow;
+    use chrono::Utc;
+    use domain::app_error::AppError;
+    use domain::entities::auction::{AuctionStrategy, AuctionWithItem};
+    use domain::entities::auction_event::AuctionEvent;
+    use domain::entities::bid::Bid;
+    use domain::entities::bid::BidWithUsername;
+    use domain::entities::email::Email;
+    use domain::entities::item::Category;
+    use domain::entities::notification::NotificationKind;
+    use domain::entities::user::User;
+    use domain::entities::user_event::UserEventKind;
+    use domain::entities::webhook::WebhookEvent;
+    use domain::id::Id;
+    use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+    use std::sync::Arc;
+
+    fn verified_user() -> User {
+        let mut user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        user.email_verified = true;
+        user
+    }
+
+    fn ongoing_auction() -> AuctionWithItem {
+        AuctionWithItem::new(
+            Id::gen(),
+            Id::gen(),
+            1.0,
+            Utc::now() + chrono::Duration::minutes(10),
+            "brief".to_string(),
+            "description".to_string(),
+            Category::Art,
+            Id::gen(),
+            AuctionStrategy::Standard,
+        )
+    }
+
+    fn auction_repository_accepting_bids(
+        auction: AuctionWithItem,
+        bids: Vec<BidWithUsername>,
+    ) -> MockIAuctionRepository {
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+        auction_repository
+            .expect_get_all_bids()
+            .returning(move |_| Ok(bids.clone()));
+        auction_repository
+            .expect_create_bid()
+            .returning(|bid: Bid, _, _, _| Ok(Some(bid)));
+        auction_repository
+    }
+
+    #[tokio::test]
+    async fn given_valid_bid_when_executing_then_bid_placed_event_is_published() {
+        // Arrange
+        let auction = ongoing_auction();
+        let auction_id = auction.id.clone();
+        let auction_repository = auction_repository_accepting_bids(auction, vec![]);
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster
+            .expect_publish()
+            .withf(move |event| match event {
+                AuctionEvent::BidPlaced {
+                    auction_id: id,
+                    value,
+                    username,
+                    ..
+                } => id.value == auction_id.value && *value == 5.0 && username == "username",
+                _ => false,
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = CreateBidUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(auction_event_broadcaster),
+        );
+        let current_user = verified_user();
+        let request = dtos::CreateBidRequest {
+            value: 5.0,
+            auction_id: auction_id.to_string(),
+            user_id: current_user.id.to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, request).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_failing_broadcaster_when_executing_then_bid_is_still_created() {
+        // Arrange
+        let auction = ongoing_auction();
+        let auction_id = auction.id.clone();
+        let auction_repository = auction_repository_accepting_bids(auction, vec![]);
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster
+            .expect_publish()
+            .returning(|_| Err(anyhow!("Broadcaster unavailable")));
+
+        let use_case = CreateBidUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(auction_event_broadcaster),
+        );
+        let current_user = verified_user();
+        let request = dtos::CreateBidRequest {
+            value: 5.0,
+            auction_id: auction_id.to_string(),
+            user_id: current_user.id.to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, request).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_previous_highest_bidder_when_executing_then_outbid_notification_and_events_are_recorded(
+    ) {
+        // Arrange
+        let auction = ongoing_auction();
+        let auction_id = auction.id.clone();
+        let owner_id = auction.user_id.clone();
+        let previous_bidder_id = Id::<User>::gen();
+        let bids = vec![BidWithUsername {
+            id: Id::gen(),
+            value: 2.0,
+            auction_id: auction_id.clone(),
+            user_id: previous_bidder_id.clone(),
+            username: "previous".to_string(),
+        }];
+
+        let outbid_user_id = previous_bidder_id.clone();
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+        auction_repository
+            .expect_get_all_bids()
+            .returning(move |_| Ok(bids.clone()));
+        auction_repository
+            .expect_create_bid()
+            .withf(move |_, _, notifications, user_events| {
+                notifications.len() == 1
+                    && notifications[0].kind == NotificationKind::Outbid
+                    && notifications[0].user_id == outbid_user_id
+                    && user_events.len() == 2
+                    && user_events.iter().any(|event| {
+                        event.kind == UserEventKind::OfferReceived && event.user_id == owner_id
+                    })
+                    && user_events.iter().any(|event| {
+                        event.kind == UserEventKind::Outbid && event.user_id == previous_bidder_id
+                    })
+            })
+            .times(1)
+            .returning(|bid, _, _, _| Ok(Some(bid)));
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster
+            .expect_publish()
+            .returning(|_| Ok(()));
+
+        let use_case = CreateBidUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(auction_event_broadcaster),
+        );
+        let current_user = verified_user();
+        let request = dtos::CreateBidRequest {
+            value: 5.0,
+            auction_id: auction_id.to_string(),
+            user_id: current_user.id.to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, request).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_previous_highest_bidder_when_executing_then_outbid_email_and_webhook_event_are_queued_with_bid(
+    ) {
+        // Arrange
+        let auction = ongoing_auction();
+        let auction_id = auction.id.clone();
+        let bids = vec![BidWithUsername {
+            id: Id::gen(),
+            value: 2.0,
+            auction_id: auction_id.clone(),
+            user_id: Id::gen(),
+            username: "previous".to_string(),
+        }];
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+        auction_repository
+            .expect_get_all_bids()
+            .returning(move |_| Ok(bids.clone()));
+        auction_repository
+            .expect_create_bid()
+            .withf(|_, jobs, _, _| {
+                jobs.iter().any(|job| job.kind == Email::JOB_KIND)
+                    && jobs.iter().any(|job| job.kind == WebhookEvent::JOB_KIND)
+            })
+            .times(1)
+            .returning(|bid, _, _, _| Ok(Some(bid)));
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster
+            .expect_publish()
+            .returning(|_| Ok(()));
+
+        let use_case = CreateBidUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(auction_event_broadcaster),
+        );
+        let current_user = verified_user();
+        let request = dtos::CreateBidRequest {
+            value: 5.0,
+            auction_id: auction_id.to_string(),
+            user_id: current_user.id.to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, request).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_auction_ending_before_bid_is_stored_when_executing_then_cannot_bid_on_expired_auction_is_returned(
+    ) {
+        // Arrange
+        let auction = ongoing_auction();
+        let auction_id = auction.id.clone();
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+        auction_repository
+            .expect_get_all_bids()
+            .returning(|_| Ok(vec![]));
+        auction_repository
+            .expect_create_bid()
+            .times(1)
+            .returning(|_, _, _, _| Ok(None));
+
+        let mut auction_event_broadcaster = MockIAuctionEventBroadcaster::new();
+        auction_event_broadcaster.expect_publish().times(0);
+
+        let use_case = CreateBidUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(auction_event_broadcaster),
+        );
+        let current_user = verified_user();
+        let request = dtos::CreateBidRequest {
+            value: 5.0,
+            auction_id: auction_id.to_string(),
+            user_id: current_user.id.to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, request).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::CannotBidOnExpiredAuction())));
+    }
+
+    #[tokio::test]
+    async fn given_unverified_user_when_executing_then_email_not_verified_error_is_returned() {
+        // Arrange
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository.expect_create_bid().times(0);
+
+        let use_case = CreateBidUseCase::new(
+            Arc::new(auction_repository),
+            Arc::new(MockIAuctionEventBroadcaster::new()),
+        );
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let request = dtos::CreateBidRequest {
+            value: 5.0,
+            auction_id: Id::<domain::entities::auction::Auction>::gen().to_string(),
+            user_id: current_user.id.to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, request).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::EmailNotVerified())));
+    }
+}
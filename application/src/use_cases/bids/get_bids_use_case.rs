@@ -1,3 +1,4 @@
+use crate::use_cases::ratings::get_user_ratings_use_case::dtos::ReputationDto;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
@@ -5,8 +6,10 @@ use domain::app_error::AppError;
 use domain::app_error::AppError::GetAuctionFailed;
 use domain::entities::auction::Auction;
 use domain::entities::bid::BidWithUsername;
+use domain::entities::reputation::Reputation;
 use domain::id::Id;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_rating_repository::IRatingRepository;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::log::{error, info};
@@ -18,15 +21,18 @@ pub struct BidDto {
     pub auction_id: String,
     pub user_id: String,
     pub username: String,
+    /// Reputation of the bidder.
+    pub reputation: ReputationDto,
 }
-impl From<BidWithUsername> for BidDto {
-    fn from(bid: BidWithUsername) -> Self {
+impl BidDto {
+    fn new(bid: BidWithUsername, reputation: Reputation) -> Self {
         BidDto {
             id: bid.id.to_string(),
             value: bid.value,
             auction_id: bid.auction_id.to_string(),
             user_id: bid.user_id.to_string(),
             username: bid.username,
+            reputation: reputation.into(),
         }
     }
 }
@@ -38,9 +44,7 @@ pub struct GetAllDto {
 
 impl GetAllDto {
     fn from_auctions_and_items(bids: Vec<BidDto>) -> GetAllDto {
-        GetAllDto {
-            bids: bids.into_iter().map(BidDto::from).collect(),
-        }
+        GetAllDto { bids }
     }
 
     fn new_empty() -> GetAllDto {
@@ -54,13 +58,17 @@ impl IntoResponse for GetAllDto {
     }
 }
 
-pub struct GetBidsUseCase<R: IAuctionRepository> {
-    auction_repository: Arc<R>,
+pub struct GetBidsUseCase<R1: IAuctionRepository, R2: IRatingRepository> {
+    auction_repository: Arc<R1>,
+    rating_repository: Arc<R2>,
 }
 
-impl<R: IAuctionRepository> GetBidsUseCase<R> {
-    pub fn new(auction_repository: Arc<R>) -> Self {
-        Self { auction_repository }
+impl<R1: IAuctionRepository, R2: IRatingRepository> GetBidsUseCase<R1, R2> {
+    pub fn new(auction_repository: Arc<R1>, rating_repository: Arc<R2>) -> Self {
+        Self {
+            auction_repository,
+            rating_repository,
+        }
     }
 
     pub async fn execute(&self, auction_id: String) -> Result<GetAllDto, AppError> {
@@ -83,10 +91,24 @@ impl<R: IAuctionRepository> GetBidsUseCase<R> {
                 if bids_result.is_empty() {
                     Ok(GetAllDto::new_empty())
                 } else {
+                    let bidder_ids = bids_result.iter().map(|bid| bid.user_id.clone()).collect();
+                    let reputations = self
+                        .rating_repository
+                        .find_reputations(bidder_ids)
+                        .await
+                        .map_err(|e| {
+                            error!("Failed to get reputations of bidders: {:?}", e);
+                            AppError::FailedToGetAuctions()
+                        })?;
+
                     Ok(GetAllDto::from_auctions_and_items(
                         bids_result
-                            .iter()
-                            .map(|bid| BidDto::from(bid.clone()))
+                            .into_iter()
+                            .map(|bid| {
+                                let reputation =
+                                    reputations.get(&bid.user_id).cloned().unwrap_or_default();
+                                BidDto::new(bid, reputation)
+                            })
                             .collect(),
                     ))
                 }
@@ -0,0 +1,327 @@
+use crate::use_cases::external_identities::start_external_login_use_case::{
+    dtos::ExternalCallbackRequest, exchange_authorization, find_provider,
+};
+use crate::use_cases::user::login_use_case::{dtos::LoginOutcome, finish_login};
+use domain::app_error::AppError;
+use domain::entities::email::Email;
+use domain::entities::external_identity::{ExternalIdentity, ExternalProfile};
+use domain::entities::user::User;
+use domain::interfaces::i_external_identity_repository::IExternalIdentityRepository;
+use domain::interfaces::i_identity_provider::IIdentityProvider;
+use domain::interfaces::i_two_factor_repository::ITwoFactorRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use rand::RngCore;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const USERNAME_MIN_LENGTH: usize = 3;
+const USERNAME_MAX_LENGTH: usize = 30;
+const USERNAME_ATTEMPTS: usize = 5;
+
+/// A username in the shape registration accepts, taken from the profile name or the email.
+fn base_username(profile: &ExternalProfile, email: &str) -> String {
+    let source = profile
+        .name
+        .clone()
+        .unwrap_or_else(|| email.split('@').next().unwrap_or_default().to_string());
+    let mut username = source
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+        .take(USERNAME_MAX_LENGTH - 5)
+        .collect::<String>();
+    while username.len() < USERNAME_MIN_LENGTH {
+        username.push('_');
+    }
+    username
+}
+
+pub struct CompleteExternalLoginUseCase<
+    R1: IExternalIdentityRepository,
+    R2: IUserRepository,
+    R3: ITwoFactorRepository,
+    P: IIdentityProvider,
+> {
+    external_identity_repository: Arc<R1>,
+    user_repository: Arc<R2>,
+    two_factor_repository: Arc<R3>,
+    identity_providers: Arc<Vec<P>>,
+}
+
+impl<
+        R1: IExternalIdentityRepository,
+        R2: IUserRepository,
+        R3: ITwoFactorRepository,
+        P: IIdentityProvider,
+    > CompleteExternalLoginUseCase<R1, R2, R3, P>
+{
+    pub fn new(
+        external_identity_repository: Arc<R1>,
+        user_repository: Arc<R2>,
+        two_factor_repository: Arc<R3>,
+        identity_providers: Arc<Vec<P>>,
+    ) -> Self {
+        Self {
+            external_identity_repository,
+            user_repository,
+            two_factor_repository,
+            identity_providers,
+        }
+    }
+
+    /// Signs in as the user linked to the external account, creating one without a password on
+    /// first sign in. An existing account with the same email is never taken over, its owner has
+    /// to link the provider while signed in.
+    pub async fn execute(
+        &self,
+        provider: String,
+        dto: ExternalCallbackRequest,
+    ) -> Result<LoginOutcome, AppError> {
+        let identity_provider = find_provider(&self.identity_providers, &provider)?;
+        let profile = exchange_authorization(
+            self.external_identity_repository.as_ref(),
+            identity_provider,
+            dto,
+            None,
+        )
+        .await?;
+        info!("Signed in with {} as {}", provider, profile.subject);
+
+        let user = self
+            .external_identity_repository
+            .find_user(provider.clone(), profile.subject.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find user of external identity: {:?}", e);
+                AppError::InternalServerError()
+            })?;
+        let user = match user {
+            Some(user) => user,
+            None => self.sign_up(provider, profile).await?,
+        };
+
+        finish_login(self.two_factor_repository.as_ref(), user).await
+    }
+
+    async fn sign_up(&self, provider: String, profile: ExternalProfile) -> Result<User, AppError> {
+        let email = profile
+            .email
+            .clone()
+            .ok_or(AppError::ExternalAccountWithoutEmail())?;
+
+        let existing_user = self
+            .user_repository
+            .find_by_email(email.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find user by email: {:?}", e);
+                AppError::InternalServerError()
+            })?;
+        if existing_user.is_some() {
+            error!("Email {} already exists", email);
+            return Err(AppError::ExternalEmailAlreadyRegistered(email));
+        }
+
+        let username = self.free_username(base_username(&profile, &email)).await?;
+        let user = User::new_external(username, email, profile.email_verified);
+        let identity = ExternalIdentity::new(user.id.clone(), provider, &profile);
+        let welcome_email = Email::registration(&user).into_job();
+
+        self.external_identity_repository
+            .insert_with_user(user.clone(), identity, vec![welcome_email])
+            .await
+            .map_err(|e| {
+                error!("Failed to insert user with external identity: {:?}", e);
+                AppError::UserRegistrationFailed(e)
+            })?;
+        info!("User {} signed up with an external identity", user.id);
+
+        Ok(user)
+    }
+
+    /// `base` if it is free, otherwise `base` with a random suffix.
+    async fn free_username(&self, base: String) -> Result<String, AppError> {
+        let mut candidate = base.clone();
+        for _ in 0..USERNAME_ATTEMPTS {
+            let taken = self
+                .user_repository
+                .find_by_username(candidate.clone())
+                .await
+                .map_err(|e| {
+                    error!("Failed to find user by username: {:?}", e);
+                    AppError::InternalServerError()
+                })?
+                .is_some();
+            if !taken {
+                return Ok(candidate);
+            }
+
+            let mut suffix = [0u8; 2];
+            rand::thread_rng().fill_bytes(&mut suffix);
+            candidate = format!("{}-{}", base, hex::encode(suffix));
+        }
+
+        error!("No free username for {}", base);
+        Err(AppError::UsernameAlreadyExists(base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_external_identity_repository::MockIExternalIdentityRepository;
+    use domain::interfaces::i_identity_provider::MockIIdentityProvider;
+    use domain::interfaces::i_two_factor_repository::MockITwoFactorRepository;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+
+    fn profile() -> ExternalProfile {
+        ExternalProfile {
+            subject: "subject".to_string(),
+            email: Some("jane@example.com".to_string()),
+            email_verified: true,
+            name: Some("Jane Doe".to_string()),
+        }
+    }
+
+    fn identity_provider() -> MockIIdentityProvider {
+        let mut identity_provider = MockIIdentityProvider::new();
+        identity_provider
+            .expect_name()
+            .returning(|| "mock".to_string());
+        identity_provider
+            .expect_exchange_code()
+            .returning(|_, _, _| Ok(profile()));
+        identity_provider
+    }
+
+    fn external_identity_repository(linked_user: Option<User>) -> MockIExternalIdentityRepository {
+        let mut external_identity_repository = MockIExternalIdentityRepository::new();
+        external_identity_repository
+            .expect_take_authorization_request()
+            .returning(|state| {
+                Ok(Some(
+                    domain::entities::external_identity::AuthorizationRequest {
+                        state,
+                        provider: "mock".to_string(),
+                        code_verifier: "verifier".to_string(),
+                        nonce: "nonce".to_string(),
+                        link_user_id: None,
+                        expires_at: chrono::Utc::now() + chrono::Duration::minutes(5),
+                        created_at: chrono::Utc::now(),
+                    },
+                ))
+            });
+        external_identity_repository
+            .expect_find_user()
+            .returning(move |_, _| Ok(linked_user.clone()));
+        external_identity_repository
+    }
+
+    fn two_factor_repository_without_two_factor() -> MockITwoFactorRepository {
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_by_user_id()
+            .returning(|_| Ok(None));
+        two_factor_repository
+    }
+
+    fn request() -> ExternalCallbackRequest {
+        ExternalCallbackRequest {
+            code: "code".to_string(),
+            state: "state".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_new_external_account_when_executing_then_user_without_password_is_created() {
+        // Arrange
+        let mut external_identity_repository = external_identity_repository(None);
+        external_identity_repository
+            .expect_insert_with_user()
+            .withf(|user, identity, _| {
+                user.name == "JaneDoe"
+                    && user.password.is_none()
+                    && user.email_verified
+                    && identity.subject == "subject"
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find_by_email()
+            .returning(|_| Ok(None));
+        user_repository
+            .expect_find_by_username()
+            .returning(|_| Ok(None));
+
+        let use_case = CompleteExternalLoginUseCase::new(
+            Arc::new(external_identity_repository),
+            Arc::new(user_repository),
+            Arc::new(two_factor_repository_without_two_factor()),
+            Arc::new(vec![identity_provider()]),
+        );
+
+        // Act
+        let result = use_case.execute("mock".to_string(), request()).await;
+
+        // Assert
+        assert!(matches!(result, Ok(LoginOutcome::SignedIn(_))));
+    }
+
+    #[tokio::test]
+    async fn given_email_of_existing_account_when_executing_then_account_is_not_taken_over() {
+        // Arrange
+        let mut external_identity_repository = external_identity_repository(None);
+        external_identity_repository
+            .expect_insert_with_user()
+            .times(0);
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository.expect_find_by_email().returning(|email| {
+            Ok(Some(User::new(
+                "jane".to_string(),
+                email,
+                "hashed_password".to_string(),
+            )))
+        });
+
+        let use_case = CompleteExternalLoginUseCase::new(
+            Arc::new(external_identity_repository),
+            Arc::new(user_repository),
+            Arc::new(two_factor_repository_without_two_factor()),
+            Arc::new(vec![identity_provider()]),
+        );
+
+        // Act
+        let result = use_case.execute("mock".to_string(), request()).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(AppError::ExternalEmailAlreadyRegistered(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_linked_external_account_when_executing_then_linked_user_is_signed_in() {
+        // Arrange
+        let linked_user = User::new_external("jane".to_string(), "email".to_string(), true);
+        let expected_id = linked_user.id.value;
+
+        let use_case = CompleteExternalLoginUseCase::new(
+            Arc::new(external_identity_repository(Some(linked_user))),
+            Arc::new(MockIUserRepository::new()),
+            Arc::new(two_factor_repository_without_two_factor()),
+            Arc::new(vec![identity_provider()]),
+        );
+
+        // Act
+        let result = use_case.execute("mock".to_string(), request()).await;
+
+        // Assert
+        match result {
+            Ok(LoginOutcome::SignedIn(user)) => assert_eq!(user.id.value, expected_id),
+            _ => panic!("expected the linked user to be signed in"),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::use_cases::external_identities::start_external_login_use_case::{
+    dtos::ExternalCallbackRequest, exchange_authorization, find_provider,
+};
+use domain::app_error::AppError;
+use domain::entities::external_identity::ExternalIdentity;
+use domain::entities::user::User;
+use domain::interfaces::i_external_identity_repository::IExternalIdentityRepository;
+use domain::interfaces::i_identity_provider::IIdentityProvider;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct LinkExternalIdentityUseCase<R: IExternalIdentityRepository, P: IIdentityProvider> {
+    external_identity_repository: Arc<R>,
+    identity_providers: Arc<Vec<P>>,
+}
+
+impl<R: IExternalIdentityRepository, P: IIdentityProvider> LinkExternalIdentityUseCase<R, P> {
+    pub fn new(external_identity_repository: Arc<R>, identity_providers: Arc<Vec<P>>) -> Self {
+        Self {
+            external_identity_repository,
+            identity_providers,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        provider: String,
+        dto: ExternalCallbackRequest,
+    ) -> Result<(), AppError> {
+        let identity_provider = find_provider(&self.identity_providers, &provider)?;
+        let profile = exchange_authorization(
+            self.external_identity_repository.as_ref(),
+            identity_provider,
+            dto,
+            Some(&current_user.id),
+        )
+        .await?;
+        info!(
+            "Linking {} account {} to user {}",
+            provider, profile.subject, current_user.id
+        );
+
+        let inserted = self
+            .external_identity_repository
+            .insert(ExternalIdentity::new(current_user.id, provider, &profile))
+            .await
+            .map_err(|e| {
+                error!("Failed to link external identity: {:?}", e);
+                AppError::InternalServerError()
+            })?;
+        if !inserted {
+            error!("External account {} is already linked.", profile.subject);
+            return Err(AppError::ExternalIdentityAlreadyLinked());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use domain::entities::external_identity::AuthorizationRequest;
+    use domain::interfaces::i_external_identity_repository::MockIExternalIdentityRepository;
+    use domain::interfaces::i_identity_provider::MockIIdentityProvider;
+
+    #[tokio::test]
+    async fn given_request_started_for_login_when_linking_then_invalid_state_error_is_returned() {
+        // Arrange
+        let mut identity_provider = MockIIdentityProvider::new();
+        identity_provider
+            .expect_name()
+            .returning(|| "mock".to_string());
+        identity_provider.expect_exchange_code().times(0);
+
+        let mut external_identity_repository = MockIExternalIdentityRepository::new();
+        external_identity_repository
+            .expect_take_authorization_request()
+            .returning(|state| {
+                Ok(Some(AuthorizationRequest {
+                    state,
+                    provider: "mock".to_string(),
+                    code_verifier: "verifier".to_string(),
+                    nonce: "nonce".to_string(),
+                    link_user_id: None,
+                    expires_at: Utc::now() + Duration::minutes(5),
+                    created_at: Utc::now(),
+                }))
+            });
+        external_identity_repository.expect_insert().times(0);
+
+        let use_case = LinkExternalIdentityUseCase::new(
+            Arc::new(external_identity_repository),
+            Arc::new(vec![identity_provider]),
+        );
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let dto = ExternalCallbackRequest {
+            code: "code".to_string(),
+            state: "state".to_string(),
+        };
+
+        // Act
+        let result = use_case
+            .execute(current_user, "mock".to_string(), dto)
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidAuthorizationState())));
+    }
+}
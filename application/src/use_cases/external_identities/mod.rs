@@ -0,0 +1,3 @@
+pub mod complete_external_login_use_case;
+pub mod link_external_identity_use_case;
+pub mod start_external_login_use_case;
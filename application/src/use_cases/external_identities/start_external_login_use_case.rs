@@ -0,0 +1,211 @@
+use crate::pkce;
+use chrono::{Duration, Utc};
+use domain::app_error::AppError;
+use domain::entities::external_identity::{AuthorizationRequest, ExternalProfile};
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_external_identity_repository::IExternalIdentityRepository;
+use domain::interfaces::i_identity_provider::IIdentityProvider;
+use rand::RngCore;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const AUTHORIZATION_LIFETIME_MINUTES: i64 = 10;
+
+pub mod dtos {
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use serde::{Deserialize, Serialize};
+    use validator::Validate;
+
+    #[derive(Serialize, Debug)]
+    pub struct AuthorizationUrlDto {
+        pub authorization_url: String,
+    }
+
+    impl IntoResponse for AuthorizationUrlDto {
+        fn into_response(self) -> Response {
+            Json(self).into_response()
+        }
+    }
+
+    /// What the identity provider appends to the redirect back to the frontend.
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct ExternalCallbackRequest {
+        #[validate(length(min = 1, message = "Code is required"))]
+        pub code: String,
+        #[validate(length(min = 1, message = "State is required"))]
+        pub state: String,
+    }
+}
+
+fn random_hex(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+pub(crate) fn find_provider<'a, P: IIdentityProvider>(
+    identity_providers: &'a [P],
+    name: &str,
+) -> Result<&'a P, AppError> {
+    identity_providers
+        .iter()
+        .find(|provider| provider.name() == name)
+        .ok_or_else(|| AppError::UnknownIdentityProvider(name.to_string()))
+}
+
+/// Takes the authorization request the browser came back with and trades its code for the
+/// profile of the account that signed in. `link_user_id` has to match the one the request was
+/// started with, so a login cannot be turned into linking an account or the other way round.
+pub(crate) async fn exchange_authorization<R: IExternalIdentityRepository, P: IIdentityProvider>(
+    external_identity_repository: &R,
+    provider: &P,
+    dto: dtos::ExternalCallbackRequest,
+    link_user_id: Option<&Id<User>>,
+) -> Result<ExternalProfile, AppError> {
+    let request = external_identity_repository
+        .take_authorization_request(dto.state)
+        .await
+        .map_err(|e| {
+            error!("Failed to get authorization request: {:?}", e);
+            AppError::InternalServerError()
+        })?
+        .filter(|request| {
+            request.provider == provider.name()
+                && request.link_user_id.as_ref().map(|id| id.value)
+                    == link_user_id.map(|id| id.value)
+        })
+        .ok_or(AppError::InvalidAuthorizationState())?;
+
+    provider
+        .exchange_code(dto.code, request.code_verifier, request.nonce)
+        .await
+        .map_err(|e| {
+            error!("Failed to exchange authorization code: {:?}", e);
+            AppError::ExternalLoginFailed()
+        })
+}
+
+pub struct StartExternalLoginUseCase<R: IExternalIdentityRepository, P: IIdentityProvider> {
+    external_identity_repository: Arc<R>,
+    identity_providers: Arc<Vec<P>>,
+}
+
+impl<R: IExternalIdentityRepository, P: IIdentityProvider> StartExternalLoginUseCase<R, P> {
+    pub fn new(external_identity_repository: Arc<R>, identity_providers: Arc<Vec<P>>) -> Self {
+        Self {
+            external_identity_repository,
+            identity_providers,
+        }
+    }
+
+    /// Returns where to send the browser. With `link_user` the account signed in with is linked
+    /// to that user rather than signed in as.
+    pub async fn execute(
+        &self,
+        provider: String,
+        link_user: Option<User>,
+    ) -> Result<dtos::AuthorizationUrlDto, AppError> {
+        info!("Starting sign in with {}", provider);
+        let identity_provider = find_provider(&self.identity_providers, &provider)?;
+
+        let code_verifier = pkce::generate_code_verifier();
+        let request = AuthorizationRequest {
+            state: random_hex(32),
+            provider,
+            code_verifier: code_verifier.clone(),
+            nonce: random_hex(16),
+            link_user_id: link_user.map(|user| user.id),
+            expires_at: Utc::now() + Duration::minutes(AUTHORIZATION_LIFETIME_MINUTES),
+            created_at: Utc::now(),
+        };
+
+        let authorization_url = identity_provider
+            .authorization_url(
+                request.state.clone(),
+                pkce::code_challenge(&code_verifier),
+                request.nonce.clone(),
+            )
+            .await
+            .map_err(|e| {
+                error!("Failed to build authorization URL: {:?}", e);
+                AppError::ExternalLoginFailed()
+            })?;
+
+        self.external_identity_repository
+            .insert_authorization_request(request)
+            .await
+            .map_err(|e| {
+                error!("Failed to store authorization request: {:?}", e);
+                AppError::InternalServerError()
+            })?;
+
+        Ok(dtos::AuthorizationUrlDto { authorization_url })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_external_identity_repository::MockIExternalIdentityRepository;
+    use domain::interfaces::i_identity_provider::MockIIdentityProvider;
+
+    #[tokio::test]
+    async fn given_known_provider_when_executing_then_request_is_stored_and_url_is_returned() {
+        // Arrange
+        let mut identity_provider = MockIIdentityProvider::new();
+        identity_provider
+            .expect_name()
+            .returning(|| "mock".to_string());
+        identity_provider
+            .expect_authorization_url()
+            .returning(|state, code_challenge, _| {
+                Ok(format!(
+                    "http://idp/authorize?state={}&code_challenge={}",
+                    state, code_challenge
+                ))
+            });
+
+        let mut external_identity_repository = MockIExternalIdentityRepository::new();
+        external_identity_repository
+            .expect_insert_authorization_request()
+            .withf(|request| request.provider == "mock" && request.link_user_id.is_none())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = StartExternalLoginUseCase::new(
+            Arc::new(external_identity_repository),
+            Arc::new(vec![identity_provider]),
+        );
+
+        // Act
+        let result = use_case.execute("mock".to_string(), None).await;
+
+        // Assert
+        let url = result.unwrap().authorization_url;
+        assert!(url.starts_with("http://idp/authorize?state="));
+        assert!(url.contains("&code_challenge="));
+    }
+
+    #[tokio::test]
+    async fn given_unknown_provider_when_executing_then_unknown_identity_provider_error_is_returned(
+    ) {
+        // Arrange
+        let mut identity_provider = MockIIdentityProvider::new();
+        identity_provider
+            .expect_name()
+            .returning(|| "mock".to_string());
+
+        let use_case = StartExternalLoginUseCase::new(
+            Arc::new(MockIExternalIdentityRepository::new()),
+            Arc::new(vec![identity_provider]),
+        );
+
+        // Act
+        let result = use_case.execute("other".to_string(), None).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::UnknownIdentityProvider(_))));
+    }
+}
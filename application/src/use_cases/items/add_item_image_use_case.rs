@@ -0,0 +1,107 @@
+use crate::images::{discard_blobs, store_item_images};
+use crate::use_cases::items::update_item_use_case::find_editable_item;
+use domain::app_error::AppError;
+use domain::entities::item_image::MAX_ITEM_IMAGES;
+use domain::entities::user::User;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_blob_store::IBlobStore;
+use domain::interfaces::i_item_image_repository::IItemImageRepository;
+use domain::interfaces::i_item_repository::IItemRepository;
+use std::io::Read;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use axum_typed_multipart::{FieldData, TryFromMultipart};
+    use serde::Serialize;
+    use tempfile::NamedTempFile;
+
+    #[derive(Debug, TryFromMultipart)]
+    pub struct AddItemImageRequest {
+        pub image: FieldData<NamedTempFile>,
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct ItemImageDto {
+        pub position: i32,
+    }
+
+    impl IntoResponse for ItemImageDto {
+        fn into_response(self) -> Response {
+            (StatusCode::CREATED, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct AddItemImageUseCase<
+    R1: IItemRepository,
+    R2: IAuctionRepository,
+    R3: IItemImageRepository,
+    R4: IBlobStore,
+> {
+    item_repository: Arc<R1>,
+    auction_repository: Arc<R2>,
+    item_image_repository: Arc<R3>,
+    blob_store: Arc<R4>,
+}
+
+impl<R1: IItemRepository, R2: IAuctionRepository, R3: IItemImageRepository, R4: IBlobStore>
+    AddItemImageUseCase<R1, R2, R3, R4>
+{
+    pub fn new(
+        item_repository: Arc<R1>,
+        auction_repository: Arc<R2>,
+        item_image_repository: Arc<R3>,
+        blob_store: Arc<R4>,
+    ) -> Self {
+        Self {
+            item_repository,
+            auction_repository,
+            item_image_repository,
+            blob_store,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        item_id: String,
+        mut request: dtos::AddItemImageRequest,
+    ) -> Result<dtos::ItemImageDto, AppError> {
+        info!("Adding image to item with id: {}", item_id);
+
+        let item = find_editable_item(
+            self.item_repository.as_ref(),
+            self.auction_repository.as_ref(),
+            &current_user,
+            item_id,
+        )
+        .await?;
+
+        let mut data = Vec::new();
+        request.image.contents.read_to_end(&mut data).map_err(|e| {
+            error!("Failed to read image from request: {:?}", e);
+            AppError::FailedToUpdateItemImages()
+        })?;
+
+        // the position is assigned by the repository
+        let images = store_item_images(self.blob_store.as_ref(), item.id, 0, data).await?;
+        let keys = images.iter().map(|image| image.blob_key.clone()).collect();
+
+        let result = self.item_image_repository.append(images).await;
+        if !matches!(result, Ok(Some(_))) {
+            discard_blobs(self.blob_store.as_ref(), keys).await;
+        }
+        let position = result
+            .map_err(|e| {
+                error!("Failed to add image: {:?}", e);
+                AppError::FailedToUpdateItemImages()
+            })?
+            .ok_or(AppError::TooManyItemImages(MAX_ITEM_IMAGES))?;
+
+        Ok(dtos::ItemImageDto { position })
+    }
+}
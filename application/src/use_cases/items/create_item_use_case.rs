@@ -1,8 +1,11 @@
+use crate::images::{discard_blobs, store_item_images};
 use anyhow::anyhow;
 use domain::app_error::AppError;
 use domain::entities::item::Item;
 use domain::entities::user::User;
+use domain::interfaces::i_blob_store::IBlobStore;
 use domain::interfaces::i_item_repository::IItemRepository;
+use std::io::Read;
 use std::sync::Arc;
 use tracing::{error, info};
 
@@ -12,11 +15,10 @@ pub mod dtos {
     use domain::app_error::AppError;
     use domain::entities::item::Item;
     use domain::id::Id;
-    use std::io::Read;
     use tempfile::NamedTempFile;
     use validator::{Validate, ValidationError};
 
-    fn validate_category(value: &str) -> Result<(), ValidationError> {
+    pub(crate) fn validate_category(value: &str) -> Result<(), ValidationError> {
         match value {
             "art" | "sport" | "electronics" | "services" | "diverse" => Ok(()),
             _ => Err(ValidationError::new(
@@ -51,21 +53,10 @@ pub mod dtos {
     impl TryFrom<CreateItemRequest> for Item {
         type Error = AppError;
 
-        fn try_from(mut dto: CreateItemRequest) -> Result<Item, AppError> {
-            let mut picture = Vec::new();
-            if let Some(field) = dto.picture.as_mut() {
-                field.contents.read_to_end(&mut picture).map_err(|e| {
-                    AppError::CreateItemFailed(anyhow!(
-                        "Failed to read picture from request: {:?}",
-                        e
-                    ))
-                })?;
-            }
-
+        fn try_from(dto: CreateItemRequest) -> Result<Item, AppError> {
             Ok(Item::new(
                 dto.brief,
                 dto.description,
-                picture,
                 Id::try_from(dto.user_id.unwrap_or_default().clone()).map_err(|_| {
                     AppError::CreateItemFailed(anyhow!(
                         "Cannot assign invalid user_id to newly created items"
@@ -77,35 +68,55 @@ pub mod dtos {
     }
 }
 
-pub struct CreateItemUseCase<R: IItemRepository> {
-    item_repository: Arc<R>,
+pub struct CreateItemUseCase<R1: IItemRepository, R2: IBlobStore> {
+    item_repository: Arc<R1>,
+    blob_store: Arc<R2>,
 }
 
-impl<R: IItemRepository> CreateItemUseCase<R> {
-    pub fn new(item_repository: Arc<R>) -> Self {
-        Self { item_repository }
+impl<R1: IItemRepository, R2: IBlobStore> CreateItemUseCase<R1, R2> {
+    pub fn new(item_repository: Arc<R1>, blob_store: Arc<R2>) -> Self {
+        Self {
+            item_repository,
+            blob_store,
+        }
     }
 
     pub async fn execute(
         &self,
         current_user: User,
-        dto: dtos::CreateItemRequest,
+        mut dto: dtos::CreateItemRequest,
     ) -> Result<(), AppError> {
         info!("Creating items with brief: {}", dto.brief);
 
+        let mut picture = Vec::new();
+        if let Some(field) = dto.picture.as_mut() {
+            field.contents.read_to_end(&mut picture).map_err(|e| {
+                AppError::CreateItemFailed(anyhow!("Failed to read picture from request: {:?}", e))
+            })?;
+        }
+
         let dto = dtos::CreateItemRequest {
             user_id: Some(current_user.id.to_string()),
             ..dto
         };
         let item: Item = dto.try_into()?;
+        // the picture of the create form is the first image of the gallery
+        let images = match picture.is_empty() {
+            true => vec![],
+            false => {
+                store_item_images(self.blob_store.as_ref(), item.id.clone(), 0, picture).await?
+            }
+        };
+        let keys = images.iter().map(|image| image.blob_key.clone()).collect();
 
-        match self.item_repository.insert(item).await {
+        match self.item_repository.insert(item, images).await {
             Ok(Some(_)) => {
                 info!("Item created successfully");
                 Ok(())
             }
             _ => {
                 error!("Failed to create items");
+                discard_blobs(self.blob_store.as_ref(), keys).await;
                 Err(AppError::CreateItemFailed(anyhow!(
                     "Failed to create items"
                 )))
@@ -120,6 +131,7 @@ mod tests {
     use domain::app_error::AppError::CreateItemFailed;
     use domain::entities::item::{Category, Item};
     use domain::id::Id;
+    use domain::interfaces::i_blob_store::MockIBlobStore;
     use domain::interfaces::i_item_repository::MockIItemRepository;
     use std::sync::Arc;
     use uuid::Uuid;
@@ -130,17 +142,17 @@ mod tests {
         let mut item_repository = MockIItemRepository::new();
         let user_id = Uuid::new_v4();
 
-        item_repository.expect_insert().returning(move |_| {
+        item_repository.expect_insert().returning(move |_, _| {
             Ok(Some(Item::new(
                 "brief".to_string(),
                 "description".to_string(),
-                vec![0],
                 Id::try_from(user_id.clone().to_string()).unwrap(),
                 Category::Art,
             )))
         });
 
-        let use_case = CreateItemUseCase::new(Arc::new(item_repository));
+        let use_case =
+            CreateItemUseCase::new(Arc::new(item_repository), Arc::new(MockIBlobStore::new()));
 
         let current_user = domain::entities::user::User::new(
             "username".to_string(),
@@ -168,9 +180,10 @@ mod tests {
         // Arrange
         let mut item_repository = MockIItemRepository::new();
 
-        item_repository.expect_insert().returning(|_| Ok(None));
+        item_repository.expect_insert().returning(|_, _| Ok(None));
 
-        let use_case = CreateItemUseCase::new(Arc::new(item_repository));
+        let use_case =
+            CreateItemUseCase::new(Arc::new(item_repository), Arc::new(MockIBlobStore::new()));
 
         let current_user = domain::entities::user::User::new(
             "username".to_string(),
@@ -0,0 +1,74 @@
+use crate::images::discard_blobs;
+use crate::use_cases::items::update_item_use_case::find_editable_item;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_blob_store::IBlobStore;
+use domain::interfaces::i_item_image_repository::IItemImageRepository;
+use domain::interfaces::i_item_repository::IItemRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct DeleteItemImageUseCase<
+    R1: IItemRepository,
+    R2: IAuctionRepository,
+    R3: IItemImageRepository,
+    R4: IBlobStore,
+> {
+    item_repository: Arc<R1>,
+    auction_repository: Arc<R2>,
+    item_image_repository: Arc<R3>,
+    blob_store: Arc<R4>,
+}
+
+impl<R1: IItemRepository, R2: IAuctionRepository, R3: IItemImageRepository, R4: IBlobStore>
+    DeleteItemImageUseCase<R1, R2, R3, R4>
+{
+    pub fn new(
+        item_repository: Arc<R1>,
+        auction_repository: Arc<R2>,
+        item_image_repository: Arc<R3>,
+        blob_store: Arc<R4>,
+    ) -> Self {
+        Self {
+            item_repository,
+            auction_repository,
+            item_image_repository,
+            blob_store,
+        }
+    }
+
+    /// The images after the deleted one move up by one position.
+    pub async fn execute(
+        &self,
+        current_user: User,
+        item_id: String,
+        position: i32,
+    ) -> Result<(), AppError> {
+        info!("Deleting image {} of item with id: {}", position, item_id);
+
+        let item = find_editable_item(
+            self.item_repository.as_ref(),
+            self.auction_repository.as_ref(),
+            &current_user,
+            item_id.clone(),
+        )
+        .await?;
+
+        let deleted = self
+            .item_image_repository
+            .delete(item.id, position)
+            .await
+            .map_err(|e| {
+                error!("Failed to delete image: {:?}", e);
+                AppError::FailedToUpdateItemImages()
+            })?;
+
+        if deleted.is_empty() {
+            return Err(AppError::ItemImageNotFound(item_id, position));
+        }
+        discard_blobs(self.blob_store.as_ref(), deleted).await;
+
+        Ok(())
+    }
+}
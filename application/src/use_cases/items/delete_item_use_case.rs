@@ -0,0 +1,100 @@
+use crate::use_cases::items::update_item_use_case::find_editable_item;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_item_repository::IItemRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct DeleteItemUseCase<R1: IItemRepository, R2: IAuctionRepository> {
+    item_repository: Arc<R1>,
+    auction_repository: Arc<R2>,
+}
+
+impl<R1: IItemRepository, R2: IAuctionRepository> DeleteItemUseCase<R1, R2> {
+    pub fn new(item_repository: Arc<R1>, auction_repository: Arc<R2>) -> Self {
+        Self {
+            item_repository,
+            auction_repository,
+        }
+    }
+
+    pub async fn execute(&self, current_user: User, item_id: String) -> Result<(), AppError> {
+        info!("Deleting item with id: {}", item_id);
+
+        let item = find_editable_item(
+            self.item_repository.as_ref(),
+            self.auction_repository.as_ref(),
+            &current_user,
+            item_id.clone(),
+        )
+        .await?;
+
+        let deleted = self
+            .item_repository
+            .soft_delete(item.id, current_user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to delete item: {:?}", e);
+                AppError::FailedToDeleteItem()
+            })?;
+
+        match deleted {
+            true => Ok(()),
+            false => Err(AppError::ItemHasAuction(item_id)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use domain::entities::auction::{Auction, AuctionStrategy};
+    use domain::entities::item::{Category, Item};
+    use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+    use domain::interfaces::i_item_repository::MockIItemRepository;
+
+    #[tokio::test]
+    async fn given_item_with_ongoing_auction_when_executing_then_item_is_not_deleted() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email@example.com".to_string(),
+            "password".to_string(),
+        );
+        let item = Item::new(
+            "brief".to_string(),
+            "description".to_string(),
+            current_user.id.clone(),
+            Category::Art,
+        );
+        let item_id = item.id.to_string();
+        let auction = Auction::new(
+            item.id.clone(),
+            10.0,
+            Utc::now() + Duration::days(1),
+            AuctionStrategy::Standard,
+        );
+
+        let mut item_repository = MockIItemRepository::new();
+        item_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(item.clone())));
+        item_repository.expect_soft_delete().times(0);
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_item_id()
+            .returning(move |_| Ok(Some(auction.clone())));
+
+        let use_case =
+            DeleteItemUseCase::new(Arc::new(item_repository), Arc::new(auction_repository));
+
+        // Act
+        let result = use_case.execute(current_user, item_id).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::ItemHasAuction(_))));
+    }
+}
@@ -1,28 +1,128 @@
+use crate::images::load_image_data;
 use anyhow::anyhow;
 use domain::app_error::AppError;
 use domain::entities::item::Item;
+use domain::entities::item_image::THUMBNAIL_SIZES;
 use domain::entities::user::User;
 use domain::id::Id;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_blob_store::IBlobStore;
+use domain::interfaces::i_item_image_repository::IItemImageRepository;
 use domain::interfaces::i_item_repository::IItemRepository;
 use std::sync::Arc;
 use tracing::{error, info};
 
-pub struct GetItemImageUseCase<R1: IItemRepository, R2: IAuctionRepository> {
+pub mod dtos {
+    use axum::http::{header, StatusCode};
+    use axum::response::{IntoResponse, Response};
+    use serde::Deserialize;
+
+    /// Images are private to the owner and bidders, and a position shows another image once the
+    /// gallery is reordered, so clients keep their copy but check it with the ETag.
+    const CACHE_CONTROL: &str = "private, no-cache";
+
+    #[derive(Deserialize, Debug, Default)]
+    pub struct GetItemImageRequest {
+        /// Longest edge of the thumbnail to serve instead of the image itself.
+        pub size: Option<i32>,
+    }
+
+    #[derive(Debug)]
+    pub enum ItemImageFileDto {
+        Image {
+            etag: String,
+            content_type: String,
+            data: Vec<u8>,
+        },
+        /// The copy named by `If-None-Match` is still current.
+        NotModified { etag: String },
+    }
+
+    impl IntoResponse for ItemImageFileDto {
+        fn into_response(self) -> Response {
+            match self {
+                ItemImageFileDto::Image {
+                    etag,
+                    content_type,
+                    data,
+                } => (
+                    StatusCode::OK,
+                    [
+                        (header::CONTENT_TYPE, content_type),
+                        (header::ETAG, etag),
+                        (header::CACHE_CONTROL, CACHE_CONTROL.to_string()),
+                    ],
+                    data,
+                )
+                    .into_response(),
+                ItemImageFileDto::NotModified { etag } => (
+                    StatusCode::NOT_MODIFIED,
+                    [
+                        (header::ETAG, etag),
+                        (header::CACHE_CONTROL, CACHE_CONTROL.to_string()),
+                    ],
+                )
+                    .into_response(),
+            }
+        }
+    }
+}
+
+/// Weak comparison as `If-None-Match` asks for, `W/"a"` matches `"a"`.
+fn matches_etag(if_none_match: &str, etag: &str) -> bool {
+    if_none_match
+        .split(',')
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+pub struct GetItemImageUseCase<
+    R1: IItemRepository,
+    R2: IAuctionRepository,
+    R3: IItemImageRepository,
+    R4: IBlobStore,
+> {
     item_repository: Arc<R1>,
     auction_repository: Arc<R2>,
+    item_image_repository: Arc<R3>,
+    blob_store: Arc<R4>,
 }
 
-impl<R1: IItemRepository, R2: IAuctionRepository> GetItemImageUseCase<R1, R2> {
-    pub fn new(item_repository: Arc<R1>, auction_repository: Arc<R2>) -> Self {
+impl<R1: IItemRepository, R2: IAuctionRepository, R3: IItemImageRepository, R4: IBlobStore>
+    GetItemImageUseCase<R1, R2, R3, R4>
+{
+    pub fn new(
+        item_repository: Arc<R1>,
+        auction_repository: Arc<R2>,
+        item_image_repository: Arc<R3>,
+        blob_store: Arc<R4>,
+    ) -> Self {
         Self {
             item_repository,
             auction_repository,
+            item_image_repository,
+            blob_store,
         }
     }
 
-    pub async fn execute(&self, current_user: User, item_id: String) -> Result<Vec<u8>, AppError> {
-        info!("Getting image for items with id: {}", item_id);
+    /// The single-picture API asks for position 0.
+    pub async fn execute(
+        &self,
+        current_user: User,
+        item_id: String,
+        position: i32,
+        request: dtos::GetItemImageRequest,
+        if_none_match: Option<String>,
+    ) -> Result<dtos::ItemImageFileDto, AppError> {
+        info!("Getting image {} for items with id: {}", position, item_id);
+
+        if let Some(size) = request.size {
+            if !THUMBNAIL_SIZES.contains(&size) {
+                return Err(AppError::InvalidThumbnailSize(
+                    THUMBNAIL_SIZES.map(|size| size.to_string()).join(", "),
+                ));
+            }
+        }
 
         let id = Id::<Item>::try_from(item_id.clone()).map_err(|_| {
             AppError::GetItemImageFailed(anyhow!("Cannot assign invalid item_id to get image"))
@@ -43,7 +143,40 @@ impl<R1: IItemRepository, R2: IAuctionRepository> GetItemImageUseCase<R1, R2> {
                 match item.user_id == current_user.id || auction.is_some() {
                     true => {
                         info!("Image belongs to user or has an ongoing auction");
-                        Ok(item.picture)
+                        let image = self
+                            .item_image_repository
+                            .find(item.id, position, request.size)
+                            .await
+                            .map_err(|_| {
+                                AppError::GetItemImageFailed(anyhow!("Failed to get image"))
+                            })?
+                            .ok_or(AppError::ItemImageNotFound(item_id.clone(), position))?;
+
+                        // rows are never updated in place, so the id stands for the content
+                        let etag = format!("\"{}\"", image.id);
+                        if let Some(tags) = if_none_match {
+                            if matches_etag(&tags, &etag) {
+                                return Ok(dtos::ItemImageFileDto::NotModified { etag });
+                            }
+                        }
+
+                        let data = load_image_data(
+                            self.blob_store.as_ref(),
+                            self.item_image_repository.as_ref(),
+                            &image,
+                        )
+                        .await
+                        .map_err(|e| {
+                            error!("Failed to get image data: {:?}", e);
+                            AppError::GetItemImageFailed(anyhow!("Failed to get image data"))
+                        })?
+                        .ok_or(AppError::ItemImageNotFound(item_id, position))?;
+
+                        Ok(dtos::ItemImageFileDto::Image {
+                            etag,
+                            content_type: image.content_type,
+                            data,
+                        })
                     }
                     _ => {
                         error!(
@@ -66,10 +199,13 @@ impl<R1: IItemRepository, R2: IAuctionRepository> GetItemImageUseCase<R1, R2> {
 
 #[cfg(test)]
 mod tests {
-    use crate::use_cases::items::get_item_image_use_case::GetItemImageUseCase;
+    use crate::use_cases::items::get_item_image_use_case::{dtos, GetItemImageUseCase};
     use domain::app_error::AppError;
     use domain::entities::item::{Category, Item};
+    use domain::entities::item_image::ItemImage;
     use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+    use domain::interfaces::i_blob_store::MockIBlobStore;
+    use domain::interfaces::i_item_image_repository::MockIItemImageRepository;
     use domain::interfaces::i_item_repository::MockIItemRepository;
     use std::sync::Arc;
 
@@ -81,7 +217,6 @@ mod tests {
         let item = Item::new(
             "brief".to_string(),
             "description".to_string(),
-            vec![],
             user_id.clone().try_into().unwrap(),
             Category::Diverse,
         );
@@ -99,7 +234,90 @@ mod tests {
             id: user_id.try_into().unwrap(),
             name: "username".to_string(),
             email: "email".to_string(),
-            password: "password".to_string(),
+            password: Some("password".to_string()),
+            email_verified: true,
+            role: domain::entities::user::Role::User,
+            suspended_at: None,
+            deleted_at: None,
+        };
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_item_id()
+            .returning(|_| Ok(None));
+        let auction_repository = Arc::new(auction_repository);
+
+        let mut item_image_repository = MockIItemImageRepository::new();
+        item_image_repository
+            .expect_find()
+            .withf(|_, position, _| *position == 0)
+            .returning(|item_id, position, _| {
+                Ok(Some(ItemImage::new(
+                    item_id,
+                    position,
+                    "image/png".to_string(),
+                )))
+            });
+
+        let mut blob_store = MockIBlobStore::new();
+        blob_store.expect_get().returning(|_| Ok(Some(vec![1])));
+
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            item_repository,
+            auction_repository,
+            Arc::new(item_image_repository),
+            Arc::new(blob_store),
+        );
+
+        // Act
+        let result = get_item_image_use_case
+            .execute(
+                current_user,
+                item_id.to_string(),
+                0,
+                Default::default(),
+                None,
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Ok(dtos::ItemImageFileDto::Image { data, .. }) if data == vec![1]
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_image_not_moved_to_blob_store_when_get_item_image_use_case_then_return_legacy_data(
+    ) {
+        // Arrange
+        let item_id = String::from("00000000-0000-0000-0000-000000000000");
+        let user_id = String::from("00000000-0000-0000-0000-000000000001");
+        let item = Item::new(
+            "brief".to_string(),
+            "description".to_string(),
+            user_id.clone().try_into().unwrap(),
+            Category::Diverse,
+        );
+        let mut item_repository = MockIItemRepository::new();
+
+        let item_id_clone = item_id.clone();
+        item_repository
+            .expect_find()
+            .withf(move |id| id.value.to_string() == item_id_clone)
+            .returning(move |_| Ok(Some(item.clone())));
+
+        let item_repository = Arc::new(item_repository);
+
+        let current_user = domain::entities::user::User {
+            id: user_id.try_into().unwrap(),
+            name: "username".to_string(),
+            email: "email".to_string(),
+            password: Some("password".to_string()),
+            email_verified: true,
+            role: domain::entities::user::Role::User,
+            suspended_at: None,
+            deleted_at: None,
         };
 
         let mut auction_repository = MockIAuctionRepository::new();
@@ -108,15 +326,48 @@ mod tests {
             .returning(|_| Ok(None));
         let auction_repository = Arc::new(auction_repository);
 
-        let get_item_image_use_case = GetItemImageUseCase::new(item_repository, auction_repository);
+        let mut item_image_repository = MockIItemImageRepository::new();
+        item_image_repository
+            .expect_find()
+            .withf(|_, position, _| *position == 0)
+            .returning(|item_id, position, _| {
+                Ok(Some(ItemImage::new(
+                    item_id,
+                    position,
+                    "image/png".to_string(),
+                )))
+            });
+        item_image_repository
+            .expect_find_legacy_data()
+            .times(1)
+            .returning(|_| Ok(Some(vec![2])));
+
+        let mut blob_store = MockIBlobStore::new();
+        blob_store.expect_get().times(1).returning(|_| Ok(None));
+
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            item_repository,
+            auction_repository,
+            Arc::new(item_image_repository),
+            Arc::new(blob_store),
+        );
 
         // Act
         let result = get_item_image_use_case
-            .execute(current_user, item_id.to_string())
+            .execute(
+                current_user,
+                item_id.to_string(),
+                0,
+                Default::default(),
+                None,
+            )
             .await;
 
         // Assert
-        assert!(result.is_ok());
+        assert!(matches!(
+            result,
+            Ok(dtos::ItemImageFileDto::Image { data, .. }) if data == vec![2]
+        ));
     }
 
     #[tokio::test]
@@ -129,7 +380,6 @@ mod tests {
         let item = Item::new(
             "brief".to_string(),
             "description".to_string(),
-            vec![],
             user_id.clone().try_into().unwrap(),
             Category::Diverse,
         );
@@ -147,7 +397,11 @@ mod tests {
             id: not_owner_user_id.try_into().unwrap(),
             name: "username".to_string(),
             email: "email".to_string(),
-            password: "password".to_string(),
+            password: Some("password".to_string()),
+            email_verified: true,
+            role: domain::entities::user::Role::User,
+            suspended_at: None,
+            deleted_at: None,
         };
 
         let mut auction_repository = MockIAuctionRepository::new();
@@ -166,15 +420,44 @@ mod tests {
             .returning(move |_| Ok(Some(auction.clone())));
         let auction_repository = Arc::new(auction_repository);
 
-        let get_item_image_use_case = GetItemImageUseCase::new(item_repository, auction_repository);
+        let mut item_image_repository = MockIItemImageRepository::new();
+        item_image_repository
+            .expect_find()
+            .withf(|_, position, _| *position == 0)
+            .returning(|item_id, position, _| {
+                Ok(Some(ItemImage::new(
+                    item_id,
+                    position,
+                    "image/png".to_string(),
+                )))
+            });
+
+        let mut blob_store = MockIBlobStore::new();
+        blob_store.expect_get().returning(|_| Ok(Some(vec![1])));
+
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            item_repository,
+            auction_repository,
+            Arc::new(item_image_repository),
+            Arc::new(blob_store),
+        );
 
         // Act
         let result = get_item_image_use_case
-            .execute(current_user, item_id.to_string())
+            .execute(
+                current_user,
+                item_id.to_string(),
+                0,
+                Default::default(),
+                None,
+            )
             .await;
 
         // Assert
-        assert!(result.is_ok());
+        assert!(matches!(
+            result,
+            Ok(dtos::ItemImageFileDto::Image { data, .. }) if data == vec![1]
+        ));
     }
 
     #[tokio::test]
@@ -201,11 +484,22 @@ mod tests {
             .returning(|_| Ok(None));
         let auction_repository = Arc::new(auction_repository);
 
-        let get_item_image_use_case = GetItemImageUseCase::new(item_repository, auction_repository);
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            item_repository,
+            auction_repository,
+            Arc::new(MockIItemImageRepository::new()),
+            Arc::new(MockIBlobStore::new()),
+        );
 
         // Act
         let result = get_item_image_use_case
-            .execute(current_user, item_id.to_string())
+            .execute(
+                current_user,
+                item_id.to_string(),
+                0,
+                Default::default(),
+                None,
+            )
             .await;
 
         // Assert
@@ -222,7 +516,6 @@ mod tests {
         let item = Item::new(
             "brief".to_string(),
             "description".to_string(),
-            vec![],
             String::from("00000000-0000-0000-0000-000000000001")
                 .try_into()
                 .unwrap(),
@@ -248,11 +541,22 @@ mod tests {
             .returning(|_| Ok(None));
         let auction_repository = Arc::new(auction_repository);
 
-        let get_item_image_use_case = GetItemImageUseCase::new(item_repository, auction_repository);
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            item_repository,
+            auction_repository,
+            Arc::new(MockIItemImageRepository::new()),
+            Arc::new(MockIBlobStore::new()),
+        );
 
         // Act
         let result = get_item_image_use_case
-            .execute(current_user, item_id.to_string())
+            .execute(
+                current_user,
+                item_id.to_string(),
+                0,
+                Default::default(),
+                None,
+            )
             .await;
 
         // Assert
@@ -261,4 +565,145 @@ mod tests {
             _ => panic!("Test failed"),
         }
     }
+
+    #[tokio::test]
+    async fn given_position_past_the_last_image_when_get_item_image_use_case_then_return_not_found()
+    {
+        // Arrange
+        let current_user = domain::entities::user::User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "password".to_string(),
+        );
+        let item = Item::new(
+            "brief".to_string(),
+            "description".to_string(),
+            current_user.id.clone(),
+            Category::Diverse,
+        );
+        let item_id = item.id.to_string();
+
+        let mut item_repository = MockIItemRepository::new();
+        item_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(item.clone())));
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_item_id()
+            .returning(|_| Ok(None));
+
+        let mut item_image_repository = MockIItemImageRepository::new();
+        item_image_repository
+            .expect_find()
+            .returning(|_, _, _| Ok(None));
+
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            Arc::new(item_repository),
+            Arc::new(auction_repository),
+            Arc::new(item_image_repository),
+            Arc::new(MockIBlobStore::new()),
+        );
+
+        // Act
+        let result = get_item_image_use_case
+            .execute(current_user, item_id, 3, Default::default(), None)
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::ItemImageNotFound(_, 3))));
+    }
+
+    #[tokio::test]
+    async fn given_etag_of_current_image_when_get_item_image_use_case_then_return_not_modified() {
+        // Arrange
+        let current_user = domain::entities::user::User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "password".to_string(),
+        );
+        let item = Item::new(
+            "brief".to_string(),
+            "description".to_string(),
+            current_user.id.clone(),
+            Category::Diverse,
+        );
+        let item_id = item.id.to_string();
+        let image = ItemImage::new(item.id.clone(), 0, "image/jpeg".to_string());
+        let etag = format!("\"{}\"", image.id);
+
+        let mut item_repository = MockIItemRepository::new();
+        item_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(item.clone())));
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_item_id()
+            .returning(|_| Ok(None));
+
+        let mut item_image_repository = MockIItemImageRepository::new();
+        item_image_repository
+            .expect_find()
+            .withf(|_, _, thumbnail_size| *thumbnail_size == Some(160))
+            .returning(move |_, _, _| Ok(Some(image.clone())));
+
+        let mut blob_store = MockIBlobStore::new();
+        blob_store.expect_get().times(0);
+
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            Arc::new(item_repository),
+            Arc::new(auction_repository),
+            Arc::new(item_image_repository),
+            Arc::new(blob_store),
+        );
+
+        // Act
+        let result = get_item_image_use_case
+            .execute(
+                current_user,
+                item_id,
+                0,
+                dtos::GetItemImageRequest { size: Some(160) },
+                Some(format!("\"other\", W/{}", etag)),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Ok(dtos::ItemImageFileDto::NotModified { etag: returned }) if returned == etag
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_unknown_thumbnail_size_when_get_item_image_use_case_then_return_error() {
+        // Arrange
+        let current_user = domain::entities::user::User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "password".to_string(),
+        );
+
+        let get_item_image_use_case = GetItemImageUseCase::new(
+            Arc::new(MockIItemRepository::new()),
+            Arc::new(MockIAuctionRepository::new()),
+            Arc::new(MockIItemImageRepository::new()),
+            Arc::new(MockIBlobStore::new()),
+        );
+
+        // Act
+        let result = get_item_image_use_case
+            .execute(
+                current_user,
+                "00000000-0000-0000-0000-000000000000".to_string(),
+                0,
+                dtos::GetItemImageRequest { size: Some(100) },
+                None,
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidThumbnailSize(_))));
+    }
 }
@@ -82,7 +82,6 @@ mod tests {
         let item = Item::new(
             "brief".to_string(),
             "description".to_string(),
-            vec![],
             user_id.clone().try_into().unwrap(),
             Category::Diverse,
         );
@@ -107,7 +106,11 @@ mod tests {
             id: user_id.try_into().unwrap(),
             name: "username".to_string(),
             email: "email".to_string(),
-            password: "password".to_string(),
+            password: Some("password".to_string()),
+            email_verified: true,
+            role: domain::entities::user::Role::User,
+            suspended_at: None,
+            deleted_at: None,
         };
         let use_case = GetItemUseCase::new(item_repository, auction_repository);
 
@@ -128,7 +131,6 @@ mod tests {
         let item = Item::new(
             "brief".to_string(),
             "description".to_string(),
-            vec![],
             user_id.clone().try_into().unwrap(),
             Category::Diverse,
         );
@@ -162,7 +164,11 @@ mod tests {
             id: not_owner_user_id.try_into().unwrap(),
             name: "username".to_string(),
             email: "email".to_string(),
-            password: "password".to_string(),
+            password: Some("password".to_string()),
+            email_verified: true,
+            role: domain::entities::user::Role::User,
+            suspended_at: None,
+            deleted_at: None,
         };
         let use_case = GetItemUseCase::new(item_repository, auction_repository);
 
@@ -216,7 +222,6 @@ mod tests {
         let item = Item::new(
             "brief".to_string(),
             "description".to_string(),
-            vec![],
             String::from("00000000-0000-0000-0000-000000000001")
                 .try_into()
                 .unwrap(),
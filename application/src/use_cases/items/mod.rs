@@ -1,4 +1,9 @@
+pub mod add_item_image_use_case;
 pub mod create_item_use_case;
+pub mod delete_item_image_use_case;
+pub mod delete_item_use_case;
 pub mod get_item_image_use_case;
 pub mod get_item_use_case;
 pub mod get_items_use_case;
+pub mod reorder_item_images_use_case;
+pub mod update_item_use_case;
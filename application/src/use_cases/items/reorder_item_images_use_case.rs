@@ -0,0 +1,144 @@
+use crate::use_cases::items::update_item_use_case::find_editable_item;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_item_image_repository::IItemImageRepository;
+use domain::interfaces::i_item_repository::IItemRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct ReorderItemImagesRequest {
+        /// Current positions in their new order, `[2, 0, 1]` makes the last of three images the
+        /// first one.
+        pub order: Vec<i32>,
+    }
+}
+
+/// Whether `order` holds each position from 0 to its length exactly once.
+fn is_permutation(order: &[i32]) -> bool {
+    let mut sorted = order.to_vec();
+    sorted.sort_unstable();
+    sorted
+        .into_iter()
+        .enumerate()
+        .all(|(index, position)| index as i32 == position)
+}
+
+pub struct ReorderItemImagesUseCase<
+    R1: IItemRepository,
+    R2: IAuctionRepository,
+    R3: IItemImageRepository,
+> {
+    item_repository: Arc<R1>,
+    auction_repository: Arc<R2>,
+    item_image_repository: Arc<R3>,
+}
+
+impl<R1: IItemRepository, R2: IAuctionRepository, R3: IItemImageRepository>
+    ReorderItemImagesUseCase<R1, R2, R3>
+{
+    pub fn new(
+        item_repository: Arc<R1>,
+        auction_repository: Arc<R2>,
+        item_image_repository: Arc<R3>,
+    ) -> Self {
+        Self {
+            item_repository,
+            auction_repository,
+            item_image_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        item_id: String,
+        request: dtos::ReorderItemImagesRequest,
+    ) -> Result<(), AppError> {
+        info!("Reordering images of item with id: {}", item_id);
+
+        if !is_permutation(&request.order) {
+            error!("Invalid image order {:?}", request.order);
+            return Err(AppError::InvalidImageOrder());
+        }
+
+        let item = find_editable_item(
+            self.item_repository.as_ref(),
+            self.auction_repository.as_ref(),
+            &current_user,
+            item_id,
+        )
+        .await?;
+
+        // the repository compares the order against the images actually stored
+        let reordered = self
+            .item_image_repository
+            .reorder(item.id, request.order)
+            .await
+            .map_err(|e| {
+                error!("Failed to reorder images: {:?}", e);
+                AppError::FailedToUpdateItemImages()
+            })?;
+
+        match reordered {
+            true => Ok(()),
+            false => Err(AppError::InvalidImageOrder()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+    use domain::interfaces::i_item_image_repository::MockIItemImageRepository;
+    use domain::interfaces::i_item_repository::MockIItemRepository;
+
+    #[test]
+    fn given_orders_when_checking_permutation_then_only_complete_orders_pass() {
+        assert!(is_permutation(&[2, 0, 1]));
+        assert!(is_permutation(&[]));
+        assert!(!is_permutation(&[0, 0, 1]));
+        assert!(!is_permutation(&[1, 2]));
+    }
+
+    #[tokio::test]
+    async fn given_duplicate_positions_when_executing_then_images_are_not_reordered() {
+        // Arrange
+        let mut item_repository = MockIItemRepository::new();
+        item_repository.expect_find().times(0);
+        let mut item_image_repository = MockIItemImageRepository::new();
+        item_image_repository.expect_reorder().times(0);
+
+        let use_case = ReorderItemImagesUseCase::new(
+            Arc::new(item_repository),
+            Arc::new(MockIAuctionRepository::new()),
+            Arc::new(item_image_repository),
+        );
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "password".to_string(),
+        );
+        let request = dtos::ReorderItemImagesRequest {
+            order: vec![1, 1, 0],
+        };
+
+        // Act
+        let result = use_case
+            .execute(
+                current_user,
+                "00000000-0000-0000-0000-000000000000".to_string(),
+                request,
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidImageOrder())));
+    }
+}
@@ -0,0 +1,317 @@
+use crate::images::{discard_blobs, store_item_images};
+use crate::use_cases::items::get_items_use_case::dtos::ItemDto;
+use domain::app_error::AppError;
+use domain::entities::item::Item;
+use domain::entities::item_image::ItemImage;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_blob_store::IBlobStore;
+use domain::interfaces::i_item_image_repository::IItemImageRepository;
+use domain::interfaces::i_item_repository::IItemRepository;
+use std::io::Read;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use crate::use_cases::items::create_item_use_case::dtos::validate_category;
+    use axum_typed_multipart::{FieldData, TryFromMultipart};
+    use tempfile::NamedTempFile;
+    use validator::Validate;
+
+    /// Fields left out stay as they are.
+    #[derive(Debug, Validate, TryFromMultipart)]
+    pub struct UpdateItemRequest {
+        #[validate(length(
+            min = 3,
+            max = 30,
+            message = "Brief must be between 3 and 30 characters"
+        ))]
+        pub brief: Option<String>,
+        #[validate(length(
+            min = 3,
+            max = 255,
+            message = "Description must be between 3 and 255 characters"
+        ))]
+        pub description: Option<String>,
+        /// Replaces the first image of the gallery.
+        pub picture: Option<FieldData<NamedTempFile>>,
+        #[validate(custom(
+            function = "validate_category",
+            message = "Invalid category. Must be one of: art, sport, electronics, services, diverse"
+        ))]
+        pub category: Option<String>,
+    }
+}
+
+/// Finds an item the current user may change, which rules out items that are up for auction.
+pub(crate) async fn find_editable_item<R1: IItemRepository, R2: IAuctionRepository>(
+    item_repository: &R1,
+    auction_repository: &R2,
+    current_user: &User,
+    item_id: String,
+) -> Result<Item, AppError> {
+    let id = Id::<Item>::try_from(item_id.clone())
+        .map_err(|_| AppError::ItemNotFound(item_id.clone()))?;
+
+    let item = item_repository
+        .find(id.clone())
+        .await
+        .map_err(|e| {
+            error!("Failed to get item: {:?}", e);
+            AppError::FailedToUpdateItem()
+        })?
+        .ok_or(AppError::ItemNotFound(item_id.clone()))?;
+
+    if item.user_id.value != current_user.id.value {
+        error!(
+            "Item {} does not belong to user {}",
+            item_id, current_user.id
+        );
+        return Err(AppError::ItemDoesNotBelongToUser(
+            item_id,
+            current_user.id.to_string(),
+        ));
+    }
+
+    let auction = auction_repository
+        .find_ongoing_by_item_id(id)
+        .await
+        .map_err(|e| {
+            error!("Failed to get auction: {:?}", e);
+            AppError::FailedToUpdateItem()
+        })?;
+    if auction.is_some() {
+        error!("Item {} has an ongoing auction", item_id);
+        return Err(AppError::ItemHasAuction(item_id));
+    }
+
+    Ok(item)
+}
+
+pub struct UpdateItemUseCase<
+    R1: IItemRepository,
+    R2: IAuctionRepository,
+    R3: IItemImageRepository,
+    R4: IBlobStore,
+> {
+    item_repository: Arc<R1>,
+    auction_repository: Arc<R2>,
+    item_image_repository: Arc<R3>,
+    blob_store: Arc<R4>,
+}
+
+impl<R1: IItemRepository, R2: IAuctionRepository, R3: IItemImageRepository, R4: IBlobStore>
+    UpdateItemUseCase<R1, R2, R3, R4>
+{
+    pub fn new(
+        item_repository: Arc<R1>,
+        auction_repository: Arc<R2>,
+        item_image_repository: Arc<R3>,
+        blob_store: Arc<R4>,
+    ) -> Self {
+        Self {
+            item_repository,
+            auction_repository,
+            item_image_repository,
+            blob_store,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        item_id: String,
+        mut request: dtos::UpdateItemRequest,
+    ) -> Result<ItemDto, AppError> {
+        info!("Updating item with id: {}", item_id);
+
+        let mut item = find_editable_item(
+            self.item_repository.as_ref(),
+            self.auction_repository.as_ref(),
+            &current_user,
+            item_id.clone(),
+        )
+        .await?;
+
+        if let Some(brief) = request.brief {
+            item.brief = brief;
+        }
+        if let Some(description) = request.description {
+            item.description = description;
+        }
+        if let Some(category) = request.category {
+            item.category = category.into();
+        }
+        let mut picture = Vec::new();
+        if let Some(field) = request.picture.as_mut() {
+            field.contents.read_to_end(&mut picture).map_err(|e| {
+                error!("Failed to read picture from request: {:?}", e);
+                AppError::FailedToUpdateItem()
+            })?;
+        }
+        // checked before anything changes, a rejected picture leaves the item as it was
+        let images = match picture.is_empty() {
+            true => vec![],
+            false => {
+                store_item_images(self.blob_store.as_ref(), item.id.clone(), 0, picture).await?
+            }
+        };
+        let keys = images.iter().map(|image| image.blob_key.clone()).collect();
+
+        match self.save(item, item_id, images).await {
+            Ok(item) => Ok(item.into()),
+            Err(e) => {
+                discard_blobs(self.blob_store.as_ref(), keys).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn save(
+        &self,
+        item: Item,
+        item_id: String,
+        images: Vec<ItemImage>,
+    ) -> Result<Item, AppError> {
+        // an auction may have been created since the check above, and with it a new owner
+        let item = self
+            .item_repository
+            .update(item)
+            .await
+            .map_err(|e| {
+                error!("Failed to update item: {:?}", e);
+                AppError::FailedToUpdateItem()
+            })?
+            .ok_or(AppError::ItemHasAuction(item_id))?;
+
+        if !images.is_empty() {
+            let replaced = self
+                .item_image_repository
+                .replace_first(item.id.clone(), images)
+                .await
+                .map_err(|e| {
+                    error!("Failed to replace picture: {:?}", e);
+                    AppError::FailedToUpdateItem()
+                })?;
+            discard_blobs(self.blob_store.as_ref(), replaced).await;
+        }
+
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::item::Category;
+    use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+    use domain::interfaces::i_blob_store::MockIBlobStore;
+    use domain::interfaces::i_item_image_repository::MockIItemImageRepository;
+    use domain::interfaces::i_item_repository::MockIItemRepository;
+
+    fn request(brief: &str) -> dtos::UpdateItemRequest {
+        dtos::UpdateItemRequest {
+            brief: Some(brief.to_string()),
+            description: None,
+            picture: None,
+            category: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn given_item_of_another_user_when_executing_then_item_is_not_updated() {
+        // Arrange
+        let owner = User::new(
+            "owner".to_string(),
+            "owner@example.com".to_string(),
+            "password".to_string(),
+        );
+        let current_user = User::new(
+            "username".to_string(),
+            "email@example.com".to_string(),
+            "password".to_string(),
+        );
+        let item = Item::new(
+            "brief".to_string(),
+            "description".to_string(),
+            owner.id.clone(),
+            Category::Art,
+        );
+        let item_id = item.id.to_string();
+
+        let mut item_repository = MockIItemRepository::new();
+        item_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(item.clone())));
+        item_repository.expect_update().times(0);
+
+        let use_case = UpdateItemUseCase::new(
+            Arc::new(item_repository),
+            Arc::new(MockIAuctionRepository::new()),
+            Arc::new(MockIItemImageRepository::new()),
+            Arc::new(MockIBlobStore::new()),
+        );
+
+        // Act
+        let result = use_case
+            .execute(current_user, item_id, request("new brief"))
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(AppError::ItemDoesNotBelongToUser(_, _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_own_item_without_auction_when_executing_then_only_given_fields_change() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email@example.com".to_string(),
+            "password".to_string(),
+        );
+        let item = Item::new(
+            "brief".to_string(),
+            "description".to_string(),
+            current_user.id.clone(),
+            Category::Art,
+        );
+        let item_id = item.id.to_string();
+
+        let mut item_repository = MockIItemRepository::new();
+        item_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(item.clone())));
+        item_repository
+            .expect_update()
+            .withf(|item| item.brief == "new brief" && item.description == "description")
+            .times(1)
+            .returning(|item| Ok(Some(item)));
+
+        let mut auction_repository = MockIAuctionRepository::new();
+        auction_repository
+            .expect_find_ongoing_by_item_id()
+            .returning(|_| Ok(None));
+
+        let mut item_image_repository = MockIItemImageRepository::new();
+        item_image_repository.expect_replace_first().times(0);
+
+        let use_case = UpdateItemUseCase::new(
+            Arc::new(item_repository),
+            Arc::new(auction_repository),
+            Arc::new(item_image_repository),
+            Arc::new(MockIBlobStore::new()),
+        );
+
+        // Act
+        let result = use_case
+            .execute(current_user, item_id, request("new brief"))
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap().brief, "new brief");
+    }
+}
@@ -0,0 +1 @@
+pub mod run_jobs_use_case;
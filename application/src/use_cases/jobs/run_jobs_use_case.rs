@@ -0,0 +1,271 @@
+use crate::jobs::job_handler_registry::JobHandlerRegistry;
+use anyhow::anyhow;
+use domain::app_error::AppError;
+use domain::entities::job::Job;
+use domain::interfaces::i_job_repository::IJobRepository;
+use futures::StreamExt;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info};
+
+const BATCH_SIZE: i64 = 16;
+const CONCURRENCY: usize = 8;
+/// Keeps a whole batch well within the lock timeout of the job repository, after which a claimed
+/// job is handed to another worker: two rounds of `CONCURRENCY` jobs take two minutes at most.
+const JOB_TIMEOUT: Duration = Duration::from_secs(60);
+/// Dead jobs are kept this long to look into, and may still hold an email with a reset link
+/// until then.
+const FINISHED_JOB_RETENTION_DAYS: i64 = 7;
+
+pub struct RunJobsUseCase<R: IJobRepository> {
+    job_repository: Arc<R>,
+    registry: Arc<JobHandlerRegistry>,
+}
+
+impl<R: IJobRepository> RunJobsUseCase<R> {
+    pub fn new(job_repository: Arc<R>, registry: Arc<JobHandlerRegistry>) -> Self {
+        Self {
+            job_repository,
+            registry,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<(), AppError> {
+        self.purge_finished().await;
+
+        let jobs = self
+            .job_repository
+            .claim(self.registry.kinds(), BATCH_SIZE)
+            .await
+            .map_err(|e| {
+                error!("Failed to claim jobs: {:?}", e);
+                AppError::FailedToRunJobs()
+            })?;
+
+        if jobs.is_empty() {
+            return Ok(());
+        }
+        info!("Running {} jobs", jobs.len());
+
+        // a failing job is rescheduled or dead-lettered and does not block the rest of the batch
+        futures::stream::iter(jobs)
+            .for_each_concurrent(CONCURRENCY, |job| self.run(job))
+            .await;
+
+        Ok(())
+    }
+
+    async fn purge_finished(&self) {
+        let before = chrono::Utc::now() - chrono::Duration::days(FINISHED_JOB_RETENTION_DAYS);
+        match self.job_repository.purge_finished(before).await {
+            Ok(0) => {}
+            Ok(purged) => info!("Purged {} finished jobs", purged),
+            Err(e) => error!("Failed to purge finished jobs: {:?}", e),
+        }
+    }
+
+    async fn run(&self, job: Job) {
+        let result = match self.registry.get(&job.kind) {
+            Some(handler) => tokio::time::timeout(JOB_TIMEOUT, handler.handle(&job))
+                .await
+                .unwrap_or_else(|_| Err(anyhow!("Job timed out after {:?}", JOB_TIMEOUT))),
+            None => Err(anyhow!("No handler registered for job kind {}", job.kind)),
+        };
+
+        let update = match result {
+            Ok(()) => self.job_repository.complete(job.id.clone()).await,
+            Err(e) if job.is_exhausted() => {
+                error!(
+                    "Job {} of kind {} failed for the last time: {:?}",
+                    job.id, job.kind, e
+                );
+                self.job_repository
+                    .dead_letter(job.id.clone(), e.to_string())
+                    .await
+            }
+            Err(e) => {
+                error!("Job {} of kind {} failed: {:?}", job.id, job.kind, e);
+                self.job_repository
+                    .retry(job.id.clone(), e.to_string(), job.next_attempt_at())
+                    .await
+            }
+        };
+        if let Err(e) = update {
+            error!("Failed to update job {}: {:?}", job.id, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::i_job_handler::MockIJobHandler;
+    use domain::entities::job::Job;
+    use domain::interfaces::i_job_repository::MockIJobRepository;
+    use serde_json::json;
+
+    fn claimed_job(attempts: i32) -> Job {
+        let mut job = Job::new("test", json!({}));
+        job.attempts = attempts;
+        job
+    }
+
+    fn registry_with_handler_returning(result: fn() -> anyhow::Result<()>) -> JobHandlerRegistry {
+        let mut handler = MockIJobHandler::new();
+        handler.expect_kind().return_const("test");
+        handler.expect_handle().returning(move |_| result());
+        JobHandlerRegistry::new().register(handler)
+    }
+
+    fn purging_job_repository() -> MockIJobRepository {
+        let mut job_repository = MockIJobRepository::new();
+        job_repository.expect_purge_finished().returning(|_| Ok(0));
+        job_repository
+    }
+
+    #[tokio::test]
+    async fn given_succeeding_handler_when_executing_then_job_is_completed() {
+        // Arrange
+        let job = claimed_job(1);
+        let job_id = job.id.clone();
+
+        let mut job_repository = purging_job_repository();
+        job_repository
+            .expect_claim()
+            .withf(|kinds, _| kinds == &vec!["test".to_string()])
+            .returning(move |_, _| Ok(vec![job.clone()]));
+        job_repository
+            .expect_complete()
+            .withf(move |id| id.value == job_id.value)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = RunJobsUseCase::new(
+            Arc::new(job_repository),
+            Arc::new(registry_with_handler_returning(|| Ok(()))),
+        );
+
+        // Act
+        let result = use_case.execute().await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_several_jobs_when_executing_then_each_is_completed_once() {
+        // Arrange
+        let jobs = vec![claimed_job(1), claimed_job(1), claimed_job(1)];
+
+        let mut job_repository = purging_job_repository();
+        job_repository
+            .expect_claim()
+            .withf(|_, limit| *limit == BATCH_SIZE)
+            .returning(move |_, _| Ok(jobs.clone()));
+        job_repository
+            .expect_complete()
+            .times(3)
+            .returning(|_| Ok(()));
+
+        let use_case = RunJobsUseCase::new(
+            Arc::new(job_repository),
+            Arc::new(registry_with_handler_returning(|| Ok(()))),
+        );
+
+        // Act
+        let result = use_case.execute().await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_failing_handler_when_attempts_remain_then_job_is_retried_later() {
+        // Arrange
+        let job = claimed_job(1);
+
+        let mut job_repository = purging_job_repository();
+        job_repository
+            .expect_claim()
+            .returning(move |_, _| Ok(vec![job.clone()]));
+        job_repository
+            .expect_retry()
+            .withf(|_, error, run_at| error == "boom" && *run_at > chrono::Utc::now())
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        job_repository.expect_dead_letter().times(0);
+
+        let use_case = RunJobsUseCase::new(
+            Arc::new(job_repository),
+            Arc::new(registry_with_handler_returning(|| Err(anyhow!("boom")))),
+        );
+
+        // Act
+        let result = use_case.execute().await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_failing_handler_when_attempts_are_exhausted_then_job_is_dead_lettered() {
+        // Arrange
+        let job = claimed_job(5);
+
+        let mut job_repository = purging_job_repository();
+        job_repository
+            .expect_claim()
+            .returning(move |_, _| Ok(vec![job.clone()]));
+        job_repository.expect_retry().times(0);
+        job_repository
+            .expect_dead_letter()
+            .withf(|_, error| error == "boom")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = RunJobsUseCase::new(
+            Arc::new(job_repository),
+            Arc::new(registry_with_handler_returning(|| Err(anyhow!("boom")))),
+        );
+
+        // Act
+        let result = use_case.execute().await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_failing_purge_when_executing_then_jobs_still_run() {
+        // Arrange
+        let job = claimed_job(1);
+
+        let mut job_repository = MockIJobRepository::new();
+        job_repository
+            .expect_purge_finished()
+            .withf(|before| {
+                *before
+                    < chrono::Utc::now() - chrono::Duration::days(FINISHED_JOB_RETENTION_DAYS - 1)
+            })
+            .times(1)
+            .returning(|_| Err(anyhow!("Database unavailable")));
+        job_repository
+            .expect_claim()
+            .returning(move |_, _| Ok(vec![job.clone()]));
+        job_repository
+            .expect_complete()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = RunJobsUseCase::new(
+            Arc::new(job_repository),
+            Arc::new(registry_with_handler_returning(|| Ok(()))),
+        );
+
+        // Act
+        let result = use_case.execute().await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
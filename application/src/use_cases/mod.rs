@@ -1,4 +1,14 @@
+pub mod account;
+pub mod admin;
+pub mod api_keys;
 pub mod auctions;
 pub mod bids;
+pub mod external_identities;
 pub mod items;
+pub mod jobs;
+pub mod notifications;
+pub mod ratings;
+pub mod sessions;
+pub mod two_factor;
 pub mod user;
+pub mod webhooks;
@@ -0,0 +1,218 @@
+use crate::use_cases::notifications::get_notifications_use_case::dtos::{
+    GetAllNotificationsDto, GetNotificationsRequest, NotificationDto,
+};
+use domain::app_error::AppError;
+use domain::entities::notification::Notification;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_notification_repository::INotificationRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+
+pub mod dtos {
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::notification::Notification;
+    use serde::{Deserialize, Serialize};
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct GetNotificationsRequest {
+        #[validate(range(min = 1, max = 100, message = "Limit must be 1 to 100"))]
+        pub limit: Option<i64>,
+        /// Id of the last notification of the previous page.
+        pub before: Option<String>,
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct NotificationDto {
+        pub id: String,
+        pub kind: String,
+        pub message: String,
+        pub auction_id: Option<String>,
+        pub item_id: Option<String>,
+        pub is_read: bool,
+        pub created_at: i64,
+    }
+
+    impl From<Notification> for NotificationDto {
+        fn from(notification: Notification) -> Self {
+            NotificationDto {
+                id: notification.id.to_string(),
+                kind: notification.kind.into(),
+                message: notification.message,
+                auction_id: notification.auction_id.map(|id| id.to_string()),
+                item_id: notification.item_id.map(|id| id.to_string()),
+                is_read: notification.is_read,
+                created_at: notification.created_at.timestamp(),
+            }
+        }
+    }
+
+    impl IntoResponse for NotificationDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct GetAllNotificationsDto {
+        pub notifications: Vec<NotificationDto>,
+        /// Passed as `before` to get the next page, `None` on the last one.
+        pub next: Option<String>,
+    }
+
+    impl IntoResponse for GetAllNotificationsDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct GetNotificationsUseCase<R: INotificationRepository> {
+    notification_repository: Arc<R>,
+}
+
+impl<R: INotificationRepository> GetNotificationsUseCase<R> {
+    pub fn new(notification_repository: Arc<R>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        request: GetNotificationsRequest,
+    ) -> Result<GetAllNotificationsDto, AppError> {
+        info!("Getting notifications for user with id {}", current_user.id);
+
+        let limit = request.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        let before = request
+            .before
+            .map(|before| {
+                Id::<Notification>::try_from(before.clone())
+                    .map_err(|_| AppError::NotificationNotFound(before))
+            })
+            .transpose()?;
+
+        let notifications: Vec<NotificationDto> = self
+            .notification_repository
+            .find_by_user_id(current_user.id, before, limit)
+            .await
+            .map_err(|e| {
+                error!("Failed to get notifications: {:?}", e);
+                AppError::FailedToGetNotifications()
+            })?
+            .into_iter()
+            .map(NotificationDto::from)
+            .collect();
+
+        let next = match notifications.len() as i64 == limit {
+            true => notifications
+                .last()
+                .map(|notification| notification.id.clone()),
+            false => None,
+        };
+
+        Ok(GetAllNotificationsDto {
+            notifications,
+            next,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::use_cases::notifications::get_notifications_use_case::dtos::GetNotificationsRequest;
+    use crate::use_cases::notifications::get_notifications_use_case::GetNotificationsUseCase;
+    use domain::entities::notification::{Notification, NotificationKind};
+    use domain::entities::user::User;
+    use domain::id::Id;
+    use domain::interfaces::i_notification_repository::MockINotificationRepository;
+    use std::sync::Arc;
+
+    fn notification(user_id: Id<User>) -> Notification {
+        Notification::new(
+            user_id,
+            NotificationKind::Outbid,
+            "message".to_string(),
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn given_full_page_when_executing_then_next_points_at_last_notification() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let before = Id::<Notification>::gen();
+        let expected_before = before.clone();
+
+        let mut notification_repository = MockINotificationRepository::new();
+        notification_repository
+            .expect_find_by_user_id()
+            .withf(move |_, before, limit| *before == Some(expected_before.clone()) && *limit == 2)
+            .returning(|user_id, _, _| {
+                Ok(vec![notification(user_id.clone()), notification(user_id)])
+            });
+
+        let use_case = GetNotificationsUseCase::new(Arc::new(notification_repository));
+
+        // Act
+        let result = use_case
+            .execute(
+                current_user,
+                GetNotificationsRequest {
+                    limit: Some(2),
+                    before: Some(before.to_string()),
+                },
+            )
+            .await;
+
+        // Assert
+        let page = result.unwrap();
+        assert_eq!(page.notifications.len(), 2);
+        assert_eq!(page.next, Some(page.notifications[1].id.clone()));
+    }
+
+    #[tokio::test]
+    async fn given_last_page_when_executing_then_there_is_no_next() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+
+        let mut notification_repository = MockINotificationRepository::new();
+        notification_repository
+            .expect_find_by_user_id()
+            .returning(|user_id, _, _| Ok(vec![notification(user_id)]));
+
+        let use_case = GetNotificationsUseCase::new(Arc::new(notification_repository));
+
+        // Act
+        let result = use_case
+            .execute(
+                current_user,
+                GetNotificationsRequest {
+                    limit: None,
+                    before: None,
+                },
+            )
+            .await;
+
+        // Assert
+        let page = result.unwrap();
+        assert_eq!(page.notifications.len(), 1);
+        assert_eq!(page.next, None);
+    }
+}
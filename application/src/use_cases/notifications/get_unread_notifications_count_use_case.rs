@@ -0,0 +1,117 @@
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_notification_repository::INotificationRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use serde::Serialize;
+
+    #[derive(Serialize, Debug)]
+    pub struct UnreadNotificationsCountDto {
+        pub count: i64,
+    }
+
+    impl IntoResponse for UnreadNotificationsCountDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct GetUnreadNotificationsCountUseCase<R: INotificationRepository> {
+    notification_repository: Arc<R>,
+}
+
+impl<R: INotificationRepository> GetUnreadNotificationsCountUseCase<R> {
+    pub fn new(notification_repository: Arc<R>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+    ) -> Result<dtos::UnreadNotificationsCountDto, AppError> {
+        info!(
+            "Counting unread notifications for user with id {}",
+            current_user.id
+        );
+
+        let count = self
+            .notification_repository
+            .count_unread(current_user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to count unread notifications: {:?}", e);
+                AppError::FailedToGetNotifications()
+            })?;
+
+        Ok(dtos::UnreadNotificationsCountDto { count })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::use_cases::notifications::get_unread_notifications_count_use_case::GetUnreadNotificationsCountUseCase;
+    use anyhow::anyhow;
+    use domain::app_error::AppError;
+    use domain::entities::user::User;
+    use domain::interfaces::i_notification_repository::MockINotificationRepository;
+    use mockall::predicate::eq;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn given_unread_notifications_when_executing_then_count_is_returned() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let mut notification_repository = MockINotificationRepository::new();
+        notification_repository
+            .expect_count_unread()
+            .with(eq(current_user.id.clone()))
+            .returning(|_| Ok(3));
+
+        let use_case = GetUnreadNotificationsCountUseCase::new(Arc::new(notification_repository));
+
+        // Act
+        let result = use_case.execute(current_user).await;
+
+        // Assert
+        assert_eq!(result.unwrap().count, 3);
+    }
+
+    #[tokio::test]
+    async fn given_failing_repository_when_executing_then_failed_to_get_notifications_is_returned()
+    {
+        // Arrange
+        let mut notification_repository = MockINotificationRepository::new();
+        notification_repository
+            .expect_count_unread()
+            .returning(|_| Err(anyhow!("Unexpected error")));
+
+        let use_case = GetUnreadNotificationsCountUseCase::new(Arc::new(notification_repository));
+
+        // Act
+        let result = use_case
+            .execute(User::new(
+                "username".to_string(),
+                "email".to_string(),
+                "hashed_password".to_string(),
+            ))
+            .await;
+
+        // Assert
+        match result {
+            Err(AppError::FailedToGetNotifications()) => assert!(true),
+            _ => panic!("Test failed"),
+        }
+    }
+}
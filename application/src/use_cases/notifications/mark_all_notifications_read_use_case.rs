@@ -0,0 +1,36 @@
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_notification_repository::INotificationRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct MarkAllNotificationsReadUseCase<R: INotificationRepository> {
+    notification_repository: Arc<R>,
+}
+
+impl<R: INotificationRepository> MarkAllNotificationsReadUseCase<R> {
+    pub fn new(notification_repository: Arc<R>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    pub async fn execute(&self, current_user: User) -> Result<(), AppError> {
+        info!(
+            "Marking all notifications as read for user with id {}",
+            current_user.id
+        );
+
+        let updated = self
+            .notification_repository
+            .mark_all_read(current_user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to mark notifications as read: {:?}", e);
+                AppError::FailedToUpdateNotifications()
+            })?;
+
+        info!("{} notifications marked as read", updated);
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use domain::app_error::AppError;
+use domain::entities::notification::Notification;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_notification_repository::INotificationRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct MarkNotificationReadUseCase<R: INotificationRepository> {
+    notification_repository: Arc<R>,
+}
+
+impl<R: INotificationRepository> MarkNotificationReadUseCase<R> {
+    pub fn new(notification_repository: Arc<R>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        notification_id: String,
+    ) -> Result<(), AppError> {
+        info!("Marking notification with id {} as read", notification_id);
+
+        let id = Id::<Notification>::try_from(notification_id.clone())
+            .map_err(|_| AppError::NotificationNotFound(notification_id.clone()))?;
+
+        // notifications of other users are reported as missing
+        match self
+            .notification_repository
+            .mark_read(id, current_user.id)
+            .await
+        {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => {
+                error!("Notification with id {} not found", notification_id);
+                Err(AppError::NotificationNotFound(notification_id))
+            }
+            Err(e) => {
+                error!("Failed to mark notification as read: {:?}", e);
+                Err(AppError::FailedToUpdateNotifications())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::use_cases::notifications::mark_notification_read_use_case::MarkNotificationReadUseCase;
+    use domain::app_error::AppError;
+    use domain::entities::notification::{Notification, NotificationKind};
+    use domain::entities::user::User;
+    use domain::interfaces::i_notification_repository::MockINotificationRepository;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn given_own_notification_when_executing_then_notification_is_marked_read() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let notification = Notification::new(
+            current_user.id.clone(),
+            NotificationKind::Outbid,
+            "message".to_string(),
+            None,
+            None,
+        );
+        let notification_id = notification.id.to_string();
+
+        let mut notification_repository = MockINotificationRepository::new();
+        notification_repository
+            .expect_mark_read()
+            .returning(move |_, _| {
+                Ok(Some(Notification {
+                    is_read: true,
+                    ..notification.clone()
+                }))
+            });
+
+        let use_case = MarkNotificationReadUseCase::new(Arc::new(notification_repository));
+
+        // Act
+        let result = use_case.execute(current_user, notification_id).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_notification_of_other_user_when_executing_then_notification_not_found_is_returned(
+    ) {
+        // Arrange
+        let mut notification_repository = MockINotificationRepository::new();
+        notification_repository
+            .expect_mark_read()
+            .returning(|_, _| Ok(None));
+
+        let use_case = MarkNotificationReadUseCase::new(Arc::new(notification_repository));
+        let notification_id = "00000000-0000-0000-0000-000000000000".to_string();
+
+        // Act
+        let result = use_case
+            .execute(
+                User::new(
+                    "username".to_string(),
+                    "email".to_string(),
+                    "hashed_password".to_string(),
+                ),
+                notification_id.clone(),
+            )
+            .await;
+
+        // Assert
+        match result {
+            Err(AppError::NotificationNotFound(id)) => assert_eq!(id, notification_id),
+            _ => panic!("Test failed"),
+        }
+    }
+}
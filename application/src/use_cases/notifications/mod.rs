@@ -0,0 +1,4 @@
+pub mod get_notifications_use_case;
+pub mod get_unread_notifications_count_use_case;
+pub mod mark_all_notifications_read_use_case;
+pub mod mark_notification_read_use_case;
@@ -0,0 +1,100 @@
+use crate::use_cases::user::get_public_profile_use_case::find_visible_user;
+use domain::app_error::AppError;
+use domain::interfaces::i_rating_repository::IRatingRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::rating::RatingWithRater;
+    use domain::entities::reputation::Reputation;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ReputationDto {
+        pub completed_sales: i64,
+        pub rating_count: i64,
+        pub average_score: Option<f64>,
+    }
+
+    impl From<Reputation> for ReputationDto {
+        fn from(reputation: Reputation) -> Self {
+            Self {
+                completed_sales: reputation.completed_sales,
+                rating_count: reputation.rating_count,
+                average_score: reputation.average_score,
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct RatingDto {
+        pub id: String,
+        pub rater: String,
+        /// Whether the rated user was the buyer or the seller.
+        pub role: String,
+        pub score: i16,
+        pub comment: Option<String>,
+        pub created_at: i64,
+    }
+
+    impl From<RatingWithRater> for RatingDto {
+        fn from(rating: RatingWithRater) -> Self {
+            Self {
+                id: rating.rating.id.to_string(),
+                rater: rating.rater_name,
+                role: rating.rating.ratee_role.into(),
+                score: rating.rating.score,
+                comment: rating.rating.comment,
+                created_at: rating.rating.created_at.timestamp(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct GetUserRatingsDto {
+        pub ratings: Vec<RatingDto>,
+    }
+
+    impl IntoResponse for GetUserRatingsDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct GetUserRatingsUseCase<R1: IUserRepository, R2: IRatingRepository> {
+    user_repository: Arc<R1>,
+    rating_repository: Arc<R2>,
+}
+
+impl<R1: IUserRepository, R2: IRatingRepository> GetUserRatingsUseCase<R1, R2> {
+    pub fn new(user_repository: Arc<R1>, rating_repository: Arc<R2>) -> Self {
+        Self {
+            user_repository,
+            rating_repository,
+        }
+    }
+
+    pub async fn execute(&self, username: String) -> Result<dtos::GetUserRatingsDto, AppError> {
+        info!("Getting ratings of user {}", username);
+
+        let user = find_visible_user(self.user_repository.as_ref(), username).await?;
+
+        let ratings = self
+            .rating_repository
+            .find_all_by_ratee_id(user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to get ratings: {:?}", e);
+                AppError::FailedToGetRatings()
+            })?;
+
+        Ok(dtos::GetUserRatingsDto {
+            ratings: ratings.into_iter().map(dtos::RatingDto::from).collect(),
+        })
+    }
+}
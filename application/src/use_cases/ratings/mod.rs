@@ -0,0 +1,2 @@
+pub mod get_user_ratings_use_case;
+pub mod rate_counterparty_use_case;
@@ -0,0 +1,221 @@
+use domain::app_error::AppError;
+use domain::entities::auction::Auction;
+use domain::entities::rating::Rating;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_rating_repository::IRatingRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct RateCounterpartyRequest {
+        #[validate(range(min = 1, max = 5, message = "Score must be between 1 and 5"))]
+        pub score: i16,
+        #[validate(length(max = 500, message = "Comment must be at most 500 characters"))]
+        pub comment: Option<String>,
+    }
+}
+
+pub struct RateCounterpartyUseCase<R: IRatingRepository> {
+    rating_repository: Arc<R>,
+}
+
+impl<R: IRatingRepository> RateCounterpartyUseCase<R> {
+    pub fn new(rating_repository: Arc<R>) -> Self {
+        Self { rating_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        auction_id: String,
+        request: dtos::RateCounterpartyRequest,
+    ) -> Result<(), AppError> {
+        info!(
+            "User {} rating the counterparty of auction {}",
+            current_user.id, auction_id
+        );
+
+        let parsed_auction_id = Id::<Auction>::try_from(auction_id.clone())
+            .map_err(|_| AppError::SaleNotFound(auction_id.clone()))?;
+
+        let sale = self
+            .rating_repository
+            .find_sale_by_auction_id(parsed_auction_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find sale: {:?}", e);
+                AppError::FailedToRate()
+            })?
+            .ok_or_else(|| AppError::SaleNotFound(auction_id.clone()))?;
+
+        // a sale the user took no part in is reported as missing
+        let (ratee_id, ratee_role) = sale
+            .counterparty_of(&current_user.id)
+            .ok_or_else(|| AppError::SaleNotFound(auction_id.clone()))?;
+
+        if !sale.is_open_for_rating() {
+            return Err(AppError::RatingWindowClosed());
+        }
+
+        let comment = request
+            .comment
+            .map(|comment| comment.trim().to_string())
+            .filter(|comment| !comment.is_empty());
+        let rating = Rating::new(
+            sale.id,
+            current_user.id,
+            ratee_id,
+            ratee_role,
+            request.score,
+            comment,
+        );
+
+        let inserted = self.rating_repository.insert(rating).await.map_err(|e| {
+            error!("Failed to insert rating: {:?}", e);
+            AppError::FailedToRate()
+        })?;
+
+        if !inserted {
+            return Err(AppError::AlreadyRated());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use domain::entities::rating::{RatingRole, Sale, RATING_WINDOW_DAYS};
+    use domain::interfaces::i_rating_repository::MockIRatingRepository;
+
+    fn user(name: &str) -> User {
+        User::new(
+            name.to_string(),
+            format!("{}@example.com", name),
+            "hashed_password".to_string(),
+        )
+    }
+
+    fn sale(seller: &User, buyer: &User, completed_days_ago: i64) -> Sale {
+        Sale {
+            id: Id::gen(),
+            auction_id: Id::gen(),
+            item_id: Id::gen(),
+            seller_id: seller.id.clone(),
+            buyer_id: buyer.id.clone(),
+            completed_at: Utc::now() - Duration::days(completed_days_ago),
+        }
+    }
+
+    fn request() -> dtos::RateCounterpartyRequest {
+        dtos::RateCounterpartyRequest {
+            score: 5,
+            comment: Some(" Fast shipping ".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_buyer_of_sale_when_executing_then_seller_is_rated() {
+        // Arrange
+        let (seller, buyer) = (user("seller"), user("buyer"));
+        let sale = sale(&seller, &buyer, 1);
+        let auction_id = sale.auction_id.to_string();
+        let seller_id = seller.id.clone();
+
+        let mut rating_repository = MockIRatingRepository::new();
+        rating_repository
+            .expect_find_sale_by_auction_id()
+            .returning(move |_| Ok(Some(sale.clone())));
+        rating_repository
+            .expect_insert()
+            .withf(move |rating| {
+                rating.ratee_id.value == seller_id.value
+                    && rating.ratee_role == RatingRole::Seller
+                    && rating.comment.as_deref() == Some("Fast shipping")
+            })
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let use_case = RateCounterpartyUseCase::new(Arc::new(rating_repository));
+
+        // Act
+        let result = use_case.execute(buyer, auction_id, request()).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_user_outside_sale_when_executing_then_sale_not_found_is_returned() {
+        // Arrange
+        let sale = sale(&user("seller"), &user("buyer"), 1);
+        let auction_id = sale.auction_id.to_string();
+
+        let mut rating_repository = MockIRatingRepository::new();
+        rating_repository
+            .expect_find_sale_by_auction_id()
+            .returning(move |_| Ok(Some(sale.clone())));
+        rating_repository.expect_insert().times(0);
+
+        let use_case = RateCounterpartyUseCase::new(Arc::new(rating_repository));
+
+        // Act
+        let result = use_case
+            .execute(user("stranger"), auction_id, request())
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::SaleNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn given_sale_past_rating_window_when_executing_then_rating_window_closed_is_returned() {
+        // Arrange
+        let (seller, buyer) = (user("seller"), user("buyer"));
+        let sale = sale(&seller, &buyer, RATING_WINDOW_DAYS + 1);
+        let auction_id = sale.auction_id.to_string();
+
+        let mut rating_repository = MockIRatingRepository::new();
+        rating_repository
+            .expect_find_sale_by_auction_id()
+            .returning(move |_| Ok(Some(sale.clone())));
+        rating_repository.expect_insert().times(0);
+
+        let use_case = RateCounterpartyUseCase::new(Arc::new(rating_repository));
+
+        // Act
+        let result = use_case.execute(seller, auction_id, request()).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::RatingWindowClosed())));
+    }
+
+    #[tokio::test]
+    async fn given_sale_already_rated_when_executing_then_already_rated_is_returned() {
+        // Arrange
+        let (seller, buyer) = (user("seller"), user("buyer"));
+        let sale = sale(&seller, &buyer, 1);
+        let auction_id = sale.auction_id.to_string();
+
+        let mut rating_repository = MockIRatingRepository::new();
+        rating_repository
+            .expect_find_sale_by_auction_id()
+            .returning(move |_| Ok(Some(sale.clone())));
+        rating_repository.expect_insert().returning(|_| Ok(false));
+
+        let use_case = RateCounterpartyUseCase::new(Arc::new(rating_repository));
+
+        // Act
+        let result = use_case.execute(seller, auction_id, request()).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::AlreadyRated())));
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::Utc;
+use domain::app_error::AppError;
+use domain::entities::session::Session;
+use domain::id::Id;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use std::sync::Arc;
+use tracing::error;
+
+const LAST_SEEN_PRECISION_SECONDS: i64 = 60;
+
+pub struct CheckSessionUseCase<R: ISessionRepository> {
+    session_repository: Arc<R>,
+}
+
+impl<R: ISessionRepository> CheckSessionUseCase<R> {
+    pub fn new(session_repository: Arc<R>) -> Self {
+        Self { session_repository }
+    }
+
+    /// Returns the session an access token was issued for, unless it has been revoked, and
+    /// records that it was seen from `ip_address`.
+    pub async fn execute(
+        &self,
+        session_id: String,
+        ip_address: Option<String>,
+    ) -> Result<Session, AppError> {
+        let session_id = Id::<Session>::try_from(session_id).map_err(|_| AppError::InvalidJwt())?;
+
+        let session = self
+            .session_repository
+            .find_by_id(session_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find session: {:?}", e);
+                AppError::InvalidJwt()
+            })?
+            .ok_or_else(AppError::InvalidJwt)?;
+
+        if session.is_revoked() {
+            error!("Session {} is revoked", session.id);
+            return Err(AppError::InvalidJwt());
+        }
+
+        // last-seen is only as precise as the sessions list needs it, not written on every request
+        if session.last_seen_at
+            < Utc::now() - chrono::Duration::seconds(LAST_SEEN_PRECISION_SECONDS)
+        {
+            if let Err(e) = self
+                .session_repository
+                .touch(session.id.clone(), ip_address)
+                .await
+            {
+                error!("Failed to record session last seen time: {:?}", e);
+            }
+        }
+
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::user::User;
+    use domain::interfaces::i_session_repository::MockISessionRepository;
+
+    #[tokio::test]
+    async fn given_revoked_session_when_executing_then_invalid_jwt_error_is_returned() {
+        // Arrange
+        let mut session = Session::new(Id::<User>::gen(), None, None);
+        session.revoked_at = Some(Utc::now());
+        let session_id = session.id.to_string();
+
+        let mut session_repository = MockISessionRepository::new();
+        session_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(session.clone())));
+        session_repository.expect_touch().times(0);
+
+        let use_case = CheckSessionUseCase::new(Arc::new(session_repository));
+
+        // Act
+        let result = use_case.execute(session_id, None).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidJwt())));
+    }
+
+    #[tokio::test]
+    async fn given_session_not_seen_recently_when_executing_then_last_seen_is_recorded() {
+        // Arrange
+        let mut session = Session::new(Id::<User>::gen(), None, None);
+        session.last_seen_at = Utc::now() - chrono::Duration::hours(1);
+        let session_id = session.id.to_string();
+
+        let mut session_repository = MockISessionRepository::new();
+        session_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(session.clone())));
+        session_repository
+            .expect_touch()
+            .withf(|_, ip_address| ip_address.as_deref() == Some("203.0.113.7"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = CheckSessionUseCase::new(Arc::new(session_repository));
+
+        // Act
+        let result = use_case
+            .execute(session_id, Some("203.0.113.7".to_string()))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
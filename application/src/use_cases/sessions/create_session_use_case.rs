@@ -0,0 +1,83 @@
+use chrono::Utc;
+use domain::app_error::AppError;
+use domain::entities::session::{RefreshToken, Session};
+use domain::entities::user::User;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tracing::{error, info};
+
+const REFRESH_TOKEN_LIFETIME_DAYS: i64 = 30;
+
+pub mod dtos {
+    /// Where a login comes from, as reported by the request headers.
+    #[derive(Debug, Clone, Default)]
+    pub struct ClientInfo {
+        pub user_agent: Option<String>,
+        pub ip_address: Option<String>,
+    }
+
+    /// The refresh token is only ever handed out here; the database keeps its hash.
+    #[derive(Debug)]
+    pub struct IssuedSession {
+        pub session_id: String,
+        pub refresh_token: String,
+    }
+}
+
+/// Generates a refresh token for `session` and returns it in plain text along with the stored
+/// record.
+pub(crate) fn generate_refresh_token(session: &Session) -> (String, RefreshToken) {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let token = hex::encode(bytes);
+
+    let refresh_token = RefreshToken::new(
+        session.id.clone(),
+        hash_refresh_token(&token),
+        Utc::now() + chrono::Duration::days(REFRESH_TOKEN_LIFETIME_DAYS),
+    );
+
+    (token, refresh_token)
+}
+
+/// Refresh tokens are random, so a fast unsalted hash is enough to look them up by.
+pub(crate) fn hash_refresh_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+pub struct CreateSessionUseCase<R: ISessionRepository> {
+    session_repository: Arc<R>,
+}
+
+impl<R: ISessionRepository> CreateSessionUseCase<R> {
+    pub fn new(session_repository: Arc<R>) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user: &User,
+        client: dtos::ClientInfo,
+    ) -> Result<dtos::IssuedSession, AppError> {
+        info!("Creating session for user with id: {}", user.id);
+
+        let session = Session::new(user.id.clone(), client.user_agent, client.ip_address);
+        let (token, refresh_token) = generate_refresh_token(&session);
+        let session_id = session.id.to_string();
+
+        self.session_repository
+            .insert(session, refresh_token)
+            .await
+            .map_err(|e| {
+                error!("Failed to create session: {:?}", e);
+                AppError::FailedToCreateSession()
+            })?;
+
+        Ok(dtos::IssuedSession {
+            session_id,
+            refresh_token: token,
+        })
+    }
+}
@@ -0,0 +1,113 @@
+use domain::app_error::AppError;
+use domain::entities::session::Session;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use std::sync::Arc;
+use tracing::error;
+
+pub mod dtos {
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::session::Session;
+    use serde::Serialize;
+
+    #[derive(Serialize, Debug)]
+    pub struct SessionDto {
+        pub id: String,
+        pub user_agent: Option<String>,
+        pub ip_address: Option<String>,
+        pub created_at: i64,
+        pub last_seen_at: i64,
+        /// Whether this is the session making the request.
+        pub current: bool,
+    }
+
+    impl SessionDto {
+        pub fn new(session: Session, current: &Session) -> Self {
+            Self {
+                id: session.id.to_string(),
+                current: session.id.value == current.id.value,
+                user_agent: session.user_agent,
+                ip_address: session.ip_address,
+                created_at: session.created_at.timestamp(),
+                last_seen_at: session.last_seen_at.timestamp(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct GetSessionsDto {
+        pub sessions: Vec<SessionDto>,
+    }
+
+    impl IntoResponse for GetSessionsDto {
+        fn into_response(self) -> Response {
+            Json(self).into_response()
+        }
+    }
+}
+
+pub struct GetSessionsUseCase<R: ISessionRepository> {
+    session_repository: Arc<R>,
+}
+
+impl<R: ISessionRepository> GetSessionsUseCase<R> {
+    pub fn new(session_repository: Arc<R>) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        current_session: Session,
+    ) -> Result<dtos::GetSessionsDto, AppError> {
+        let sessions = self
+            .session_repository
+            .find_active_by_user_id(current_session.user_id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to get sessions: {:?}", e);
+                AppError::FailedToGetSessions()
+            })?;
+
+        Ok(dtos::GetSessionsDto {
+            sessions: sessions
+                .into_iter()
+                .map(|session| dtos::SessionDto::new(session, &current_session))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::user::User;
+    use domain::id::Id;
+    use domain::interfaces::i_session_repository::MockISessionRepository;
+
+    #[tokio::test]
+    async fn given_several_sessions_when_executing_then_current_one_is_marked() {
+        // Arrange
+        let user_id = Id::<User>::gen();
+        let current_session = Session::new(user_id.clone(), None, None);
+        let other_session = Session::new(user_id, Some("curl/8.0".to_string()), None);
+        let sessions = vec![other_session.clone(), current_session.clone()];
+
+        let mut session_repository = MockISessionRepository::new();
+        session_repository
+            .expect_find_active_by_user_id()
+            .returning(move |_| Ok(sessions.clone()));
+
+        let use_case = GetSessionsUseCase::new(Arc::new(session_repository));
+
+        // Act
+        let result = use_case.execute(current_session.clone()).await;
+
+        // Assert
+        let sessions = result.unwrap().sessions;
+        assert_eq!(sessions.len(), 2);
+        assert!(!sessions[0].current);
+        assert_eq!(sessions[0].user_agent.as_deref(), Some("curl/8.0"));
+        assert!(sessions[1].current);
+        assert_eq!(sessions[1].id, current_session.id.to_string());
+    }
+}
@@ -0,0 +1,27 @@
+use domain::app_error::AppError;
+use domain::entities::session::Session;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct LogoutUseCase<R: ISessionRepository> {
+    session_repository: Arc<R>,
+}
+
+impl<R: ISessionRepository> LogoutUseCase<R> {
+    pub fn new(session_repository: Arc<R>) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn execute(&self, session: Session) -> Result<(), AppError> {
+        info!("Revoking session with id: {}", session.id);
+
+        self.session_repository
+            .revoke(session.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke session: {:?}", e);
+                AppError::FailedToRevokeSession()
+            })
+    }
+}
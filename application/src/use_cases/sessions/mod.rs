@@ -0,0 +1,7 @@
+pub mod check_session_use_case;
+pub mod create_session_use_case;
+pub mod get_sessions_use_case;
+pub mod logout_use_case;
+pub mod refresh_session_use_case;
+pub mod revoke_other_sessions_use_case;
+pub mod revoke_session_use_case;
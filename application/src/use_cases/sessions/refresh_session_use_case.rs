@@ -0,0 +1,259 @@
+use crate::use_cases::sessions::create_session_use_case::dtos::IssuedSession;
+use crate::use_cases::sessions::create_session_use_case::{
+    generate_refresh_token, hash_refresh_token,
+};
+use domain::app_error::AppError;
+use domain::entities::session::Session;
+use domain::entities::user::User;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+use tracing::{error, warn};
+
+pub mod dtos {
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug)]
+    pub struct RefreshSessionRequest {
+        pub refresh_token: String,
+    }
+}
+
+pub struct RefreshSessionUseCase<R1: ISessionRepository, R2: IUserRepository> {
+    session_repository: Arc<R1>,
+    user_repository: Arc<R2>,
+}
+
+impl<R1: ISessionRepository, R2: IUserRepository> RefreshSessionUseCase<R1, R2> {
+    pub fn new(session_repository: Arc<R1>, user_repository: Arc<R2>) -> Self {
+        Self {
+            session_repository,
+            user_repository,
+        }
+    }
+
+    /// Exchanges a refresh token for the next one in its session. Presenting a token that has
+    /// already been exchanged means it leaked, so the whole session is revoked.
+    pub async fn execute(
+        &self,
+        request: dtos::RefreshSessionRequest,
+    ) -> Result<(User, IssuedSession), AppError> {
+        let refresh_token = self
+            .session_repository
+            .find_refresh_token(hash_refresh_token(&request.refresh_token))
+            .await
+            .map_err(|e| {
+                error!("Failed to find refresh token: {:?}", e);
+                AppError::InvalidRefreshToken()
+            })?
+            .ok_or_else(AppError::InvalidRefreshToken)?;
+
+        let session = self
+            .session_repository
+            .find_by_id(refresh_token.session_id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find session: {:?}", e);
+                AppError::InvalidRefreshToken()
+            })?
+            .ok_or_else(AppError::InvalidRefreshToken)?;
+
+        if session.is_revoked() {
+            error!("Refresh token used for revoked session {}", session.id);
+            return Err(AppError::InvalidRefreshToken());
+        }
+
+        if refresh_token.is_used() {
+            return Err(self.revoke_reused(session).await);
+        }
+
+        if refresh_token.is_expired() {
+            error!("Refresh token for session {} is expired", session.id);
+            return Err(AppError::InvalidRefreshToken());
+        }
+
+        let (token, next_refresh_token) = generate_refresh_token(&session);
+        let rotated = self
+            .session_repository
+            .rotate_refresh_token(refresh_token.id, next_refresh_token)
+            .await
+            .map_err(|e| {
+                error!("Failed to rotate refresh token: {:?}", e);
+                AppError::InvalidRefreshToken()
+            })?;
+
+        // another request exchanged the same token in the meantime
+        if !rotated {
+            return Err(self.revoke_reused(session).await);
+        }
+
+        let user = self
+            .user_repository
+            .find(session.user_id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find user: {:?}", e);
+                AppError::InvalidRefreshToken()
+            })?
+            .ok_or_else(AppError::InvalidRefreshToken)?;
+
+        Ok((
+            user,
+            IssuedSession {
+                session_id: session.id.to_string(),
+                refresh_token: token,
+            },
+        ))
+    }
+
+    async fn revoke_reused(&self, session: Session) -> AppError {
+        warn!(
+            "Refresh token reused for session {}, revoking it",
+            session.id
+        );
+
+        if let Err(e) = self.session_repository.revoke(session.id).await {
+            error!("Failed to revoke session: {:?}", e);
+        }
+
+        AppError::InvalidRefreshToken()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use domain::entities::session::RefreshToken;
+    use domain::interfaces::i_session_repository::MockISessionRepository;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+
+    fn user() -> User {
+        User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        )
+    }
+
+    fn session_repository_with(
+        session: Session,
+        refresh_token: RefreshToken,
+    ) -> MockISessionRepository {
+        let mut session_repository = MockISessionRepository::new();
+        session_repository
+            .expect_find_refresh_token()
+            .returning(move |_| Ok(Some(refresh_token.clone())));
+        session_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(session.clone())));
+        session_repository
+    }
+
+    #[tokio::test]
+    async fn given_unused_refresh_token_when_executing_then_it_is_rotated() {
+        // Arrange
+        let user = user();
+        let session = Session::new(user.id.clone(), None, None);
+        let refresh_token = RefreshToken::new(
+            session.id.clone(),
+            hash_refresh_token("token"),
+            Utc::now() + chrono::Duration::days(1),
+        );
+        let used_token_id = refresh_token.id.value;
+        let session_id = session.id.value;
+
+        let mut session_repository = session_repository_with(session, refresh_token);
+        session_repository
+            .expect_rotate_refresh_token()
+            .withf(move |used, next| {
+                used.value == used_token_id
+                    && next.session_id.value == session_id
+                    && next.token_hash != hash_refresh_token("token")
+            })
+            .times(1)
+            .returning(|_, _| Ok(true));
+        session_repository.expect_revoke().times(0);
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(user.clone())));
+
+        let use_case =
+            RefreshSessionUseCase::new(Arc::new(session_repository), Arc::new(user_repository));
+
+        // Act
+        let result = use_case
+            .execute(dtos::RefreshSessionRequest {
+                refresh_token: "token".to_string(),
+            })
+            .await;
+
+        // Assert
+        let (_, issued_session) = result.unwrap();
+        assert_eq!(issued_session.session_id, session_id.to_string());
+        assert_ne!(issued_session.refresh_token, "token");
+    }
+
+    #[tokio::test]
+    async fn given_already_used_refresh_token_when_executing_then_session_is_revoked() {
+        // Arrange
+        let session = Session::new(user().id, None, None);
+        let mut refresh_token = RefreshToken::new(
+            session.id.clone(),
+            hash_refresh_token("token"),
+            Utc::now() + chrono::Duration::days(1),
+        );
+        refresh_token.used_at = Some(Utc::now());
+        let session_id = session.id.value;
+
+        let mut session_repository = session_repository_with(session, refresh_token);
+        session_repository.expect_rotate_refresh_token().times(0);
+        session_repository
+            .expect_revoke()
+            .withf(move |id| id.value == session_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = RefreshSessionUseCase::new(
+            Arc::new(session_repository),
+            Arc::new(MockIUserRepository::new()),
+        );
+
+        // Act
+        let result = use_case
+            .execute(dtos::RefreshSessionRequest {
+                refresh_token: "token".to_string(),
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidRefreshToken())));
+    }
+
+    #[tokio::test]
+    async fn given_unknown_refresh_token_when_executing_then_invalid_refresh_token_error_is_returned(
+    ) {
+        // Arrange
+        let mut session_repository = MockISessionRepository::new();
+        session_repository
+            .expect_find_refresh_token()
+            .returning(|_| Ok(None));
+
+        let use_case = RefreshSessionUseCase::new(
+            Arc::new(session_repository),
+            Arc::new(MockIUserRepository::new()),
+        );
+
+        // Act
+        let result = use_case
+            .execute(dtos::RefreshSessionRequest {
+                refresh_token: "token".to_string(),
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidRefreshToken())));
+    }
+}
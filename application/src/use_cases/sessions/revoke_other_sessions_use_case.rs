@@ -0,0 +1,30 @@
+use domain::app_error::AppError;
+use domain::entities::session::Session;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct RevokeOtherSessionsUseCase<R: ISessionRepository> {
+    session_repository: Arc<R>,
+}
+
+impl<R: ISessionRepository> RevokeOtherSessionsUseCase<R> {
+    pub fn new(session_repository: Arc<R>) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn execute(&self, current_session: Session) -> Result<(), AppError> {
+        info!(
+            "Revoking all sessions of user {} except {}",
+            current_session.user_id, current_session.id
+        );
+
+        self.session_repository
+            .revoke_all_for_user(current_session.user_id, Some(current_session.id))
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke sessions: {:?}", e);
+                AppError::FailedToRevokeSession()
+            })
+    }
+}
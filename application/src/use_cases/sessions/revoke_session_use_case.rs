@@ -0,0 +1,77 @@
+use domain::app_error::AppError;
+use domain::entities::session::Session;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct RevokeSessionUseCase<R: ISessionRepository> {
+    session_repository: Arc<R>,
+}
+
+impl<R: ISessionRepository> RevokeSessionUseCase<R> {
+    pub fn new(session_repository: Arc<R>) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn execute(&self, current_user: User, id: String) -> Result<(), AppError> {
+        info!("Revoking session with id: {}", id);
+
+        let session_id = Id::<Session>::try_from(id.clone())
+            .map_err(|_| AppError::SessionNotFound(id.clone()))?;
+
+        // another user's session is reported as missing rather than forbidden
+        let session = self
+            .session_repository
+            .find_by_id(session_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find session: {:?}", e);
+                AppError::FailedToRevokeSession()
+            })?
+            .filter(|session| session.user_id == current_user.id && !session.is_revoked())
+            .ok_or_else(|| AppError::SessionNotFound(id.clone()))?;
+
+        self.session_repository
+            .revoke(session.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke session: {:?}", e);
+                AppError::FailedToRevokeSession()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_session_repository::MockISessionRepository;
+
+    #[tokio::test]
+    async fn given_session_of_another_user_when_executing_then_session_not_found_error_is_returned()
+    {
+        // Arrange
+        let session = Session::new(Id::gen(), None, None);
+        let session_id = session.id.to_string();
+
+        let mut session_repository = MockISessionRepository::new();
+        session_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(session.clone())));
+        session_repository.expect_revoke().times(0);
+
+        let use_case = RevokeSessionUseCase::new(Arc::new(session_repository));
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+
+        // Act
+        let result = use_case.execute(current_user, session_id).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::SessionNotFound(_))));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::totp;
+use chrono::Utc;
+use domain::app_error::AppError;
+use domain::entities::two_factor::RecoveryCode;
+use domain::entities::user::User;
+use domain::interfaces::i_two_factor_repository::ITwoFactorRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use serde::{Deserialize, Serialize};
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct ConfirmTwoFactorRequest {
+        #[validate(length(equal = 6, message = "Code must have 6 digits"))]
+        pub code: String,
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct RecoveryCodesDto {
+        /// Shown once, only their hashes are stored.
+        pub recovery_codes: Vec<String>,
+    }
+
+    impl IntoResponse for RecoveryCodesDto {
+        fn into_response(self) -> Response {
+            Json(self).into_response()
+        }
+    }
+}
+
+pub struct ConfirmTwoFactorUseCase<R: ITwoFactorRepository> {
+    two_factor_repository: Arc<R>,
+}
+
+impl<R: ITwoFactorRepository> ConfirmTwoFactorUseCase<R> {
+    pub fn new(two_factor_repository: Arc<R>) -> Self {
+        Self {
+            two_factor_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        dto: dtos::ConfirmTwoFactorRequest,
+    ) -> Result<dtos::RecoveryCodesDto, AppError> {
+        info!(
+            "Confirming two-factor enrollment of user {}",
+            current_user.id
+        );
+
+        let two_factor = self
+            .two_factor_repository
+            .find_by_user_id(current_user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to get two-factor settings: {:?}", e);
+                AppError::FailedToUpdateTwoFactor()
+            })?
+            .ok_or(AppError::TwoFactorNotEnrolled())?;
+        if two_factor.is_enabled() {
+            error!("User {} already has two-factor enabled.", current_user.id);
+            return Err(AppError::TwoFactorAlreadyEnabled());
+        }
+
+        let step = totp::verify_code(&two_factor.secret, &dto.code, Utc::now().timestamp())
+            .ok_or(AppError::InvalidTwoFactorCode())?;
+        self.two_factor_repository
+            .record_used_step(current_user.id.clone(), step)
+            .await
+            .map_err(|e| {
+                error!("Failed to record used code: {:?}", e);
+                AppError::FailedToUpdateTwoFactor()
+            })?;
+
+        let recovery_codes = totp::generate_recovery_codes();
+        let hashed_codes = recovery_codes
+            .iter()
+            .map(|code| RecoveryCode::new(current_user.id.clone(), totp::hash_recovery_code(code)))
+            .collect();
+        self.two_factor_repository
+            .enable(current_user.id, hashed_codes)
+            .await
+            .map_err(|e| {
+                error!("Failed to enable two-factor: {:?}", e);
+                AppError::FailedToUpdateTwoFactor()
+            })?;
+
+        Ok(dtos::RecoveryCodesDto { recovery_codes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::two_factor::TwoFactor;
+    use domain::interfaces::i_two_factor_repository::MockITwoFactorRepository;
+
+    #[tokio::test]
+    async fn given_wrong_code_when_executing_then_two_factor_is_not_enabled() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let two_factor = TwoFactor::new(current_user.id.clone(), totp::generate_secret());
+
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(two_factor.clone())));
+        two_factor_repository.expect_enable().times(0);
+
+        let use_case = ConfirmTwoFactorUseCase::new(Arc::new(two_factor_repository));
+        let dto = dtos::ConfirmTwoFactorRequest {
+            code: "abcdef".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, dto).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidTwoFactorCode())));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::totp;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_two_factor_repository::ITwoFactorRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct DisableTwoFactorRequest {
+        /// Required unless the user only ever signed in through an identity provider.
+        pub password: Option<String>,
+        /// A TOTP code or a recovery code.
+        #[validate(length(min = 1, message = "Code is required"))]
+        pub code: String,
+    }
+}
+
+pub struct DisableTwoFactorUseCase<R: ITwoFactorRepository> {
+    two_factor_repository: Arc<R>,
+}
+
+impl<R: ITwoFactorRepository> DisableTwoFactorUseCase<R> {
+    pub fn new(two_factor_repository: Arc<R>) -> Self {
+        Self {
+            two_factor_repository,
+        }
+    }
+
+    /// Requires the password, if the user has one, and a second factor again, a stolen session
+    /// alone is not enough.
+    pub async fn execute(
+        &self,
+        current_user: User,
+        dto: dtos::DisableTwoFactorRequest,
+    ) -> Result<(), AppError> {
+        info!("Disabling two-factor of user {}", current_user.id);
+
+        if let Some(password) = current_user.password.as_deref() {
+            let matches =
+                bcrypt::verify(dto.password.unwrap_or_default(), password).map_err(|_| {
+                    error!("Failed to verify password");
+                    AppError::BadPassword()
+                })?;
+            if !matches {
+                error!("Bad password.");
+                return Err(AppError::BadPassword());
+            }
+        }
+
+        let two_factor = self
+            .two_factor_repository
+            .find_by_user_id(current_user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to get two-factor settings: {:?}", e);
+                AppError::FailedToUpdateTwoFactor()
+            })?
+            .filter(|two_factor| two_factor.is_enabled())
+            .ok_or(AppError::TwoFactorNotEnabled())?;
+
+        let accepted =
+            totp::accept_code(self.two_factor_repository.as_ref(), &two_factor, &dto.code)
+                .await
+                .map_err(|e| {
+                    error!("Failed to check two-factor code: {:?}", e);
+                    AppError::FailedToUpdateTwoFactor()
+                })?;
+        if !accepted {
+            error!("Invalid two-factor code.");
+            return Err(AppError::InvalidTwoFactorCode());
+        }
+
+        self.two_factor_repository
+            .disable(current_user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to disable two-factor: {:?}", e);
+                AppError::FailedToUpdateTwoFactor()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use domain::entities::two_factor::TwoFactor;
+    use domain::interfaces::i_two_factor_repository::MockITwoFactorRepository;
+
+    #[tokio::test]
+    async fn given_used_recovery_code_when_executing_then_two_factor_stays_enabled() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            bcrypt::hash("Password1!", 4).unwrap(),
+        );
+        let mut two_factor = TwoFactor::new(current_user.id.clone(), totp::generate_secret());
+        two_factor.enabled_at = Some(Utc::now());
+
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(two_factor.clone())));
+        two_factor_repository
+            .expect_use_recovery_code()
+            .returning(|_, _| Ok(false));
+        two_factor_repository.expect_disable().times(0);
+
+        let use_case = DisableTwoFactorUseCase::new(Arc::new(two_factor_repository));
+        let dto = dtos::DisableTwoFactorRequest {
+            password: Some("Password1!".to_string()),
+            code: "ABCD-EFGH".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, dto).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidTwoFactorCode())));
+    }
+
+    #[tokio::test]
+    async fn given_user_without_password_when_executing_then_code_alone_disables_two_factor() {
+        // Arrange
+        let current_user = User::new_external("username".to_string(), "email".to_string(), true);
+        let mut two_factor = TwoFactor::new(current_user.id.clone(), totp::generate_secret());
+        two_factor.enabled_at = Some(Utc::now());
+
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(two_factor.clone())));
+        two_factor_repository
+            .expect_use_recovery_code()
+            .returning(|_, _| Ok(true));
+        two_factor_repository
+            .expect_disable()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = DisableTwoFactorUseCase::new(Arc::new(two_factor_repository));
+        let dto = dtos::DisableTwoFactorRequest {
+            password: None,
+            code: "ABCD-EFGH".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, dto).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::totp;
+use domain::app_error::AppError;
+use domain::entities::two_factor::TwoFactor;
+use domain::entities::user::User;
+use domain::interfaces::i_two_factor_repository::ITwoFactorRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use serde::Serialize;
+
+    #[derive(Serialize, Debug)]
+    pub struct TwoFactorEnrollmentDto {
+        /// For entering the secret by hand when the QR code cannot be scanned.
+        pub secret: String,
+        /// To be rendered as a QR code for authenticator apps.
+        pub provisioning_uri: String,
+    }
+
+    impl IntoResponse for TwoFactorEnrollmentDto {
+        fn into_response(self) -> Response {
+            Json(self).into_response()
+        }
+    }
+}
+
+pub struct EnrollTwoFactorUseCase<R: ITwoFactorRepository> {
+    two_factor_repository: Arc<R>,
+}
+
+impl<R: ITwoFactorRepository> EnrollTwoFactorUseCase<R> {
+    pub fn new(two_factor_repository: Arc<R>) -> Self {
+        Self {
+            two_factor_repository,
+        }
+    }
+
+    /// Starts over with a new secret when a previous enrollment was never confirmed.
+    pub async fn execute(
+        &self,
+        current_user: User,
+    ) -> Result<dtos::TwoFactorEnrollmentDto, AppError> {
+        info!(
+            "Enrolling user {} in two-factor authentication",
+            current_user.id
+        );
+
+        let secret = totp::generate_secret();
+        let inserted = self
+            .two_factor_repository
+            .insert_pending(TwoFactor::new(current_user.id.clone(), secret.clone()))
+            .await
+            .map_err(|e| {
+                error!("Failed to store two-factor secret: {:?}", e);
+                AppError::FailedToUpdateTwoFactor()
+            })?;
+        if !inserted {
+            error!("User {} already has two-factor enabled.", current_user.id);
+            return Err(AppError::TwoFactorAlreadyEnabled());
+        }
+
+        Ok(dtos::TwoFactorEnrollmentDto {
+            provisioning_uri: totp::provisioning_uri(&secret, &current_user.email),
+            secret,
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod confirm_two_factor_use_case;
+pub mod disable_two_factor_use_case;
+pub mod enroll_two_factor_use_case;
+pub mod verify_login_challenge_use_case;
@@ -0,0 +1,481 @@
+use crate::totp;
+use crate::use_cases::user::login_use_case::{
+    hash_challenge_token, record_failure, FailedLogin, ACCOUNT_MAX_FAILED_ATTEMPTS,
+};
+use domain::app_error::AppError;
+use domain::entities::audit_event::AuditEventKind;
+use domain::entities::login_throttle::LoginThrottle;
+use domain::entities::user::User;
+use domain::interfaces::i_audit_log_repository::IAuditLogRepository;
+use domain::interfaces::i_login_throttle_repository::ILoginThrottleRepository;
+use domain::interfaces::i_two_factor_repository::ITwoFactorRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const MAX_FAILED_ATTEMPTS: i32 = 5;
+
+pub mod dtos {
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct VerifyLoginChallengeRequest {
+        pub challenge_token: String,
+        /// A TOTP code or a recovery code.
+        #[validate(length(min = 1, message = "Code is required"))]
+        pub code: String,
+    }
+}
+
+pub struct VerifyLoginChallengeUseCase<
+    R1: ITwoFactorRepository,
+    R2: IUserRepository,
+    R3: ILoginThrottleRepository,
+    R4: IAuditLogRepository,
+> {
+    two_factor_repository: Arc<R1>,
+    user_repository: Arc<R2>,
+    login_throttle_repository: Arc<R3>,
+    audit_log_repository: Arc<R4>,
+}
+
+impl<
+        R1: ITwoFactorRepository,
+        R2: IUserRepository,
+        R3: ILoginThrottleRepository,
+        R4: IAuditLogRepository,
+    > VerifyLoginChallengeUseCase<R1, R2, R3, R4>
+{
+    pub fn new(
+        two_factor_repository: Arc<R1>,
+        user_repository: Arc<R2>,
+        login_throttle_repository: Arc<R3>,
+        audit_log_repository: Arc<R4>,
+    ) -> Self {
+        Self {
+            two_factor_repository,
+            user_repository,
+            login_throttle_repository,
+            audit_log_repository,
+        }
+    }
+
+    /// Completes a login started by `LoginUseCase`, returning the user to create a session for.
+    /// Wrong codes count against the account like wrong passwords, as every login with the
+    /// password hands out a fresh challenge.
+    pub async fn execute(
+        &self,
+        dto: dtos::VerifyLoginChallengeRequest,
+        ip_address: Option<String>,
+    ) -> Result<User, AppError> {
+        let challenge = self
+            .two_factor_repository
+            .find_challenge(hash_challenge_token(&dto.challenge_token))
+            .await
+            .map_err(|e| {
+                error!("Failed to find login challenge: {:?}", e);
+                AppError::InternalServerError()
+            })?
+            .filter(|challenge| challenge.is_usable(MAX_FAILED_ATTEMPTS))
+            .ok_or(AppError::InvalidLoginChallenge())?;
+        info!("Verifying login challenge of user {}", challenge.user_id);
+
+        // disabled in the meantime, the challenge cannot be completed anymore
+        let two_factor = self
+            .two_factor_repository
+            .find_by_user_id(challenge.user_id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to get two-factor settings: {:?}", e);
+                AppError::InternalServerError()
+            })?
+            .filter(|two_factor| two_factor.is_enabled())
+            .ok_or(AppError::InvalidLoginChallenge())?;
+
+        let user = self
+            .user_repository
+            .find(challenge.user_id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find user: {:?}", e);
+                AppError::InternalServerError()
+            })?
+            .ok_or(AppError::InvalidLoginChallenge())?;
+
+        // challenges handed out before the account was locked must not keep guessing
+        let account_key = LoginThrottle::account_key(&user.email);
+        let throttles = self
+            .login_throttle_repository
+            .find_all(vec![account_key.clone()])
+            .await
+            .map_err(|e| {
+                error!("Failed to get login throttles: {:?}", e);
+                AppError::InternalServerError()
+            })?;
+        if throttles.iter().any(LoginThrottle::is_locked) {
+            error!("Login for user {} is locked.", user.id);
+            return Err(AppError::TooManyLoginAttempts());
+        }
+
+        let accepted =
+            totp::accept_code(self.two_factor_repository.as_ref(), &two_factor, &dto.code)
+                .await
+                .map_err(|e| {
+                    error!("Failed to check two-factor code: {:?}", e);
+                    AppError::InternalServerError()
+                })?;
+        if !accepted {
+            error!("Invalid two-factor code for user {}.", challenge.user_id);
+            if let Err(e) = self
+                .two_factor_repository
+                .record_challenge_failure(challenge.id)
+                .await
+            {
+                error!("Failed to record failed challenge: {:?}", e);
+            }
+            record_failure(
+                self.login_throttle_repository.as_ref(),
+                self.audit_log_repository.as_ref(),
+                account_key,
+                ACCOUNT_MAX_FAILED_ATTEMPTS,
+                AuditEventKind::AccountLocked,
+                &FailedLogin {
+                    user_id: Some(user.id.clone()),
+                    email: &user.email,
+                    ip_address: &ip_address,
+                },
+            )
+            .await;
+            return Err(AppError::InvalidTwoFactorCode());
+        }
+
+        let consumed = self
+            .two_factor_repository
+            .consume_challenge(challenge.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to consume login challenge: {:?}", e);
+                AppError::InternalServerError()
+            })?;
+        if !consumed {
+            return Err(AppError::InvalidLoginChallenge());
+        }
+
+        if user.is_suspended() {
+            error!("User {} is suspended.", user.id);
+            return Err(AppError::AccountSuspended());
+        }
+
+        // the IP counter is kept, as on logins without a second factor
+        if let Err(e) = self.login_throttle_repository.clear(account_key).await {
+            error!("Failed to clear login throttle: {:?}", e);
+        }
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use domain::entities::two_factor::{LoginChallenge, TwoFactor};
+    use domain::interfaces::i_audit_log_repository::MockIAuditLogRepository;
+    use domain::interfaces::i_login_throttle_repository::MockILoginThrottleRepository;
+    use domain::interfaces::i_two_factor_repository::MockITwoFactorRepository;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    fn user() -> User {
+        User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        )
+    }
+
+    fn user_repository(user: User) -> MockIUserRepository {
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(user.clone())));
+        user_repository
+    }
+
+    fn throttle(key: String, failed_attempts: i32) -> LoginThrottle {
+        LoginThrottle {
+            key,
+            failed_attempts,
+            locked_until: None,
+            last_failed_at: Utc::now(),
+        }
+    }
+
+    /// Counts every failure as the first one, so nothing gets locked.
+    fn login_throttle_repository_without_locks() -> MockILoginThrottleRepository {
+        let mut login_throttle_repository = MockILoginThrottleRepository::new();
+        login_throttle_repository
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        login_throttle_repository
+            .expect_record_failure()
+            .returning(|key, _| Ok(throttle(key, 1)));
+        login_throttle_repository
+    }
+
+    fn two_factor_repository(
+        challenge: LoginChallenge,
+        recovery_code_accepted: bool,
+    ) -> MockITwoFactorRepository {
+        let mut two_factor = TwoFactor::new(challenge.user_id.clone(), totp::generate_secret());
+        two_factor.enabled_at = Some(Utc::now());
+
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_challenge()
+            .returning(move |_| Ok(Some(challenge.clone())));
+        two_factor_repository
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(two_factor.clone())));
+        two_factor_repository
+            .expect_use_recovery_code()
+            .returning(move |_, _| Ok(recovery_code_accepted));
+        two_factor_repository
+    }
+
+    #[tokio::test]
+    async fn given_valid_recovery_code_when_executing_then_user_is_returned() {
+        // Arrange
+        let user = user();
+        let challenge = LoginChallenge::new(
+            user.id.clone(),
+            hash_challenge_token("token"),
+            Utc::now() + Duration::minutes(5),
+        );
+
+        let mut two_factor_repository = two_factor_repository(challenge, true);
+        two_factor_repository
+            .expect_consume_challenge()
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let mut login_throttle_repository = login_throttle_repository_without_locks();
+        login_throttle_repository
+            .expect_clear()
+            .withf(|key| *key == LoginThrottle::account_key("email"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let expected_user = user.clone();
+        let use_case = VerifyLoginChallengeUseCase::new(
+            Arc::new(two_factor_repository),
+            Arc::new(user_repository(user)),
+            Arc::new(login_throttle_repository),
+            Arc::new(MockIAuditLogRepository::new()),
+        );
+        let dto = dtos::VerifyLoginChallengeRequest {
+            challenge_token: "token".to_string(),
+            code: "ABCD-EFGH".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(dto, None).await;
+
+        // Assert
+        assert_eq!(result.unwrap().id.value, expected_user.id.value);
+    }
+
+    #[tokio::test]
+    async fn given_wrong_code_when_executing_then_failure_is_recorded_against_challenge_and_account(
+    ) {
+        // Arrange
+        let user = user();
+        let challenge = LoginChallenge::new(
+            user.id.clone(),
+            hash_challenge_token("token"),
+            Utc::now() + Duration::minutes(5),
+        );
+
+        let mut two_factor_repository = two_factor_repository(challenge, false);
+        two_factor_repository
+            .expect_record_challenge_failure()
+            .times(1)
+            .returning(|_| Ok(()));
+        two_factor_repository.expect_consume_challenge().times(0);
+
+        let mut login_throttle_repository = MockILoginThrottleRepository::new();
+        login_throttle_repository
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        login_throttle_repository
+            .expect_record_failure()
+            .withf(|key, _| *key == LoginThrottle::account_key("email"))
+            .times(1)
+            .returning(|key, _| Ok(throttle(key, 1)));
+        login_throttle_repository.expect_clear().times(0);
+
+        let use_case = VerifyLoginChallengeUseCase::new(
+            Arc::new(two_factor_repository),
+            Arc::new(user_repository(user)),
+            Arc::new(login_throttle_repository),
+            Arc::new(MockIAuditLogRepository::new()),
+        );
+        let dto = dtos::VerifyLoginChallengeRequest {
+            challenge_token: "token".to_string(),
+            code: "ABCD-EFGH".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(dto, None).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidTwoFactorCode())));
+    }
+
+    #[tokio::test]
+    async fn given_expired_challenge_when_executing_then_code_is_not_checked() {
+        // Arrange
+        let challenge = LoginChallenge::new(
+            user().id,
+            hash_challenge_token("token"),
+            Utc::now() - Duration::minutes(1),
+        );
+
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_challenge()
+            .returning(move |_| Ok(Some(challenge.clone())));
+        two_factor_repository.expect_find_by_user_id().times(0);
+
+        let use_case = VerifyLoginChallengeUseCase::new(
+            Arc::new(two_factor_repository),
+            Arc::new(MockIUserRepository::new()),
+            Arc::new(MockILoginThrottleRepository::new()),
+            Arc::new(MockIAuditLogRepository::new()),
+        );
+        let dto = dtos::VerifyLoginChallengeRequest {
+            challenge_token: "token".to_string(),
+            code: "123456".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(dto, None).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidLoginChallenge())));
+    }
+
+    #[tokio::test]
+    async fn given_wrong_codes_on_fresh_challenges_when_executing_then_account_is_locked_and_audited(
+    ) {
+        // Arrange
+        let user = user();
+        let challenge = LoginChallenge::new(
+            user.id.clone(),
+            hash_challenge_token("token"),
+            Utc::now() + Duration::minutes(5),
+        );
+
+        let mut two_factor_repository = two_factor_repository(challenge, false);
+        two_factor_repository
+            .expect_record_challenge_failure()
+            .returning(|_| Ok(()));
+
+        let failed_attempts = Arc::new(AtomicI32::new(0));
+        let mut login_throttle_repository = MockILoginThrottleRepository::new();
+        login_throttle_repository
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        login_throttle_repository
+            .expect_record_failure()
+            .returning(move |key, _| {
+                Ok(throttle(
+                    key,
+                    failed_attempts.fetch_add(1, Ordering::SeqCst) + 1,
+                ))
+            });
+        login_throttle_repository
+            .expect_lock()
+            .withf(|key, _| *key == LoginThrottle::account_key("email"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut audit_log_repository = MockIAuditLogRepository::new();
+        audit_log_repository
+            .expect_insert()
+            .withf(|event| event.kind == AuditEventKind::AccountLocked)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = VerifyLoginChallengeUseCase::new(
+            Arc::new(two_factor_repository),
+            Arc::new(user_repository(user)),
+            Arc::new(login_throttle_repository),
+            Arc::new(audit_log_repository),
+        );
+
+        // Act
+        // one guess on each of as many challenges as there are attempts before the lock
+        for _ in 0..ACCOUNT_MAX_FAILED_ATTEMPTS {
+            let dto = dtos::VerifyLoginChallengeRequest {
+                challenge_token: "token".to_string(),
+                code: "ABCD-EFGH".to_string(),
+            };
+            let result = use_case.execute(dto, None).await;
+
+            // Assert
+            assert!(matches!(result, Err(AppError::InvalidTwoFactorCode())));
+        }
+    }
+
+    #[tokio::test]
+    async fn given_locked_account_when_executing_then_code_is_not_checked() {
+        // Arrange
+        let user = user();
+        let challenge = LoginChallenge::new(
+            user.id.clone(),
+            hash_challenge_token("token"),
+            Utc::now() + Duration::minutes(5),
+        );
+
+        let mut two_factor = TwoFactor::new(user.id.clone(), totp::generate_secret());
+        two_factor.enabled_at = Some(Utc::now());
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_challenge()
+            .returning(move |_| Ok(Some(challenge.clone())));
+        two_factor_repository
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(two_factor.clone())));
+        two_factor_repository.expect_use_recovery_code().times(0);
+        two_factor_repository.expect_consume_challenge().times(0);
+
+        let mut login_throttle_repository = MockILoginThrottleRepository::new();
+        login_throttle_repository
+            .expect_find_all()
+            .returning(|keys| {
+                Ok(vec![LoginThrottle {
+                    locked_until: Some(Utc::now() + Duration::minutes(1)),
+                    ..throttle(keys[0].clone(), 5)
+                }])
+            });
+
+        let use_case = VerifyLoginChallengeUseCase::new(
+            Arc::new(two_factor_repository),
+            Arc::new(user_repository(user)),
+            Arc::new(login_throttle_repository),
+            Arc::new(MockIAuditLogRepository::new()),
+        );
+        let dto = dtos::VerifyLoginChallengeRequest {
+            challenge_token: "token".to_string(),
+            code: "ABCD-EFGH".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(dto, None).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::TooManyLoginAttempts())));
+    }
+}
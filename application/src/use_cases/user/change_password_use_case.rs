@@ -0,0 +1,94 @@
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use crate::validators::validate_password;
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct ChangePasswordRequest {
+        pub old_password: String,
+        #[validate(custom(function = "validate_password",))]
+        pub new_password: String,
+    }
+}
+
+pub struct ChangePasswordUseCase<R: IUserRepository> {
+    user_repository: Arc<R>,
+}
+
+impl<R: IUserRepository> ChangePasswordUseCase<R> {
+    pub fn new(user_repository: Arc<R>) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        dto: dtos::ChangePasswordRequest,
+    ) -> Result<(), AppError> {
+        info!("Changing password of user {}", current_user.id);
+
+        // users who signed up through an identity provider set a first password by resetting it
+        let Some(password) = current_user.password.as_deref() else {
+            error!("User {} has no password.", current_user.id);
+            return Err(AppError::BadPassword());
+        };
+        let matches = bcrypt::verify(dto.old_password, password).map_err(|_| {
+            error!("Failed to verify password");
+            AppError::BadPassword()
+        })?;
+        if !matches {
+            error!("Bad password.");
+            return Err(AppError::BadPassword());
+        }
+
+        let hashed_password = bcrypt::hash(dto.new_password, 12).map_err(|_| {
+            error!("Failed to hash password");
+            AppError::FailedToChangePassword()
+        })?;
+
+        self.user_repository
+            .update_password(current_user.id, hashed_password)
+            .await
+            .map_err(|e| {
+                error!("Failed to update password: {:?}", e);
+                AppError::FailedToChangePassword()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+
+    #[tokio::test]
+    async fn given_wrong_old_password_when_executing_then_bad_password_error_is_returned() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            bcrypt::hash("Password1!", 4).unwrap(),
+        );
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository.expect_update_password().times(0);
+
+        let use_case = ChangePasswordUseCase::new(Arc::new(user_repository));
+        let dto = dtos::ChangePasswordRequest {
+            old_password: "Wrong1!".to_string(),
+            new_password: "NewPassword1!".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, dto).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::BadPassword())));
+    }
+}
@@ -0,0 +1,219 @@
+use chrono::{Duration, Utc};
+use domain::app_error::AppError;
+use domain::entities::email::Email;
+use domain::entities::password_reset::PasswordResetToken;
+use domain::interfaces::i_password_reset_repository::IPasswordResetRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tracing::{error, info};
+
+const RESET_TOKEN_LIFETIME_MINUTES: i64 = 30;
+const RESET_COOLDOWN_MINUTES: i64 = 5;
+
+pub mod dtos {
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct ForgotPasswordRequest {
+        #[validate(email(message = "Invalid email"))]
+        pub email: String,
+    }
+}
+
+/// Reset tokens are random, so a fast unsalted hash is enough to look them up by.
+pub(crate) fn hash_reset_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+pub struct ForgotPasswordUseCase<R1: IUserRepository, R2: IPasswordResetRepository> {
+    user_repository: Arc<R1>,
+    password_reset_repository: Arc<R2>,
+    reset_url: String,
+}
+
+impl<R1: IUserRepository, R2: IPasswordResetRepository> ForgotPasswordUseCase<R1, R2> {
+    pub fn new(
+        user_repository: Arc<R1>,
+        password_reset_repository: Arc<R2>,
+        reset_url: String,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_reset_repository,
+            reset_url,
+        }
+    }
+
+    pub async fn execute(&self, dto: dtos::ForgotPasswordRequest) -> Result<(), AppError> {
+        info!("Password reset requested for email: {}", dto.email);
+
+        // an unknown email gets the same answer so the endpoint cannot be used to probe accounts
+        let user = match self.user_repository.find_by_email(dto.email.clone()).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                info!("Email {} is not registered.", dto.email);
+                return Ok(());
+            }
+            Err(e) => {
+                error!("Failed to find user by email: {:?}", e);
+                return Err(AppError::FailedToResetPassword());
+            }
+        };
+
+        // the link sent moments ago is still valid, and the address is not flooded with emails
+        let latest_token = self
+            .password_reset_repository
+            .find_latest_by_user_id(user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find latest password reset token: {:?}", e);
+                AppError::FailedToResetPassword()
+            })?;
+        if latest_token.is_some_and(|token| {
+            Utc::now() - token.created_at < Duration::minutes(RESET_COOLDOWN_MINUTES)
+        }) {
+            info!("Password reset for user {} was sent moments ago.", user.id);
+            return Ok(());
+        }
+
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let token = hex::encode(bytes);
+
+        let reset_token = PasswordResetToken::new(
+            user.id.clone(),
+            hash_reset_token(&token),
+            Utc::now() + Duration::minutes(RESET_TOKEN_LIFETIME_MINUTES),
+        );
+        let email = Email::password_reset(&user, format!("{}?token={}", self.reset_url, token));
+
+        self.password_reset_repository
+            .insert(reset_token, vec![email.into_job()])
+            .await
+            .map_err(|e| {
+                error!("Failed to insert password reset token: {:?}", e);
+                AppError::FailedToResetPassword()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::user::User;
+    use domain::interfaces::i_password_reset_repository::MockIPasswordResetRepository;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+
+    fn use_case(
+        user_repository: MockIUserRepository,
+        password_reset_repository: MockIPasswordResetRepository,
+    ) -> ForgotPasswordUseCase<MockIUserRepository, MockIPasswordResetRepository> {
+        ForgotPasswordUseCase::new(
+            Arc::new(user_repository),
+            Arc::new(password_reset_repository),
+            "http://localhost/reset-password".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn given_registered_email_when_executing_then_hashed_token_is_stored_with_reset_email() {
+        // Arrange
+        let mut user_repository = MockIUserRepository::new();
+        user_repository.expect_find_by_email().returning(|_| {
+            Ok(Some(User::new(
+                "username".to_string(),
+                "email@example.com".to_string(),
+                "hashed_password".to_string(),
+            )))
+        });
+
+        let mut password_reset_repository = MockIPasswordResetRepository::new();
+        password_reset_repository
+            .expect_find_latest_by_user_id()
+            .returning(|_| Ok(None));
+        password_reset_repository
+            .expect_insert()
+            .withf(|token, jobs| {
+                jobs.len() == 1
+                    && jobs[0].kind == Email::JOB_KIND
+                    && !jobs[0].payload.to_string().contains(&token.token_hash)
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = use_case(user_repository, password_reset_repository);
+        let dto = dtos::ForgotPasswordRequest {
+            email: "email@example.com".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(dto).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_reset_sent_moments_ago_when_executing_then_ok_is_returned_without_another_email()
+    {
+        // Arrange
+        let user = User::new(
+            "username".to_string(),
+            "email@example.com".to_string(),
+            "hashed_password".to_string(),
+        );
+        let latest_token = PasswordResetToken::new(
+            user.id.clone(),
+            "hash".to_string(),
+            Utc::now() + Duration::minutes(RESET_TOKEN_LIFETIME_MINUTES),
+        );
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find_by_email()
+            .returning(move |_| Ok(Some(user.clone())));
+
+        let mut password_reset_repository = MockIPasswordResetRepository::new();
+        password_reset_repository
+            .expect_find_latest_by_user_id()
+            .returning(move |_| Ok(Some(latest_token.clone())));
+        password_reset_repository.expect_insert().times(0);
+
+        let use_case = use_case(user_repository, password_reset_repository);
+        let dto = dtos::ForgotPasswordRequest {
+            email: "email@example.com".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(dto).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_unknown_email_when_executing_then_ok_is_returned_without_issuing_a_token() {
+        // Arrange
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find_by_email()
+            .returning(|_| Ok(None));
+
+        let mut password_reset_repository = MockIPasswordResetRepository::new();
+        password_reset_repository.expect_insert().times(0);
+
+        let use_case = use_case(user_repository, password_reset_repository);
+        let dto = dtos::ForgotPasswordRequest {
+            email: "unknown@example.com".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(dto).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
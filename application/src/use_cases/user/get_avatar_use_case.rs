@@ -0,0 +1,66 @@
+use crate::use_cases::user::get_public_profile_use_case::find_visible_user;
+use domain::app_error::AppError;
+use domain::interfaces::i_user_profile_repository::IUserProfileRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::http::{header, StatusCode};
+    use axum::response::{IntoResponse, Response};
+
+    #[derive(Debug)]
+    pub struct AvatarDto {
+        pub content_type: String,
+        pub data: Vec<u8>,
+    }
+
+    impl IntoResponse for AvatarDto {
+        fn into_response(self) -> Response {
+            (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, self.content_type),
+                    // avatars are shown to everyone, browsers must not guess another type
+                    (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+                ],
+                self.data,
+            )
+                .into_response()
+        }
+    }
+}
+
+pub struct GetAvatarUseCase<R1: IUserRepository, R2: IUserProfileRepository> {
+    user_repository: Arc<R1>,
+    user_profile_repository: Arc<R2>,
+}
+
+impl<R1: IUserRepository, R2: IUserProfileRepository> GetAvatarUseCase<R1, R2> {
+    pub fn new(user_repository: Arc<R1>, user_profile_repository: Arc<R2>) -> Self {
+        Self {
+            user_repository,
+            user_profile_repository,
+        }
+    }
+
+    pub async fn execute(&self, username: String) -> Result<dtos::AvatarDto, AppError> {
+        info!("Getting avatar of user {}", username);
+
+        let user = find_visible_user(self.user_repository.as_ref(), username.clone()).await?;
+
+        let profile = self
+            .user_profile_repository
+            .find(user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to get profile: {:?}", e);
+                AppError::FailedToGetProfile()
+            })?;
+
+        match profile.and_then(|profile| profile.avatar.zip(profile.avatar_content_type)) {
+            Some((data, content_type)) => Ok(dtos::AvatarDto { content_type, data }),
+            None => Err(AppError::AvatarNotFound(username)),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::entities::user_profile::UserProfile;
+use domain::interfaces::i_user_profile_repository::IUserProfileRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::user::{Role, User};
+    use domain::entities::user_profile::UserProfile;
+    use serde::Serialize;
+
+    /// The profile as its owner sees it, email included.
+    #[derive(Serialize, Debug)]
+    pub struct ProfileDto {
+        pub id: String,
+        pub username: String,
+        pub email: String,
+        pub email_verified: bool,
+        pub role: Role,
+        pub display_name: Option<String>,
+        pub bio: Option<String>,
+        pub location: Option<String>,
+        pub has_avatar: bool,
+    }
+
+    impl ProfileDto {
+        pub fn new(user: User, profile: UserProfile) -> Self {
+            Self {
+                id: user.id.to_string(),
+                username: user.name,
+                email: user.email,
+                email_verified: user.email_verified,
+                role: user.role,
+                display_name: profile.display_name,
+                bio: profile.bio,
+                location: profile.location,
+                has_avatar: profile.avatar.is_some(),
+            }
+        }
+    }
+
+    impl IntoResponse for ProfileDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct GetProfileUseCase<R: IUserProfileRepository> {
+    user_profile_repository: Arc<R>,
+}
+
+impl<R: IUserProfileRepository> GetProfileUseCase<R> {
+    pub fn new(user_profile_repository: Arc<R>) -> Self {
+        Self {
+            user_profile_repository,
+        }
+    }
+
+    pub async fn execute(&self, current_user: User) -> Result<dtos::ProfileDto, AppError> {
+        info!("Getting profile of user with id {}", current_user.id);
+
+        let profile = self
+            .user_profile_repository
+            .find(current_user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to get profile: {:?}", e);
+                AppError::FailedToGetProfile()
+            })?
+            .unwrap_or_else(|| UserProfile::empty(current_user.id.clone()));
+
+        Ok(dtos::ProfileDto::new(current_user, profile))
+    }
+}
@@ -0,0 +1,178 @@
+use crate::use_cases::auctions::get_ongoing_auctions_use_case::AuctionWithItemDto;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::entities::user_profile::UserProfile;
+use domain::interfaces::i_auction_repository::IAuctionRepository;
+use domain::interfaces::i_rating_repository::IRatingRepository;
+use domain::interfaces::i_user_profile_repository::IUserProfileRepository;
+use domain::interfaces::i_user_repository::IUserRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use crate::use_cases::auctions::get_ongoing_auctions_use_case::AuctionWithItemDto;
+    use crate::use_cases::ratings::get_user_ratings_use_case::dtos::ReputationDto;
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use serde::Serialize;
+
+    /// What anyone can see of a seller, the email is left out.
+    #[derive(Serialize, Debug)]
+    pub struct PublicProfileDto {
+        pub username: String,
+        pub display_name: Option<String>,
+        pub bio: Option<String>,
+        pub location: Option<String>,
+        pub has_avatar: bool,
+        pub reputation: ReputationDto,
+        pub active_auctions: Vec<AuctionWithItemDto>,
+    }
+
+    impl IntoResponse for PublicProfileDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+/// Suspended and deleted users are reported as missing so their page disappears along with their
+/// access.
+pub(crate) async fn find_visible_user<R: IUserRepository>(
+    user_repository: &R,
+    username: String,
+) -> Result<User, AppError> {
+    user_repository
+        .find_by_username(username.clone())
+        .await
+        .map_err(|e| {
+            error!("Failed to find user by username: {:?}", e);
+            AppError::FailedToGetProfile()
+        })?
+        .filter(|user| !user.is_suspended() && !user.is_deleted())
+        .ok_or(AppError::UserNotFound(username))
+}
+
+pub struct GetPublicProfileUseCase<
+    R1: IUserRepository,
+    R2: IUserProfileRepository,
+    R3: IAuctionRepository,
+    R4: IRatingRepository,
+> {
+    user_repository: Arc<R1>,
+    user_profile_repository: Arc<R2>,
+    auction_repository: Arc<R3>,
+    rating_repository: Arc<R4>,
+}
+
+impl<
+        R1: IUserRepository,
+        R2: IUserProfileRepository,
+        R3: IAuctionRepository,
+        R4: IRatingRepository,
+    > GetPublicProfileUseCase<R1, R2, R3, R4>
+{
+    pub fn new(
+        user_repository: Arc<R1>,
+        user_profile_repository: Arc<R2>,
+        auction_repository: Arc<R3>,
+        rating_repository: Arc<R4>,
+    ) -> Self {
+        Self {
+            user_repository,
+            user_profile_repository,
+            auction_repository,
+            rating_repository,
+        }
+    }
+
+    pub async fn execute(&self, username: String) -> Result<dtos::PublicProfileDto, AppError> {
+        info!("Getting public profile of user {}", username);
+
+        let user = find_visible_user(self.user_repository.as_ref(), username).await?;
+
+        let profile = self
+            .user_profile_repository
+            .find(user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to get profile: {:?}", e);
+                AppError::FailedToGetProfile()
+            })?
+            .unwrap_or_else(|| UserProfile::empty(user.id.clone()));
+
+        let reputation = self
+            .rating_repository
+            .find_reputations(vec![user.id.clone()])
+            .await
+            .map_err(|e| {
+                error!("Failed to get reputation: {:?}", e);
+                AppError::FailedToGetProfile()
+            })?
+            .remove(&user.id)
+            .unwrap_or_default();
+
+        let active_auctions = self
+            .auction_repository
+            .find_all_ongoing_by_user_id(user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to get auctions of user: {:?}", e);
+                AppError::FailedToGetProfile()
+            })?;
+
+        Ok(dtos::PublicProfileDto {
+            username: user.name,
+            display_name: profile.display_name,
+            bio: profile.bio,
+            location: profile.location,
+            has_avatar: profile.avatar.is_some(),
+            active_auctions: active_auctions
+                .iter()
+                .map(|auction| AuctionWithItemDto::new(auction, reputation.clone()))
+                .collect(),
+            reputation: reputation.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use domain::interfaces::i_auction_repository::MockIAuctionRepository;
+    use domain::interfaces::i_rating_repository::MockIRatingRepository;
+    use domain::interfaces::i_user_profile_repository::MockIUserProfileRepository;
+    use domain::interfaces::i_user_repository::MockIUserRepository;
+
+    #[tokio::test]
+    async fn given_suspended_user_when_executing_then_user_not_found_is_returned() {
+        // Arrange
+        let mut user = User::new(
+            "seller".to_string(),
+            "seller@example.com".to_string(),
+            "hashed_password".to_string(),
+        );
+        user.suspended_at = Some(Utc::now());
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find_by_username()
+            .returning(move |_| Ok(Some(user.clone())));
+        let mut user_profile_repository = MockIUserProfileRepository::new();
+        user_profile_repository.expect_find().times(0);
+
+        let use_case = GetPublicProfileUseCase::new(
+            Arc::new(user_repository),
+            Arc::new(user_profile_repository),
+            Arc::new(MockIAuctionRepository::new()),
+            Arc::new(MockIRatingRepository::new()),
+        );
+
+        // Act
+        let result = use_case.execute("seller".to_string()).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::UserNotFound(username)) if username == "seller"));
+    }
+}
@@ -0,0 +1,198 @@
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::entities::user_event::UserEventCursor;
+use domain::interfaces::i_user_event_repository::IUserEventRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub const PAGE_SIZE: i64 = 100;
+
+pub mod dtos {
+    use domain::entities::user_event::{UserEvent, UserEventCursor};
+    use serde::Serialize;
+
+    #[derive(Serialize, Debug, Clone)]
+    pub struct UserEventDto {
+        pub id: i64,
+        /// Resumes a feed right after this event.
+        #[serde(skip)]
+        pub cursor: UserEventCursor,
+        pub kind: String,
+        pub auction_id: Option<String>,
+        pub item_id: Option<String>,
+        pub value: Option<f32>,
+        pub created_at: i64,
+    }
+
+    impl From<UserEvent> for UserEventDto {
+        fn from(event: UserEvent) -> Self {
+            UserEventDto {
+                id: event.sequence,
+                cursor: event.cursor(),
+                kind: event.kind.into(),
+                auction_id: event.auction_id.map(|id| id.to_string()),
+                item_id: event.item_id.map(|id| id.to_string()),
+                value: event.value,
+                created_at: event.created_at.timestamp(),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct UserEventsPage {
+        pub events: Vec<UserEventDto>,
+        /// Where to resume on the next call.
+        pub cursor: UserEventCursor,
+        /// Whether more events are committed but wait for older transactions to end.
+        pub held_back: bool,
+    }
+}
+
+pub struct GetUserEventsUseCase<R: IUserEventRepository> {
+    user_event_repository: Arc<R>,
+}
+
+impl<R: IUserEventRepository> GetUserEventsUseCase<R> {
+    pub fn new(user_event_repository: Arc<R>) -> Self {
+        Self {
+            user_event_repository,
+        }
+    }
+
+    /// Without a cursor nothing is replayed and the page only tells where the feed currently ends.
+    pub async fn execute(
+        &self,
+        current_user: User,
+        after: Option<UserEventCursor>,
+    ) -> Result<dtos::UserEventsPage, AppError> {
+        let after = match after {
+            Some(after) => after,
+            None => {
+                info!("Starting event feed for user with id: {}", current_user.id);
+
+                let cursor = self.user_event_repository.find_head().await.map_err(|e| {
+                    error!("Failed to get last user event: {:?}", e);
+                    AppError::FailedToGetUserEvents()
+                })?;
+
+                return Ok(dtos::UserEventsPage {
+                    events: Vec::new(),
+                    cursor,
+                    held_back: false,
+                });
+            }
+        };
+
+        let batch = self
+            .user_event_repository
+            .find_after(current_user.id.clone(), after, PAGE_SIZE)
+            .await
+            .map_err(|e| {
+                error!("Failed to get user events: {:?}", e);
+                AppError::FailedToGetUserEvents()
+            })?;
+
+        let cursor = batch
+            .events
+            .last()
+            .map(|event| event.cursor())
+            .unwrap_or(after);
+
+        Ok(dtos::UserEventsPage {
+            events: batch
+                .events
+                .into_iter()
+                .map(dtos::UserEventDto::from)
+                .collect(),
+            cursor,
+            held_back: batch.held_back,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::use_cases::user::get_user_events_use_case::GetUserEventsUseCase;
+    use domain::entities::user::User;
+    use domain::entities::user_event::{UserEvent, UserEventBatch, UserEventCursor, UserEventKind};
+    use domain::interfaces::i_user_event_repository::MockIUserEventRepository;
+    use mockall::predicate::eq;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn given_no_cursor_when_executing_then_feed_starts_at_head() {
+        // Arrange
+        let head = UserEventCursor {
+            xact_id: 41,
+            sequence: i64::MAX,
+        };
+        let mut user_event_repository = MockIUserEventRepository::new();
+        user_event_repository
+            .expect_find_head()
+            .returning(move || Ok(head));
+        user_event_repository.expect_find_after().never();
+
+        let use_case = GetUserEventsUseCase::new(Arc::new(user_event_repository));
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+
+        // Act
+        let result = use_case.execute(current_user, None).await;
+
+        // Assert
+        let page = result.unwrap();
+        assert!(page.events.is_empty());
+        assert_eq!(page.cursor, head);
+    }
+
+    #[tokio::test]
+    async fn given_cursor_when_executing_then_events_after_cursor_are_returned() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let user_id = current_user.id.clone();
+        let after = UserEventCursor {
+            xact_id: 5,
+            sequence: 7,
+        };
+
+        let mut user_event_repository = MockIUserEventRepository::new();
+        user_event_repository
+            .expect_find_after()
+            .with(eq(user_id.clone()), eq(after), eq(100))
+            .returning(move |user_id, _, _| {
+                let mut event =
+                    UserEvent::new(user_id, UserEventKind::Outbid, None, None, Some(10.0));
+                event.xact_id = 6;
+                event.sequence = 3;
+                Ok(UserEventBatch {
+                    events: vec![event],
+                    held_back: true,
+                })
+            });
+
+        let use_case = GetUserEventsUseCase::new(Arc::new(user_event_repository));
+
+        // Act
+        let result = use_case.execute(current_user, Some(after)).await;
+
+        // Assert
+        let page = result.unwrap();
+        assert_eq!(page.events.len(), 1);
+        assert_eq!(page.events[0].kind, "outbid");
+        assert_eq!(
+            page.cursor,
+            UserEventCursor {
+                xact_id: 6,
+                sequence: 3
+            }
+        );
+        assert!(page.held_back);
+    }
+}
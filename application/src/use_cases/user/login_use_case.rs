@@ -1,39 +1,40 @@
+use chrono::{Duration, Utc};
 use domain::app_error::AppError;
+use domain::entities::audit_event::{AuditEvent, AuditEventKind};
+use domain::entities::login_throttle::LoginThrottle;
+use domain::entities::two_factor::LoginChallenge;
 use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_audit_log_repository::IAuditLogRepository;
+use domain::interfaces::i_login_throttle_repository::ILoginThrottleRepository;
+use domain::interfaces::i_two_factor_repository::ITwoFactorRepository;
 use domain::interfaces::i_user_repository::IUserRepository;
+use lazy_static::lazy_static;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use tracing::error;
 use tracing::log::info;
 
+pub(crate) const ACCOUNT_MAX_FAILED_ATTEMPTS: i32 = 5;
+const IP_MAX_FAILED_ATTEMPTS: i32 = 20;
+const BASE_LOCKOUT_SECONDS: i64 = 30;
+const MAX_LOCKOUT_SECONDS: i64 = 60 * 60;
+const FORGET_FAILURES_AFTER_HOURS: i64 = 24;
+const CHALLENGE_LIFETIME_MINUTES: i64 = 5;
+
+lazy_static! {
+    // checked when the email is unknown, so both cases take as long
+    static ref DUMMY_PASSWORD_HASH: String = bcrypt::hash("dummy password", 12).unwrap();
+}
+
 pub mod dtos {
-    use fancy_regex::Regex;
-    use lazy_static::lazy_static;
-    use serde::Deserialize;
-    use validator::{Validate, ValidationError};
-
-    lazy_static! {
-        static ref LOWERCASE_REGEX: Regex = Regex::new(r"[a-z]").unwrap();
-        static ref UPPERCASE_REGEX: Regex = Regex::new(r"[A-Z]").unwrap();
-        static ref DIGIT_REGEX: Regex = Regex::new(r"\d").unwrap();
-        static ref SPECIAL_REGEX: Regex = Regex::new(r"[^\da-zA-Z]").unwrap();
-        static ref LENGTH_REGEX: Regex = Regex::new(r".{6,}").unwrap();
-    }
-
-    fn validate_password(value: &str) -> Result<(), ValidationError> {
-        if LOWERCASE_REGEX.is_match(value).unwrap()
-            && UPPERCASE_REGEX.is_match(value).unwrap()
-            && DIGIT_REGEX.is_match(value).unwrap()
-            && SPECIAL_REGEX.is_match(value).unwrap()
-            && LENGTH_REGEX.is_match(value).unwrap()
-        {
-            Ok(())
-        } else {
-            Err(ValidationError::new(
-                "Password must contain at least one lowercase letter, one uppercase letter, \
-         one digit, one special character and must be at least 6 characters long",
-            ))
-        }
-    }
+    use crate::validators::validate_password;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::user::User;
+    use serde::{Deserialize, Serialize};
+    use validator::Validate;
 
     #[derive(Deserialize, Debug, Validate, Clone)]
     pub struct LoginRequest {
@@ -42,59 +43,347 @@ pub mod dtos {
         #[validate(custom(function = "validate_password",))]
         pub password: String,
     }
+
+    #[derive(Serialize, Debug)]
+    pub struct LoginChallengeDto {
+        /// Traded for a session together with a two-factor code.
+        pub challenge_token: String,
+        pub expires_at: i64,
+    }
+
+    impl IntoResponse for LoginChallengeDto {
+        fn into_response(self) -> Response {
+            Json(self).into_response()
+        }
+    }
+
+    #[derive(Debug)]
+    pub enum LoginOutcome {
+        SignedIn(User),
+        /// The password matched but the user has two-factor authentication enabled.
+        ChallengeRequired(LoginChallengeDto),
+    }
+}
+
+pub(crate) fn hash_challenge_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// How long a key stays locked once it has `failed_attempts`, doubling with every failure past
+/// `max_failed_attempts`.
+fn lockout_duration(failed_attempts: i32, max_failed_attempts: i32) -> Option<Duration> {
+    let excess = failed_attempts - max_failed_attempts;
+    if excess < 0 {
+        return None;
+    }
+
+    let seconds = BASE_LOCKOUT_SECONDS
+        .saturating_mul(1i64 << excess.min(32))
+        .min(MAX_LOCKOUT_SECONDS);
+    Some(Duration::seconds(seconds))
+}
+
+/// Who a failed login attempt was made for, as written to the audit log once it locks a key.
+pub(crate) struct FailedLogin<'a> {
+    pub user_id: Option<Id<User>>,
+    pub email: &'a str,
+    pub ip_address: &'a Option<String>,
+}
+
+/// Counts the failure against `key` and locks it once it has too many. Errors are only logged,
+/// the caller answers with invalid credentials either way.
+pub(crate) async fn record_failure<R1: ILoginThrottleRepository, R2: IAuditLogRepository>(
+    login_throttle_repository: &R1,
+    audit_log_repository: &R2,
+    key: String,
+    max_failed_attempts: i32,
+    kind: AuditEventKind,
+    failed_login: &FailedLogin<'_>,
+) {
+    let forget_before = Utc::now() - Duration::hours(FORGET_FAILURES_AFTER_HOURS);
+    let throttle = match login_throttle_repository
+        .record_failure(key.clone(), forget_before)
+        .await
+    {
+        Ok(throttle) => throttle,
+        Err(e) => {
+            error!("Failed to record failed login: {:?}", e);
+            return;
+        }
+    };
+
+    let Some(duration) = lockout_duration(throttle.failed_attempts, max_failed_attempts) else {
+        return;
+    };
+    let locked_until = Utc::now() + duration;
+    if let Err(e) = login_throttle_repository
+        .lock(key.clone(), locked_until)
+        .await
+    {
+        error!("Failed to lock login: {:?}", e);
+        return;
+    }
+
+    info!("Locked {} until {}", key, locked_until);
+    let event = AuditEvent::new(
+        kind,
+        failed_login.user_id.clone(),
+        Some(failed_login.email.to_string()),
+        failed_login.ip_address.clone(),
+        Some(format!(
+            "Locked for {} seconds after {} failed attempts",
+            duration.num_seconds(),
+            throttle.failed_attempts
+        )),
+    );
+    if let Err(e) = audit_log_repository.insert(event).await {
+        error!("Failed to write audit event: {:?}", e);
+    }
 }
 
-pub struct LoginUseCase<R: IUserRepository> {
-    user_repository: Arc<R>,
+/// Ends a login whose first factor was accepted, either signing the user in or asking for their
+/// second factor.
+pub(crate) async fn finish_login<R: ITwoFactorRepository>(
+    two_factor_repository: &R,
+    user: User,
+) -> Result<dtos::LoginOutcome, AppError> {
+    if user.is_suspended() {
+        error!("User {} is suspended.", user.id);
+        return Err(AppError::AccountSuspended());
+    }
+
+    let two_factor = two_factor_repository
+        .find_by_user_id(user.id.clone())
+        .await
+        .map_err(|e| {
+            error!("Failed to get two-factor settings: {:?}", e);
+            AppError::InternalServerError()
+        })?;
+    if !two_factor.is_some_and(|two_factor| two_factor.is_enabled()) {
+        return Ok(dtos::LoginOutcome::SignedIn(user));
+    }
+
+    info!("User {} has to complete a two-factor challenge", user.id);
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let challenge_token = hex::encode(bytes);
+    let challenge = LoginChallenge::new(
+        user.id,
+        hash_challenge_token(&challenge_token),
+        Utc::now() + Duration::minutes(CHALLENGE_LIFETIME_MINUTES),
+    );
+    let expires_at = challenge.expires_at.timestamp();
+
+    two_factor_repository
+        .insert_challenge(challenge)
+        .await
+        .map_err(|e| {
+            error!("Failed to create login challenge: {:?}", e);
+            AppError::InternalServerError()
+        })?;
+
+    Ok(dtos::LoginOutcome::ChallengeRequired(
+        dtos::LoginChallengeDto {
+            challenge_token,
+            expires_at,
+        },
+    ))
 }
 
-impl<R: IUserRepository> LoginUseCase<R> {
-    pub fn new(user_repository: Arc<R>) -> Self {
-        Self { user_repository }
+pub struct LoginUseCase<
+    R1: IUserRepository,
+    R2: ILoginThrottleRepository,
+    R3: IAuditLogRepository,
+    R4: ITwoFactorRepository,
+> {
+    user_repository: Arc<R1>,
+    login_throttle_repository: Arc<R2>,
+    audit_log_repository: Arc<R3>,
+    two_factor_repository: Arc<R4>,
+}
+
+impl<
+        R1: IUserRepository,
+        R2: ILoginThrottleRepository,
+        R3: IAuditLogRepository,
+        R4: ITwoFactorRepository,
+    > LoginUseCase<R1, R2, R3, R4>
+{
+    pub fn new(
+        user_repository: Arc<R1>,
+        login_throttle_repository: Arc<R2>,
+        audit_log_repository: Arc<R3>,
+        two_factor_repository: Arc<R4>,
+    ) -> Self {
+        Self {
+            user_repository,
+            login_throttle_repository,
+            audit_log_repository,
+            two_factor_repository,
+        }
     }
 
-    pub async fn execute(&self, dto: dtos::LoginRequest) -> Result<User, AppError> {
+    pub async fn execute(
+        &self,
+        dto: dtos::LoginRequest,
+        ip_address: Option<String>,
+    ) -> Result<dtos::LoginOutcome, AppError> {
         info!("Logging user with email: {}", dto.email);
 
-        // check whether the email is registered AND the password matches the hash.
-        let user: User = match self.user_repository.find_by_email(dto.email.clone()).await {
-            Ok(Some(user)) => user,
-            _ => {
-                error!("Email {} is not registered.", dto.email);
-                return Err(AppError::NotRegisteredEmail(dto.email.clone()));
-            }
-        };
+        let account_key = LoginThrottle::account_key(&dto.email);
+        let ip_key = ip_address.as_deref().map(LoginThrottle::ip_key);
+        let keys = std::iter::once(account_key.clone())
+            .chain(ip_key.clone())
+            .collect::<Vec<_>>();
+
+        let throttles = self
+            .login_throttle_repository
+            .find_all(keys)
+            .await
+            .map_err(|e| {
+                error!("Failed to get login throttles: {:?}", e);
+                AppError::InternalServerError()
+            })?;
+        if throttles.iter().any(LoginThrottle::is_locked) {
+            error!("Login for email {} is locked.", dto.email);
+            return Err(AppError::TooManyLoginAttempts());
+        }
 
-        let login_result =
-            bcrypt::verify(dto.password.clone(), user.password.as_str()).map_err(|_| {
-                error!("Failed to hash password");
-                AppError::BadPassword()
+        let user = self
+            .user_repository
+            .find_by_email(dto.email.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find user by email: {:?}", e);
+                AppError::InternalServerError()
             })?;
 
-        match login_result {
-            true => {
-                info!("Login succeeded!");
+        // an unknown email, a user without password and a wrong password all look the same from
+        // the outside
+        let password_hash = user.as_ref().and_then(|user| user.password.as_deref());
+        let password_matches = bcrypt::verify(
+            dto.password.clone(),
+            password_hash.unwrap_or(DUMMY_PASSWORD_HASH.as_str()),
+        )
+        .unwrap_or(false)
+            && password_hash.is_some();
+
+        let user = match user {
+            Some(user) if password_matches => user,
+            user => {
+                error!("Bad credentials for email {}.", dto.email);
+                let failed_login = FailedLogin {
+                    user_id: user.map(|user| user.id),
+                    email: &dto.email,
+                    ip_address: &ip_address,
+                };
+                record_failure(
+                    self.login_throttle_repository.as_ref(),
+                    self.audit_log_repository.as_ref(),
+                    account_key,
+                    ACCOUNT_MAX_FAILED_ATTEMPTS,
+                    AuditEventKind::AccountLocked,
+                    &failed_login,
+                )
+                .await;
+                if let Some(ip_key) = ip_key {
+                    record_failure(
+                        self.login_throttle_repository.as_ref(),
+                        self.audit_log_repository.as_ref(),
+                        ip_key,
+                        IP_MAX_FAILED_ATTEMPTS,
+                        AuditEventKind::IpLocked,
+                        &failed_login,
+                    )
+                    .await;
+                }
+                return Err(AppError::InvalidCredentials());
             }
-            false => {
-                error!("Bad password.");
-                return Err(AppError::BadPassword());
+        };
+        info!("Login succeeded!");
+
+        // only told after the password matched, so suspension does not reveal the account
+        let outcome = finish_login(self.two_factor_repository.as_ref(), user).await?;
+
+        // the IP counter is kept, a single valid account must not reset it for everyone else;
+        // with a second factor to come, failed codes keep counting until it is accepted
+        if matches!(outcome, dtos::LoginOutcome::SignedIn(_)) {
+            if let Err(e) = self.login_throttle_repository.clear(account_key).await {
+                error!("Failed to clear login throttle: {:?}", e);
             }
         }
 
-        Ok(user)
+        Ok(outcome)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::use_cases::user::login_use_case::{dtos, LoginUseCase};
+    use crate::use_cases::user::login_use_case::{dtos, lockout_duration, LoginUseCase};
     use anyhow::anyhow;
+    use chrono::{Duration, Utc};
     use domain::app_error::AppError;
+    use domain::entities::audit_event::AuditEventKind;
+    use domain::entities::login_throttle::LoginThrottle;
+    use domain::entities::two_factor::TwoFactor;
     use domain::entities::user::User;
+    use domain::interfaces::i_audit_log_repository::MockIAuditLogRepository;
+    use domain::interfaces::i_login_throttle_repository::MockILoginThrottleRepository;
+    use domain::interfaces::i_two_factor_repository::MockITwoFactorRepository;
     use domain::interfaces::i_user_repository::MockIUserRepository;
     use mockall::predicate::eq;
     use std::sync::Arc;
 
+    fn throttle(key: String, failed_attempts: i32) -> LoginThrottle {
+        LoginThrottle {
+            key,
+            failed_attempts,
+            locked_until: None,
+            last_failed_at: Utc::now(),
+        }
+    }
+
+    /// Counts every failure as the first one, so nothing gets locked.
+    fn login_throttle_repository_without_locks() -> MockILoginThrottleRepository {
+        let mut login_throttle_repository = MockILoginThrottleRepository::new();
+        login_throttle_repository
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        login_throttle_repository
+            .expect_record_failure()
+            .returning(|key, _| Ok(throttle(key, 1)));
+        login_throttle_repository
+            .expect_clear()
+            .returning(|_| Ok(()));
+        login_throttle_repository
+    }
+
+    fn two_factor_repository_without_two_factor() -> MockITwoFactorRepository {
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_by_user_id()
+            .returning(|_| Ok(None));
+        two_factor_repository
+    }
+
+    fn use_case(
+        user_repository: MockIUserRepository,
+        login_throttle_repository: MockILoginThrottleRepository,
+        audit_log_repository: MockIAuditLogRepository,
+    ) -> LoginUseCase<
+        MockIUserRepository,
+        MockILoginThrottleRepository,
+        MockIAuditLogRepository,
+        MockITwoFactorRepository,
+    > {
+        LoginUseCase::new(
+            Arc::new(user_repository),
+            Arc::new(login_throttle_repository),
+            Arc::new(audit_log_repository),
+            Arc::new(two_factor_repository_without_two_factor()),
+        )
+    }
+
     #[tokio::test]
     async fn given_request_with_valid_data_when_executing_then_user_is_signed_in() {
         //Arrange
@@ -109,21 +398,80 @@ mod tests {
             .with(eq("email".to_string()))
             .returning(move |_| Ok(Some(user.clone())));
 
-        let use_case = LoginUseCase::new(Arc::new(user_repository));
+        let use_case = use_case(
+            user_repository,
+            login_throttle_repository_without_locks(),
+            MockIAuditLogRepository::new(),
+        );
+        let dto = dtos::LoginRequest {
+            email: "email".to_string(),
+            password: "password".to_string(),
+        };
+
+        //Act
+        let result = use_case.execute(dto, None).await;
+
+        //Assert
+        assert!(matches!(result, Ok(dtos::LoginOutcome::SignedIn(_))));
+    }
+
+    #[tokio::test]
+    async fn given_user_with_two_factor_when_executing_then_challenge_is_returned_instead() {
+        //Arrange
+        let user = User::new(
+            "name".to_string(),
+            "email".to_string(),
+            bcrypt::hash("password", 4).unwrap(),
+        );
+        let mut two_factor = TwoFactor::new(user.id.clone(), "SECRET".to_string());
+        two_factor.enabled_at = Some(Utc::now());
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find_by_email()
+            .returning(move |_| Ok(Some(user.clone())));
+
+        let mut two_factor_repository = MockITwoFactorRepository::new();
+        two_factor_repository
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(two_factor.clone())));
+        two_factor_repository
+            .expect_insert_challenge()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // cleared once the second factor is accepted
+        let mut login_throttle_repository = MockILoginThrottleRepository::new();
+        login_throttle_repository
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        login_throttle_repository.expect_clear().times(0);
+
+        let use_case = LoginUseCase::new(
+            Arc::new(user_repository),
+            Arc::new(login_throttle_repository),
+            Arc::new(MockIAuditLogRepository::new()),
+            Arc::new(two_factor_repository),
+        );
         let dto = dtos::LoginRequest {
             email: "email".to_string(),
             password: "password".to_string(),
         };
 
         //Act
-        let result = use_case.execute(dto).await;
+        let result = use_case.execute(dto, None).await;
 
         //Assert
-        assert!(result.is_ok());
+        match result {
+            Ok(dtos::LoginOutcome::ChallengeRequired(challenge)) => {
+                assert_eq!(challenge.challenge_token.len(), 64)
+            }
+            _ => panic!("expected a login challenge"),
+        }
     }
 
     #[tokio::test]
-    async fn given_request_with_invalid_email_when_executing_then_not_registered_email_error_is_returned(
+    async fn given_request_with_invalid_email_when_executing_then_invalid_credentials_error_is_returned(
     ) {
         //Arrange
         let mut user_repository = MockIUserRepository::new();
@@ -131,25 +479,30 @@ mod tests {
             .expect_find_by_email()
             .returning(|_| Ok(None));
 
-        let use_case = LoginUseCase::new(Arc::new(user_repository));
+        let use_case = use_case(
+            user_repository,
+            login_throttle_repository_without_locks(),
+            MockIAuditLogRepository::new(),
+        );
         let dto = dtos::LoginRequest {
             email: "invalid_email".to_string(),
             password: "password".to_string(),
         };
 
         //Act
-        let result = use_case.execute(dto.clone()).await;
+        let result = use_case.execute(dto.clone(), None).await;
 
         //Assert
         assert!(result.is_err());
         match result {
-            Err(AppError::NotRegisteredEmail(message)) => assert_eq!(message, dto.email.clone()),
+            Err(AppError::InvalidCredentials()) => assert_eq!(true, true),
             _ => assert_eq!(true, false),
         }
     }
 
     #[tokio::test]
-    async fn given_request_with_bad_password_when_executing_then_bad_password_error_is_returned() {
+    async fn given_request_with_bad_password_when_executing_then_invalid_credentials_error_is_returned(
+    ) {
         //Arrange
         let mut user_repository = MockIUserRepository::new();
         user_repository.expect_find_by_email().returning(|_| {
@@ -160,23 +513,58 @@ mod tests {
             )))
         });
 
-        let use_case = LoginUseCase::new(Arc::new(user_repository));
+        let use_case = use_case(
+            user_repository,
+            login_throttle_repository_without_locks(),
+            MockIAuditLogRepository::new(),
+        );
         let dto = dtos::LoginRequest {
             email: "email".to_string(),
             password: "another_password".to_string(),
         };
 
         //Act
-        let result = use_case.execute(dto.clone()).await;
+        let result = use_case.execute(dto.clone(), None).await;
 
         //Assert
         assert!(result.is_err());
         match result {
-            Err(AppError::BadPassword()) => assert_eq!(true, true),
+            Err(AppError::InvalidCredentials()) => assert_eq!(true, true),
             _ => assert_eq!(true, false),
         }
     }
 
+    #[tokio::test]
+    async fn given_user_without_password_when_executing_then_invalid_credentials_error_is_returned()
+    {
+        //Arrange
+        let mut user_repository = MockIUserRepository::new();
+        user_repository.expect_find_by_email().returning(|_| {
+            Ok(Some(User::new_external(
+                "name".to_string(),
+                "email".to_string(),
+                true,
+            )))
+        });
+
+        let use_case = use_case(
+            user_repository,
+            login_throttle_repository_without_locks(),
+            MockIAuditLogRepository::new(),
+        );
+        // the password of the hash checked in place of the missing one
+        let dto = dtos::LoginRequest {
+            email: "email".to_string(),
+            password: "dummy password".to_string(),
+        };
+
+        //Act
+        let result = use_case.execute(dto, None).await;
+
+        //Assert
+        assert!(matches!(result, Err(AppError::InvalidCredentials())));
+    }
+
     #[tokio::test]
     async fn given_any_request_when_executing_then_unexpected_error_is_returned() {
         //Arrange
@@ -185,14 +573,18 @@ mod tests {
             .expect_find_by_email()
             .returning(|_| Err(anyhow!("Unexpected_error")));
 
-        let use_case = LoginUseCase::new(Arc::new(user_repository));
+        let use_case = use_case(
+            user_repository,
+            login_throttle_repository_without_locks(),
+            MockIAuditLogRepository::new(),
+        );
         let dto = dtos::LoginRequest {
             email: "email".to_string(),
             password: "password".to_string(),
         };
 
         //Act
-        let result = use_case.execute(dto.clone()).await;
+        let result = use_case.execute(dto.clone(), None).await;
 
         //Assert
         assert!(result.is_err());
@@ -201,4 +593,123 @@ mod tests {
             _ => assert_eq!(true, false),
         }
     }
+
+    #[tokio::test]
+    async fn given_suspended_user_when_executing_then_account_suspended_error_is_returned() {
+        //Arrange
+        let mut user = User::new(
+            "name".to_string(),
+            "email".to_string(),
+            bcrypt::hash("password", 4).unwrap(),
+        );
+        user.suspended_at = Some(Utc::now());
+
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find_by_email()
+            .returning(move |_| Ok(Some(user.clone())));
+
+        let use_case = use_case(
+            user_repository,
+            login_throttle_repository_without_locks(),
+            MockIAuditLogRepository::new(),
+        );
+        let dto = dtos::LoginRequest {
+            email: "email".to_string(),
+            password: "password".to_string(),
+        };
+
+        //Act
+        let result = use_case.execute(dto, None).await;
+
+        //Assert
+        assert!(matches!(result, Err(AppError::AccountSuspended())));
+    }
+
+    #[tokio::test]
+    async fn given_locked_account_when_executing_then_password_is_not_checked() {
+        //Arrange
+        let mut user_repository = MockIUserRepository::new();
+        user_repository.expect_find_by_email().times(0);
+
+        let mut login_throttle_repository = MockILoginThrottleRepository::new();
+        login_throttle_repository
+            .expect_find_all()
+            .returning(|keys| {
+                Ok(vec![LoginThrottle {
+                    locked_until: Some(Utc::now() + Duration::minutes(1)),
+                    ..throttle(keys[0].clone(), 5)
+                }])
+            });
+
+        let use_case = use_case(
+            user_repository,
+            login_throttle_repository,
+            MockIAuditLogRepository::new(),
+        );
+        let dto = dtos::LoginRequest {
+            email: "email".to_string(),
+            password: "password".to_string(),
+        };
+
+        //Act
+        let result = use_case.execute(dto, Some("127.0.0.1".to_string())).await;
+
+        //Assert
+        assert!(matches!(result, Err(AppError::TooManyLoginAttempts())));
+    }
+
+    #[tokio::test]
+    async fn given_too_many_failures_for_account_when_executing_then_account_is_locked_and_audited()
+    {
+        //Arrange
+        let mut user_repository = MockIUserRepository::new();
+        user_repository
+            .expect_find_by_email()
+            .returning(|_| Ok(None));
+
+        let mut login_throttle_repository = MockILoginThrottleRepository::new();
+        login_throttle_repository
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        login_throttle_repository
+            .expect_record_failure()
+            .returning(|key, _| Ok(throttle(key, 5)));
+        login_throttle_repository
+            .expect_lock()
+            .withf(|key, _| *key == LoginThrottle::account_key("email"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut audit_log_repository = MockIAuditLogRepository::new();
+        audit_log_repository
+            .expect_insert()
+            .withf(|event| event.kind == AuditEventKind::AccountLocked)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = use_case(
+            user_repository,
+            login_throttle_repository,
+            audit_log_repository,
+        );
+        let dto = dtos::LoginRequest {
+            email: "email".to_string(),
+            password: "password".to_string(),
+        };
+
+        //Act
+        let result = use_case.execute(dto, Some("127.0.0.1".to_string())).await;
+
+        //Assert
+        assert!(matches!(result, Err(AppError::InvalidCredentials())));
+    }
+
+    #[test]
+    fn given_failures_past_the_limit_when_computing_lockout_then_it_doubles_up_to_the_cap() {
+        assert_eq!(lockout_duration(4, 5), None);
+        assert_eq!(lockout_duration(5, 5), Some(Duration::seconds(30)));
+        assert_eq!(lockout_duration(6, 5), Some(Duration::seconds(60)));
+        assert_eq!(lockout_duration(100, 5), Some(Duration::hours(1)));
+    }
 }
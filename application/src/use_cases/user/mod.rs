@@ -1,3 +1,13 @@
+pub mod change_password_use_case;
+pub mod forgot_password_use_case;
+pub mod get_avatar_use_case;
+pub mod get_profile_use_case;
+pub mod get_public_profile_use_case;
+pub mod get_user_events_use_case;
 pub mod get_user_use_case;
 pub mod login_use_case;
 pub mod register_use_case;
+pub mod resend_verification_email_use_case;
+pub mod reset_password_use_case;
+pub mod update_profile_use_case;
+pub mod verify_email_use_case;
@@ -1,39 +1,17 @@
+use crate::signers::verification_link_signer::VerificationLinkSigner;
 use anyhow::anyhow;
 use domain::app_error::AppError;
+use domain::entities::email::Email;
 use domain::entities::user::User;
+use domain::interfaces::i_email_verification_repository::IEmailVerificationRepository;
 use domain::interfaces::i_user_repository::IUserRepository;
 use std::sync::Arc;
 use tracing::{error, info};
 
 pub mod dtos {
-    use fancy_regex::Regex;
-    use lazy_static::lazy_static;
+    use crate::validators::validate_password;
     use serde::Deserialize;
-    use validator::{Validate, ValidationError};
-
-    lazy_static! {
-        static ref LOWERCASE_REGEX: Regex = Regex::new(r"[a-z]").unwrap();
-        static ref UPPERCASE_REGEX: Regex = Regex::new(r"[A-Z]").unwrap();
-        static ref DIGIT_REGEX: Regex = Regex::new(r"\d").unwrap();
-        static ref SPECIAL_REGEX: Regex = Regex::new(r"[^\da-zA-Z]").unwrap();
-        static ref LENGTH_REGEX: Regex = Regex::new(r".{6,}").unwrap();
-    }
-
-    fn validate_password(value: &str) -> Result<(), ValidationError> {
-        if LOWERCASE_REGEX.is_match(value).unwrap()
-            && UPPERCASE_REGEX.is_match(value).unwrap()
-            && DIGIT_REGEX.is_match(value).unwrap()
-            && SPECIAL_REGEX.is_match(value).unwrap()
-            && LENGTH_REGEX.is_match(value).unwrap()
-        {
-            Ok(())
-        } else {
-            Err(ValidationError::new(
-                "Password must contain at least one lowercase letter, one uppercase letter, \
-         one digit, one special character and must be at least 6 characters long",
-            ))
-        }
-    }
+    use validator::Validate;
 
     #[derive(Deserialize, Debug, Validate)]
     pub struct RegisterRequest {
@@ -50,13 +28,23 @@ pub mod dtos {
     }
 }
 
-pub struct RegisterUseCase<R: IUserRepository> {
-    user_repository: Arc<R>,
+pub struct RegisterUseCase<R1: IUserRepository, R2: IEmailVerificationRepository> {
+    user_repository: Arc<R1>,
+    email_verification_repository: Arc<R2>,
+    verification_link_signer: Arc<VerificationLinkSigner>,
 }
 
-impl<R: IUserRepository> RegisterUseCase<R> {
-    pub fn new(user_repository: Arc<R>) -> Self {
-        Self { user_repository }
+impl<R1: IUserRepository, R2: IEmailVerificationRepository> RegisterUseCase<R1, R2> {
+    pub fn new(
+        user_repository: Arc<R1>,
+        email_verification_repository: Arc<R2>,
+        verification_link_signer: Arc<VerificationLinkSigner>,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_verification_repository,
+            verification_link_signer,
+        }
     }
 
     pub async fn execute(&self, dto: dtos::RegisterRequest) -> Result<(), AppError> {
@@ -101,14 +89,28 @@ impl<R: IUserRepository> RegisterUseCase<R> {
             AppError::UserRegistrationFailed(anyhow!("Failed to hash password"))
         })?;
 
-        // create user account
+        // create user account, the welcome email is queued along with it
         let user = User::new(dto.name, dto.email, hashed_password);
-        self.user_repository.insert(user).await.map_err(|e| {
-            error!("Failed to insert user: {:?}", e);
-            AppError::UserRegistrationFailed(anyhow!("Failed to insert user"))
-        })?;
+        let welcome_email = Email::registration(&user).into_job();
+        let (verification_token, verification_email) = self.verification_link_signer.issue(&user);
+        self.user_repository
+            .insert(user, vec![welcome_email])
+            .await
+            .map_err(|e| {
+                error!("Failed to insert user: {:?}", e);
+                AppError::UserRegistrationFailed(anyhow!("Failed to insert user"))
+            })?;
         info!("User registered successfully");
 
+        // the account exists at this point, a lost verification email can be resent by the user
+        if let Err(e) = self
+            .email_verification_repository
+            .insert(verification_token, vec![verification_email.into_job()])
+            .await
+        {
+            error!("Failed to insert verification token: {:?}", e);
+        }
+
         Ok(())
     }
 }
@@ -116,10 +118,26 @@ impl<R: IUserRepository> RegisterUseCase<R> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use domain::entities::email::Email;
     use domain::entities::user::User;
+    use domain::interfaces::i_email_verification_repository::MockIEmailVerificationRepository;
     use domain::interfaces::i_user_repository::MockIUserRepository;
     use mockall::predicate::*;
 
+    fn use_case(
+        user_repository: MockIUserRepository,
+        email_verification_repository: MockIEmailVerificationRepository,
+    ) -> RegisterUseCase<MockIUserRepository, MockIEmailVerificationRepository> {
+        RegisterUseCase::new(
+            Arc::new(user_repository),
+            Arc::new(email_verification_repository),
+            Arc::new(VerificationLinkSigner::new(
+                "key".to_string(),
+                "http://localhost/verify-email".to_string(),
+            )),
+        )
+    }
+
     #[tokio::test]
     async fn given_valid_dto_when_executing_then_user_is_registered() {
         // Arrange
@@ -135,16 +153,27 @@ mod tests {
             .returning(|_| Ok(None));
         user_repository
             .expect_insert()
-            .withf(|user: &User| user.name == "username" && user.email == "email")
-            .returning(|_| {
+            .withf(|user: &User, jobs| {
+                user.name == "username"
+                    && user.email == "email"
+                    && jobs.len() == 1
+                    && jobs[0].kind == Email::JOB_KIND
+            })
+            .returning(|_, _| {
                 Ok(Some(User::new(
                     "username".to_string(),
                     "email".to_string(),
                     "hashed_password".to_string(),
                 )))
             });
+        let mut email_verification_repository = MockIEmailVerificationRepository::new();
+        email_verification_repository
+            .expect_insert()
+            .withf(|_, jobs| jobs.len() == 1 && jobs[0].kind == Email::JOB_KIND)
+            .times(1)
+            .returning(|_, _| Ok(()));
 
-        let use_case = RegisterUseCase::new(Arc::new(user_repository));
+        let use_case = use_case(user_repository, email_verification_repository);
 
         let dto = dtos::RegisterRequest {
             name: "username".to_string(),
@@ -176,7 +205,7 @@ mod tests {
                 )))
             });
 
-        let use_case = RegisterUseCase::new(Arc::new(user_repository));
+        let use_case = use_case(user_repository, MockIEmailVerificationRepository::new());
 
         let dto = dtos::RegisterRequest {
             name: "username".to_string(),
@@ -216,7 +245,7 @@ mod tests {
                 )))
             });
 
-        let use_case = RegisterUseCase::new(Arc::new(user_repository));
+        let use_case = use_case(user_repository, MockIEmailVerificationRepository::new());
 
         let dto = dtos::RegisterRequest {
             name: "username".to_string(),
@@ -250,10 +279,10 @@ mod tests {
             .returning(|_| Ok(None));
         user_repository
             .expect_insert()
-            .withf(|user: &User| user.name == "username" && user.email == "email")
-            .returning(|_| Err(anyhow!("Failed to insert user")));
+            .withf(|user: &User, _| user.name == "username" && user.email == "email")
+            .returning(|_, _| Err(anyhow!("Failed to insert user")));
 
-        let use_case = RegisterUseCase::new(Arc::new(user_repository));
+        let use_case = use_case(user_repository, MockIEmailVerificationRepository::new());
 
         let dto = dtos::RegisterRequest {
             name: "username".to_string(),
@@ -0,0 +1,101 @@
+use crate::signers::verification_link_signer::VerificationLinkSigner;
+use chrono::{Duration, Utc};
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_email_verification_repository::IEmailVerificationRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const RESEND_COOLDOWN_SECONDS: i64 = 60;
+
+pub struct ResendVerificationEmailUseCase<R: IEmailVerificationRepository> {
+    email_verification_repository: Arc<R>,
+    verification_link_signer: Arc<VerificationLinkSigner>,
+}
+
+impl<R: IEmailVerificationRepository> ResendVerificationEmailUseCase<R> {
+    pub fn new(
+        email_verification_repository: Arc<R>,
+        verification_link_signer: Arc<VerificationLinkSigner>,
+    ) -> Self {
+        Self {
+            email_verification_repository,
+            verification_link_signer,
+        }
+    }
+
+    pub async fn execute(&self, current_user: User) -> Result<(), AppError> {
+        info!("Resending verification email to user {}", current_user.id);
+
+        if current_user.email_verified {
+            return Err(AppError::EmailAlreadyVerified());
+        }
+
+        let latest_token = self
+            .email_verification_repository
+            .find_latest_by_user_id(current_user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to find latest verification token: {:?}", e);
+                AppError::FailedToSendVerificationEmail()
+            })?;
+        if latest_token.is_some_and(|token| {
+            Utc::now() - token.created_at < Duration::seconds(RESEND_COOLDOWN_SECONDS)
+        }) {
+            return Err(AppError::VerificationEmailThrottled());
+        }
+
+        // earlier links stay valid until they expire
+        let (token, email) = self.verification_link_signer.issue(&current_user);
+        self.email_verification_repository
+            .insert(token, vec![email.into_job()])
+            .await
+            .map_err(|e| {
+                error!("Failed to insert verification token: {:?}", e);
+                AppError::FailedToSendVerificationEmail()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::email_verification::EmailVerificationToken;
+    use domain::interfaces::i_email_verification_repository::MockIEmailVerificationRepository;
+
+    #[tokio::test]
+    async fn given_email_sent_moments_ago_when_executing_then_verification_email_throttled_error_is_returned(
+    ) {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let latest_token =
+            EmailVerificationToken::new(current_user.id.clone(), Utc::now() + Duration::hours(24));
+
+        let mut email_verification_repository = MockIEmailVerificationRepository::new();
+        email_verification_repository
+            .expect_find_latest_by_user_id()
+            .returning(move |_| Ok(Some(latest_token.clone())));
+        email_verification_repository.expect_insert().times(0);
+
+        let use_case = ResendVerificationEmailUseCase::new(
+            Arc::new(email_verification_repository),
+            Arc::new(VerificationLinkSigner::new(
+                "key".to_string(),
+                "http://localhost/verify-email".to_string(),
+            )),
+        );
+
+        // Act
+        let result = use_case.execute(current_user).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(AppError::VerificationEmailThrottled())
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::use_cases::user::forgot_password_use_case::hash_reset_token;
+use domain::app_error::AppError;
+use domain::interfaces::i_password_reset_repository::IPasswordResetRepository;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use crate::validators::validate_password;
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct ResetPasswordRequest {
+        pub token: String,
+        #[validate(custom(function = "validate_password",))]
+        pub password: String,
+    }
+}
+
+pub struct ResetPasswordUseCase<R1: IPasswordResetRepository, R2: ISessionRepository> {
+    password_reset_repository: Arc<R1>,
+    session_repository: Arc<R2>,
+}
+
+impl<R1: IPasswordResetRepository, R2: ISessionRepository> ResetPasswordUseCase<R1, R2> {
+    pub fn new(password_reset_repository: Arc<R1>, session_repository: Arc<R2>) -> Self {
+        Self {
+            password_reset_repository,
+            session_repository,
+        }
+    }
+
+    pub async fn execute(&self, dto: dtos::ResetPasswordRequest) -> Result<(), AppError> {
+        let hashed_password = bcrypt::hash(dto.password, 12).map_err(|_| {
+            error!("Failed to hash password");
+            AppError::FailedToResetPassword()
+        })?;
+
+        // unknown, expired and already used tokens are all reported the same way
+        let user_id = self
+            .password_reset_repository
+            .consume(hash_reset_token(&dto.token), hashed_password)
+            .await
+            .map_err(|e| {
+                error!("Failed to consume password reset token: {:?}", e);
+                AppError::FailedToResetPassword()
+            })?
+            .ok_or_else(|| AppError::InvalidPasswordResetToken())?;
+        info!("Password of user {} reset", user_id);
+
+        // whoever knew the old password is logged out everywhere
+        self.session_repository
+            .revoke_all_for_user(user_id, None)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke sessions after password reset: {:?}", e);
+                AppError::FailedToResetPassword()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::id::Id;
+    use domain::interfaces::i_password_reset_repository::MockIPasswordResetRepository;
+    use domain::interfaces::i_session_repository::MockISessionRepository;
+
+    fn dto() -> dtos::ResetPasswordRequest {
+        dtos::ResetPasswordRequest {
+            token: "token".to_string(),
+            password: "Password1!".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_valid_token_when_executing_then_all_sessions_of_user_are_revoked() {
+        // Arrange
+        let user_id = Id::gen();
+        let revoked_user_id = user_id.clone();
+
+        let mut password_reset_repository = MockIPasswordResetRepository::new();
+        password_reset_repository
+            .expect_consume()
+            .withf(|token_hash, _| *token_hash == hash_reset_token("token"))
+            .returning(move |_, _| Ok(Some(user_id.clone())));
+
+        let mut session_repository = MockISessionRepository::new();
+        session_repository
+            .expect_revoke_all_for_user()
+            .withf(move |id, except| *id == revoked_user_id && except.is_none())
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = ResetPasswordUseCase::new(
+            Arc::new(password_reset_repository),
+            Arc::new(session_repository),
+        );
+
+        // Act
+        let result = use_case.execute(dto()).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_used_or_expired_token_when_executing_then_invalid_password_reset_token_error_is_returned(
+    ) {
+        // Arrange
+        let mut password_reset_repository = MockIPasswordResetRepository::new();
+        password_reset_repository
+            .expect_consume()
+            .returning(|_, _| Ok(None));
+
+        let mut session_repository = MockISessionRepository::new();
+        session_repository.expect_revoke_all_for_user().times(0);
+
+        let use_case = ResetPasswordUseCase::new(
+            Arc::new(password_reset_repository),
+            Arc::new(session_repository),
+        );
+
+        // Act
+        let result = use_case.execute(dto()).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidPasswordResetToken())));
+    }
+}
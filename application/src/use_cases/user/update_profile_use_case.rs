@@ -0,0 +1,161 @@
+use crate::images::prepare_avatar;
+use crate::use_cases::user::get_profile_use_case::dtos::ProfileDto;
+use chrono::Utc;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::entities::user_profile::UserProfile;
+use domain::interfaces::i_user_profile_repository::IUserProfileRepository;
+use std::io::Read;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const MAX_AVATAR_BYTES: usize = 1024 * 1024;
+
+pub mod dtos {
+    use axum_typed_multipart::{FieldData, TryFromMultipart};
+    use tempfile::NamedTempFile;
+    use validator::Validate;
+
+    /// Fields left out stay as they are, an empty text field clears it.
+    #[derive(Debug, Validate, TryFromMultipart)]
+    pub struct UpdateProfileRequest {
+        #[validate(length(max = 50, message = "Display name must be at most 50 characters"))]
+        pub display_name: Option<String>,
+        #[validate(length(max = 500, message = "Bio must be at most 500 characters"))]
+        pub bio: Option<String>,
+        #[validate(length(max = 100, message = "Location must be at most 100 characters"))]
+        pub location: Option<String>,
+        pub avatar: Option<FieldData<NamedTempFile>>,
+        pub remove_avatar: Option<bool>,
+    }
+}
+
+fn updated_field(current: Option<String>, requested: Option<String>) -> Option<String> {
+    match requested {
+        Some(value) if value.trim().is_empty() => None,
+        Some(value) => Some(value.trim().to_string()),
+        None => current,
+    }
+}
+
+pub struct UpdateProfileUseCase<R: IUserProfileRepository> {
+    user_profile_repository: Arc<R>,
+}
+
+impl<R: IUserProfileRepository> UpdateProfileUseCase<R> {
+    pub fn new(user_profile_repository: Arc<R>) -> Self {
+        Self {
+            user_profile_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        mut request: dtos::UpdateProfileRequest,
+    ) -> Result<ProfileDto, AppError> {
+        info!("Updating profile of user with id {}", current_user.id);
+
+        let profile = self
+            .user_profile_repository
+            .find(current_user.id.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to get profile: {:?}", e);
+                AppError::FailedToUpdateProfile()
+            })?
+            .unwrap_or_else(|| UserProfile::empty(current_user.id.clone()));
+
+        let (avatar, avatar_content_type) = match request.avatar.as_mut() {
+            Some(field) => {
+                let mut avatar = Vec::new();
+                field.contents.read_to_end(&mut avatar).map_err(|e| {
+                    error!("Failed to read avatar from request: {:?}", e);
+                    AppError::FailedToUpdateProfile()
+                })?;
+                if avatar.len() > MAX_AVATAR_BYTES {
+                    return Err(AppError::AvatarTooLarge(MAX_AVATAR_BYTES));
+                }
+                // served to everyone, so only images are kept and never the bytes as uploaded
+                let (avatar, content_type) = prepare_avatar(avatar).await?;
+                (Some(avatar), Some(content_type))
+            }
+            None if request.remove_avatar.unwrap_or(false) => (None, None),
+            None => (profile.avatar, profile.avatar_content_type),
+        };
+
+        let profile = UserProfile {
+            user_id: profile.user_id,
+            display_name: updated_field(profile.display_name, request.display_name),
+            bio: updated_field(profile.bio, request.bio),
+            location: updated_field(profile.location, request.location),
+            avatar,
+            avatar_content_type,
+            updated_at: Utc::now(),
+        };
+
+        self.user_profile_repository
+            .upsert(profile.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to update profile: {:?}", e);
+                AppError::FailedToUpdateProfile()
+            })?;
+
+        Ok(ProfileDto::new(current_user, profile))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_user_profile_repository::MockIUserProfileRepository;
+
+    #[tokio::test]
+    async fn given_partial_request_when_executing_then_only_sent_fields_change() {
+        // Arrange
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let mut existing = UserProfile::empty(current_user.id.clone());
+        existing.display_name = Some("Seller".to_string());
+        existing.location = Some("Berlin".to_string());
+        existing.avatar = Some(vec![1, 2, 3]);
+        existing.avatar_content_type = Some("image/png".to_string());
+
+        let mut user_profile_repository = MockIUserProfileRepository::new();
+        user_profile_repository
+            .expect_find()
+            .returning(move |_| Ok(Some(existing.clone())));
+        user_profile_repository
+            .expect_upsert()
+            .withf(|profile| {
+                profile.display_name.as_deref() == Some("Seller")
+                    && profile.bio.as_deref() == Some("Vintage cameras")
+                    && profile.location.is_none()
+                    && profile.avatar == Some(vec![1, 2, 3])
+                    && profile.avatar_content_type.as_deref() == Some("image/png")
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = UpdateProfileUseCase::new(Arc::new(user_profile_repository));
+        let request = dtos::UpdateProfileRequest {
+            display_name: None,
+            bio: Some("Vintage cameras".to_string()),
+            location: Some("".to_string()),
+            avatar: None,
+            remove_avatar: None,
+        };
+
+        // Act
+        let result = use_case.execute(current_user, request).await;
+
+        // Assert
+        let profile = result.unwrap();
+        assert!(profile.has_avatar);
+        assert_eq!(profile.bio.as_deref(), Some("Vintage cameras"));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::signers::verification_link_signer::VerificationLinkSigner;
+use domain::app_error::AppError;
+use domain::interfaces::i_email_verification_repository::IEmailVerificationRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug)]
+    pub struct VerifyEmailRequest {
+        pub token: String,
+    }
+}
+
+pub struct VerifyEmailUseCase<R: IEmailVerificationRepository> {
+    email_verification_repository: Arc<R>,
+    verification_link_signer: Arc<VerificationLinkSigner>,
+}
+
+impl<R: IEmailVerificationRepository> VerifyEmailUseCase<R> {
+    pub fn new(
+        email_verification_repository: Arc<R>,
+        verification_link_signer: Arc<VerificationLinkSigner>,
+    ) -> Self {
+        Self {
+            email_verification_repository,
+            verification_link_signer,
+        }
+    }
+
+    pub async fn execute(&self, dto: dtos::VerifyEmailRequest) -> Result<(), AppError> {
+        let token_id = self
+            .verification_link_signer
+            .verify(&dto.token)
+            .ok_or_else(|| {
+                error!("Verification token has an invalid signature");
+                AppError::InvalidVerificationToken()
+            })?;
+
+        // unknown, expired and already used tokens are all reported the same way
+        let user_id = self
+            .email_verification_repository
+            .consume(token_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to consume verification token: {:?}", e);
+                AppError::InvalidVerificationToken()
+            })?
+            .ok_or_else(|| AppError::InvalidVerificationToken())?;
+        info!("Email of user {} verified", user_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::user::User;
+    use domain::interfaces::i_email_verification_repository::MockIEmailVerificationRepository;
+
+    fn signer() -> Arc<VerificationLinkSigner> {
+        Arc::new(VerificationLinkSigner::new(
+            "key".to_string(),
+            "http://localhost/verify-email".to_string(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn given_forged_token_when_executing_then_repository_is_not_queried() {
+        // Arrange
+        let mut email_verification_repository = MockIEmailVerificationRepository::new();
+        email_verification_repository.expect_consume().times(0);
+
+        let use_case = VerifyEmailUseCase::new(Arc::new(email_verification_repository), signer());
+        let dto = dtos::VerifyEmailRequest {
+            token: format!("{}.{}", uuid::Uuid::new_v4(), "00".repeat(32)),
+        };
+
+        // Act
+        let result = use_case.execute(dto).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidVerificationToken())));
+    }
+
+    #[tokio::test]
+    async fn given_already_used_token_when_executing_then_invalid_verification_token_error_is_returned(
+    ) {
+        // Arrange
+        let signer = signer();
+        let user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+        let (_, email) = signer.issue(&user);
+        let token = email
+            .body
+            .split("?token=")
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .unwrap()
+            .to_string();
+
+        let mut email_verification_repository = MockIEmailVerificationRepository::new();
+        email_verification_repository
+            .expect_consume()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        let use_case = VerifyEmailUseCase::new(Arc::new(email_verification_repository), signer);
+
+        // Act
+        let result = use_case.execute(dtos::VerifyEmailRequest { token }).await;
+
+        // Assert
+        assert!(matches!(result, Err(AppError::InvalidVerificationToken())));
+    }
+}
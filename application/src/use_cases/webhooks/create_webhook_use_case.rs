@@ -0,0 +1,201 @@
+use crate::use_cases::webhooks::get_webhooks_use_case::dtos::WebhookDto;
+use axum::http::Uri;
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::entities::webhook::{is_public_address, WebhookEventType, WebhookSubscription};
+use domain::interfaces::i_webhook_repository::IWebhookRepository;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Debug, Validate)]
+    pub struct CreateWebhookRequest {
+        #[validate(url(message = "Invalid webhook url"))]
+        pub url: String,
+        #[validate(length(min = 1, message = "At least one event type is required"))]
+        pub event_types: Vec<String>,
+        #[validate(length(min = 16, message = "Secret must be at least 16 characters long"))]
+        pub secret: String,
+    }
+}
+
+/// Rejects urls pointing at the API's own network. Host names are only checked for what they
+/// resolve to when a webhook is sent, as that may change after the subscription is created.
+fn check_target(url: &str) -> Result<(), AppError> {
+    let invalid = || AppError::InvalidWebhookUrl(url.to_string());
+    let uri = url.parse::<Uri>().map_err(|_| invalid())?;
+
+    if !matches!(uri.scheme_str(), Some("http") | Some("https")) {
+        return Err(invalid());
+    }
+    let host = uri
+        .host()
+        .ok_or_else(invalid)?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.')
+        .to_lowercase();
+
+    if host == "localhost" || host.ends_with(".localhost") {
+        return Err(invalid());
+    }
+    match host.parse::<IpAddr>() {
+        Ok(address) if !is_public_address(address) => Err(invalid()),
+        _ => Ok(()),
+    }
+}
+
+pub struct CreateWebhookUseCase<R: IWebhookRepository> {
+    webhook_repository: Arc<R>,
+}
+
+impl<R: IWebhookRepository> CreateWebhookUseCase<R> {
+    pub fn new(webhook_repository: Arc<R>) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        request: dtos::CreateWebhookRequest,
+    ) -> Result<WebhookDto, AppError> {
+        info!("Creating webhook for user with id {}", current_user.id);
+
+        check_target(&request.url)?;
+
+        let event_types = request
+            .event_types
+            .into_iter()
+            .map(|event_type| {
+                WebhookEventType::try_from(event_type.clone())
+                    .map_err(|_| AppError::InvalidWebhookEventType(event_type))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let subscription =
+            WebhookSubscription::new(current_user.id, request.url, event_types, request.secret);
+
+        match self.webhook_repository.insert(subscription).await {
+            Ok(Some(subscription)) => Ok(WebhookDto::from(subscription)),
+            Ok(None) => {
+                error!("Failed to create webhook: webhook not returned from repository");
+                Err(AppError::FailedToCreateWebhook())
+            }
+            Err(e) => {
+                error!("Failed to create webhook: {:?}", e);
+                Err(AppError::FailedToCreateWebhook())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::interfaces::i_webhook_repository::MockIWebhookRepository;
+
+    fn current_user() -> User {
+        User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn given_valid_request_when_executing_then_webhook_is_created_for_current_user() {
+        // Arrange
+        let current_user = current_user();
+        let user_id = current_user.id.clone();
+
+        let mut webhook_repository = MockIWebhookRepository::new();
+        webhook_repository
+            .expect_insert()
+            .withf(move |subscription| {
+                subscription.user_id == user_id
+                    && subscription.event_types
+                        == vec![
+                            WebhookEventType::BidPlaced,
+                            WebhookEventType::AuctionFinalized,
+                        ]
+            })
+            .times(1)
+            .returning(|subscription| Ok(Some(subscription)));
+
+        let use_case = CreateWebhookUseCase::new(Arc::new(webhook_repository));
+        let request = dtos::CreateWebhookRequest {
+            url: "https://shop.example.com/webhook".to_string(),
+            event_types: vec!["bid_placed".to_string(), "auction_finalized".to_string()],
+            secret: "0123456789abcdef".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user, request).await;
+
+        // Assert
+        let webhook = result.unwrap();
+        assert_eq!(webhook.event_types, vec!["bid_placed", "auction_finalized"]);
+    }
+
+    #[tokio::test]
+    async fn given_internal_urls_when_executing_then_invalid_webhook_url_is_returned() {
+        for url in [
+            "http://localhost:8000/webhook",
+            "http://127.0.0.1/webhook",
+            "http://10.0.0.5/webhook",
+            "http://192.168.1.1/webhook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/webhook",
+            "http://[::ffff:127.0.0.1]/webhook",
+            "http://[fd00:ec2::254]/webhook",
+            "file:///etc/passwd",
+        ] {
+            // Arrange
+            let mut webhook_repository = MockIWebhookRepository::new();
+            webhook_repository.expect_insert().times(0);
+
+            let use_case = CreateWebhookUseCase::new(Arc::new(webhook_repository));
+            let request = dtos::CreateWebhookRequest {
+                url: url.to_string(),
+                event_types: vec!["bid_placed".to_string()],
+                secret: "0123456789abcdef".to_string(),
+            };
+
+            // Act
+            let result = use_case.execute(current_user(), request).await;
+
+            // Assert
+            assert!(
+                matches!(result, Err(AppError::InvalidWebhookUrl(_))),
+                "{} was accepted",
+                url
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn given_unknown_event_type_when_executing_then_invalid_webhook_event_type_is_returned() {
+        // Arrange
+        let use_case = CreateWebhookUseCase::new(Arc::new(MockIWebhookRepository::new()));
+        let request = dtos::CreateWebhookRequest {
+            url: "https://shop.example.com/webhook".to_string(),
+            event_types: vec!["item_burned".to_string()],
+            secret: "0123456789abcdef".to_string(),
+        };
+
+        // Act
+        let result = use_case.execute(current_user(), request).await;
+
+        // Assert
+        match result {
+            Err(AppError::InvalidWebhookEventType(event_type)) => {
+                assert_eq!(event_type, "item_burned")
+            }
+            _ => panic!("Test failed"),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::entities::webhook::WebhookSubscription;
+use domain::id::Id;
+use domain::interfaces::i_webhook_repository::IWebhookRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct DeleteWebhookUseCase<R: IWebhookRepository> {
+    webhook_repository: Arc<R>,
+}
+
+impl<R: IWebhookRepository> DeleteWebhookUseCase<R> {
+    pub fn new(webhook_repository: Arc<R>) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn execute(&self, current_user: User, id: String) -> Result<(), AppError> {
+        info!("Deleting webhook with id {}", id);
+
+        let webhook_id = Id::<WebhookSubscription>::try_from(id.clone())
+            .map_err(|_| AppError::WebhookNotFound(id.clone()))?;
+
+        match self
+            .webhook_repository
+            .delete(webhook_id, current_user.id)
+            .await
+        {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(AppError::WebhookNotFound(id)),
+            Err(e) => {
+                error!("Failed to delete webhook: {:?}", e);
+                Err(AppError::FailedToDeleteWebhook())
+            }
+        }
+    }
+}
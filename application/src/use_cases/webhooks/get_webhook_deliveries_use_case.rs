@@ -0,0 +1,146 @@
+use crate::use_cases::webhooks::get_webhook_deliveries_use_case::dtos::{
+    GetWebhookDeliveriesDto, WebhookDeliveryDto,
+};
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::entities::webhook::WebhookSubscription;
+use domain::id::Id;
+use domain::interfaces::i_webhook_repository::IWebhookRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const DELIVERY_LOG_SIZE: i64 = 100;
+
+pub mod dtos {
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::webhook::WebhookDelivery;
+    use serde::Serialize;
+
+    #[derive(Serialize, Debug)]
+    pub struct WebhookDeliveryDto {
+        pub id: String,
+        pub event_id: String,
+        pub event_type: String,
+        pub attempt: i32,
+        pub status_code: Option<i32>,
+        pub error: Option<String>,
+        pub succeeded: bool,
+        pub created_at: i64,
+    }
+
+    impl From<WebhookDelivery> for WebhookDeliveryDto {
+        fn from(delivery: WebhookDelivery) -> Self {
+            WebhookDeliveryDto {
+                id: delivery.id.to_string(),
+                event_id: delivery.event_id,
+                event_type: delivery.event_type.into(),
+                attempt: delivery.attempt,
+                status_code: delivery.status_code,
+                error: delivery.error,
+                succeeded: delivery.succeeded,
+                created_at: delivery.created_at.timestamp(),
+            }
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct GetWebhookDeliveriesDto {
+        pub deliveries: Vec<WebhookDeliveryDto>,
+    }
+
+    impl IntoResponse for GetWebhookDeliveriesDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct GetWebhookDeliveriesUseCase<R: IWebhookRepository> {
+    webhook_repository: Arc<R>,
+}
+
+impl<R: IWebhookRepository> GetWebhookDeliveriesUseCase<R> {
+    pub fn new(webhook_repository: Arc<R>) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        current_user: User,
+        id: String,
+    ) -> Result<GetWebhookDeliveriesDto, AppError> {
+        info!("Getting deliveries for webhook with id {}", id);
+
+        let webhook_id = Id::<WebhookSubscription>::try_from(id.clone())
+            .map_err(|_| AppError::WebhookNotFound(id.clone()))?;
+
+        // someone else's webhook is reported as missing rather than forbidden
+        match self.webhook_repository.find_by_id(webhook_id.clone()).await {
+            Ok(Some(subscription)) if subscription.user_id == current_user.id => {}
+            Ok(_) => return Err(AppError::WebhookNotFound(id)),
+            Err(e) => {
+                error!("Failed to get webhook: {:?}", e);
+                return Err(AppError::FailedToGetWebhooks());
+            }
+        }
+
+        let deliveries = self
+            .webhook_repository
+            .find_deliveries(webhook_id, DELIVERY_LOG_SIZE)
+            .await
+            .map_err(|e| {
+                error!("Failed to get webhook deliveries: {:?}", e);
+                AppError::FailedToGetWebhooks()
+            })?;
+
+        Ok(GetWebhookDeliveriesDto {
+            deliveries: deliveries
+                .into_iter()
+                .map(WebhookDeliveryDto::from)
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::webhook::WebhookEventType;
+    use domain::interfaces::i_webhook_repository::MockIWebhookRepository;
+
+    #[tokio::test]
+    async fn given_webhook_of_another_user_when_executing_then_webhook_not_found_is_returned() {
+        // Arrange
+        let subscription = WebhookSubscription::new(
+            Id::gen(),
+            "https://shop.example.com/webhook".to_string(),
+            vec![WebhookEventType::BidPlaced],
+            "0123456789abcdef".to_string(),
+        );
+        let webhook_id = subscription.id.to_string();
+
+        let mut webhook_repository = MockIWebhookRepository::new();
+        webhook_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(subscription.clone())));
+        webhook_repository.expect_find_deliveries().times(0);
+
+        let use_case = GetWebhookDeliveriesUseCase::new(Arc::new(webhook_repository));
+        let current_user = User::new(
+            "username".to_string(),
+            "email".to_string(),
+            "hashed_password".to_string(),
+        );
+
+        // Act
+        let result = use_case.execute(current_user, webhook_id.clone()).await;
+
+        // Assert
+        match result {
+            Err(AppError::WebhookNotFound(id)) => assert_eq!(id, webhook_id),
+            _ => panic!("Test failed"),
+        }
+    }
+}
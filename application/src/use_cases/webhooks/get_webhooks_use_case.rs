@@ -0,0 +1,82 @@
+use crate::use_cases::webhooks::get_webhooks_use_case::dtos::{GetAllWebhooksDto, WebhookDto};
+use domain::app_error::AppError;
+use domain::entities::user::User;
+use domain::interfaces::i_webhook_repository::IWebhookRepository;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub mod dtos {
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::Json;
+    use domain::entities::webhook::WebhookSubscription;
+    use serde::Serialize;
+
+    /// The secret is never sent back once the subscription is created.
+    #[derive(Serialize, Debug)]
+    pub struct WebhookDto {
+        pub id: String,
+        pub url: String,
+        pub event_types: Vec<String>,
+        pub created_at: i64,
+    }
+
+    impl From<WebhookSubscription> for WebhookDto {
+        fn from(subscription: WebhookSubscription) -> Self {
+            WebhookDto {
+                id: subscription.id.to_string(),
+                url: subscription.url,
+                event_types: subscription
+                    .event_types
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                created_at: subscription.created_at.timestamp(),
+            }
+        }
+    }
+
+    impl IntoResponse for WebhookDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+
+    #[derive(Serialize, Debug)]
+    pub struct GetAllWebhooksDto {
+        pub webhooks: Vec<WebhookDto>,
+    }
+
+    impl IntoResponse for GetAllWebhooksDto {
+        fn into_response(self) -> Response {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+pub struct GetWebhooksUseCase<R: IWebhookRepository> {
+    webhook_repository: Arc<R>,
+}
+
+impl<R: IWebhookRepository> GetWebhooksUseCase<R> {
+    pub fn new(webhook_repository: Arc<R>) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn execute(&self, current_user: User) -> Result<GetAllWebhooksDto, AppError> {
+        info!("Getting webhooks for user with id {}", current_user.id);
+
+        let webhooks = self
+            .webhook_repository
+            .find_all_by_user_id(current_user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to get webhooks: {:?}", e);
+                AppError::FailedToGetWebhooks()
+            })?;
+
+        Ok(GetAllWebhooksDto {
+            webhooks: webhooks.into_iter().map(WebhookDto::from).collect(),
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_webhook_use_case;
+pub mod delete_webhook_use_case;
+pub mod get_webhook_deliveries_use_case;
+pub mod get_webhooks_use_case;
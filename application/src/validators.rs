@@ -0,0 +1,28 @@
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+use validator::ValidationError;
+
+lazy_static! {
+    static ref LOWERCASE_REGEX: Regex = Regex::new(r"[a-z]").unwrap();
+    static ref UPPERCASE_REGEX: Regex = Regex::new(r"[A-Z]").unwrap();
+    static ref DIGIT_REGEX: Regex = Regex::new(r"\d").unwrap();
+    static ref SPECIAL_REGEX: Regex = Regex::new(r"[^\da-zA-Z]").unwrap();
+    static ref LENGTH_REGEX: Regex = Regex::new(r".{6,}").unwrap();
+}
+
+/// The password rules shared by every request that accepts a password.
+pub(crate) fn validate_password(value: &str) -> Result<(), ValidationError> {
+    if LOWERCASE_REGEX.is_match(value).unwrap()
+        && UPPERCASE_REGEX.is_match(value).unwrap()
+        && DIGIT_REGEX.is_match(value).unwrap()
+        && SPECIAL_REGEX.is_match(value).unwrap()
+        && LENGTH_REGEX.is_match(value).unwrap()
+    {
+        Ok(())
+    } else {
+        Err(ValidationError::new(
+            "Password must contain at least one lowercase letter, one uppercase letter, \
+         one digit, one special character and must be at least 6 characters long",
+        ))
+    }
+}
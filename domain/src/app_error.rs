@@ -11,8 +11,10 @@ pub enum AppError {
     EmailAlreadyExists(String),
     #[error("User registration failed")]
     UserRegistrationFailed(#[source] anyhow::Error),
-    #[error("Email {0} is not registered.")]
-    NotRegisteredEmail(String),
+    #[error("Invalid email or password.")]
+    InvalidCredentials(),
+    #[error("Too many failed login attempts. Try again later.")]
+    TooManyLoginAttempts(),
     #[error("User login failed. Bad password.")]
     BadPassword(),
     #[error("Missing or expired jwt.")]
@@ -69,6 +71,158 @@ pub enum AppError {
     CannotConfirmAuctionIfAuctionIsNotExpired(),
     #[error("Cannot bid on expired auction.")]
     CannotBidOnExpiredAuction(),
+    #[error("Failed to get user events.")]
+    FailedToGetUserEvents(),
+    #[error("Failed to get notifications.")]
+    FailedToGetNotifications(),
+    #[error("Notification with id {0} not found")]
+    NotificationNotFound(String),
+    #[error("Failed to update notifications.")]
+    FailedToUpdateNotifications(),
+    #[error("Failed to run jobs.")]
+    FailedToRunJobs(),
+    #[error("Unknown webhook event type {0}")]
+    InvalidWebhookEventType(String),
+    #[error("Webhooks cannot be delivered to {0}")]
+    InvalidWebhookUrl(String),
+    #[error("Failed to create webhook.")]
+    FailedToCreateWebhook(),
+    #[error("Failed to get webhooks.")]
+    FailedToGetWebhooks(),
+    #[error("Webhook with id {0} not found")]
+    WebhookNotFound(String),
+    #[error("Failed to delete webhook.")]
+    FailedToDeleteWebhook(),
+    #[error("Invalid or expired refresh token.")]
+    InvalidRefreshToken(),
+    #[error("Failed to create session.")]
+    FailedToCreateSession(),
+    #[error("Failed to revoke session.")]
+    FailedToRevokeSession(),
+    #[error("Session with id {0} not found.")]
+    SessionNotFound(String),
+    #[error("Failed to get sessions.")]
+    FailedToGetSessions(),
+    #[error("Email address is not verified.")]
+    EmailNotVerified(),
+    #[error("Email address is already verified.")]
+    EmailAlreadyVerified(),
+    #[error("Invalid or expired verification token.")]
+    InvalidVerificationToken(),
+    #[error("A verification email has been sent recently. Try again later.")]
+    VerificationEmailThrottled(),
+    #[error("Failed to send verification email.")]
+    FailedToSendVerificationEmail(),
+    #[error("Invalid or expired password reset token.")]
+    InvalidPasswordResetToken(),
+    #[error("Failed to reset password.")]
+    FailedToResetPassword(),
+    #[error("Failed to change password.")]
+    FailedToChangePassword(),
+    #[error("This action requires the {0} role.")]
+    InsufficientRole(String),
+    #[error("This account has been suspended.")]
+    AccountSuspended(),
+    #[error("Failed to get users.")]
+    FailedToGetUsers(),
+    #[error("Cannot suspend user {0} with the admin role.")]
+    CannotSuspendAdmin(String),
+    #[error("Failed to suspend user.")]
+    FailedToSuspendUser(),
+    #[error("Failed to close auction.")]
+    FailedToCloseAuction(),
+    #[error("Two-factor authentication is already enabled.")]
+    TwoFactorAlreadyEnabled(),
+    #[error("Two-factor authentication is not enabled.")]
+    TwoFactorNotEnabled(),
+    #[error("Two-factor authentication has not been set up.")]
+    TwoFactorNotEnrolled(),
+    #[error("Invalid two-factor code.")]
+    InvalidTwoFactorCode(),
+    #[error("Invalid or expired login challenge.")]
+    InvalidLoginChallenge(),
+    #[error("Failed to update two-factor authentication.")]
+    FailedToUpdateTwoFactor(),
+    #[error("Unknown identity provider: {0}.")]
+    UnknownIdentityProvider(String),
+    #[error("Invalid or expired authorization state.")]
+    InvalidAuthorizationState(),
+    #[error("Sign in with the identity provider failed.")]
+    ExternalLoginFailed(),
+    #[error("An account with email {0} already exists. Sign in and link the provider instead.")]
+    ExternalEmailAlreadyRegistered(String),
+    #[error("The identity provider did not share an email address.")]
+    ExternalAccountWithoutEmail(),
+    #[error("This external account is already linked to a user.")]
+    ExternalIdentityAlreadyLinked(),
+    #[error("Invalid or expired API key.")]
+    InvalidApiKey(),
+    #[error("API keys cannot be used for this route.")]
+    ApiKeyNotAllowed(),
+    #[error("The API key lacks the {0} scope.")]
+    MissingApiKeyScope(String),
+    #[error("Invalid API key scope: {0}.")]
+    InvalidApiKeyScope(String),
+    #[error("API key not found: {0}.")]
+    ApiKeyNotFound(String),
+    #[error("Failed to create API key.")]
+    FailedToCreateApiKey(),
+    #[error("Failed to get API keys.")]
+    FailedToGetApiKeys(),
+    #[error("Failed to revoke API key.")]
+    FailedToRevokeApiKey(),
+    #[error("Failed to get profile.")]
+    FailedToGetProfile(),
+    #[error("Failed to update profile.")]
+    FailedToUpdateProfile(),
+    #[error("Avatar must be at most {0} bytes.")]
+    AvatarTooLarge(usize),
+    #[error("User {0} has no avatar.")]
+    AvatarNotFound(String),
+    #[error("No sale of auction {0} to rate.")]
+    SaleNotFound(String),
+    #[error("The rating window of this sale has closed.")]
+    RatingWindowClosed(),
+    #[error("This sale has already been rated.")]
+    AlreadyRated(),
+    #[error("Failed to rate.")]
+    FailedToRate(),
+    #[error("Failed to get ratings.")]
+    FailedToGetRatings(),
+    #[error("Failed to export account data.")]
+    FailedToExportAccount(),
+    #[error("Failed to delete account.")]
+    FailedToDeleteAccount(),
+    #[error("The account has auctions that are not finalized yet.")]
+    AccountHasLiveAuctions(),
+    #[error("The account holds the highest bid of an ongoing auction.")]
+    AccountHasLeadingBids(),
+    #[error("Item {0} not found.")]
+    ItemNotFound(String),
+    #[error("Item {0} has an auction that is not finalized yet.")]
+    ItemHasAuction(String),
+    #[error("Failed to update item.")]
+    FailedToUpdateItem(),
+    #[error("Failed to delete item.")]
+    FailedToDeleteItem(),
+    #[error("Item {0} has no image {1}.")]
+    ItemImageNotFound(String, i32),
+    #[error("An item can have at most {0} images.")]
+    TooManyItemImages(usize),
+    #[error("The order must list every image position exactly once.")]
+    InvalidImageOrder(),
+    #[error("Failed to update item images.")]
+    FailedToUpdateItemImages(),
+    #[error("Image must be at most {0} bytes.")]
+    ImageTooLarge(usize),
+    #[error("Image must be at most {0} pixels wide and high.")]
+    ImageDimensionsTooLarge(u32),
+    #[error("Image must be a JPEG, PNG or WebP file.")]
+    UnsupportedImageType(),
+    #[error("Image could not be decoded.")]
+    InvalidImage(),
+    #[error("Thumbnail size must be one of {0}.")]
+    InvalidThumbnailSize(String),
 }
 
 impl IntoResponse for AppError {
@@ -85,9 +239,12 @@ impl IntoResponse for AppError {
             AppError::UserRegistrationFailed(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
             }
-            AppError::NotRegisteredEmail(_) => {
+            AppError::InvalidCredentials() => {
                 (StatusCode::UNAUTHORIZED, error_message).into_response()
             }
+            AppError::TooManyLoginAttempts() => {
+                (StatusCode::TOO_MANY_REQUESTS, error_message).into_response()
+            }
             AppError::BadPassword() => (StatusCode::UNAUTHORIZED, error_message).into_response(),
             AppError::InvalidJwt() => (StatusCode::UNAUTHORIZED, error_message).into_response(),
             AppError::UserNotFound(_) => (StatusCode::NOT_FOUND, error_message).into_response(),
@@ -162,6 +319,202 @@ impl IntoResponse for AppError {
             AppError::CannotBidOnExpiredAuction() => {
                 (StatusCode::FORBIDDEN, error_message).into_response()
             }
+            AppError::FailedToGetUserEvents() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToGetNotifications() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::NotificationNotFound(_) => {
+                (StatusCode::NOT_FOUND, error_message).into_response()
+            }
+            AppError::FailedToUpdateNotifications() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToRunJobs() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::InvalidWebhookEventType(_) => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
+            AppError::InvalidWebhookUrl(_) => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
+            AppError::FailedToCreateWebhook() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToGetWebhooks() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::WebhookNotFound(_) => (StatusCode::NOT_FOUND, error_message).into_response(),
+            AppError::FailedToDeleteWebhook() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::InvalidRefreshToken() => {
+                (StatusCode::UNAUTHORIZED, error_message).into_response()
+            }
+            AppError::FailedToCreateSession() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToRevokeSession() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::SessionNotFound(_) => (StatusCode::NOT_FOUND, error_message).into_response(),
+            AppError::FailedToGetSessions() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::EmailNotVerified() => (StatusCode::FORBIDDEN, error_message).into_response(),
+            AppError::EmailAlreadyVerified() => {
+                (StatusCode::CONFLICT, error_message).into_response()
+            }
+            AppError::InvalidVerificationToken() => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
+            AppError::VerificationEmailThrottled() => {
+                (StatusCode::TOO_MANY_REQUESTS, error_message).into_response()
+            }
+            AppError::FailedToSendVerificationEmail() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::InvalidPasswordResetToken() => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
+            AppError::FailedToResetPassword() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToChangePassword() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::InsufficientRole(_) => (StatusCode::FORBIDDEN, error_message).into_response(),
+            AppError::AccountSuspended() => (StatusCode::FORBIDDEN, error_message).into_response(),
+            AppError::FailedToGetUsers() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::CannotSuspendAdmin(_) => {
+                (StatusCode::FORBIDDEN, error_message).into_response()
+            }
+            AppError::FailedToSuspendUser() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToCloseAuction() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::TwoFactorAlreadyEnabled() => {
+                (StatusCode::CONFLICT, error_message).into_response()
+            }
+            AppError::TwoFactorNotEnabled() => {
+                (StatusCode::CONFLICT, error_message).into_response()
+            }
+            AppError::TwoFactorNotEnrolled() => {
+                (StatusCode::CONFLICT, error_message).into_response()
+            }
+            AppError::InvalidTwoFactorCode() => {
+                (StatusCode::UNAUTHORIZED, error_message).into_response()
+            }
+            AppError::InvalidLoginChallenge() => {
+                (StatusCode::UNAUTHORIZED, error_message).into_response()
+            }
+            AppError::FailedToUpdateTwoFactor() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::UnknownIdentityProvider(_) => {
+                (StatusCode::NOT_FOUND, error_message).into_response()
+            }
+            AppError::InvalidAuthorizationState() => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
+            AppError::ExternalLoginFailed() => {
+                (StatusCode::BAD_GATEWAY, error_message).into_response()
+            }
+            AppError::ExternalEmailAlreadyRegistered(_) => {
+                (StatusCode::CONFLICT, error_message).into_response()
+            }
+            AppError::ExternalAccountWithoutEmail() => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
+            AppError::ExternalIdentityAlreadyLinked() => {
+                (StatusCode::CONFLICT, error_message).into_response()
+            }
+            AppError::InvalidApiKey() => (StatusCode::UNAUTHORIZED, error_message).into_response(),
+            AppError::ApiKeyNotAllowed() => (StatusCode::FORBIDDEN, error_message).into_response(),
+            AppError::MissingApiKeyScope(_) => {
+                (StatusCode::FORBIDDEN, error_message).into_response()
+            }
+            AppError::InvalidApiKeyScope(_) => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
+            AppError::ApiKeyNotFound(_) => (StatusCode::NOT_FOUND, error_message).into_response(),
+            AppError::FailedToCreateApiKey() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToGetApiKeys() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToRevokeApiKey() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToGetProfile() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToUpdateProfile() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::AvatarTooLarge(_) => {
+                (StatusCode::PAYLOAD_TOO_LARGE, error_message).into_response()
+            }
+            AppError::AvatarNotFound(_) => (StatusCode::NOT_FOUND, error_message).into_response(),
+            AppError::SaleNotFound(_) => (StatusCode::NOT_FOUND, error_message).into_response(),
+            AppError::RatingWindowClosed() => (StatusCode::CONFLICT, error_message).into_response(),
+            AppError::AlreadyRated() => (StatusCode::CONFLICT, error_message).into_response(),
+            AppError::FailedToRate() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToGetRatings() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToExportAccount() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToDeleteAccount() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::AccountHasLiveAuctions() => {
+                (StatusCode::CONFLICT, error_message).into_response()
+            }
+            AppError::AccountHasLeadingBids() => {
+                (StatusCode::CONFLICT, error_message).into_response()
+            }
+            AppError::ItemNotFound(_) => (StatusCode::NOT_FOUND, error_message).into_response(),
+            AppError::ItemHasAuction(_) => (StatusCode::CONFLICT, error_message).into_response(),
+            AppError::FailedToUpdateItem() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::FailedToDeleteItem() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::ItemImageNotFound(_, _) => {
+                (StatusCode::NOT_FOUND, error_message).into_response()
+            }
+            AppError::TooManyItemImages(_) => (StatusCode::CONFLICT, error_message).into_response(),
+            AppError::InvalidImageOrder() => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
+            AppError::FailedToUpdateItemImages() => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+            AppError::ImageTooLarge(_) => {
+                (StatusCode::PAYLOAD_TOO_LARGE, error_message).into_response()
+            }
+            AppError::ImageDimensionsTooLarge(_) => {
+                (StatusCode::PAYLOAD_TOO_LARGE, error_message).into_response()
+            }
+            AppError::UnsupportedImageType() => {
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, error_message).into_response()
+            }
+            AppError::InvalidImage() => (StatusCode::BAD_REQUEST, error_message).into_response(),
+            AppError::InvalidThumbnailSize(_) => {
+                (StatusCode::BAD_REQUEST, error_message).into_response()
+            }
         }
     }
 }
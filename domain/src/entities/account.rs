@@ -0,0 +1,32 @@
+use crate::entities::auction::AuctionWithItem;
+use crate::entities::bid::Bid;
+use crate::entities::item::Item;
+use crate::entities::item_image::ItemImage;
+use crate::entities::rating::{Rating, Sale};
+use crate::entities::session::Session;
+use crate::entities::user::User;
+use crate::entities::user_event::UserEvent;
+use crate::entities::user_profile::UserProfile;
+
+/// Everything held about a user, as handed out on a data export request.
+#[derive(Debug, Clone)]
+pub struct AccountExport {
+    pub user: User,
+    pub profile: Option<UserProfile>,
+    pub items: Vec<Item>,
+    pub item_images: Vec<ItemImage>,
+    pub bids: Vec<Bid>,
+    pub auctions: Vec<AuctionWithItem>,
+    pub sales: Vec<Sale>,
+    pub ratings_given: Vec<Rating>,
+    pub ratings_received: Vec<Rating>,
+    pub events: Vec<UserEvent>,
+    pub sessions: Vec<Session>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AccountDeletion {
+    Deleted,
+    BlockedByLiveAuctions,
+    BlockedByLeadingBids,
+}
@@ -0,0 +1,89 @@
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+/// A long-lived credential for scripts. Only a hash of the key is stored, it is shown to the
+/// user once when created.
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub id: Id<ApiKey>,
+    pub user_id: Id<User>,
+    pub name: String,
+    /// The first characters of the key, so users can tell their keys apart.
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    pub fn new(
+        user_id: Id<User>,
+        name: String,
+        prefix: String,
+        key_hash: String,
+        scopes: Vec<ApiKeyScope>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            name,
+            prefix,
+            key_hash,
+            scopes,
+            expires_at,
+            last_used_at: None,
+            revoked_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+            && self
+                .expires_at
+                .map_or(true, |expires_at| expires_at > Utc::now())
+    }
+
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
+
+/// What an API key may do. Routes without a scope cannot be used with an API key at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ApiKeyScope {
+    Read,
+    ItemsWrite,
+    AuctionsWrite,
+    BidsWrite,
+}
+
+impl TryFrom<String> for ApiKeyScope {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "read" => Ok(ApiKeyScope::Read),
+            "items:write" => Ok(ApiKeyScope::ItemsWrite),
+            "auctions:write" => Ok(ApiKeyScope::AuctionsWrite),
+            "bids:write" => Ok(ApiKeyScope::BidsWrite),
+            _ => Err(anyhow::anyhow!("Unknown API key scope {}", value)),
+        }
+    }
+}
+
+impl From<ApiKeyScope> for String {
+    fn from(value: ApiKeyScope) -> Self {
+        match value {
+            ApiKeyScope::Read => "read".to_string(),
+            ApiKeyScope::ItemsWrite => "items:write".to_string(),
+            ApiKeyScope::AuctionsWrite => "auctions:write".to_string(),
+            ApiKeyScope::BidsWrite => "bids:write".to_string(),
+        }
+    }
+}
@@ -1,5 +1,11 @@
+use crate::entities::bid::BidWithUsername;
+use crate::entities::email::Email;
 use crate::entities::item::{Category, Item};
+use crate::entities::job::Job;
+use crate::entities::notification::Notification;
 use crate::entities::user::User;
+use crate::entities::user_event::{UserEvent, UserEventKind};
+use crate::entities::webhook::WebhookEvent;
 use crate::id::Id;
 use chrono::{DateTime, Utc};
 
@@ -94,3 +100,64 @@ impl From<AuctionStrategy> for String {
         }
     }
 }
+
+/// What finalizing an auction writes besides removing it: the new owner of the item, if it is
+/// sold, and the jobs, notifications and user events telling everybody involved.
+#[derive(Debug)]
+pub struct Finalization {
+    pub new_owner_id: Option<Id<User>>,
+    pub jobs: Vec<Job>,
+    pub notifications: Vec<Notification>,
+    pub user_events: Vec<UserEvent>,
+}
+
+/// Decides the finalization from the bids, which are read once the auction is claimed so no bid
+/// can come in after the winner is picked.
+pub type FinalizationPlan = Box<dyn FnOnce(&[BidWithUsername]) -> Finalization + Send>;
+
+impl Finalization {
+    /// Sells the item to `winner`, or ends the auction without a sale when there is none.
+    pub fn new(
+        auction: &AuctionWithItem,
+        bids: &[BidWithUsername],
+        winner: Option<(Id<User>, f32)>,
+    ) -> Self {
+        let mut jobs = vec![WebhookEvent::auction_finalized(auction, winner.clone()).into_job()];
+        let mut user_events = vec![];
+        if let Some((winner_id, winning_bid)) = winner.clone() {
+            jobs.extend([
+                WebhookEvent::item_transferred(
+                    auction.item_id.clone(),
+                    auction.user_id.clone(),
+                    winner_id.clone(),
+                )
+                .into_job(),
+                Email::auction_won(winner_id.clone(), auction, winning_bid).into_job(),
+                Email::item_sold(auction.user_id.clone(), auction, winning_bid).into_job(),
+            ]);
+            user_events.extend([
+                UserEvent::new(
+                    winner_id,
+                    UserEventKind::AuctionWon,
+                    Some(auction.id.clone()),
+                    Some(auction.item_id.clone()),
+                    Some(winning_bid),
+                ),
+                UserEvent::new(
+                    auction.user_id.clone(),
+                    UserEventKind::ItemSold,
+                    Some(auction.id.clone()),
+                    Some(auction.item_id.clone()),
+                    Some(winning_bid),
+                ),
+            ]);
+        }
+
+        Self {
+            new_owner_id: winner.clone().map(|(winner_id, _)| winner_id),
+            jobs,
+            notifications: Notification::for_finalized_auction(auction, bids, winner),
+            user_events,
+        }
+    }
+}
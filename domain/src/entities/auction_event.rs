@@ -0,0 +1,33 @@
+use crate::entities::auction::Auction;
+use crate::entities::bid::Bid;
+use crate::entities::user::User;
+use crate::id::Id;
+
+#[derive(Debug, Clone)]
+pub enum AuctionEvent {
+    BidPlaced {
+        auction_id: Id<Auction>,
+        bid_id: Id<Bid>,
+        user_id: Id<User>,
+        username: String,
+        value: f32,
+    },
+    Finalized {
+        auction_id: Id<Auction>,
+        winner_id: Option<Id<User>>,
+        winning_bid: Option<f32>,
+    },
+}
+
+impl AuctionEvent {
+    pub fn auction_id(&self) -> Id<Auction> {
+        match self {
+            AuctionEvent::BidPlaced { auction_id, .. } => auction_id.clone(),
+            AuctionEvent::Finalized { auction_id, .. } => auction_id.clone(),
+        }
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(self, AuctionEvent::Finalized { .. })
+    }
+}
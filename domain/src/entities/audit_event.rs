@@ -0,0 +1,59 @@
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+/// A security relevant event kept for later review.
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    pub id: Id<AuditEvent>,
+    pub kind: AuditEventKind,
+    pub user_id: Option<Id<User>>,
+    pub email: Option<String>,
+    pub ip_address: Option<String>,
+    pub details: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    pub fn new(
+        kind: AuditEventKind,
+        user_id: Option<Id<User>>,
+        email: Option<String>,
+        ip_address: Option<String>,
+        details: Option<String>,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            kind,
+            user_id,
+            email,
+            ip_address,
+            details,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AuditEventKind {
+    AccountLocked,
+    IpLocked,
+}
+
+impl From<AuditEventKind> for String {
+    fn from(kind: AuditEventKind) -> Self {
+        match kind {
+            AuditEventKind::AccountLocked => "account_locked".to_string(),
+            AuditEventKind::IpLocked => "ip_locked".to_string(),
+        }
+    }
+}
+
+impl From<String> for AuditEventKind {
+    fn from(kind: String) -> Self {
+        match kind.as_str() {
+            "ip_locked" => Self::IpLocked,
+            _ => Self::AccountLocked,
+        }
+    }
+}
@@ -31,3 +31,12 @@ pub struct BidWithUsername {
     pub user_id: Id<User>,
     pub username: String,
 }
+
+impl BidWithUsername {
+    /// The bidder and amount of the highest bid, who wins the auction.
+    pub fn winner(bids: &[Self]) -> Option<(Id<User>, f32)> {
+        bids.iter()
+            .max_by(|a, b| a.value.partial_cmp(&b.value).unwrap())
+            .map(|bid| (bid.user_id.clone(), bid.value))
+    }
+}
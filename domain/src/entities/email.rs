@@ -0,0 +1,159 @@
+use crate::entities::auction::AuctionWithItem;
+use crate::entities::job::Job;
+use crate::entities::user::User;
+use crate::id::Id;
+use serde_json::json;
+
+/// A rendered email; the recipient address is resolved when the send job runs.
+#[derive(Debug, Clone)]
+pub struct Email {
+    pub recipient_id: Id<User>,
+    pub subject: String,
+    pub body: String,
+}
+
+impl Email {
+    pub const JOB_KIND: &'static str = "send_email";
+
+    pub fn new(recipient_id: Id<User>, subject: String, body: String) -> Self {
+        Self {
+            recipient_id,
+            subject,
+            body,
+        }
+    }
+
+    pub fn into_job(self) -> Job {
+        Job::new(
+            Self::JOB_KIND,
+            json!({
+                "recipient_id": self.recipient_id.to_string(),
+                "subject": self.subject,
+                "body": self.body,
+            }),
+        )
+    }
+
+    pub fn registration(user: &User) -> Self {
+        Self::new(
+            user.id.clone(),
+            "Welcome to RainbowBid".to_string(),
+            Self::with_signature(format!(
+                "Hello {},\n\nyour RainbowBid account has been created. \
+                Once you confirm your email address you can list items and bid on auctions.",
+                user.name
+            )),
+        )
+    }
+
+    pub fn email_verification(user: &User, link: String) -> Self {
+        Self::new(
+            user.id.clone(),
+            "Confirm your email address".to_string(),
+            Self::with_signature(format!(
+                "Hello {},\n\nplease confirm your email address by opening the link below. \
+                It expires in 24 hours.\n\n{}",
+                user.name, link
+            )),
+        )
+    }
+
+    pub fn password_reset(user: &User, link: String) -> Self {
+        Self::new(
+            user.id.clone(),
+            "Reset your password".to_string(),
+            Self::with_signature(format!(
+                "Hello {},\n\nsomeone asked to reset the password of your account. \
+                If it was you, open the link below to choose a new one. It expires in 30 minutes \
+                and can only be used once.\n\n{}\n\nIf it was not you, you can ignore this email.",
+                user.name, link
+            )),
+        )
+    }
+
+    pub fn auction_closed_by_moderator(auction: &AuctionWithItem, reason: &str) -> Self {
+        Self::new(
+            auction.user_id.clone(),
+            format!("Your auction for \"{}\" was closed", auction.brief),
+            Self::with_signature(format!(
+                "Hello,\n\nyour auction for \"{}\" was closed by a moderator before it ended. \
+                The item stays yours and the bids placed on it were discarded.\n\nReason: {}",
+                auction.brief, reason
+            )),
+        )
+    }
+
+    pub fn outbid(recipient_id: Id<User>, auction: &AuctionWithItem, highest_bid: f32) -> Self {
+        Self::new(
+            recipient_id,
+            format!("You have been outbid on \"{}\"", auction.brief),
+            Self::with_signature(format!(
+                "Hello,\n\nsomeone placed a bid of {} on \"{}\", which is higher than yours. \
+                Bid again before the auction ends to stay in the race.",
+                highest_bid, auction.brief
+            )),
+        )
+    }
+
+    pub fn auction_won(
+        recipient_id: Id<User>,
+        auction: &AuctionWithItem,
+        winning_bid: f32,
+    ) -> Self {
+        Self::new(
+            recipient_id,
+            format!("You won \"{}\"", auction.brief),
+            Self::with_signature(format!(
+                "Hello,\n\ncongratulations, you won the auction for \"{}\" with a bid of {}. \
+                The item is now listed among your items.",
+                auction.brief, winning_bid
+            )),
+        )
+    }
+
+    pub fn item_sold(recipient_id: Id<User>, auction: &AuctionWithItem, winning_bid: f32) -> Self {
+        Self::new(
+            recipient_id,
+            format!("\"{}\" was sold", auction.brief),
+            Self::with_signature(format!(
+                "Hello,\n\nyour auction for \"{}\" ended and the item was sold for {}.",
+                auction.brief, winning_bid
+            )),
+        )
+    }
+
+    pub fn confirmation_required(recipient_id: Id<User>, auction: &AuctionWithItem) -> Self {
+        Self::new(
+            recipient_id,
+            format!("Your auction for \"{}\" needs confirmation", auction.brief),
+            Self::with_signature(format!(
+                "Hello,\n\nyour auction for \"{}\" has ended. \
+                Confirm or reject the highest bid to finalize it.",
+                auction.brief
+            )),
+        )
+    }
+
+    fn with_signature(text: String) -> String {
+        format!("{}\n\nThe RainbowBid team", text)
+    }
+}
+
+impl TryFrom<&Job> for Email {
+    type Error = anyhow::Error;
+
+    fn try_from(job: &Job) -> Result<Self, Self::Error> {
+        let field = |name: &str| {
+            job.payload[name]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("Email job is missing {}", name))
+        };
+
+        Ok(Email::new(
+            Id::try_from(field("recipient_id")?)?,
+            field("subject")?,
+            field("body")?,
+        ))
+    }
+}
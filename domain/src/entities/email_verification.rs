@@ -0,0 +1,26 @@
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+/// A single-use proof that the user can read mail sent to their address. The link in the email
+/// carries the id together with a signature of it.
+#[derive(Debug, Clone)]
+pub struct EmailVerificationToken {
+    pub id: Id<EmailVerificationToken>,
+    pub user_id: Id<User>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl EmailVerificationToken {
+    pub fn new(user_id: Id<User>, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            expires_at,
+            used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+/// An account at an identity provider that can be used to sign in as `user_id`.
+#[derive(Debug, Clone)]
+pub struct ExternalIdentity {
+    pub id: Id<ExternalIdentity>,
+    pub user_id: Id<User>,
+    pub provider: String,
+    /// The provider's stable id of the account, its email may change.
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ExternalIdentity {
+    pub fn new(user_id: Id<User>, provider: String, profile: &ExternalProfile) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            provider,
+            subject: profile.subject.clone(),
+            email: profile.email.clone(),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// What an identity provider tells about the account that signed in.
+#[derive(Debug, Clone)]
+pub struct ExternalProfile {
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: Option<String>,
+}
+
+/// Remembers a redirect to an identity provider until the browser comes back with the
+/// authorization code.
+#[derive(Debug, Clone)]
+pub struct AuthorizationRequest {
+    pub state: String,
+    pub provider: String,
+    /// The PKCE secret, only its hash is sent along with the redirect.
+    pub code_verifier: String,
+    pub nonce: String,
+    /// Set when a signed in user links another account rather than signing in.
+    pub link_user_id: Option<Id<User>>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
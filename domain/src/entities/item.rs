@@ -6,26 +6,18 @@ pub struct Item {
     pub id: Id<Item>,
     pub brief: String,
     pub description: String,
-    pub picture: Vec<u8>,
     pub user_id: Id<User>,
     pub category: Category,
 }
 
 impl Item {
-    pub fn new(
-        brief: String,
-        description: String,
-        picture: Vec<u8>,
-        user_id: Id<User>,
-        category: Category,
-    ) -> Self {
+    pub fn new(brief: String, description: String, user_id: Id<User>, category: Category) -> Self {
         let id = Id::gen();
 
         Self {
             id,
             brief,
             description,
-            picture,
             user_id,
             category,
         }
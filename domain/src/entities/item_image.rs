@@ -0,0 +1,57 @@
+use crate::entities::item::Item;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+pub const MAX_ITEM_IMAGES: usize = 10;
+/// Longest edge in pixels of the thumbnails generated for every image.
+pub const THUMBNAIL_SIZES: [i32; 3] = [160, 320, 640];
+
+/// One picture of an item's gallery. Positions of an item run from 0 without gaps, position 0 is
+/// the picture shown where an item only has one.
+///
+/// Thumbnails share the position of their image and carry their `thumbnail_size`, so moving or
+/// removing an image takes them along. The data itself lives in the blob store under `blob_key`.
+#[derive(Debug, Clone)]
+pub struct ItemImage {
+    pub id: Id<ItemImage>,
+    pub item_id: Id<Item>,
+    pub position: i32,
+    /// `None` for the uploaded image itself.
+    pub thumbnail_size: Option<i32>,
+    pub content_type: String,
+    pub blob_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ItemImage {
+    pub fn new(item_id: Id<Item>, position: i32, content_type: String) -> Self {
+        let id = Id::gen();
+        Self {
+            blob_key: blob_key(&item_id, &id),
+            id,
+            item_id,
+            position,
+            thumbnail_size: None,
+            content_type,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn thumbnail(&self, size: i32) -> Self {
+        let id = Id::gen();
+        Self {
+            blob_key: blob_key(&self.item_id, &id),
+            id,
+            item_id: self.item_id.clone(),
+            position: self.position,
+            thumbnail_size: Some(size),
+            content_type: self.content_type.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Matches the keys the migration gave to images stored before there was a blob store.
+fn blob_key(item_id: &Id<Item>, id: &Id<ItemImage>) -> String {
+    format!("item-images/{}/{}", item_id, id)
+}
@@ -0,0 +1,78 @@
+use crate::id::Id;
+use chrono::{DateTime, Duration, Utc};
+
+const DEFAULT_MAX_ATTEMPTS: i32 = 5;
+const BASE_BACKOFF_SECONDS: i64 = 30;
+const MAX_BACKOFF_SECONDS: i64 = 60 * 60;
+
+/// A unit of background work; `kind` selects the handler and `payload` is its JSON input.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub id: Id<Job>,
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub status: JobStatus,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Job {
+    pub fn new(kind: &str, payload: serde_json::Value) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Id::gen(),
+            kind: kind.to_string(),
+            payload,
+            status: JobStatus::Pending,
+            attempts: 0,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            run_at: now,
+            last_error: None,
+            created_at: now,
+        }
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.attempts >= self.max_attempts
+    }
+
+    /// Exponential backoff based on the attempts made so far, capped at an hour.
+    pub fn next_attempt_at(&self) -> DateTime<Utc> {
+        let exponent = (self.attempts - 1).clamp(0, 16) as u32;
+        let seconds = (BASE_BACKOFF_SECONDS * 2_i64.pow(exponent)).min(MAX_BACKOFF_SECONDS);
+        Utc::now() + Duration::seconds(seconds)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    Dead,
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "running" => JobStatus::Running,
+            "completed" => JobStatus::Completed,
+            "dead" => JobStatus::Dead,
+            _ => JobStatus::Pending,
+        }
+    }
+}
+
+impl From<JobStatus> for String {
+    fn from(value: JobStatus) -> Self {
+        match value {
+            JobStatus::Pending => "pending".to_string(),
+            JobStatus::Running => "running".to_string(),
+            JobStatus::Completed => "completed".to_string(),
+            JobStatus::Dead => "dead".to_string(),
+        }
+    }
+}
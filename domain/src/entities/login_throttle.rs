@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+
+/// Failed login attempts counted against one account or one IP address. Accounts are keyed by
+/// email, so unknown emails are throttled exactly like registered ones.
+#[derive(Debug, Clone)]
+pub struct LoginThrottle {
+    pub key: String,
+    pub failed_attempts: i32,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub last_failed_at: DateTime<Utc>,
+}
+
+impl LoginThrottle {
+    pub fn account_key(email: &str) -> String {
+        format!("account:{}", email.trim().to_lowercase())
+    }
+
+    pub fn ip_key(ip_address: &str) -> String {
+        format!("ip:{}", ip_address)
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked_until
+            .is_some_and(|locked_until| locked_until > Utc::now())
+    }
+}
@@ -1,5 +1,24 @@
+pub mod account;
+pub mod api_key;
 pub mod auction;
+pub mod auction_event;
+pub mod audit_event;
 pub mod bid;
+pub mod email;
+pub mod email_verification;
+pub mod external_identity;
 pub mod item;
+pub mod item_image;
+pub mod job;
+pub mod login_throttle;
+pub mod notification;
+pub mod password_reset;
+pub mod rating;
+pub mod reputation;
+pub mod session;
 pub mod token_claims;
+pub mod two_factor;
 pub mod user;
+pub mod user_event;
+pub mod user_profile;
+pub mod webhook;
@@ -0,0 +1,152 @@
+use crate::entities::auction::{Auction, AuctionWithItem};
+use crate::entities::bid::BidWithUsername;
+use crate::entities::item::Item;
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub id: Id<Notification>,
+    pub user_id: Id<User>,
+    pub kind: NotificationKind,
+    pub message: String,
+    pub auction_id: Option<Id<Auction>>,
+    pub item_id: Option<Id<Item>>,
+    pub is_read: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Notification {
+    pub fn new(
+        user_id: Id<User>,
+        kind: NotificationKind,
+        message: String,
+        auction_id: Option<Id<Auction>>,
+        item_id: Option<Id<Item>>,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            kind,
+            message,
+            auction_id,
+            item_id,
+            is_read: false,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn outbid(user_id: Id<User>, auction: &AuctionWithItem, highest_bid: f32) -> Self {
+        Self::new(
+            user_id,
+            NotificationKind::Outbid,
+            format!(
+                "You have been outbid on \"{}\". The highest bid is now {}.",
+                auction.brief, highest_bid
+            ),
+            Some(auction.id.clone()),
+            Some(auction.item_id.clone()),
+        )
+    }
+
+    pub fn auction_won(user_id: Id<User>, auction: &AuctionWithItem, winning_bid: f32) -> Self {
+        Self::new(
+            user_id,
+            NotificationKind::AuctionWon,
+            format!(
+                "You won the auction for \"{}\" with a bid of {}.",
+                auction.brief, winning_bid
+            ),
+            Some(auction.id.clone()),
+            Some(auction.item_id.clone()),
+        )
+    }
+
+    pub fn auction_lost(user_id: Id<User>, auction: &AuctionWithItem) -> Self {
+        Self::new(
+            user_id,
+            NotificationKind::AuctionLost,
+            format!(
+                "The auction for \"{}\" ended without you winning it.",
+                auction.brief
+            ),
+            Some(auction.id.clone()),
+            Some(auction.item_id.clone()),
+        )
+    }
+
+    pub fn item_sold(user_id: Id<User>, auction: &AuctionWithItem, winning_bid: f32) -> Self {
+        Self::new(
+            user_id,
+            NotificationKind::ItemSold,
+            format!("\"{}\" was sold for {}.", auction.brief, winning_bid),
+            Some(auction.id.clone()),
+            Some(auction.item_id.clone()),
+        )
+    }
+
+    /// Tells the winner, the seller and every other bidder how a finalized auction ended.
+    pub fn for_finalized_auction(
+        auction: &AuctionWithItem,
+        bids: &[BidWithUsername],
+        winner: Option<(Id<User>, f32)>,
+    ) -> Vec<Self> {
+        let mut notifications = Vec::new();
+
+        if let Some((winner_id, winning_bid)) = winner.clone() {
+            notifications.push(Self::auction_won(winner_id, auction, winning_bid));
+            notifications.push(Self::item_sold(
+                auction.user_id.clone(),
+                auction,
+                winning_bid,
+            ));
+        }
+
+        let winner_id = winner.map(|(winner_id, _)| winner_id);
+        let losers = bids
+            .iter()
+            .map(|bid| bid.user_id.clone())
+            .filter(|user_id| Some(user_id) != winner_id.as_ref())
+            .collect::<HashSet<_>>();
+        for loser_id in losers {
+            notifications.push(Self::auction_lost(loser_id, auction));
+        }
+
+        notifications
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NotificationKind {
+    Outbid,
+    AuctionWon,
+    AuctionLost,
+    ItemSold,
+}
+
+impl TryFrom<String> for NotificationKind {
+    type Error = anyhow::Error;
+
+    fn try_from(kind: String) -> Result<Self, Self::Error> {
+        match kind.as_str() {
+            "outbid" => Ok(Self::Outbid),
+            "auction_won" => Ok(Self::AuctionWon),
+            "auction_lost" => Ok(Self::AuctionLost),
+            "item_sold" => Ok(Self::ItemSold),
+            _ => Err(anyhow::anyhow!("Unknown notification kind {}", kind)),
+        }
+    }
+}
+
+impl From<NotificationKind> for String {
+    fn from(kind: NotificationKind) -> Self {
+        match kind {
+            NotificationKind::Outbid => "outbid".to_string(),
+            NotificationKind::AuctionWon => "auction_won".to_string(),
+            NotificationKind::AuctionLost => "auction_lost".to_string(),
+            NotificationKind::ItemSold => "item_sold".to_string(),
+        }
+    }
+}
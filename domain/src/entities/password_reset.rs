@@ -0,0 +1,28 @@
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+/// A short-lived, single-use permission to set a new password without knowing the old one.
+/// Only the hash of the token sent by email is stored.
+#[derive(Debug, Clone)]
+pub struct PasswordResetToken {
+    pub id: Id<PasswordResetToken>,
+    pub user_id: Id<User>,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    pub fn new(user_id: Id<User>, token_hash: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            token_hash,
+            expires_at,
+            used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+}
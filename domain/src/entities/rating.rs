@@ -0,0 +1,102 @@
+use crate::entities::auction::Auction;
+use crate::entities::item::Item;
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Duration, Utc};
+
+/// How long after a sale its buyer and seller may rate each other.
+pub const RATING_WINDOW_DAYS: i64 = 30;
+
+/// An auction that finalized with a winner. Auctions are deleted once finalized, so this is what
+/// ratings refer to.
+#[derive(Debug, Clone)]
+pub struct Sale {
+    pub id: Id<Sale>,
+    pub auction_id: Id<Auction>,
+    pub item_id: Id<Item>,
+    pub seller_id: Id<User>,
+    pub buyer_id: Id<User>,
+    pub completed_at: DateTime<Utc>,
+}
+
+impl Sale {
+    /// The other side of the sale, `None` when `user_id` took no part in it.
+    pub fn counterparty_of(&self, user_id: &Id<User>) -> Option<(Id<User>, RatingRole)> {
+        if user_id.value == self.buyer_id.value {
+            Some((self.seller_id.clone(), RatingRole::Seller))
+        } else if user_id.value == self.seller_id.value {
+            Some((self.buyer_id.clone(), RatingRole::Buyer))
+        } else {
+            None
+        }
+    }
+
+    pub fn is_open_for_rating(&self) -> bool {
+        Utc::now() < self.completed_at + Duration::days(RATING_WINDOW_DAYS)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rating {
+    pub id: Id<Rating>,
+    pub sale_id: Id<Sale>,
+    pub rater_id: Id<User>,
+    pub ratee_id: Id<User>,
+    /// The part the rated user played in the sale.
+    pub ratee_role: RatingRole,
+    pub score: i16,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Rating {
+    pub fn new(
+        sale_id: Id<Sale>,
+        rater_id: Id<User>,
+        ratee_id: Id<User>,
+        ratee_role: RatingRole,
+        score: i16,
+        comment: Option<String>,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            sale_id,
+            rater_id,
+            ratee_id,
+            ratee_role,
+            score,
+            comment,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RatingWithRater {
+    pub rating: Rating,
+    pub rater_name: String,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RatingRole {
+    Buyer,
+    Seller,
+}
+
+impl From<String> for RatingRole {
+    fn from(role: String) -> Self {
+        match role.as_str() {
+            "buyer" => Self::Buyer,
+            _ => Self::Seller,
+        }
+    }
+}
+
+impl From<RatingRole> for String {
+    fn from(role: RatingRole) -> Self {
+        match role {
+            RatingRole::Buyer => "buyer".to_string(),
+            RatingRole::Seller => "seller".to_string(),
+        }
+    }
+}
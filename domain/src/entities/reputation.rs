@@ -0,0 +1,8 @@
+/// What other users can tell about a counterparty from their history.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Reputation {
+    pub completed_sales: i64,
+    pub rating_count: i64,
+    /// `None` until the user has been rated at least once.
+    pub average_score: Option<f64>,
+}
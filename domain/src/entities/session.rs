@@ -0,0 +1,70 @@
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+/// A signed-in device. Every refresh token issued after a login belongs to the same session, so
+/// revoking it logs out the whole token family.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub id: Id<Session>,
+    pub user_id: Id<User>,
+    pub user_agent: Option<String>,
+    /// Address the session was last seen from.
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    pub fn new(user_id: Id<User>, user_agent: Option<String>, ip_address: Option<String>) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Id::gen(),
+            user_id,
+            user_agent,
+            ip_address,
+            created_at: now,
+            last_seen_at: now,
+            revoked_at: None,
+        }
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
+
+/// Only the hash of a refresh token is stored. A token is used once; refreshing marks it used and
+/// issues the next one in the same session.
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+    pub id: Id<RefreshToken>,
+    pub session_id: Id<Session>,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RefreshToken {
+    pub fn new(session_id: Id<Session>, token_hash: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Id::gen(),
+            session_id,
+            token_hash,
+            expires_at,
+            used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used_at.is_some()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::entities::user::Role;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -5,4 +6,9 @@ pub struct TokenClaims {
     pub exp: usize,
     pub sub: String,
     pub username: String,
+    /// Id of the session the token was issued for.
+    pub sid: String,
+    /// Role at the time the token was issued. Route guards check the role stored in the database,
+    /// so a demotion applies before the token expires.
+    pub role: Role,
 }
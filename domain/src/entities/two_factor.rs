@@ -0,0 +1,85 @@
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+/// The TOTP secret of a user. It only protects logins once the user has proven with a first code
+/// that their authenticator app holds it.
+#[derive(Debug, Clone)]
+pub struct TwoFactor {
+    pub user_id: Id<User>,
+    /// Base32 encoded, as shown to authenticator apps.
+    pub secret: String,
+    pub enabled_at: Option<DateTime<Utc>>,
+    /// The last time step a code was accepted for, so a code cannot be replayed.
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TwoFactor {
+    pub fn new(user_id: Id<User>, secret: String) -> Self {
+        Self {
+            user_id,
+            secret,
+            enabled_at: None,
+            last_used_step: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+}
+
+/// A single-use code that stands in for a TOTP code when the authenticator is lost.
+#[derive(Debug, Clone)]
+pub struct RecoveryCode {
+    pub id: Id<RecoveryCode>,
+    pub user_id: Id<User>,
+    pub code_hash: String,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl RecoveryCode {
+    pub fn new(user_id: Id<User>, code_hash: String) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            code_hash,
+            used_at: None,
+        }
+    }
+}
+
+/// Issued by a login whose password matched for a user with two-factor authentication. It is
+/// traded for a session together with a TOTP or recovery code.
+#[derive(Debug, Clone)]
+pub struct LoginChallenge {
+    pub id: Id<LoginChallenge>,
+    pub user_id: Id<User>,
+    pub token_hash: String,
+    pub failed_attempts: i32,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoginChallenge {
+    pub fn new(user_id: Id<User>, token_hash: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            token_hash,
+            failed_attempts: 0,
+            expires_at,
+            used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_usable(&self, max_failed_attempts: i32) -> bool {
+        self.used_at.is_none()
+            && self.expires_at > Utc::now()
+            && self.failed_attempts < max_failed_attempts
+    }
+}
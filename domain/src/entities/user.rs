@@ -1,11 +1,19 @@
 use crate::id::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct User {
     pub id: Id<User>,
     pub name: String,
     pub email: String,
-    pub password: String,
+    /// `None` for users who only ever signed in through an identity provider.
+    pub password: Option<String>,
+    pub email_verified: bool,
+    pub role: Role,
+    pub suspended_at: Option<DateTime<Utc>>,
+    /// Set once the account is deleted, its row stays behind anonymized for bids and sales.
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -14,7 +22,66 @@ impl User {
             id: Id::gen(),
             name,
             email,
-            password,
+            password: Some(password),
+            email_verified: false,
+            role: Role::User,
+            suspended_at: None,
+            deleted_at: None,
+        }
+    }
+
+    /// A user signing up through an identity provider, who has no password.
+    pub fn new_external(name: String, email: String, email_verified: bool) -> Self {
+        Self {
+            id: Id::gen(),
+            name,
+            email,
+            password: None,
+            email_verified,
+            role: Role::User,
+            suspended_at: None,
+            deleted_at: None,
+        }
+    }
+
+    /// Roles are ordered, so an admin also has everything a moderator has.
+    pub fn has_role(&self, role: Role) -> bool {
+        self.role >= role
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    User,
+    Moderator,
+    Admin,
+}
+
+impl From<Role> for String {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::User => "user".to_string(),
+            Role::Moderator => "moderator".to_string(),
+            Role::Admin => "admin".to_string(),
+        }
+    }
+}
+
+impl From<String> for Role {
+    fn from(role: String) -> Self {
+        match role.as_str() {
+            "admin" => Role::Admin,
+            "moderator" => Role::Moderator,
+            _ => Role::User,
         }
     }
 }
@@ -0,0 +1,125 @@
+use crate::entities::auction::Auction;
+use crate::entities::item::Item;
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct UserEvent {
+    pub id: Id<UserEvent>,
+    pub sequence: i64,
+    /// Transaction that recorded the event, feeds follow the order in which these committed.
+    pub xact_id: i64,
+    pub user_id: Id<User>,
+    pub kind: UserEventKind,
+    pub auction_id: Option<Id<Auction>>,
+    pub item_id: Option<Id<Item>>,
+    pub value: Option<f32>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserEvent {
+    /// The sequence and transaction are assigned by the storage once the event is persisted.
+    pub fn new(
+        user_id: Id<User>,
+        kind: UserEventKind,
+        auction_id: Option<Id<Auction>>,
+        item_id: Option<Id<Item>>,
+        value: Option<f32>,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            sequence: 0,
+            xact_id: 0,
+            user_id,
+            kind,
+            auction_id,
+            item_id,
+            value,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn cursor(&self) -> UserEventCursor {
+        UserEventCursor {
+            xact_id: self.xact_id,
+            sequence: self.sequence,
+        }
+    }
+}
+
+/// Position in a user's feed. Sequences are taken before commit, so they alone can be passed by
+/// an event committing late; ordering by transaction first keeps every event after the cursor
+/// it was missing from.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct UserEventCursor {
+    pub xact_id: i64,
+    pub sequence: i64,
+}
+
+impl Display for UserEventCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.xact_id, self.sequence)
+    }
+}
+
+impl FromStr for UserEventCursor {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (xact_id, sequence) = value
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("Invalid user event cursor {}", value))?;
+
+        Ok(UserEventCursor {
+            xact_id: xact_id.parse()?,
+            sequence: sequence.parse()?,
+        })
+    }
+}
+
+/// Events following a cursor that may be delivered already.
+#[derive(Debug, Clone)]
+pub struct UserEventBatch {
+    pub events: Vec<UserEvent>,
+    /// Whether committed events are waiting behind transactions that are still open.
+    pub held_back: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum UserEventKind {
+    Outbid,
+    AuctionWon,
+    ConfirmationRequired,
+    ItemSold,
+    OfferReceived,
+}
+
+impl TryFrom<String> for UserEventKind {
+    type Error = anyhow::Error;
+
+    fn try_from(kind: String) -> Result<Self, Self::Error> {
+        match kind.as_str() {
+            "outbid" => Ok(Self::Outbid),
+            "auction_won" => Ok(Self::AuctionWon),
+            "confirmation_required" => Ok(Self::ConfirmationRequired),
+            "item_sold" => Ok(Self::ItemSold),
+            "offer_received" => Ok(Self::OfferReceived),
+            _ => Err(anyhow::anyhow!("Unknown user event kind {}", kind)),
+        }
+    }
+}
+
+impl From<UserEventKind> for String {
+    fn from(kind: UserEventKind) -> Self {
+        match kind {
+            UserEventKind::Outbid => "outbid".to_string(),
+            UserEventKind::AuctionWon => "auction_won".to_string(),
+            UserEventKind::ConfirmationRequired => "confirmation_required".to_string(),
+            UserEventKind::ItemSold => "item_sold".to_string(),
+            UserEventKind::OfferReceived => "offer_received".to_string(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+
+/// The public part of a user, kept apart from `User` so the avatar is not loaded on every request.
+#[derive(Debug, Clone)]
+pub struct UserProfile {
+    pub user_id: Id<User>,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub location: Option<String>,
+    pub avatar: Option<Vec<u8>>,
+    /// Detected from the avatar when it was uploaded.
+    pub avatar_content_type: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserProfile {
+    /// The profile of a user who never filled it in.
+    pub fn empty(user_id: Id<User>) -> Self {
+        Self {
+            user_id,
+            display_name: None,
+            bio: None,
+            location: None,
+            avatar: None,
+            avatar_content_type: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
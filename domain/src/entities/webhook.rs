@@ -0,0 +1,314 @@
+use crate::entities::auction::{Auction, AuctionWithItem};
+use crate::entities::bid::Bid;
+use crate::entities::item::Item;
+use crate::entities::job::Job;
+use crate::entities::user::User;
+use crate::id::Id;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use std::net::IpAddr;
+
+#[derive(Debug, Clone)]
+pub struct WebhookSubscription {
+    pub id: Id<WebhookSubscription>,
+    pub user_id: Id<User>,
+    pub url: String,
+    pub event_types: Vec<WebhookEventType>,
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub fn new(
+        user_id: Id<User>,
+        url: String,
+        event_types: Vec<WebhookEventType>,
+        secret: String,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            url,
+            event_types,
+            secret,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Whether webhooks may be delivered to `address`. Loopback, private, link-local (which holds
+/// the cloud metadata endpoints) and other internal ranges are off limits, so a subscription
+/// cannot be used to reach services behind the API.
+pub fn is_public_address(address: IpAddr) -> bool {
+    match address {
+        IpAddr::V4(v4) => {
+            let [a, b, ..] = v4.octets();
+            !(v4.is_unspecified()
+                || v4.is_loopback()
+                || v4.is_private()
+                || v4.is_link_local()
+                || v4.is_broadcast()
+                || v4.is_documentation()
+                || v4.is_multicast()
+                || a == 0
+                // shared address space, used for carrier-grade NAT and some metadata services
+                || (a == 100 && (64..128).contains(&b))
+                // reserved for future use
+                || a >= 240)
+        }
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => is_public_address(IpAddr::V4(v4)),
+            None => {
+                let first = v6.segments()[0];
+                !(v6.is_unspecified()
+                    || v6.is_loopback()
+                    || v6.is_multicast()
+                    // unique local
+                    || (first & 0xfe00) == 0xfc00
+                    // link-local
+                    || (first & 0xffc0) == 0xfe80)
+            }
+        },
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WebhookEventType {
+    AuctionCreated,
+    BidPlaced,
+    AuctionFinalized,
+    ItemTransferred,
+}
+
+impl TryFrom<String> for WebhookEventType {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "auction_created" => Ok(WebhookEventType::AuctionCreated),
+            "bid_placed" => Ok(WebhookEventType::BidPlaced),
+            "auction_finalized" => Ok(WebhookEventType::AuctionFinalized),
+            "item_transferred" => Ok(WebhookEventType::ItemTransferred),
+            _ => Err(anyhow::anyhow!("Unknown webhook event type {}", value)),
+        }
+    }
+}
+
+impl From<WebhookEventType> for String {
+    fn from(value: WebhookEventType) -> Self {
+        match value {
+            WebhookEventType::AuctionCreated => "auction_created".to_string(),
+            WebhookEventType::BidPlaced => "bid_placed".to_string(),
+            WebhookEventType::AuctionFinalized => "auction_finalized".to_string(),
+            WebhookEventType::ItemTransferred => "item_transferred".to_string(),
+        }
+    }
+}
+
+/// One delivery attempt of an event to a subscription, kept as the delivery log.
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Id<WebhookDelivery>,
+    pub subscription_id: Id<WebhookSubscription>,
+    pub event_id: String,
+    pub event_type: WebhookEventType,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub succeeded: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    pub fn new(
+        subscription_id: Id<WebhookSubscription>,
+        event_id: String,
+        event_type: WebhookEventType,
+        attempt: i32,
+        status_code: Option<i32>,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            subscription_id,
+            event_id,
+            event_type,
+            attempt,
+            succeeded: error.is_none(),
+            status_code,
+            error,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// An event to be fanned out to the webhook subscriptions of `user_ids`.
+#[derive(Debug, Clone)]
+pub struct WebhookEvent {
+    pub event_type: WebhookEventType,
+    pub user_ids: Vec<Id<User>>,
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    pub const JOB_KIND: &'static str = "webhook_event";
+
+    pub fn auction_created(auction: &Auction, owner_id: Id<User>) -> Self {
+        Self {
+            event_type: WebhookEventType::AuctionCreated,
+            user_ids: vec![owner_id],
+            data: json!({
+                "auction_id": auction.id.to_string(),
+                "item_id": auction.item_id.to_string(),
+                "starting_price": auction.starting_price,
+                "end_date": auction.end_date.timestamp_millis(),
+                "strategy": String::from(auction.strategy.clone()),
+            }),
+        }
+    }
+
+    pub fn bid_placed(auction: &AuctionWithItem, bid: &Bid) -> Self {
+        Self {
+            event_type: WebhookEventType::BidPlaced,
+            user_ids: vec![auction.user_id.clone(), bid.user_id.clone()],
+            data: json!({
+                "auction_id": auction.id.to_string(),
+                "item_id": auction.item_id.to_string(),
+                "bid_id": bid.id.to_string(),
+                "user_id": bid.user_id.to_string(),
+                "value": bid.value,
+            }),
+        }
+    }
+
+    pub fn auction_finalized(auction: &AuctionWithItem, winner: Option<(Id<User>, f32)>) -> Self {
+        let mut user_ids = vec![auction.user_id.clone()];
+        user_ids.extend(winner.iter().map(|(winner_id, _)| winner_id.clone()));
+
+        Self {
+            event_type: WebhookEventType::AuctionFinalized,
+            user_ids,
+            data: json!({
+                "auction_id": auction.id.to_string(),
+                "item_id": auction.item_id.to_string(),
+                "winner_id": winner.as_ref().map(|(winner_id, _)| winner_id.to_string()),
+                "winning_bid": winner.as_ref().map(|(_, value)| *value),
+            }),
+        }
+    }
+
+    pub fn item_transferred(item_id: Id<Item>, from: Id<User>, to: Id<User>) -> Self {
+        Self {
+            event_type: WebhookEventType::ItemTransferred,
+            user_ids: vec![from.clone(), to.clone()],
+            data: json!({
+                "item_id": item_id.to_string(),
+                "from_user_id": from.to_string(),
+                "to_user_id": to.to_string(),
+            }),
+        }
+    }
+
+    pub fn into_job(self) -> Job {
+        Job::new(
+            Self::JOB_KIND,
+            json!({
+                "event_type": String::from(self.event_type),
+                "user_ids": self.user_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>(),
+                "data": self.data,
+            }),
+        )
+    }
+}
+
+impl TryFrom<&Job> for WebhookEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(job: &Job) -> Result<Self, Self::Error> {
+        let event_type = job.payload["event_type"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Webhook event job is missing event_type"))?;
+        let user_ids = job.payload["user_ids"]
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("Webhook event job is missing user_ids"))?
+            .iter()
+            .map(|id| {
+                id.as_str()
+                    .ok_or_else(|| anyhow::anyhow!("Invalid user id in webhook event job"))
+                    .and_then(|id| Id::try_from(id.to_string()))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(WebhookEvent {
+            event_type: WebhookEventType::try_from(event_type.to_string())?,
+            user_ids,
+            data: job.payload["data"].clone(),
+        })
+    }
+}
+
+/// A serialized event addressed to a single subscription; retries resend the same body and event id.
+#[derive(Debug, Clone)]
+pub struct WebhookMessage {
+    pub subscription_id: Id<WebhookSubscription>,
+    pub event_id: String,
+    pub event_type: WebhookEventType,
+    pub body: String,
+}
+
+impl WebhookMessage {
+    pub const JOB_KIND: &'static str = "deliver_webhook";
+
+    pub fn new(
+        subscription_id: Id<WebhookSubscription>,
+        event_id: String,
+        event: &WebhookEvent,
+    ) -> Self {
+        let body = json!({
+            "id": event_id,
+            "type": String::from(event.event_type),
+            "created_at": Utc::now().timestamp_millis(),
+            "data": event.data,
+        })
+        .to_string();
+
+        Self {
+            subscription_id,
+            event_id,
+            event_type: event.event_type,
+            body,
+        }
+    }
+
+    pub fn into_job(self) -> Job {
+        Job::new(
+            Self::JOB_KIND,
+            json!({
+                "subscription_id": self.subscription_id.to_string(),
+                "event_id": self.event_id,
+                "event_type": String::from(self.event_type),
+                "body": self.body,
+            }),
+        )
+    }
+}
+
+impl TryFrom<&Job> for WebhookMessage {
+    type Error = anyhow::Error;
+
+    fn try_from(job: &Job) -> Result<Self, Self::Error> {
+        let field = |name: &str| {
+            job.payload[name]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("Webhook delivery job is missing {}", name))
+        };
+
+        Ok(WebhookMessage {
+            subscription_id: Id::try_from(field("subscription_id")?)?,
+            event_id: field("event_id")?,
+            event_type: WebhookEventType::try_from(field("event_type")?)?,
+            body: field("body")?,
+        })
+    }
+}
@@ -0,0 +1,14 @@
+use crate::entities::account::{AccountDeletion, AccountExport};
+use crate::entities::user::User;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IAccountRepository {
+    async fn find_export(&self, user_id: Id<User>) -> anyhow::Result<Option<AccountExport>>;
+    /// Anonymizes the user and deletes their personal data in one transaction, unless they still
+    /// have an auction that is not finalized or the highest bid on one.
+    async fn delete(&self, user_id: Id<User>) -> anyhow::Result<AccountDeletion>;
+}
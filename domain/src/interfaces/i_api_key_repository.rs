@@ -0,0 +1,17 @@
+use crate::entities::api_key::ApiKey;
+use crate::entities::user::User;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IApiKeyRepository {
+    async fn insert(&self, api_key: ApiKey) -> anyhow::Result<()>;
+    async fn find_by_hash(&self, key_hash: String) -> anyhow::Result<Option<ApiKey>>;
+    /// Keys of the user that are not revoked, expired ones included.
+    async fn find_all_by_user_id(&self, user_id: Id<User>) -> anyhow::Result<Vec<ApiKey>>;
+    /// Returns `false` when the user has no such unrevoked key.
+    async fn revoke(&self, id: Id<ApiKey>, user_id: Id<User>) -> anyhow::Result<bool>;
+    async fn touch(&self, id: Id<ApiKey>) -> anyhow::Result<()>;
+}
@@ -1,6 +1,10 @@
-use crate::entities::auction::{Auction, AuctionWithItem};
+use crate::entities::auction::{Auction, AuctionWithItem, FinalizationPlan};
 use crate::entities::bid::{Bid, BidWithUsername};
 use crate::entities::item::{Category, Item};
+use crate::entities::job::Job;
+use crate::entities::notification::Notification;
+use crate::entities::user::User;
+use crate::entities::user_event::UserEvent;
 use crate::id::Id;
 use async_trait::async_trait;
 use mockall::automock;
@@ -8,7 +12,9 @@ use mockall::automock;
 #[automock]
 #[async_trait]
 pub trait IAuctionRepository {
-    async fn insert(&self, auction: Auction) -> anyhow::Result<Option<Auction>>;
+    /// Inserts the auction and enqueues `jobs` in the same transaction.
+    async fn insert(&self, auction: Auction, jobs: Vec<Job>) -> anyhow::Result<Option<Auction>>;
+    /// Expired auctions that have not been quarantined.
     async fn find_all_expired(&self) -> anyhow::Result<Vec<Auction>>;
     async fn find_by_id(&self, auction_id: Id<Auction>) -> anyhow::Result<Option<AuctionWithItem>>;
     async fn find_ongoing_by_item_id(&self, item_id: Id<Item>) -> anyhow::Result<Option<Auction>>;
@@ -20,11 +26,39 @@ pub trait IAuctionRepository {
         &self,
         category: Option<Category>,
     ) -> anyhow::Result<Vec<AuctionWithItem>>;
+    async fn find_all_ongoing_by_user_id(
+        &self,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Vec<AuctionWithItem>>;
 
-    async fn create_bid(&self, bid: Bid) -> anyhow::Result<Option<Bid>>;
+    /// Inserts the bid, enqueues `jobs` and stores `notifications` and `user_events` in the same
+    /// transaction. Returns `None` without writing anything once the auction has ended or is gone.
+    async fn create_bid(
+        &self,
+        bid: Bid,
+        jobs: Vec<Job>,
+        notifications: Vec<Notification>,
+        user_events: Vec<UserEvent>,
+    ) -> anyhow::Result<Option<Bid>>;
     async fn get_all_bids(&self, auction_id: Id<Auction>) -> anyhow::Result<Vec<BidWithUsername>>;
 
-    async fn delete_auction(&self, auction_id: Id<Auction>) -> anyhow::Result<()>;
+    /// Claims the auction and hands its bids to `plan`, then records the sale and hands the item
+    /// to the new owner when there is one, deletes the bids and the auction itself, enqueues the
+    /// jobs and stores the notifications and user events of the plan, all in one transaction. Returns the bids
+    /// the plan was made from, or `None` without touching anything when the auction is already
+    /// finalized or being finalized or bid on elsewhere.
+    async fn finalize_auction(
+        &self,
+        auction_id: Id<Auction>,
+        plan: FinalizationPlan,
+    ) -> anyhow::Result<Option<Vec<BidWithUsername>>>;
 
-    async fn delete_bids_for_auction(&self, auction_id: Id<Auction>) -> anyhow::Result<()>;
+    /// Counts a failed finalization attempt and quarantines the auction once `max_attempts` is
+    /// reached. Returns whether the auction is quarantined.
+    async fn record_finalization_failure(
+        &self,
+        auction_id: Id<Auction>,
+        error: String,
+        max_attempts: i32,
+    ) -> anyhow::Result<bool>;
 }
@@ -0,0 +1,9 @@
+use crate::entities::audit_event::AuditEvent;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IAuditLogRepository {
+    async fn insert(&self, event: AuditEvent) -> anyhow::Result<()>;
+}
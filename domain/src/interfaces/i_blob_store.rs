@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+/// Keeps file contents such as item images outside the database, under keys of the form
+/// `item-images/<item id>/<image id>`.
+#[automock]
+#[async_trait]
+pub trait IBlobStore {
+    async fn put(&self, key: String, content_type: String, data: Vec<u8>) -> anyhow::Result<()>;
+    async fn get(&self, key: String) -> anyhow::Result<Option<Vec<u8>>>;
+    /// Succeeds when there is nothing stored under `key`.
+    async fn delete(&self, key: String) -> anyhow::Result<()>;
+}
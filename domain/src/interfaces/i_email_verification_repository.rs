@@ -0,0 +1,21 @@
+use crate::entities::email_verification::EmailVerificationToken;
+use crate::entities::job::Job;
+use crate::entities::user::User;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IEmailVerificationRepository {
+    /// Inserts the token and enqueues `jobs` in the same transaction.
+    async fn insert(&self, token: EmailVerificationToken, jobs: Vec<Job>) -> anyhow::Result<()>;
+    async fn find_latest_by_user_id(
+        &self,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Option<EmailVerificationToken>>;
+
+    /// Marks the token used and its user verified in one transaction. Returns `None` when the
+    /// token does not exist, has expired or has already been used.
+    async fn consume(&self, id: Id<EmailVerificationToken>) -> anyhow::Result<Option<Id<User>>>;
+}
@@ -0,0 +1,30 @@
+use crate::entities::external_identity::{AuthorizationRequest, ExternalIdentity};
+use crate::entities::job::Job;
+use crate::entities::user::User;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IExternalIdentityRepository {
+    async fn insert_authorization_request(
+        &self,
+        request: AuthorizationRequest,
+    ) -> anyhow::Result<()>;
+    /// Deletes and returns the unexpired request with `state`, so it can only be used once.
+    async fn take_authorization_request(
+        &self,
+        state: String,
+    ) -> anyhow::Result<Option<AuthorizationRequest>>;
+    async fn find_user(&self, provider: String, subject: String) -> anyhow::Result<Option<User>>;
+    /// Returns `false` when the external account is already linked to a user.
+    async fn insert(&self, identity: ExternalIdentity) -> anyhow::Result<bool>;
+    /// Creates a user signing up through an identity provider, linked to `identity`, and enqueues
+    /// `jobs` in the same transaction.
+    async fn insert_with_user(
+        &self,
+        user: User,
+        identity: ExternalIdentity,
+        jobs: Vec<Job>,
+    ) -> anyhow::Result<()>;
+}
@@ -0,0 +1,24 @@
+use crate::entities::external_identity::ExternalProfile;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IIdentityProvider {
+    /// The name of the provider in URLs and stored identities, e.g. `google`.
+    fn name(&self) -> String;
+    /// The URL the browser is sent to for signing in, asking for a PKCE `code_challenge`.
+    async fn authorization_url(
+        &self,
+        state: String,
+        code_challenge: String,
+        nonce: String,
+    ) -> anyhow::Result<String>;
+    /// Trades the authorization code for the profile of the account that signed in.
+    async fn exchange_code(
+        &self,
+        code: String,
+        code_verifier: String,
+        nonce: String,
+    ) -> anyhow::Result<ExternalProfile>;
+}
@@ -0,0 +1,37 @@
+use crate::entities::item::Item;
+use crate::entities::item_image::ItemImage;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IItemImageRepository {
+    /// Falls back to the image itself when there is no thumbnail of `thumbnail_size`, as for
+    /// images stored before thumbnails were generated.
+    async fn find(
+        &self,
+        item_id: Id<Item>,
+        position: i32,
+        thumbnail_size: Option<i32>,
+    ) -> anyhow::Result<Option<ItemImage>>;
+    /// Adds an image and its thumbnails after the last image and returns their position, `None`
+    /// once the item has `MAX_ITEM_IMAGES`.
+    async fn append(&self, images: Vec<ItemImage>) -> anyhow::Result<Option<i32>>;
+    /// Swaps the first image and its thumbnails, or adds them when the item has none yet. Returns
+    /// the blob keys of the rows replaced.
+    async fn replace_first(
+        &self,
+        item_id: Id<Item>,
+        images: Vec<ItemImage>,
+    ) -> anyhow::Result<Vec<String>>;
+    /// Closes the gap left behind by moving the following images up. Returns the blob keys of the
+    /// image and its thumbnails, none when there is no image at `position`.
+    async fn delete(&self, item_id: Id<Item>, position: i32) -> anyhow::Result<Vec<String>>;
+    /// `order` lists the current positions in their new order, `false` if it does not cover
+    /// every image of the item.
+    async fn reorder(&self, item_id: Id<Item>, order: Vec<i32>) -> anyhow::Result<bool>;
+    /// Data still held in the table, for images saved before the blob store that have not been
+    /// moved out yet.
+    async fn find_legacy_data(&self, id: Id<ItemImage>) -> anyhow::Result<Option<Vec<u8>>>;
+}
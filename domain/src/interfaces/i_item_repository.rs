@@ -1,4 +1,5 @@
 use crate::entities::item::{Category, Item};
+use crate::entities::item_image::ItemImage;
 use crate::entities::user::User;
 use crate::id::Id;
 use async_trait::async_trait;
@@ -7,7 +8,7 @@ use mockall::automock;
 #[automock]
 #[async_trait]
 pub trait IItemRepository {
-    async fn insert(&self, item: Item) -> anyhow::Result<Option<Item>>;
+    async fn insert(&self, item: Item, images: Vec<ItemImage>) -> anyhow::Result<Option<Item>>;
     async fn find(&self, id: Id<Item>) -> anyhow::Result<Option<Item>>;
     async fn get_all_by_user_id(
         &self,
@@ -19,4 +20,10 @@ pub trait IItemRepository {
         item_id: Id<Item>,
         new_owner_id: Id<User>,
     ) -> anyhow::Result<Option<Item>>;
+    /// Leaves items that are deleted, have an auction or no longer belong to `item.user_id`
+    /// untouched and returns `None` for them.
+    async fn update(&self, item: Item) -> anyhow::Result<Option<Item>>;
+    /// Marks the item deleted unless it has an auction or no longer belongs to `user_id`, it stays
+    /// in the table for sales that point at it.
+    async fn soft_delete(&self, id: Id<Item>, user_id: Id<User>) -> anyhow::Result<bool>;
 }
@@ -0,0 +1,20 @@
+use crate::entities::job::Job;
+use crate::id::Id;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IJobRepository {
+    async fn enqueue(&self, job: Job) -> anyhow::Result<Option<Job>>;
+    /// Marks up to `limit` runnable jobs of the given kinds as running and counts the attempt.
+    async fn claim(&self, kinds: Vec<String>, limit: i64) -> anyhow::Result<Vec<Job>>;
+    /// Marks the job done and drops its payload, which may hold secrets such as the link of a
+    /// verification email.
+    async fn complete(&self, id: Id<Job>) -> anyhow::Result<()>;
+    async fn retry(&self, id: Id<Job>, error: String, run_at: DateTime<Utc>) -> anyhow::Result<()>;
+    async fn dead_letter(&self, id: Id<Job>, error: String) -> anyhow::Result<()>;
+    /// Deletes completed and dead jobs created before `before`. Returns how many were deleted.
+    async fn purge_finished(&self, before: DateTime<Utc>) -> anyhow::Result<u64>;
+}
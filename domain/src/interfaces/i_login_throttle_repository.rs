@@ -0,0 +1,19 @@
+use crate::entities::login_throttle::LoginThrottle;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait ILoginThrottleRepository {
+    async fn find_all(&self, keys: Vec<String>) -> anyhow::Result<Vec<LoginThrottle>>;
+    /// Counts a failed attempt for `key`. Earlier failures are forgotten when the last one
+    /// happened before `forget_before`.
+    async fn record_failure(
+        &self,
+        key: String,
+        forget_before: DateTime<Utc>,
+    ) -> anyhow::Result<LoginThrottle>;
+    async fn lock(&self, key: String, locked_until: DateTime<Utc>) -> anyhow::Result<()>;
+    async fn clear(&self, key: String) -> anyhow::Result<()>;
+}
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IMailer {
+    async fn send(&self, to: String, subject: String, body: String) -> anyhow::Result<()>;
+}
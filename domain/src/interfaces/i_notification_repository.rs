@@ -0,0 +1,25 @@
+use crate::entities::notification::Notification;
+use crate::entities::user::User;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait INotificationRepository {
+    async fn insert(&self, notification: Notification) -> anyhow::Result<Option<Notification>>;
+    /// Newest first, starting after the notification `before` when given.
+    async fn find_by_user_id(
+        &self,
+        user_id: Id<User>,
+        before: Option<Id<Notification>>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<Notification>>;
+    async fn mark_read(
+        &self,
+        id: Id<Notification>,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Option<Notification>>;
+    async fn mark_all_read(&self, user_id: Id<User>) -> anyhow::Result<u64>;
+    async fn count_unread(&self, user_id: Id<User>) -> anyhow::Result<i64>;
+}
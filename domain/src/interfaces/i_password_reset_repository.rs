@@ -0,0 +1,25 @@
+use crate::entities::job::Job;
+use crate::entities::password_reset::PasswordResetToken;
+use crate::entities::user::User;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IPasswordResetRepository {
+    /// Inserts the token and enqueues `jobs` in the same transaction.
+    async fn insert(&self, token: PasswordResetToken, jobs: Vec<Job>) -> anyhow::Result<()>;
+    async fn find_latest_by_user_id(
+        &self,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Option<PasswordResetToken>>;
+
+    /// Marks the token used and sets its user's password to `password` in one transaction.
+    /// Returns `None` when the token does not exist, has expired or has already been used.
+    async fn consume(
+        &self,
+        token_hash: String,
+        password: String,
+    ) -> anyhow::Result<Option<Id<User>>>;
+}
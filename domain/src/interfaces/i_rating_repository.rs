@@ -0,0 +1,28 @@
+use crate::entities::auction::Auction;
+use crate::entities::rating::{Rating, RatingWithRater, Sale};
+use crate::entities::reputation::Reputation;
+use crate::entities::user::User;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+use std::collections::HashMap;
+
+#[automock]
+#[async_trait]
+pub trait IRatingRepository {
+    async fn find_sale_by_auction_id(
+        &self,
+        auction_id: Id<Auction>,
+    ) -> anyhow::Result<Option<Sale>>;
+    /// Returns `false` when the rater already rated this sale.
+    async fn insert(&self, rating: Rating) -> anyhow::Result<bool>;
+    async fn find_all_by_ratee_id(
+        &self,
+        ratee_id: Id<User>,
+    ) -> anyhow::Result<Vec<RatingWithRater>>;
+    /// Unknown users are left out of the map.
+    async fn find_reputations(
+        &self,
+        user_ids: Vec<Id<User>>,
+    ) -> anyhow::Result<HashMap<Id<User>, Reputation>>;
+}
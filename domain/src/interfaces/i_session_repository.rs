@@ -0,0 +1,33 @@
+use crate::entities::session::{RefreshToken, Session};
+use crate::entities::user::User;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait ISessionRepository {
+    /// Inserts the session together with its first refresh token.
+    async fn insert(&self, session: Session, refresh_token: RefreshToken) -> anyhow::Result<()>;
+    async fn find_by_id(&self, id: Id<Session>) -> anyhow::Result<Option<Session>>;
+    /// Sessions of the user that have not been revoked, most recently seen first.
+    async fn find_active_by_user_id(&self, user_id: Id<User>) -> anyhow::Result<Vec<Session>>;
+    async fn find_refresh_token(&self, token_hash: String) -> anyhow::Result<Option<RefreshToken>>;
+
+    /// Marks `used` as used and inserts `next` in the same transaction. Returns `false` when
+    /// `used` had already been used, e.g. by a concurrent refresh.
+    async fn rotate_refresh_token(
+        &self,
+        used: Id<RefreshToken>,
+        next: RefreshToken,
+    ) -> anyhow::Result<bool>;
+    async fn revoke(&self, id: Id<Session>) -> anyhow::Result<()>;
+    /// Revokes every session of the user except `except`.
+    async fn revoke_all_for_user(
+        &self,
+        user_id: Id<User>,
+        except: Option<Id<Session>>,
+    ) -> anyhow::Result<()>;
+    /// Records that the session was just used from `ip_address`.
+    async fn touch(&self, id: Id<Session>, ip_address: Option<String>) -> anyhow::Result<()>;
+}
@@ -0,0 +1,33 @@
+use crate::entities::two_factor::{LoginChallenge, RecoveryCode, TwoFactor};
+use crate::entities::user::User;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait ITwoFactorRepository {
+    async fn find_by_user_id(&self, user_id: Id<User>) -> anyhow::Result<Option<TwoFactor>>;
+    /// Stores a new secret awaiting confirmation. Returns `false` without touching anything when
+    /// two-factor authentication is already enabled for the user.
+    async fn insert_pending(&self, two_factor: TwoFactor) -> anyhow::Result<bool>;
+    /// Enables the user's secret and replaces their recovery codes in one transaction.
+    async fn enable(
+        &self,
+        user_id: Id<User>,
+        recovery_codes: Vec<RecoveryCode>,
+    ) -> anyhow::Result<()>;
+    async fn disable(&self, user_id: Id<User>) -> anyhow::Result<()>;
+    /// Remembers that a code of time step `step` was accepted. Returns `false` when a code of the
+    /// same or a later step was accepted before.
+    async fn record_used_step(&self, user_id: Id<User>, step: i64) -> anyhow::Result<bool>;
+    /// Marks the unused recovery code with `code_hash` as used. Returns `false` when there is none.
+    async fn use_recovery_code(&self, user_id: Id<User>, code_hash: String)
+        -> anyhow::Result<bool>;
+
+    async fn insert_challenge(&self, challenge: LoginChallenge) -> anyhow::Result<()>;
+    async fn find_challenge(&self, token_hash: String) -> anyhow::Result<Option<LoginChallenge>>;
+    async fn record_challenge_failure(&self, id: Id<LoginChallenge>) -> anyhow::Result<()>;
+    /// Marks the challenge used. Returns `false` when it was used before.
+    async fn consume_challenge(&self, id: Id<LoginChallenge>) -> anyhow::Result<bool>;
+}
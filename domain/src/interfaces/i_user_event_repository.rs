@@ -0,0 +1,23 @@
+use crate::entities::user::User;
+use crate::entities::user_event::{UserEvent, UserEventBatch, UserEventCursor};
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IUserEventRepository {
+    /// Returns `None` when an equivalent one-off event was already recorded.
+    async fn insert(&self, event: UserEvent) -> anyhow::Result<Option<UserEvent>>;
+    /// Events are only returned once every transaction that started before theirs has ended, so
+    /// nothing can commit behind the last one returned.
+    async fn find_after(
+        &self,
+        user_id: Id<User>,
+        after: UserEventCursor,
+        limit: i64,
+    ) -> anyhow::Result<UserEventBatch>;
+    /// Where a feed starting now begins. Events of transactions still open lie after it, as may
+    /// some that committed just before.
+    async fn find_head(&self) -> anyhow::Result<UserEventCursor>;
+}
@@ -0,0 +1,12 @@
+use crate::entities::user::User;
+use crate::entities::user_profile::UserProfile;
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IUserProfileRepository {
+    async fn find(&self, user_id: Id<User>) -> anyhow::Result<Option<UserProfile>>;
+    async fn upsert(&self, profile: UserProfile) -> anyhow::Result<()>;
+}
@@ -1,3 +1,4 @@
+use crate::entities::job::Job;
 use crate::entities::user::User;
 use crate::id::Id;
 use async_trait::async_trait;
@@ -9,5 +10,10 @@ pub trait IUserRepository {
     async fn find(&self, id: Id<User>) -> anyhow::Result<Option<User>>;
     async fn find_by_email(&self, email: String) -> anyhow::Result<Option<User>>;
     async fn find_by_username(&self, username: String) -> anyhow::Result<Option<User>>;
-    async fn insert(&self, user: User) -> anyhow::Result<Option<User>>;
+    /// Inserts the user and enqueues `jobs` in the same transaction.
+    async fn insert(&self, user: User, jobs: Vec<Job>) -> anyhow::Result<Option<User>>;
+    async fn update_password(&self, id: Id<User>, password: String) -> anyhow::Result<()>;
+    async fn find_all(&self) -> anyhow::Result<Vec<User>>;
+    /// Returns `false` when the user does not exist or is already suspended.
+    async fn suspend(&self, id: Id<User>) -> anyhow::Result<bool>;
 }
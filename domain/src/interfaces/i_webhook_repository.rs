@@ -0,0 +1,39 @@
+use crate::entities::user::User;
+use crate::entities::webhook::{WebhookDelivery, WebhookEventType, WebhookSubscription};
+use crate::id::Id;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IWebhookRepository {
+    async fn insert(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> anyhow::Result<Option<WebhookSubscription>>;
+    async fn find_by_id(
+        &self,
+        id: Id<WebhookSubscription>,
+    ) -> anyhow::Result<Option<WebhookSubscription>>;
+    async fn find_all_by_user_id(
+        &self,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Vec<WebhookSubscription>>;
+    /// Subscriptions of any of `user_ids` that include `event_type`.
+    async fn find_subscribed(
+        &self,
+        user_ids: Vec<Id<User>>,
+        event_type: WebhookEventType,
+    ) -> anyhow::Result<Vec<WebhookSubscription>>;
+    async fn delete(&self, id: Id<WebhookSubscription>, user_id: Id<User>) -> anyhow::Result<bool>;
+
+    async fn insert_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> anyhow::Result<Option<WebhookDelivery>>;
+    async fn find_deliveries(
+        &self,
+        subscription_id: Id<WebhookSubscription>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<WebhookDelivery>>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait IWebhookSender {
+    /// Posts `body` to `url` and returns the response status code.
+    async fn send(
+        &self,
+        url: String,
+        headers: Vec<(String, String)>,
+        body: String,
+    ) -> anyhow::Result<u16>;
+}
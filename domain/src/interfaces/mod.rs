@@ -1,3 +1,23 @@
+pub mod i_account_repository;
+pub mod i_api_key_repository;
 pub mod i_auction_repository;
+pub mod i_audit_log_repository;
+pub mod i_blob_store;
+pub mod i_email_verification_repository;
+pub mod i_external_identity_repository;
+pub mod i_identity_provider;
+pub mod i_item_image_repository;
 pub mod i_item_repository;
+pub mod i_job_repository;
+pub mod i_login_throttle_repository;
+pub mod i_mailer;
+pub mod i_notification_repository;
+pub mod i_password_reset_repository;
+pub mod i_rating_repository;
+pub mod i_session_repository;
+pub mod i_two_factor_repository;
+pub mod i_user_event_repository;
+pub mod i_user_profile_repository;
 pub mod i_user_repository;
+pub mod i_webhook_repository;
+pub mod i_webhook_sender;
@@ -0,0 +1,43 @@
+//! Moves item images stored in the database before the blob store existed into it, configured
+//! through the same variables as the API: `S3_ENDPOINT`, `S3_BUCKET`, `S3_REGION`,
+//! `S3_ACCESS_KEY` and `S3_SECRET_KEY`, or `BLOB_DIR` without an S3 endpoint.
+//!
+//! `DATABASE_URL=<DSN> cargo run -p infrastructure --bin migrate_blobs`
+
+use infrastructure::blob_stores::file_blob_store::FileBlobStore;
+use infrastructure::blob_stores::migration::move_item_images_out;
+use infrastructure::blob_stores::s3_blob_store::S3BlobStore;
+use infrastructure::blob_stores::BlobStore;
+use sqlx::PgPool;
+use std::env;
+use std::path::PathBuf;
+
+fn required(name: &str) -> String {
+    env::var(name).unwrap_or_else(|_| panic!("You need to set {}!", name))
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> anyhow::Result<()> {
+    let pool = PgPool::connect(&required("DATABASE_URL")).await?;
+
+    let blob_store = match env::var("S3_ENDPOINT").ok().filter(|e| !e.is_empty()) {
+        Some(endpoint) => BlobStore::S3(S3BlobStore::new(
+            endpoint,
+            required("S3_BUCKET"),
+            env::var("S3_REGION").unwrap_or("us-east-1".to_string()),
+            required("S3_ACCESS_KEY"),
+            required("S3_SECRET_KEY"),
+        )),
+        None => BlobStore::File(FileBlobStore::new(PathBuf::from(
+            env::var("BLOB_DIR")
+                .ok()
+                .filter(|blob_dir| !blob_dir.is_empty())
+                .expect("You need to set S3_ENDPOINT or BLOB_DIR!"),
+        ))),
+    };
+
+    let moved = move_item_images_out(&pool, &blob_store).await?;
+    println!("Moved {} item images to the blob store", moved);
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::interfaces::i_blob_store::IBlobStore;
+use log::error;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+/// Keeps blobs as files below a directory, each key being a relative path. Meant for local
+/// development and single-instance deployments with a persistent disk.
+pub struct FileBlobStore {
+    directory: PathBuf,
+}
+
+impl FileBlobStore {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    fn path(&self, key: &str) -> anyhow::Result<PathBuf> {
+        let relative = Path::new(key);
+        // keys are generated, but nothing outside the directory should ever be reachable
+        if !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(anyhow!("Invalid blob key: {}", key));
+        }
+
+        Ok(self.directory.join(relative))
+    }
+}
+
+#[async_trait]
+impl IBlobStore for FileBlobStore {
+    async fn put(&self, key: String, _content_type: String, data: Vec<u8>) -> anyhow::Result<()> {
+        let path = self.path(&key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| anyhow!("{:?}", e))?;
+        }
+
+        // readers never see a half written file
+        let partial = path.with_extension("partial");
+        tokio::fs::write(&partial, data).await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+        tokio::fs::rename(&partial, &path).await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: String) -> anyhow::Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.path(&key)?).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => {
+                error!("{:?}", e);
+                Err(anyhow!("{:?}", e))
+            }
+        }
+    }
+
+    async fn delete(&self, key: String) -> anyhow::Result<()> {
+        match tokio::fs::remove_file(self.path(&key)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                error!("{:?}", e);
+                Err(anyhow!("{:?}", e))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(name: &str) -> FileBlobStore {
+        FileBlobStore::new(std::env::temp_dir().join(format!(
+            "blobs-{}-{}",
+            name,
+            std::process::id()
+        )))
+    }
+
+    #[tokio::test]
+    async fn given_stored_blob_when_getting_and_deleting_then_it_round_trips() {
+        // Arrange
+        let store = store("round-trip");
+        let key = "item-images/item/image".to_string();
+
+        // Act
+        store
+            .put(key.clone(), "image/png".to_string(), vec![1, 2, 3])
+            .await
+            .unwrap();
+        let stored = store.get(key.clone()).await.unwrap();
+        store.delete(key.clone()).await.unwrap();
+
+        // Assert
+        assert_eq!(stored, Some(vec![1, 2, 3]));
+        assert_eq!(store.get(key.clone()).await.unwrap(), None);
+        assert!(store.delete(key).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_key_leaving_the_directory_when_putting_then_it_is_refused() {
+        // Act
+        let result = store("escape")
+            .put("../escaped".to_string(), "image/png".to_string(), vec![1])
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::anyhow;
+use domain::interfaces::i_blob_store::IBlobStore;
+use log::{error, info};
+use sqlx::types::Uuid;
+use sqlx::{FromRow, PgPool};
+
+const BATCH_SIZE: i64 = 50;
+
+#[derive(FromRow)]
+struct StoredImageModel {
+    id: Uuid,
+    blob_key: String,
+    content_type: String,
+    data: Vec<u8>,
+}
+
+/// Moves image data still held in `item_images` to the blob store and returns how many images
+/// were moved. Each row is cleared once its blob is stored, so the move can be interrupted and
+/// run again, also while the API is up.
+pub async fn move_item_images_out<B: IBlobStore>(
+    pool: &PgPool,
+    blob_store: &B,
+) -> anyhow::Result<u64> {
+    let mut moved = 0;
+
+    loop {
+        let images = sqlx::query_as::<_, StoredImageModel>(
+            "SELECT id, blob_key, content_type, data FROM item_images \
+            WHERE data IS NOT NULL \
+            LIMIT $1",
+        )
+        .bind(BATCH_SIZE)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        if images.is_empty() {
+            return Ok(moved);
+        }
+
+        for image in images {
+            blob_store
+                .put(image.blob_key.clone(), image.content_type, image.data)
+                .await?;
+
+            let result = sqlx::query("UPDATE item_images SET data = NULL WHERE id = $1")
+                .bind(image.id)
+                .execute(pool)
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+
+            match result.rows_affected() {
+                // the image was deleted in the meantime
+                0 => blob_store.delete(image.blob_key).await?,
+                _ => moved += 1,
+            }
+        }
+
+        info!("Moved {} item images to the blob store", moved);
+    }
+}
@@ -0,0 +1,38 @@
+pub mod file_blob_store;
+pub mod migration;
+pub mod s3_blob_store;
+
+use crate::blob_stores::file_blob_store::FileBlobStore;
+use crate::blob_stores::s3_blob_store::S3BlobStore;
+use async_trait::async_trait;
+use domain::interfaces::i_blob_store::IBlobStore;
+
+/// The blob store backend picked from configuration at startup.
+pub enum BlobStore {
+    S3(S3BlobStore),
+    File(FileBlobStore),
+}
+
+#[async_trait]
+impl IBlobStore for BlobStore {
+    async fn put(&self, key: String, content_type: String, data: Vec<u8>) -> anyhow::Result<()> {
+        match self {
+            BlobStore::S3(store) => store.put(key, content_type, data).await,
+            BlobStore::File(store) => store.put(key, content_type, data).await,
+        }
+    }
+
+    async fn get(&self, key: String) -> anyhow::Result<Option<Vec<u8>>> {
+        match self {
+            BlobStore::S3(store) => store.get(key).await,
+            BlobStore::File(store) => store.get(key).await,
+        }
+    }
+
+    async fn delete(&self, key: String) -> anyhow::Result<()> {
+        match self {
+            BlobStore::S3(store) => store.delete(key).await,
+            BlobStore::File(store) => store.delete(key).await,
+        }
+    }
+}
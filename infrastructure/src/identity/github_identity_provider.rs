@@ -0,0 +1,145 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::external_identity::ExternalProfile;
+use domain::interfaces::i_identity_provider::IIdentityProvider;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const AUTHORIZE_URL: &str = "https://github.com/login/oauth/authorize";
+const TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
+const API_URL: &str = "https://api.github.com";
+const SCOPES: &str = "read:user user:email";
+
+#[derive(Deserialize, Debug)]
+struct TokenResponse {
+    access_token: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GithubUser {
+    id: u64,
+    login: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct GithubEmail {
+    email: String,
+    primary: bool,
+    verified: bool,
+}
+
+/// Sign in with GitHub, which speaks plain OAuth 2.0 rather than OpenID Connect. The profile is
+/// read from its API, so there is no ID token and the nonce goes unused.
+pub struct GithubIdentityProvider {
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+    client: reqwest::Client,
+}
+
+impl GithubIdentityProvider {
+    pub fn new(client_id: String, client_secret: String, redirect_uri: String) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent("RainbowBid")
+            .build()
+            .expect("Failed to build GitHub HTTP client");
+
+        Self {
+            client_id,
+            client_secret,
+            redirect_uri,
+            client,
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str, access_token: &str) -> anyhow::Result<T> {
+        let body = self
+            .client
+            .get(format!("{}{}", API_URL, path))
+            .bearer_auth(access_token)
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| anyhow!("GitHub request to {} failed: {:?}", path, e))?
+            .text()
+            .await?;
+
+        Ok(serde_json::from_str(&body)?)
+    }
+}
+
+#[async_trait]
+impl IIdentityProvider for GithubIdentityProvider {
+    fn name(&self) -> String {
+        "github".to_string()
+    }
+
+    async fn authorization_url(
+        &self,
+        state: String,
+        code_challenge: String,
+        _nonce: String,
+    ) -> anyhow::Result<String> {
+        let url = reqwest::Url::parse_with_params(
+            AUTHORIZE_URL,
+            &[
+                ("client_id", self.client_id.as_str()),
+                ("redirect_uri", self.redirect_uri.as_str()),
+                ("scope", SCOPES),
+                ("state", state.as_str()),
+                ("code_challenge", code_challenge.as_str()),
+                ("code_challenge_method", "S256"),
+            ],
+        )?;
+
+        Ok(url.to_string())
+    }
+
+    async fn exchange_code(
+        &self,
+        code: String,
+        code_verifier: String,
+        _nonce: String,
+    ) -> anyhow::Result<ExternalProfile> {
+        let body = self
+            .client
+            .post(TOKEN_URL)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("code", code.as_str()),
+                ("redirect_uri", self.redirect_uri.as_str()),
+                ("code_verifier", code_verifier.as_str()),
+            ])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| anyhow!("GitHub token request failed: {:?}", e))?
+            .text()
+            .await?;
+        // GitHub answers a bad code with 200 and an error in the body
+        let token = serde_json::from_str::<TokenResponse>(&body)?;
+        let access_token = token
+            .access_token
+            .ok_or_else(|| anyhow!("GitHub refused the code: {:?}", token.error))?;
+
+        let user = self.get::<GithubUser>("/user", &access_token).await?;
+        let emails = self
+            .get::<Vec<GithubEmail>>("/user/emails", &access_token)
+            .await?;
+        let primary_email = emails.into_iter().find(|email| email.primary);
+
+        Ok(ExternalProfile {
+            subject: user.id.to_string(),
+            email_verified: primary_email.as_ref().is_some_and(|email| email.verified),
+            email: primary_email.map(|email| email.email),
+            name: Some(user.login),
+        })
+    }
+}
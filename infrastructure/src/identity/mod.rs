@@ -0,0 +1,60 @@
+pub mod github_identity_provider;
+pub mod oidc_identity_provider;
+
+use crate::identity::github_identity_provider::GithubIdentityProvider;
+use crate::identity::oidc_identity_provider::OidcIdentityProvider;
+use async_trait::async_trait;
+use domain::entities::external_identity::ExternalProfile;
+use domain::interfaces::i_identity_provider::IIdentityProvider;
+
+/// An identity provider configured at startup.
+pub enum IdentityProvider {
+    Oidc(OidcIdentityProvider),
+    Github(GithubIdentityProvider),
+}
+
+#[async_trait]
+impl IIdentityProvider for IdentityProvider {
+    fn name(&self) -> String {
+        match self {
+            IdentityProvider::Oidc(provider) => provider.name(),
+            IdentityProvider::Github(provider) => provider.name(),
+        }
+    }
+
+    async fn authorization_url(
+        &self,
+        state: String,
+        code_challenge: String,
+        nonce: String,
+    ) -> anyhow::Result<String> {
+        match self {
+            IdentityProvider::Oidc(provider) => {
+                provider
+                    .authorization_url(state, code_challenge, nonce)
+                    .await
+            }
+            IdentityProvider::Github(provider) => {
+                provider
+                    .authorization_url(state, code_challenge, nonce)
+                    .await
+            }
+        }
+    }
+
+    async fn exchange_code(
+        &self,
+        code: String,
+        code_verifier: String,
+        nonce: String,
+    ) -> anyhow::Result<ExternalProfile> {
+        match self {
+            IdentityProvider::Oidc(provider) => {
+                provider.exchange_code(code, code_verifier, nonce).await
+            }
+            IdentityProvider::Github(provider) => {
+                provider.exchange_code(code, code_verifier, nonce).await
+            }
+        }
+    }
+}
@@ -1,3 +1,7 @@
+pub mod blob_stores;
 pub(crate) mod db;
+pub mod identity;
+pub mod mailers;
 pub(crate) mod models;
 pub mod repositories;
+pub mod webhooks;
@@ -0,0 +1,47 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::Utc;
+use domain::interfaces::i_mailer::IMailer;
+use log::error;
+use std::path::PathBuf;
+
+/// Writes emails to `.eml` files in a directory, or to stdout when no directory is set.
+/// Meant for local development and tests.
+pub struct FileMailer {
+    directory: Option<PathBuf>,
+}
+
+impl FileMailer {
+    pub fn new(directory: Option<PathBuf>) -> Self {
+        Self { directory }
+    }
+}
+
+#[async_trait]
+impl IMailer for FileMailer {
+    async fn send(&self, to: String, subject: String, body: String) -> anyhow::Result<()> {
+        let message = format!("To: {}\nSubject: {}\n\n{}\n", to, subject, body);
+
+        match &self.directory {
+            Some(directory) => {
+                let file_name = format!(
+                    "{}-{}.eml",
+                    Utc::now().format("%Y%m%d%H%M%S%f"),
+                    to.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+                );
+                tokio::fs::create_dir_all(directory)
+                    .await
+                    .map_err(|e| anyhow!("{:?}", e))?;
+                tokio::fs::write(directory.join(file_name), message)
+                    .await
+                    .map_err(|e| {
+                        error!("{:?}", e);
+                        anyhow!("{:?}", e)
+                    })?;
+            }
+            None => println!("{}", message),
+        }
+
+        Ok(())
+    }
+}
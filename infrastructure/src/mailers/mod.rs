@@ -0,0 +1,23 @@
+pub mod file_mailer;
+pub mod smtp_mailer;
+
+use crate::mailers::file_mailer::FileMailer;
+use crate::mailers::smtp_mailer::SmtpMailer;
+use async_trait::async_trait;
+use domain::interfaces::i_mailer::IMailer;
+
+/// The mailer backend picked from configuration at startup.
+pub enum Mailer {
+    Smtp(SmtpMailer),
+    File(FileMailer),
+}
+
+#[async_trait]
+impl IMailer for Mailer {
+    async fn send(&self, to: String, subject: String, body: String) -> anyhow::Result<()> {
+        match self {
+            Mailer::Smtp(mailer) => mailer.send(to, subject, body).await,
+            Mailer::File(mailer) => mailer.send(to, subject, body).await,
+        }
+    }
+}
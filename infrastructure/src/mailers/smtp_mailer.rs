@@ -0,0 +1,43 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::interfaces::i_mailer::IMailer;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use log::error;
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(host: &str, username: String, password: String, from: &str) -> anyhow::Result<Self> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::relay(host)
+            .map_err(|e| anyhow!("{:?}", e))?
+            .credentials(Credentials::new(username, password))
+            .build();
+        let from = from.parse().map_err(|e| anyhow!("{:?}", e))?;
+
+        Ok(Self { transport, from })
+    }
+}
+
+#[async_trait]
+impl IMailer for SmtpMailer {
+    async fn send(&self, to: String, subject: String, body: String) -> anyhow::Result<()> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to.parse().map_err(|e| anyhow!("{:?}", e))?)
+            .subject(subject)
+            .body(body)
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        self.transport.send(message).await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+}
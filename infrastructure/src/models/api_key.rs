@@ -0,0 +1,95 @@
+use domain::entities::api_key::{ApiKey, ApiKeyScope};
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct ApiKeyModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub last_used_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub revoked_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<ApiKeyModel> for ApiKey {
+    type Error = anyhow::Error;
+
+    fn try_from(api_key_table: ApiKeyModel) -> Result<Self, Self::Error> {
+        Ok(ApiKey {
+            id: api_key_table.id.to_string().try_into()?,
+            user_id: api_key_table.user_id.to_string().try_into()?,
+            name: api_key_table.name,
+            prefix: api_key_table.prefix,
+            key_hash: api_key_table.key_hash,
+            scopes: api_key_table
+                .scopes
+                .into_iter()
+                .map(ApiKeyScope::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            expires_at: api_key_table.expires_at.map(|expires_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    expires_at.naive_utc(),
+                    expires_at.offset().to_owned(),
+                )
+            }),
+            last_used_at: api_key_table.last_used_at.map(|last_used_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    last_used_at.naive_utc(),
+                    last_used_at.offset().to_owned(),
+                )
+            }),
+            revoked_at: api_key_table.revoked_at.map(|revoked_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    revoked_at.naive_utc(),
+                    revoked_at.offset().to_owned(),
+                )
+            }),
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                api_key_table.created_at.naive_utc(),
+                api_key_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<ApiKey> for ApiKeyModel {
+    type Error = anyhow::Error;
+
+    fn try_from(api_key: ApiKey) -> Result<Self, Self::Error> {
+        Ok(ApiKeyModel {
+            id: Uuid::parse_str(&api_key.id.to_string())?,
+            user_id: Uuid::parse_str(&api_key.user_id.to_string())?,
+            name: api_key.name,
+            prefix: api_key.prefix,
+            key_hash: api_key.key_hash,
+            scopes: api_key.scopes.into_iter().map(String::from).collect(),
+            expires_at: api_key.expires_at.map(|expires_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    expires_at.naive_utc(),
+                    expires_at.offset().to_owned(),
+                )
+            }),
+            last_used_at: api_key.last_used_at.map(|last_used_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    last_used_at.naive_utc(),
+                    last_used_at.offset().to_owned(),
+                )
+            }),
+            revoked_at: api_key.revoked_at.map(|revoked_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    revoked_at.naive_utc(),
+                    revoked_at.offset().to_owned(),
+                )
+            }),
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                api_key.created_at.naive_utc(),
+                api_key.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use domain::entities::audit_event::AuditEvent;
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct AuditEventModel {
+    pub id: Uuid,
+    pub kind: String,
+    pub user_id: Option<Uuid>,
+    pub email: Option<String>,
+    pub ip_address: Option<String>,
+    pub details: Option<String>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<AuditEvent> for AuditEventModel {
+    type Error = anyhow::Error;
+
+    fn try_from(event: AuditEvent) -> Result<Self, Self::Error> {
+        Ok(AuditEventModel {
+            id: Uuid::parse_str(&event.id.to_string())?,
+            kind: event.kind.into(),
+            user_id: event
+                .user_id
+                .map(|user_id| Uuid::parse_str(&user_id.to_string()))
+                .transpose()?,
+            email: event.email,
+            ip_address: event.ip_address,
+            details: event.details,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                event.created_at.naive_utc(),
+                event.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
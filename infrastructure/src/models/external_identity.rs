@@ -0,0 +1,110 @@
+use domain::entities::external_identity::{AuthorizationRequest, ExternalIdentity};
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct ExternalIdentityModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<ExternalIdentityModel> for ExternalIdentity {
+    type Error = anyhow::Error;
+
+    fn try_from(identity_table: ExternalIdentityModel) -> Result<Self, Self::Error> {
+        Ok(ExternalIdentity {
+            id: identity_table.id.to_string().try_into()?,
+            user_id: identity_table.user_id.to_string().try_into()?,
+            provider: identity_table.provider,
+            subject: identity_table.subject,
+            email: identity_table.email,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                identity_table.created_at.naive_utc(),
+                identity_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<ExternalIdentity> for ExternalIdentityModel {
+    type Error = anyhow::Error;
+
+    fn try_from(identity: ExternalIdentity) -> Result<Self, Self::Error> {
+        Ok(ExternalIdentityModel {
+            id: Uuid::parse_str(&identity.id.to_string())?,
+            user_id: Uuid::parse_str(&identity.user_id.to_string())?,
+            provider: identity.provider,
+            subject: identity.subject,
+            email: identity.email,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                identity.created_at.naive_utc(),
+                identity.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct AuthorizationRequestModel {
+    pub state: String,
+    pub provider: String,
+    pub code_verifier: String,
+    pub nonce: String,
+    pub link_user_id: Option<Uuid>,
+    pub expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<AuthorizationRequestModel> for AuthorizationRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(request_table: AuthorizationRequestModel) -> Result<Self, Self::Error> {
+        Ok(AuthorizationRequest {
+            state: request_table.state,
+            provider: request_table.provider,
+            code_verifier: request_table.code_verifier,
+            nonce: request_table.nonce,
+            link_user_id: request_table
+                .link_user_id
+                .map(|id| id.to_string().try_into())
+                .transpose()?,
+            expires_at: chrono::DateTime::from_naive_utc_and_offset(
+                request_table.expires_at.naive_utc(),
+                request_table.expires_at.offset().to_owned(),
+            ),
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                request_table.created_at.naive_utc(),
+                request_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<AuthorizationRequest> for AuthorizationRequestModel {
+    type Error = anyhow::Error;
+
+    fn try_from(request: AuthorizationRequest) -> Result<Self, Self::Error> {
+        Ok(AuthorizationRequestModel {
+            state: request.state,
+            provider: request.provider,
+            code_verifier: request.code_verifier,
+            nonce: request.nonce,
+            link_user_id: request
+                .link_user_id
+                .map(|id| Uuid::parse_str(&id.to_string()))
+                .transpose()?,
+            expires_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                request.expires_at.naive_utc(),
+                request.expires_at.offset().to_owned(),
+            ),
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                request.created_at.naive_utc(),
+                request.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
@@ -7,7 +7,6 @@ pub struct ItemModel {
     pub id: Uuid,
     pub brief: String,
     pub description: String,
-    pub picture: Vec<u8>,
     pub user_id: Uuid,
     pub category: String,
 }
@@ -20,7 +19,6 @@ impl TryFrom<ItemModel> for Item {
             id: item_table.id.to_string().try_into()?,
             brief: item_table.brief,
             description: item_table.description,
-            picture: item_table.picture,
             user_id: item_table.user_id.to_string().try_into()?,
             category: item_table.category.into(),
         })
@@ -35,7 +33,6 @@ impl TryFrom<Item> for ItemModel {
             id: Uuid::parse_str(&item.id.to_string())?,
             brief: item.brief,
             description: item.description,
-            picture: item.picture,
             user_id: Uuid::parse_str(&item.user_id.to_string())?,
             category: item.category.into(),
         })
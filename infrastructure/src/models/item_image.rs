@@ -0,0 +1,56 @@
+use domain::entities::item_image::ItemImage;
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct ItemImageModel {
+    pub id: Uuid,
+    pub item_id: Uuid,
+    pub position: i32,
+    /// 0 for the uploaded image itself.
+    pub thumbnail_size: i32,
+    pub content_type: String,
+    pub blob_key: String,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<ItemImageModel> for ItemImage {
+    type Error = anyhow::Error;
+
+    fn try_from(image_table: ItemImageModel) -> Result<Self, Self::Error> {
+        Ok(ItemImage {
+            id: image_table.id.to_string().try_into()?,
+            item_id: image_table.item_id.to_string().try_into()?,
+            position: image_table.position,
+            thumbnail_size: match image_table.thumbnail_size {
+                0 => None,
+                size => Some(size),
+            },
+            content_type: image_table.content_type,
+            blob_key: image_table.blob_key,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                image_table.created_at.naive_utc(),
+                image_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<ItemImage> for ItemImageModel {
+    type Error = anyhow::Error;
+
+    fn try_from(image: ItemImage) -> Result<Self, Self::Error> {
+        Ok(ItemImageModel {
+            id: Uuid::parse_str(&image.id.to_string())?,
+            item_id: Uuid::parse_str(&image.item_id.to_string())?,
+            position: image.position,
+            thumbnail_size: image.thumbnail_size.unwrap_or(0),
+            content_type: image.content_type,
+            blob_key: image.blob_key,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                image.created_at.naive_utc(),
+                image.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
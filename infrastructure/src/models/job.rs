@@ -0,0 +1,66 @@
+use domain::entities::job::{Job, JobStatus};
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct JobModel {
+    pub id: Uuid,
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub locked_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<JobModel> for Job {
+    type Error = anyhow::Error;
+
+    fn try_from(job_table: JobModel) -> Result<Self, Self::Error> {
+        Ok(Job {
+            id: job_table.id.to_string().try_into()?,
+            kind: job_table.kind,
+            payload: job_table.payload,
+            status: JobStatus::from(job_table.status),
+            attempts: job_table.attempts,
+            max_attempts: job_table.max_attempts,
+            run_at: chrono::DateTime::from_naive_utc_and_offset(
+                job_table.run_at.naive_utc(),
+                job_table.run_at.offset().to_owned(),
+            ),
+            last_error: job_table.last_error,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                job_table.created_at.naive_utc(),
+                job_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<Job> for JobModel {
+    type Error = anyhow::Error;
+
+    fn try_from(job: Job) -> Result<Self, Self::Error> {
+        Ok(JobModel {
+            id: Uuid::parse_str(&job.id.to_string())?,
+            kind: job.kind,
+            payload: job.payload,
+            status: String::from(job.status),
+            attempts: job.attempts,
+            max_attempts: job.max_attempts,
+            run_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                job.run_at.naive_utc(),
+                job.run_at.offset().to_owned(),
+            ),
+            locked_at: None,
+            last_error: job.last_error,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                job.created_at.naive_utc(),
+                job.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
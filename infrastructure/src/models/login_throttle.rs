@@ -0,0 +1,31 @@
+use domain::entities::login_throttle::LoginThrottle;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct LoginThrottleModel {
+    pub key: String,
+    pub failed_attempts: i32,
+    pub locked_until: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub last_failed_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<LoginThrottleModel> for LoginThrottle {
+    type Error = anyhow::Error;
+
+    fn try_from(throttle_table: LoginThrottleModel) -> Result<Self, Self::Error> {
+        Ok(LoginThrottle {
+            key: throttle_table.key,
+            failed_attempts: throttle_table.failed_attempts,
+            locked_until: throttle_table.locked_until.map(|locked_until| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    locked_until.naive_utc(),
+                    locked_until.offset().to_owned(),
+                )
+            }),
+            last_failed_at: chrono::DateTime::from_naive_utc_and_offset(
+                throttle_table.last_failed_at.naive_utc(),
+                throttle_table.last_failed_at.offset().to_owned(),
+            ),
+        })
+    }
+}
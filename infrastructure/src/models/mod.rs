@@ -1,4 +1,19 @@
+pub(crate) mod api_key;
 pub(crate) mod auction;
+pub(crate) mod audit_event;
 pub(crate) mod bid;
+pub(crate) mod email_verification;
+pub(crate) mod external_identity;
 pub(crate) mod item;
+pub(crate) mod item_image;
+pub(crate) mod job;
+pub(crate) mod login_throttle;
+pub(crate) mod notification;
+pub(crate) mod password_reset;
+pub(crate) mod rating;
+pub(crate) mod session;
+pub(crate) mod two_factor;
 pub(crate) mod user;
+pub(crate) mod user_event;
+pub(crate) mod user_profile;
+pub(crate) mod webhook;
@@ -0,0 +1,67 @@
+use domain::entities::notification::{Notification, NotificationKind};
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct NotificationModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: String,
+    pub message: String,
+    pub auction_id: Option<Uuid>,
+    pub item_id: Option<Uuid>,
+    pub is_read: bool,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<NotificationModel> for Notification {
+    type Error = anyhow::Error;
+
+    fn try_from(notification_table: NotificationModel) -> Result<Self, Self::Error> {
+        Ok(Notification {
+            id: notification_table.id.to_string().try_into()?,
+            user_id: notification_table.user_id.to_string().try_into()?,
+            kind: NotificationKind::try_from(notification_table.kind)?,
+            message: notification_table.message,
+            auction_id: notification_table
+                .auction_id
+                .map(|id| id.to_string().try_into())
+                .transpose()?,
+            item_id: notification_table
+                .item_id
+                .map(|id| id.to_string().try_into())
+                .transpose()?,
+            is_read: notification_table.is_read,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                notification_table.created_at.naive_utc(),
+                notification_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<Notification> for NotificationModel {
+    type Error = anyhow::Error;
+
+    fn try_from(notification: Notification) -> Result<Self, Self::Error> {
+        Ok(NotificationModel {
+            id: Uuid::parse_str(&notification.id.to_string())?,
+            user_id: Uuid::parse_str(&notification.user_id.to_string())?,
+            kind: String::from(notification.kind),
+            message: notification.message,
+            auction_id: notification
+                .auction_id
+                .map(|id| Uuid::parse_str(&id.to_string()))
+                .transpose()?,
+            item_id: notification
+                .item_id
+                .map(|id| Uuid::parse_str(&id.to_string()))
+                .transpose()?,
+            is_read: notification.is_read,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                notification.created_at.naive_utc(),
+                notification.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use domain::entities::password_reset::PasswordResetToken;
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct PasswordResetTokenModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub used_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<PasswordResetTokenModel> for PasswordResetToken {
+    type Error = anyhow::Error;
+
+    fn try_from(token_table: PasswordResetTokenModel) -> Result<Self, Self::Error> {
+        Ok(PasswordResetToken {
+            id: token_table.id.to_string().try_into()?,
+            user_id: token_table.user_id.to_string().try_into()?,
+            token_hash: token_table.token_hash,
+            expires_at: chrono::DateTime::from_naive_utc_and_offset(
+                token_table.expires_at.naive_utc(),
+                token_table.expires_at.offset().to_owned(),
+            ),
+            used_at: token_table.used_at.map(|used_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    used_at.naive_utc(),
+                    used_at.offset().to_owned(),
+                )
+            }),
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                token_table.created_at.naive_utc(),
+                token_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<PasswordResetToken> for PasswordResetTokenModel {
+    type Error = anyhow::Error;
+
+    fn try_from(token: PasswordResetToken) -> Result<Self, Self::Error> {
+        Ok(PasswordResetTokenModel {
+            id: Uuid::parse_str(&token.id.to_string())?,
+            user_id: Uuid::parse_str(&token.user_id.to_string())?,
+            token_hash: token.token_hash,
+            expires_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                token.expires_at.naive_utc(),
+                token.expires_at.offset().to_owned(),
+            ),
+            used_at: token.used_at.map(|used_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    used_at.naive_utc(),
+                    used_at.offset().to_owned(),
+                )
+            }),
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                token.created_at.naive_utc(),
+                token.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
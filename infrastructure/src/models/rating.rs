@@ -0,0 +1,120 @@
+use domain::entities::rating::{Rating, RatingWithRater, Sale};
+use domain::entities::reputation::Reputation;
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct SaleModel {
+    pub id: Uuid,
+    pub auction_id: Uuid,
+    pub item_id: Uuid,
+    pub seller_id: Uuid,
+    pub buyer_id: Uuid,
+    pub completed_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<SaleModel> for Sale {
+    type Error = anyhow::Error;
+
+    fn try_from(sale_table: SaleModel) -> Result<Self, Self::Error> {
+        Ok(Sale {
+            id: sale_table.id.to_string().try_into()?,
+            auction_id: sale_table.auction_id.to_string().try_into()?,
+            item_id: sale_table.item_id.to_string().try_into()?,
+            seller_id: sale_table.seller_id.to_string().try_into()?,
+            buyer_id: sale_table.buyer_id.to_string().try_into()?,
+            completed_at: chrono::DateTime::from_naive_utc_and_offset(
+                sale_table.completed_at.naive_utc(),
+                sale_table.completed_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct RatingModel {
+    pub id: Uuid,
+    pub sale_id: Uuid,
+    pub rater_id: Uuid,
+    pub ratee_id: Uuid,
+    pub ratee_role: String,
+    pub score: i16,
+    pub comment: Option<String>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<RatingModel> for Rating {
+    type Error = anyhow::Error;
+
+    fn try_from(rating_table: RatingModel) -> Result<Self, Self::Error> {
+        Ok(Rating {
+            id: rating_table.id.to_string().try_into()?,
+            sale_id: rating_table.sale_id.to_string().try_into()?,
+            rater_id: rating_table.rater_id.to_string().try_into()?,
+            ratee_id: rating_table.ratee_id.to_string().try_into()?,
+            ratee_role: rating_table.ratee_role.into(),
+            score: rating_table.score,
+            comment: rating_table.comment,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                rating_table.created_at.naive_utc(),
+                rating_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<Rating> for RatingModel {
+    type Error = anyhow::Error;
+
+    fn try_from(rating: Rating) -> Result<Self, Self::Error> {
+        Ok(RatingModel {
+            id: Uuid::parse_str(&rating.id.to_string())?,
+            sale_id: Uuid::parse_str(&rating.sale_id.to_string())?,
+            rater_id: Uuid::parse_str(&rating.rater_id.to_string())?,
+            ratee_id: Uuid::parse_str(&rating.ratee_id.to_string())?,
+            ratee_role: rating.ratee_role.into(),
+            score: rating.score,
+            comment: rating.comment,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                rating.created_at.naive_utc(),
+                rating.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct RatingWithRaterModel {
+    #[sqlx(flatten)]
+    pub rating: RatingModel,
+    pub rater_name: String,
+}
+
+impl TryFrom<RatingWithRaterModel> for RatingWithRater {
+    type Error = anyhow::Error;
+
+    fn try_from(rating_table: RatingWithRaterModel) -> Result<Self, Self::Error> {
+        Ok(RatingWithRater {
+            rating: rating_table.rating.try_into()?,
+            rater_name: rating_table.rater_name,
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct ReputationModel {
+    pub user_id: Uuid,
+    pub completed_sales: i64,
+    pub rating_count: i64,
+    pub average_score: Option<f64>,
+}
+
+impl From<ReputationModel> for Reputation {
+    fn from(reputation_table: ReputationModel) -> Self {
+        Reputation {
+            completed_sales: reputation_table.completed_sales,
+            rating_count: reputation_table.rating_count,
+            average_score: reputation_table.average_score,
+        }
+    }
+}
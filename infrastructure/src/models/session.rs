@@ -0,0 +1,130 @@
+use domain::entities::session::{RefreshToken, Session};
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct SessionModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub last_seen_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub revoked_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+}
+
+impl TryFrom<SessionModel> for Session {
+    type Error = anyhow::Error;
+
+    fn try_from(session_table: SessionModel) -> Result<Self, Self::Error> {
+        Ok(Session {
+            id: session_table.id.to_string().try_into()?,
+            user_id: session_table.user_id.to_string().try_into()?,
+            user_agent: session_table.user_agent,
+            ip_address: session_table.ip_address,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                session_table.created_at.naive_utc(),
+                session_table.created_at.offset().to_owned(),
+            ),
+            last_seen_at: chrono::DateTime::from_naive_utc_and_offset(
+                session_table.last_seen_at.naive_utc(),
+                session_table.last_seen_at.offset().to_owned(),
+            ),
+            revoked_at: session_table.revoked_at.map(|revoked_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    revoked_at.naive_utc(),
+                    revoked_at.offset().to_owned(),
+                )
+            }),
+        })
+    }
+}
+
+impl TryFrom<Session> for SessionModel {
+    type Error = anyhow::Error;
+
+    fn try_from(session: Session) -> Result<Self, Self::Error> {
+        Ok(SessionModel {
+            id: Uuid::parse_str(&session.id.to_string())?,
+            user_id: Uuid::parse_str(&session.user_id.to_string())?,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                session.created_at.naive_utc(),
+                session.created_at.offset().to_owned(),
+            ),
+            last_seen_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                session.last_seen_at.naive_utc(),
+                session.last_seen_at.offset().to_owned(),
+            ),
+            revoked_at: session.revoked_at.map(|revoked_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    revoked_at.naive_utc(),
+                    revoked_at.offset().to_owned(),
+                )
+            }),
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct RefreshTokenModel {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub used_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<RefreshTokenModel> for RefreshToken {
+    type Error = anyhow::Error;
+
+    fn try_from(token_table: RefreshTokenModel) -> Result<Self, Self::Error> {
+        Ok(RefreshToken {
+            id: token_table.id.to_string().try_into()?,
+            session_id: token_table.session_id.to_string().try_into()?,
+            token_hash: token_table.token_hash,
+            expires_at: chrono::DateTime::from_naive_utc_and_offset(
+                token_table.expires_at.naive_utc(),
+                token_table.expires_at.offset().to_owned(),
+            ),
+            used_at: token_table.used_at.map(|used_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    used_at.naive_utc(),
+                    used_at.offset().to_owned(),
+                )
+            }),
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                token_table.created_at.naive_utc(),
+                token_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<RefreshToken> for RefreshTokenModel {
+    type Error = anyhow::Error;
+
+    fn try_from(token: RefreshToken) -> Result<Self, Self::Error> {
+        Ok(RefreshTokenModel {
+            id: Uuid::parse_str(&token.id.to_string())?,
+            session_id: Uuid::parse_str(&token.session_id.to_string())?,
+            token_hash: token.token_hash,
+            expires_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                token.expires_at.naive_utc(),
+                token.expires_at.offset().to_owned(),
+            ),
+            used_at: token.used_at.map(|used_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    used_at.naive_utc(),
+                    used_at.offset().to_owned(),
+                )
+            }),
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                token.created_at.naive_utc(),
+                token.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
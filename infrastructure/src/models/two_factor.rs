@@ -0,0 +1,147 @@
+use domain::entities::two_factor::{LoginChallenge, RecoveryCode, TwoFactor};
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct TwoFactorModel {
+    pub user_id: Uuid,
+    pub secret: String,
+    pub enabled_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub last_used_step: Option<i64>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<TwoFactorModel> for TwoFactor {
+    type Error = anyhow::Error;
+
+    fn try_from(two_factor_table: TwoFactorModel) -> Result<Self, Self::Error> {
+        Ok(TwoFactor {
+            user_id: two_factor_table.user_id.to_string().try_into()?,
+            secret: two_factor_table.secret,
+            enabled_at: two_factor_table.enabled_at.map(|enabled_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    enabled_at.naive_utc(),
+                    enabled_at.offset().to_owned(),
+                )
+            }),
+            last_used_step: two_factor_table.last_used_step,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                two_factor_table.created_at.naive_utc(),
+                two_factor_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<TwoFactor> for TwoFactorModel {
+    type Error = anyhow::Error;
+
+    fn try_from(two_factor: TwoFactor) -> Result<Self, Self::Error> {
+        Ok(TwoFactorModel {
+            user_id: Uuid::parse_str(&two_factor.user_id.to_string())?,
+            secret: two_factor.secret,
+            enabled_at: two_factor.enabled_at.map(|enabled_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    enabled_at.naive_utc(),
+                    enabled_at.offset().to_owned(),
+                )
+            }),
+            last_used_step: two_factor.last_used_step,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                two_factor.created_at.naive_utc(),
+                two_factor.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct RecoveryCodeModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+}
+
+impl TryFrom<RecoveryCode> for RecoveryCodeModel {
+    type Error = anyhow::Error;
+
+    fn try_from(code: RecoveryCode) -> Result<Self, Self::Error> {
+        Ok(RecoveryCodeModel {
+            id: Uuid::parse_str(&code.id.to_string())?,
+            user_id: Uuid::parse_str(&code.user_id.to_string())?,
+            code_hash: code.code_hash,
+            used_at: code.used_at.map(|used_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    used_at.naive_utc(),
+                    used_at.offset().to_owned(),
+                )
+            }),
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct LoginChallengeModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub failed_attempts: i32,
+    pub expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub used_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<LoginChallengeModel> for LoginChallenge {
+    type Error = anyhow::Error;
+
+    fn try_from(challenge_table: LoginChallengeModel) -> Result<Self, Self::Error> {
+        Ok(LoginChallenge {
+            id: challenge_table.id.to_string().try_into()?,
+            user_id: challenge_table.user_id.to_string().try_into()?,
+            token_hash: challenge_table.token_hash,
+            failed_attempts: challenge_table.failed_attempts,
+            expires_at: chrono::DateTime::from_naive_utc_and_offset(
+                challenge_table.expires_at.naive_utc(),
+                challenge_table.expires_at.offset().to_owned(),
+            ),
+            used_at: challenge_table.used_at.map(|used_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    used_at.naive_utc(),
+                    used_at.offset().to_owned(),
+                )
+            }),
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                challenge_table.created_at.naive_utc(),
+                challenge_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<LoginChallenge> for LoginChallengeModel {
+    type Error = anyhow::Error;
+
+    fn try_from(challenge: LoginChallenge) -> Result<Self, Self::Error> {
+        Ok(LoginChallengeModel {
+            id: Uuid::parse_str(&challenge.id.to_string())?,
+            user_id: Uuid::parse_str(&challenge.user_id.to_string())?,
+            token_hash: challenge.token_hash,
+            failed_attempts: challenge.failed_attempts,
+            expires_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                challenge.expires_at.naive_utc(),
+                challenge.expires_at.offset().to_owned(),
+            ),
+            used_at: challenge.used_at.map(|used_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    used_at.naive_utc(),
+                    used_at.offset().to_owned(),
+                )
+            }),
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                challenge.created_at.naive_utc(),
+                challenge.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
@@ -7,7 +7,11 @@ pub struct UserModel {
     pub id: Uuid,
     pub username: String,
     pub email: String,
-    pub password: String,
+    pub password: Option<String>,
+    pub email_verified: bool,
+    pub role: String,
+    pub suspended_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub deleted_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
 }
 
 impl TryFrom<UserModel> for User {
@@ -19,6 +23,20 @@ impl TryFrom<UserModel> for User {
             name: user_table.username,
             email: user_table.email,
             password: user_table.password,
+            email_verified: user_table.email_verified,
+            role: user_table.role.into(),
+            suspended_at: user_table.suspended_at.map(|suspended_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    suspended_at.naive_utc(),
+                    suspended_at.offset().to_owned(),
+                )
+            }),
+            deleted_at: user_table.deleted_at.map(|deleted_at| {
+                chrono::DateTime::from_naive_utc_and_offset(
+                    deleted_at.naive_utc(),
+                    deleted_at.offset().to_owned(),
+                )
+            }),
         })
     }
 }
@@ -32,6 +50,20 @@ impl TryFrom<User> for UserModel {
             username: user.name,
             email: user.email,
             password: user.password,
+            email_verified: user.email_verified,
+            role: user.role.into(),
+            suspended_at: user.suspended_at.map(|suspended_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    suspended_at.naive_utc(),
+                    suspended_at.offset().to_owned(),
+                )
+            }),
+            deleted_at: user.deleted_at.map(|deleted_at| {
+                sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                    deleted_at.naive_utc(),
+                    deleted_at.offset().to_owned(),
+                )
+            }),
         })
     }
 }
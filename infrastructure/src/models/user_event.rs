@@ -0,0 +1,70 @@
+use domain::entities::user_event::{UserEvent, UserEventKind};
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct UserEventModel {
+    pub id: Uuid,
+    pub sequence: i64,
+    pub xact_id: i64,
+    pub user_id: Uuid,
+    pub kind: String,
+    pub auction_id: Option<Uuid>,
+    pub item_id: Option<Uuid>,
+    pub value: Option<f32>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<UserEventModel> for UserEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(event_table: UserEventModel) -> Result<Self, Self::Error> {
+        Ok(UserEvent {
+            id: event_table.id.to_string().try_into()?,
+            sequence: event_table.sequence,
+            xact_id: event_table.xact_id,
+            user_id: event_table.user_id.to_string().try_into()?,
+            kind: UserEventKind::try_from(event_table.kind)?,
+            auction_id: event_table
+                .auction_id
+                .map(|id| id.to_string().try_into())
+                .transpose()?,
+            item_id: event_table
+                .item_id
+                .map(|id| id.to_string().try_into())
+                .transpose()?,
+            value: event_table.value,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                event_table.created_at.naive_utc(),
+                event_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<UserEvent> for UserEventModel {
+    type Error = anyhow::Error;
+
+    fn try_from(event: UserEvent) -> Result<Self, Self::Error> {
+        Ok(UserEventModel {
+            id: Uuid::parse_str(&event.id.to_string())?,
+            sequence: event.sequence,
+            xact_id: event.xact_id,
+            user_id: Uuid::parse_str(&event.user_id.to_string())?,
+            kind: String::from(event.kind),
+            auction_id: event
+                .auction_id
+                .map(|id| Uuid::parse_str(&id.to_string()))
+                .transpose()?,
+            item_id: event
+                .item_id
+                .map(|id| Uuid::parse_str(&id.to_string()))
+                .transpose()?,
+            value: event.value,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                event.created_at.naive_utc(),
+                event.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use domain::entities::user_profile::UserProfile;
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct UserProfileModel {
+    pub user_id: Uuid,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub location: Option<String>,
+    pub avatar: Option<Vec<u8>>,
+    pub avatar_content_type: Option<String>,
+    pub updated_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<UserProfileModel> for UserProfile {
+    type Error = anyhow::Error;
+
+    fn try_from(profile_table: UserProfileModel) -> Result<Self, Self::Error> {
+        Ok(UserProfile {
+            user_id: profile_table.user_id.to_string().try_into()?,
+            display_name: profile_table.display_name,
+            bio: profile_table.bio,
+            location: profile_table.location,
+            avatar: profile_table.avatar,
+            avatar_content_type: profile_table.avatar_content_type,
+            updated_at: chrono::DateTime::from_naive_utc_and_offset(
+                profile_table.updated_at.naive_utc(),
+                profile_table.updated_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<UserProfile> for UserProfileModel {
+    type Error = anyhow::Error;
+
+    fn try_from(profile: UserProfile) -> Result<Self, Self::Error> {
+        Ok(UserProfileModel {
+            user_id: Uuid::parse_str(&profile.user_id.to_string())?,
+            display_name: profile.display_name,
+            bio: profile.bio,
+            location: profile.location,
+            avatar: profile.avatar,
+            avatar_content_type: profile.avatar_content_type,
+            updated_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                profile.updated_at.naive_utc(),
+                profile.updated_at.offset().to_owned(),
+            ),
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use domain::entities::webhook::{WebhookDelivery, WebhookEventType, WebhookSubscription};
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct WebhookSubscriptionModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub secret: String,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<WebhookSubscriptionModel> for WebhookSubscription {
+    type Error = anyhow::Error;
+
+    fn try_from(subscription_table: WebhookSubscriptionModel) -> Result<Self, Self::Error> {
+        Ok(WebhookSubscription {
+            id: subscription_table.id.to_string().try_into()?,
+            user_id: subscription_table.user_id.to_string().try_into()?,
+            url: subscription_table.url,
+            event_types: subscription_table
+                .event_types
+                .into_iter()
+                .map(WebhookEventType::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            secret: subscription_table.secret,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                subscription_table.created_at.naive_utc(),
+                subscription_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<WebhookSubscription> for WebhookSubscriptionModel {
+    type Error = anyhow::Error;
+
+    fn try_from(subscription: WebhookSubscription) -> Result<Self, Self::Error> {
+        Ok(WebhookSubscriptionModel {
+            id: Uuid::parse_str(&subscription.id.to_string())?,
+            user_id: Uuid::parse_str(&subscription.user_id.to_string())?,
+            url: subscription.url,
+            event_types: subscription
+                .event_types
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            secret: subscription.secret,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                subscription.created_at.naive_utc(),
+                subscription.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct WebhookDeliveryModel {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_id: String,
+    pub event_type: String,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub succeeded: bool,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<WebhookDeliveryModel> for WebhookDelivery {
+    type Error = anyhow::Error;
+
+    fn try_from(delivery_table: WebhookDeliveryModel) -> Result<Self, Self::Error> {
+        Ok(WebhookDelivery {
+            id: delivery_table.id.to_string().try_into()?,
+            subscription_id: delivery_table.subscription_id.to_string().try_into()?,
+            event_id: delivery_table.event_id,
+            event_type: WebhookEventType::try_from(delivery_table.event_type)?,
+            attempt: delivery_table.attempt,
+            status_code: delivery_table.status_code,
+            error: delivery_table.error,
+            succeeded: delivery_table.succeeded,
+            created_at: chrono::DateTime::from_naive_utc_and_offset(
+                delivery_table.created_at.naive_utc(),
+                delivery_table.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
+
+impl TryFrom<WebhookDelivery> for WebhookDeliveryModel {
+    type Error = anyhow::Error;
+
+    fn try_from(delivery: WebhookDelivery) -> Result<Self, Self::Error> {
+        Ok(WebhookDeliveryModel {
+            id: Uuid::parse_str(&delivery.id.to_string())?,
+            subscription_id: Uuid::parse_str(&delivery.subscription_id.to_string())?,
+            event_id: delivery.event_id,
+            event_type: String::from(delivery.event_type),
+            attempt: delivery.attempt,
+            status_code: delivery.status_code,
+            error: delivery.error,
+            succeeded: delivery.succeeded,
+            created_at: sqlx::types::chrono::DateTime::from_naive_utc_and_offset(
+                delivery.created_at.naive_utc(),
+                delivery.created_at.offset().to_owned(),
+            ),
+        })
+    }
+}
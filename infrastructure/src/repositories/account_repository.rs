@@ -0,0 +1,287 @@
+use crate::models::auction::AuctionWithItemModel;
+use crate::models::bid::BidModel;
+use crate::models::item::ItemModel;
+use crate::models::item_image::ItemImageModel;
+use crate::models::rating::{RatingModel, SaleModel};
+use crate::models::session::SessionModel;
+use crate::models::user::UserModel;
+use crate::models::user_event::UserEventModel;
+use crate::models::user_profile::UserProfileModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::account::{AccountDeletion, AccountExport};
+use domain::entities::user::User;
+use domain::entities::user_profile::UserProfile;
+use domain::id::Id;
+use domain::interfaces::i_account_repository::IAccountRepository;
+use log::error;
+use sqlx::postgres::PgRow;
+use sqlx::types::Uuid;
+use sqlx::{FromRow, PgPool};
+
+/// Rows holding personal data that are dropped outright on deletion. Items, bids, sales and
+/// ratings stay, still pointing at the anonymized user row, so the other party keeps its history.
+const PERSONAL_DATA_DELETES: [&str; 15] = [
+    "DELETE FROM user_profiles WHERE user_id = $1",
+    "DELETE FROM api_keys WHERE user_id = $1",
+    "DELETE FROM external_identities WHERE user_id = $1",
+    "DELETE FROM authorization_requests WHERE link_user_id = $1",
+    "DELETE FROM user_two_factor WHERE user_id = $1",
+    "DELETE FROM recovery_codes WHERE user_id = $1",
+    "DELETE FROM login_challenges WHERE user_id = $1",
+    "DELETE FROM email_verification_tokens WHERE user_id = $1",
+    "DELETE FROM password_reset_tokens WHERE user_id = $1",
+    "DELETE FROM webhook_subscriptions WHERE user_id = $1",
+    "DELETE FROM notifications WHERE user_id = $1",
+    "DELETE FROM user_events WHERE user_id = $1",
+    "DELETE FROM sessions WHERE user_id = $1",
+    "DELETE FROM jobs WHERE kind = 'send_email' AND payload->>'recipient_id' = $1::text",
+    "UPDATE audit_log SET email = NULL, ip_address = NULL WHERE user_id = $1",
+];
+
+async fn fetch_all_by_user_id<M, T>(
+    pool: &PgPool,
+    query: &str,
+    user_id: Uuid,
+) -> anyhow::Result<Vec<T>>
+where
+    M: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+    T: TryFrom<M, Error = anyhow::Error>,
+{
+    let result = sqlx::query_as::<_, M>(query)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+    result
+        .into_iter()
+        .map(T::try_from)
+        .collect::<anyhow::Result<Vec<T>>>()
+}
+
+#[async_trait]
+impl IAccountRepository for DatabaseRepositoryImpl<AccountExport> {
+    async fn find_export(&self, user_id: Id<User>) -> anyhow::Result<Option<AccountExport>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let user = sqlx::query_as::<_, UserModel>("SELECT * FROM users WHERE id = $1")
+            .bind(user_id)
+            .fetch_optional(pool.as_ref())
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        let Some(user) = user else {
+            return Ok(None);
+        };
+
+        let profile =
+            sqlx::query_as::<_, UserProfileModel>("SELECT * FROM user_profiles WHERE user_id = $1")
+                .bind(user_id)
+                .fetch_optional(pool.as_ref())
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?
+                .map(UserProfile::try_from)
+                .transpose()?;
+
+        Ok(Some(AccountExport {
+            user: User::try_from(user)?,
+            profile,
+            items: fetch_all_by_user_id::<ItemModel, _>(
+                pool.as_ref(),
+                "SELECT * FROM items WHERE user_id = $1",
+                user_id,
+            )
+            .await?,
+            item_images: fetch_all_by_user_id::<ItemImageModel, _>(
+                pool.as_ref(),
+                "SELECT \
+                    item_images.id, \
+                    item_images.item_id, \
+                    item_images.position, \
+                    item_images.thumbnail_size, \
+                    item_images.content_type, \
+                    item_images.blob_key, \
+                    item_images.created_at \
+                FROM item_images INNER JOIN items ON item_images.item_id = items.id \
+                WHERE items.user_id = $1 AND item_images.thumbnail_size = 0 \
+                ORDER BY item_images.item_id, item_images.position",
+                user_id,
+            )
+            .await?,
+            bids: fetch_all_by_user_id::<BidModel, _>(
+                pool.as_ref(),
+                "SELECT * FROM bids WHERE user_id = $1",
+                user_id,
+            )
+            .await?,
+            auctions: fetch_all_by_user_id::<AuctionWithItemModel, _>(
+                pool.as_ref(),
+                "SELECT \
+                    auctions.id, \
+                    auctions.item_id, \
+                    auctions.starting_price, \
+                    auctions.end_date, \
+                    items.brief, \
+                    items.description, \
+                    items.category, \
+                    items.user_id, \
+                    auctions.strategy \
+                FROM \
+                auctions INNER JOIN items ON auctions.item_id = items.id \
+                WHERE items.user_id = $1 \
+                ORDER BY end_date",
+                user_id,
+            )
+            .await?,
+            sales: fetch_all_by_user_id::<SaleModel, _>(
+                pool.as_ref(),
+                "SELECT * FROM sales WHERE seller_id = $1 OR buyer_id = $1 ORDER BY completed_at",
+                user_id,
+            )
+            .await?,
+            ratings_given: fetch_all_by_user_id::<RatingModel, _>(
+                pool.as_ref(),
+                "SELECT * FROM ratings WHERE rater_id = $1 ORDER BY created_at",
+                user_id,
+            )
+            .await?,
+            ratings_received: fetch_all_by_user_id::<RatingModel, _>(
+                pool.as_ref(),
+                "SELECT * FROM ratings WHERE ratee_id = $1 ORDER BY created_at",
+                user_id,
+            )
+            .await?,
+            events: fetch_all_by_user_id::<UserEventModel, _>(
+                pool.as_ref(),
+                "SELECT * FROM user_events WHERE user_id = $1 ORDER BY sequence",
+                user_id,
+            )
+            .await?,
+            sessions: fetch_all_by_user_id::<SessionModel, _>(
+                pool.as_ref(),
+                "SELECT * FROM sessions WHERE user_id = $1 ORDER BY created_at",
+                user_id,
+            )
+            .await?,
+        }))
+    }
+
+    async fn delete(&self, user_id: Id<User>) -> anyhow::Result<AccountDeletion> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        // bids and auctions check the user row through their foreign keys, so holding its lock
+        // keeps new ones out until the deletion commits
+        let email = sqlx::query_scalar::<_, Option<String>>(
+            "SELECT email FROM users WHERE id = $1 FOR UPDATE",
+        )
+        .bind(user_id)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        // finalized auctions are removed, so any auction left on the user's items is still live
+        let has_live_auctions = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS (\
+                SELECT 1 FROM auctions INNER JOIN items ON auctions.item_id = items.id \
+                WHERE items.user_id = $1)",
+        )
+        .bind(user_id)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        if has_live_auctions {
+            return Ok(AccountDeletion::BlockedByLiveAuctions);
+        }
+
+        let has_leading_bids = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS (\
+                SELECT 1 FROM bids \
+                WHERE bids.user_id = $1 \
+                AND bids.value = (SELECT MAX(value) FROM bids AS highest WHERE highest.auction_id = bids.auction_id))",
+        )
+        .bind(user_id)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        if has_leading_bids {
+            return Ok(AccountDeletion::BlockedByLeadingBids);
+        }
+
+        for statement in PERSONAL_DATA_DELETES {
+            sqlx::query(statement)
+                .bind(user_id)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+        }
+
+        if let Some(email) = email {
+            sqlx::query("DELETE FROM login_throttles WHERE key = 'account:' || lower(trim($1))")
+                .bind(email)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+        }
+
+        // the row stays for the foreign keys of bids, items, sales and ratings
+        sqlx::query(
+            "UPDATE users SET \
+                username = 'deleted-' || replace(id::text, '-', ''), \
+                email = 'deleted-' || replace(id::text, '-', '') || '@deleted.invalid', \
+                password = NULL, \
+                email_verified = false, \
+                deleted_at = now() \
+            WHERE id = $1",
+        )
+        .bind(user_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(AccountDeletion::Deleted)
+    }
+}
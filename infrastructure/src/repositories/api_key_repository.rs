@@ -0,0 +1,119 @@
+use crate::models::api_key::ApiKeyModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::api_key::ApiKey;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_api_key_repository::IApiKeyRepository;
+use log::error;
+use sqlx::types::Uuid;
+
+#[async_trait]
+impl IApiKeyRepository for DatabaseRepositoryImpl<ApiKey> {
+    async fn insert(&self, api_key: ApiKey) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let api_key = ApiKeyModel::try_from(api_key)?;
+
+        sqlx::query(
+            "INSERT INTO api_keys (id, user_id, name, prefix, key_hash, scopes, expires_at, last_used_at, revoked_at, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+        )
+        .bind(api_key.id)
+        .bind(api_key.user_id)
+        .bind(api_key.name)
+        .bind(api_key.prefix)
+        .bind(api_key.key_hash)
+        .bind(api_key.scopes)
+        .bind(api_key.expires_at)
+        .bind(api_key.last_used_at)
+        .bind(api_key.revoked_at)
+        .bind(api_key.created_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, key_hash: String) -> anyhow::Result<Option<ApiKey>> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query_as::<_, ApiKeyModel>("SELECT * FROM api_keys WHERE key_hash = $1")
+            .bind(key_hash)
+            .fetch_optional(pool.as_ref())
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        match result {
+            Some(api_key) => Ok(Some(ApiKey::try_from(api_key)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all_by_user_id(&self, user_id: Id<User>) -> anyhow::Result<Vec<ApiKey>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, ApiKeyModel>(
+            "SELECT * FROM api_keys WHERE user_id = $1 AND revoked_at IS NULL ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result
+            .into_iter()
+            .map(ApiKey::try_from)
+            .collect::<anyhow::Result<Vec<ApiKey>>>()
+    }
+
+    async fn revoke(&self, id: Id<ApiKey>, user_id: Id<User>) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query(
+            "UPDATE api_keys SET revoked_at = now() \
+            WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn touch(&self, id: Id<ApiKey>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        sqlx::query("UPDATE api_keys SET last_used_at = now() WHERE id = $1")
+            .bind(id)
+            .execute(pool.as_ref())
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        Ok(())
+    }
+}
@@ -1,11 +1,18 @@
 use crate::models::auction::{AuctionModel, AuctionWithItemModel};
 use crate::models::bid::{BidModel, BidWithUsernameModel};
+use crate::repositories::job_repository::enqueue_jobs;
+use crate::repositories::notification_repository::insert_notifications;
+use crate::repositories::user_event_repository::insert_user_events;
 use crate::repositories::DatabaseRepositoryImpl;
 use anyhow::anyhow;
 use async_trait::async_trait;
-use domain::entities::auction::{Auction, AuctionStrategy, AuctionWithItem};
+use domain::entities::auction::{Auction, AuctionStrategy, AuctionWithItem, FinalizationPlan};
 use domain::entities::bid::{Bid, BidWithUsername};
 use domain::entities::item::{Category, Item};
+use domain::entities::job::Job;
+use domain::entities::notification::Notification;
+use domain::entities::user::User;
+use domain::entities::user_event::UserEvent;
 use domain::id::Id;
 use domain::interfaces::i_auction_repository::IAuctionRepository;
 use log::error;
@@ -14,9 +21,13 @@ use std::str::FromStr;
 
 #[async_trait]
 impl IAuctionRepository for DatabaseRepositoryImpl<Auction> {
-    async fn insert(&self, auction: Auction) -> anyhow::Result<Option<Auction>> {
+    async fn insert(&self, auction: Auction, jobs: Vec<Job>) -> anyhow::Result<Option<Auction>> {
         let pool = self.pool.0.clone();
         let auction = AuctionModel::try_from(auction)?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
         let result = sqlx::query_as::<_, AuctionModel>(
             "INSERT INTO auctions (id, item_id, starting_price, end_date, strategy) VALUES ($1, $2, $3, $4, $5) RETURNING *",
         )
@@ -25,13 +36,19 @@ impl IAuctionRepository for DatabaseRepositoryImpl<Auction> {
             .bind(auction.starting_price)
             .bind(auction.end_date)
             .bind(auction.strategy)
-            .fetch_optional(pool.as_ref())
+            .fetch_optional(&mut *transaction)
             .await
             .map_err(|e| {
                 error!("{:?}", e);
                 anyhow!("{:?}", e)
             })?;
 
+        enqueue_jobs(&mut transaction, jobs).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
         match result {
             Some(auction) => Ok(Some(Auction::try_from(auction)?)),
             None => Ok(None),
@@ -41,14 +58,15 @@ impl IAuctionRepository for DatabaseRepositoryImpl<Auction> {
     async fn find_all_expired(&self) -> anyhow::Result<Vec<Auction>> {
         let pool = self.pool.0.clone();
 
-        let result =
-            sqlx::query_as::<_, AuctionModel>("SELECT * FROM auctions WHERE end_date <= now()")
-                .fetch_all(pool.as_ref())
-                .await
-                .map_err(|e| {
-                    error!("{:?}", e);
-                    anyhow!("{:?}", e)
-                })?;
+        let result = sqlx::query_as::<_, AuctionModel>(
+            "SELECT * FROM auctions WHERE end_date <= now() AND quarantined_at IS NULL",
+        )
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
 
         Ok(result
             .into_iter()
@@ -187,10 +205,76 @@ impl IAuctionRepository for DatabaseRepositoryImpl<Auction> {
             .collect::<Result<Vec<AuctionWithItem>, anyhow::Error>>()?)
     }
 
-    async fn create_bid(&self, bid: Bid) -> anyhow::Result<Option<Bid>> {
+    async fn find_all_ongoing_by_user_id(
+        &self,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Vec<AuctionWithItem>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, AuctionWithItemModel>(
+            "SELECT \
+                auctions.id, \
+                auctions.item_id, \
+                auctions.starting_price, \
+                auctions.end_date, \
+                items.brief, \
+                items.description, \
+                items.category, \
+                items.user_id, \
+                auctions.strategy \
+            FROM \
+            auctions INNER JOIN items ON auctions.item_id = items.id \
+            WHERE (end_date > now() OR (end_date <= now() AND strategy = $2)) AND items.user_id = $1 \
+            ORDER BY end_date",
+        )
+        .bind(user_id)
+        .bind::<String>(AuctionStrategy::RequestFinalApproval.into())
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result
+            .into_iter()
+            .map(AuctionWithItem::try_from)
+            .collect::<anyhow::Result<Vec<AuctionWithItem>>>()
+    }
+
+    async fn create_bid(
+        &self,
+        bid: Bid,
+        jobs: Vec<Job>,
+        notifications: Vec<Notification>,
+        user_events: Vec<UserEvent>,
+    ) -> anyhow::Result<Option<Bid>> {
         let pool = self.pool.0.clone();
 
         let bid = BidModel::try_from(bid)?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        // the key share lock keeps the auction from being claimed for finalization until the bid
+        // is committed, so it cannot be missed when picking the winner
+        let open = sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM auctions WHERE id = $1 AND end_date > now() FOR KEY SHARE",
+        )
+        .bind(bid.auction_id)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        if open.is_none() {
+            return Ok(None);
+        }
 
         let result = sqlx::query_as::<_, BidModel>(
             "INSERT INTO bids (id, value, auction_id, user_id) VALUES ($1, $2, $3, $4) RETURNING *",
@@ -199,13 +283,21 @@ impl IAuctionRepository for DatabaseRepositoryImpl<Auction> {
         .bind(bid.value)
         .bind(bid.auction_id)
         .bind(bid.user_id)
-        .fetch_optional(pool.as_ref())
+        .fetch_optional(&mut *transaction)
         .await
         .map_err(|e| {
             error!("{:?}", e);
             anyhow!("{:?}", e)
         })?;
 
+        enqueue_jobs(&mut transaction, jobs).await?;
+        insert_notifications(&mut transaction, notifications).await?;
+        insert_user_events(&mut transaction, user_events).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
         match result {
             Some(bid) => Ok(Some(Bid::try_from(bid)?)),
             None => Ok(None),
@@ -243,39 +335,146 @@ impl IAuctionRepository for DatabaseRepositoryImpl<Auction> {
             .collect::<Result<Vec<BidWithUsername>, anyhow::Error>>()?)
     }
 
-    async fn delete_auction(&self, auction_id: Id<Auction>) -> anyhow::Result<()> {
+    async fn finalize_auction(
+        &self,
+        auction_id: Id<Auction>,
+        plan: FinalizationPlan,
+    ) -> anyhow::Result<Option<Vec<BidWithUsername>>> {
         let pool = self.pool.0.clone();
 
         let auction_id = Uuid::from_str(auction_id.value.to_string().as_str())
             .map_err(|e| anyhow!("{:?}", e))?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
 
-        sqlx::query("DELETE FROM auctions WHERE id = $1")
+        // the row lock is held until commit, so a concurrent run skips the auction instead of
+        // finalizing it a second time, and so does a run while a bid is being placed
+        let item_id = sqlx::query_scalar::<_, Uuid>(
+            "SELECT item_id FROM auctions WHERE id = $1 FOR UPDATE SKIP LOCKED",
+        )
+        .bind(auction_id)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        let Some(item_id) = item_id else {
+            return Ok(None);
+        };
+
+        // bids can no longer be placed, so the winner is picked from all of them
+        let bids = sqlx::query_as::<_, BidWithUsernameModel>(
+            "SELECT bids.id, bids.auction_id, bids.user_id, bids.value, users.username \
+            FROM bids INNER JOIN users ON bids.user_id = users.id \
+            WHERE bids.auction_id = $1",
+        )
+        .bind(auction_id)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?
+        .into_iter()
+        .map(BidWithUsername::try_from)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let finalization = plan(&bids);
+        let new_owner_id = finalization
+            .new_owner_id
+            .map(|id| Uuid::from_str(id.value.to_string().as_str()))
+            .transpose()
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        if let Some(new_owner_id) = new_owner_id {
+            // recorded before the item changes hands, while it still names the seller
+            sqlx::query(
+                "INSERT INTO sales (id, auction_id, item_id, seller_id, buyer_id, completed_at) \
+                SELECT gen_random_uuid(), $1, items.id, items.user_id, $2, now() FROM items WHERE items.id = $3",
+            )
             .bind(auction_id)
-            .execute(pool.as_ref())
+            .bind(new_owner_id)
+            .bind(item_id)
+            .execute(&mut *transaction)
             .await
             .map_err(|e| {
                 error!("{:?}", e);
                 anyhow!("{:?}", e)
             })?;
 
-        Ok(())
-    }
-
-    async fn delete_bids_for_auction(&self, auction_id: Id<Auction>) -> anyhow::Result<()> {
-        let pool = self.pool.0.clone();
-
-        let auction_id = Uuid::from_str(auction_id.value.to_string().as_str())
-            .map_err(|e| anyhow!("{:?}", e))?;
+            sqlx::query("UPDATE items SET user_id = $2 WHERE id = $1")
+                .bind(item_id)
+                .bind(new_owner_id)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+        }
 
         sqlx::query("DELETE FROM bids WHERE auction_id = $1")
             .bind(auction_id)
-            .execute(pool.as_ref())
+            .execute(&mut *transaction)
             .await
             .map_err(|e| {
                 error!("{:?}", e);
                 anyhow!("{:?}", e)
             })?;
 
-        Ok(())
+        sqlx::query("DELETE FROM auctions WHERE id = $1")
+            .bind(auction_id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        enqueue_jobs(&mut transaction, finalization.jobs).await?;
+        insert_notifications(&mut transaction, finalization.notifications).await?;
+        insert_user_events(&mut transaction, finalization.user_events).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(Some(bids))
+    }
+
+    async fn record_finalization_failure(
+        &self,
+        auction_id: Id<Auction>,
+        error: String,
+        max_attempts: i32,
+    ) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+
+        let auction_id = Uuid::from_str(auction_id.value.to_string().as_str())
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        let quarantined = sqlx::query_scalar::<_, bool>(
+            "UPDATE auctions SET \
+                finalization_attempts = finalization_attempts + 1, \
+                last_finalization_error = $2, \
+                quarantined_at = CASE WHEN finalization_attempts + 1 >= $3 THEN now() ELSE NULL END \
+            WHERE id = $1 \
+            RETURNING quarantined_at IS NOT NULL",
+        )
+        .bind(auction_id)
+        .bind(error)
+        .bind(max_attempts)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(quarantined.unwrap_or(false))
     }
 }
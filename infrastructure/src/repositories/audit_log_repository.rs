@@ -0,0 +1,35 @@
+use crate::models::audit_event::AuditEventModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::audit_event::AuditEvent;
+use domain::interfaces::i_audit_log_repository::IAuditLogRepository;
+use log::error;
+
+#[async_trait]
+impl IAuditLogRepository for DatabaseRepositoryImpl<AuditEvent> {
+    async fn insert(&self, event: AuditEvent) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let event = AuditEventModel::try_from(event)?;
+
+        sqlx::query(
+            "INSERT INTO audit_log (id, kind, user_id, email, ip_address, details, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(event.id)
+        .bind(event.kind)
+        .bind(event.user_id)
+        .bind(event.email)
+        .bind(event.ip_address)
+        .bind(event.details)
+        .bind(event.created_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use crate::models::email_verification::EmailVerificationTokenModel;
+use crate::repositories::job_repository::enqueue_jobs;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::email_verification::EmailVerificationToken;
+use domain::entities::job::Job;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_email_verification_repository::IEmailVerificationRepository;
+use log::error;
+use sqlx::types::Uuid;
+
+#[async_trait]
+impl IEmailVerificationRepository for DatabaseRepositoryImpl<EmailVerificationToken> {
+    async fn insert(&self, token: EmailVerificationToken, jobs: Vec<Job>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let token = EmailVerificationTokenModel::try_from(token)?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        sqlx::query(
+            "INSERT INTO email_verification_tokens (id, user_id, expires_at, used_at, created_at) \
+            VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(token.id)
+        .bind(token.user_id)
+        .bind(token.expires_at)
+        .bind(token.used_at)
+        .bind(token.created_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        enqueue_jobs(&mut transaction, jobs).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_latest_by_user_id(
+        &self,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Option<EmailVerificationToken>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, EmailVerificationTokenModel>(
+            "SELECT * FROM email_verification_tokens WHERE user_id = $1 ORDER BY created_at DESC LIMIT 1",
+        )
+        .bind(user_id)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(token) => Ok(Some(EmailVerificationToken::try_from(token)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn consume(&self, id: Id<EmailVerificationToken>) -> anyhow::Result<Option<Id<User>>> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        let user_id = sqlx::query_scalar::<_, Uuid>(
+            "UPDATE email_verification_tokens SET used_at = now() \
+            WHERE id = $1 AND used_at IS NULL AND expires_at > now() \
+            RETURNING user_id",
+        )
+        .bind(id)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        let Some(user_id) = user_id else {
+            return Ok(None);
+        };
+
+        sqlx::query("UPDATE users SET email_verified = true WHERE id = $1")
+            .bind(user_id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(Some(user_id.to_string().try_into()?))
+    }
+}
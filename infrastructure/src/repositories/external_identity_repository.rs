@@ -0,0 +1,163 @@
+use crate::models::external_identity::{AuthorizationRequestModel, ExternalIdentityModel};
+use crate::models::user::UserModel;
+use crate::repositories::job_repository::enqueue_jobs;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::external_identity::{AuthorizationRequest, ExternalIdentity};
+use domain::entities::job::Job;
+use domain::entities::user::User;
+use domain::interfaces::i_external_identity_repository::IExternalIdentityRepository;
+use log::error;
+
+#[async_trait]
+impl IExternalIdentityRepository for DatabaseRepositoryImpl<ExternalIdentity> {
+    async fn insert_authorization_request(
+        &self,
+        request: AuthorizationRequest,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let request = AuthorizationRequestModel::try_from(request)?;
+
+        sqlx::query(
+            "INSERT INTO authorization_requests (state, provider, code_verifier, nonce, link_user_id, expires_at, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(request.state)
+        .bind(request.provider)
+        .bind(request.code_verifier)
+        .bind(request.nonce)
+        .bind(request.link_user_id)
+        .bind(request.expires_at)
+        .bind(request.created_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn take_authorization_request(
+        &self,
+        state: String,
+    ) -> anyhow::Result<Option<AuthorizationRequest>> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query_as::<_, AuthorizationRequestModel>(
+            "DELETE FROM authorization_requests WHERE state = $1 AND expires_at > now() RETURNING *",
+        )
+        .bind(state)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result.map(AuthorizationRequest::try_from).transpose()
+    }
+
+    async fn find_user(&self, provider: String, subject: String) -> anyhow::Result<Option<User>> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query_as::<_, UserModel>(
+            "SELECT users.* FROM users \
+            JOIN external_identities ON external_identities.user_id = users.id \
+            WHERE external_identities.provider = $1 AND external_identities.subject = $2",
+        )
+        .bind(provider)
+        .bind(subject)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result.map(User::try_from).transpose()
+    }
+
+    async fn insert(&self, identity: ExternalIdentity) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let identity = ExternalIdentityModel::try_from(identity)?;
+
+        let result = sqlx::query(
+            "INSERT INTO external_identities (id, user_id, provider, subject, email, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT (provider, subject) DO NOTHING",
+        )
+        .bind(identity.id)
+        .bind(identity.user_id)
+        .bind(identity.provider)
+        .bind(identity.subject)
+        .bind(identity.email)
+        .bind(identity.created_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn insert_with_user(
+        &self,
+        user: User,
+        identity: ExternalIdentity,
+        jobs: Vec<Job>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let user = UserModel::try_from(user)?;
+        let identity = ExternalIdentityModel::try_from(identity)?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        sqlx::query(
+            "INSERT INTO users (id, username, email, password, email_verified, role) \
+            VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(user.id)
+        .bind(user.username)
+        .bind(user.email)
+        .bind(user.password)
+        .bind(user.email_verified)
+        .bind(user.role)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        sqlx::query(
+            "INSERT INTO external_identities (id, user_id, provider, subject, email, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(identity.id)
+        .bind(identity.user_id)
+        .bind(identity.provider)
+        .bind(identity.subject)
+        .bind(identity.email)
+        .bind(identity.created_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        enqueue_jobs(&mut transaction, jobs).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use crate::models::item_image::ItemImageModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::item::Item;
+use domain::entities::item_image::{ItemImage, MAX_ITEM_IMAGES};
+use domain::id::Id;
+use domain::interfaces::i_item_image_repository::IItemImageRepository;
+use log::error;
+use sqlx::types::Uuid;
+use sqlx::PgConnection;
+
+/// Inserts images on an open connection, so items can be created together with their pictures.
+pub(crate) async fn insert_images(
+    connection: &mut PgConnection,
+    images: Vec<ItemImage>,
+) -> anyhow::Result<()> {
+    for image in images {
+        let image = ItemImageModel::try_from(image)?;
+
+        sqlx::query(
+            "INSERT INTO item_images (id, item_id, position, thumbnail_size, content_type, blob_key, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(image.id)
+        .bind(image.item_id)
+        .bind(image.position)
+        .bind(image.thumbnail_size)
+        .bind(image.content_type)
+        .bind(image.blob_key)
+        .bind(image.created_at)
+        .execute(&mut *connection)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Serializes changes to the gallery of an item, positions are computed from the rows already there.
+async fn lock_item(connection: &mut PgConnection, item_id: Uuid) -> anyhow::Result<()> {
+    sqlx::query("SELECT id FROM items WHERE id = $1 FOR UPDATE")
+        .bind(item_id)
+        .execute(connection)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl IItemImageRepository for DatabaseRepositoryImpl<ItemImage> {
+    async fn find(
+        &self,
+        item_id: Id<Item>,
+        position: i32,
+        thumbnail_size: Option<i32>,
+    ) -> anyhow::Result<Option<ItemImage>> {
+        let pool = self.pool.0.clone();
+        let item_id =
+            Uuid::parse_str(item_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, ItemImageModel>(
+            "SELECT id, item_id, position, thumbnail_size, content_type, blob_key, created_at \
+            FROM item_images \
+            WHERE item_id = $1 AND position = $2 AND thumbnail_size IN (0, $3) \
+            ORDER BY thumbnail_size DESC \
+            LIMIT 1",
+        )
+        .bind(item_id)
+        .bind(position)
+        .bind(thumbnail_size.unwrap_or(0))
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(image) => Ok(Some(ItemImage::try_from(image)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn append(&self, images: Vec<ItemImage>) -> anyhow::Result<Option<i32>> {
+        let Some(first) = images.first() else {
+            return Ok(None);
+        };
+        let pool = self.pool.0.clone();
+        let item_id = Uuid::parse_str(first.item_id.value.to_string().as_str())
+            .map_err(|e| anyhow!("{:?}", e))?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        lock_item(&mut transaction, item_id).await?;
+
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM item_images WHERE item_id = $1 AND thumbnail_size = 0",
+        )
+        .bind(item_id)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        if count >= MAX_ITEM_IMAGES as i64 {
+            return Ok(None);
+        }
+
+        let position = count as i32;
+        let images = images
+            .into_iter()
+            .map(|image| ItemImage { position, ..image })
+            .collect();
+        insert_images(&mut transaction, images).await?;
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(Some(position))
+    }
+
+    async fn replace_first(
+        &self,
+        item_id: Id<Item>,
+        images: Vec<ItemImage>,
+    ) -> anyhow::Result<Vec<String>> {
+        let pool = self.pool.0.clone();
+        let item_id =
+            Uuid::parse_str(item_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        lock_item(&mut transaction, item_id).await?;
+
+        // new rows rather than updated ones, the id of a row serves as the ETag of its data
+        let replaced = sqlx::query_scalar::<_, String>(
+            "DELETE FROM item_images WHERE item_id = $1 AND position = 0 RETURNING blob_key",
+        )
+        .bind(item_id)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        let images = images
+            .into_iter()
+            .map(|image| ItemImage {
+                position: 0,
+                ..image
+            })
+            .collect();
+        insert_images(&mut transaction, images).await?;
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(replaced)
+    }
+
+    async fn delete(&self, item_id: Id<Item>, position: i32) -> anyhow::Result<Vec<String>> {
+        let pool = self.pool.0.clone();
+        let item_id =
+            Uuid::parse_str(item_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        lock_item(&mut transaction, item_id).await?;
+
+        let deleted = sqlx::query_scalar::<_, String>(
+            "DELETE FROM item_images WHERE item_id = $1 AND position = $2 RETURNING blob_key",
+        )
+        .bind(item_id)
+        .bind(position)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        if deleted.is_empty() {
+            return Ok(deleted);
+        }
+
+        sqlx::query(
+            "UPDATE item_images SET position = position - 1 WHERE item_id = $1 AND position > $2",
+        )
+        .bind(item_id)
+        .bind(position)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(deleted)
+    }
+
+    async fn reorder(&self, item_id: Id<Item>, order: Vec<i32>) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let item_id =
+            Uuid::parse_str(item_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        lock_item(&mut transaction, item_id).await?;
+
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM item_images WHERE item_id = $1 AND thumbnail_size = 0",
+        )
+        .bind(item_id)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        if count != order.len() as i64 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            "UPDATE item_images SET position = new_order.position - 1 \
+            FROM unnest($2::int[]) WITH ORDINALITY AS new_order(old_position, position) \
+            WHERE item_images.item_id = $1 AND item_images.position = new_order.old_position",
+        )
+        .bind(item_id)
+        .bind(order)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(true)
+    }
+
+    async fn find_legacy_data(&self, id: Id<ItemImage>) -> anyhow::Result<Option<Vec<u8>>> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_scalar::<_, Vec<u8>>(
+            "SELECT data FROM item_images WHERE id = $1 AND data IS NOT NULL",
+        )
+        .bind(id)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result)
+    }
+}
@@ -1,8 +1,10 @@
 use crate::models::item::ItemModel;
+use crate::repositories::item_image_repository::insert_images;
 use crate::repositories::DatabaseRepositoryImpl;
 use anyhow::anyhow;
 use async_trait::async_trait;
 use domain::entities::item::{Category, Item};
+use domain::entities::item_image::ItemImage;
 use domain::entities::user::User;
 use domain::id::Id;
 use domain::interfaces::i_item_repository::IItemRepository;
@@ -11,22 +13,32 @@ use sqlx::types::Uuid;
 
 #[async_trait]
 impl IItemRepository for DatabaseRepositoryImpl<Item> {
-    async fn insert(&self, item: Item) -> anyhow::Result<Option<Item>> {
+    async fn insert(&self, item: Item, images: Vec<ItemImage>) -> anyhow::Result<Option<Item>> {
         let pool = self.pool.0.clone();
         let item = ItemModel::try_from(item)?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
         let result = sqlx::query_as::<_, ItemModel>(
-            "INSERT INTO items (id, brief, description, picture, user_id, category) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+            "INSERT INTO items (id, brief, description, user_id, category) VALUES ($1, $2, $3, $4, $5) RETURNING *",
         )
             .bind(item.id)
             .bind(item.brief)
             .bind(item.description)
-            .bind(item.picture)
             .bind(item.user_id)
             .bind(item.category)
-            .fetch_optional(pool.as_ref())
+            .fetch_optional(&mut *transaction)
             .await
             .map_err(|e| anyhow!("{:?}", e))?;
 
+        insert_images(&mut transaction, images).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
         match result {
             Some(item) => Ok(Some(Item::try_from(item)?)),
             None => Ok(None),
@@ -36,11 +48,13 @@ impl IItemRepository for DatabaseRepositoryImpl<Item> {
         let pool = self.pool.0.clone();
         let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
 
-        let result = sqlx::query_as::<_, ItemModel>("SELECT * FROM items WHERE id = $1")
-            .bind(id)
-            .fetch_optional(pool.as_ref())
-            .await
-            .map_err(|e| anyhow!("{:?}", e))?;
+        let result = sqlx::query_as::<_, ItemModel>(
+            "SELECT * FROM items WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| anyhow!("{:?}", e))?;
 
         match result {
             Some(item) => Ok(Some(Item::try_from(item)?)),
@@ -59,7 +73,7 @@ impl IItemRepository for DatabaseRepositoryImpl<Item> {
         info!("{:?}", category);
 
         let result = sqlx::query_as::<_, ItemModel>(
-            "SELECT * FROM items WHERE user_id = $1 AND (category = $2 OR $2 IS NULL)",
+            "SELECT * FROM items WHERE user_id = $1 AND (category = $2 OR $2 IS NULL) AND deleted_at IS NULL",
         )
         .bind(Uuid::parse_str(user_id.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?)
         .bind(category)
@@ -105,4 +119,55 @@ impl IItemRepository for DatabaseRepositoryImpl<Item> {
             None => Ok(None),
         }
     }
+
+    async fn update(&self, item: Item) -> anyhow::Result<Option<Item>> {
+        let pool = self.pool.0.clone();
+        let item = ItemModel::try_from(item)?;
+
+        let result = sqlx::query_as::<_, ItemModel>(
+            "UPDATE items SET brief = $2, description = $3, category = $4 \
+            WHERE id = $1 AND user_id = $5 AND deleted_at IS NULL \
+            AND NOT EXISTS (SELECT 1 FROM auctions WHERE auctions.item_id = items.id) \
+            RETURNING *",
+        )
+        .bind(item.id)
+        .bind(item.brief)
+        .bind(item.description)
+        .bind(item.category)
+        .bind(item.user_id)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(item) => Ok(Some(Item::try_from(item)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn soft_delete(&self, id: Id<Item>, user_id: Id<User>) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query(
+            "UPDATE items SET deleted_at = now() \
+            WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL \
+            AND NOT EXISTS (SELECT 1 FROM auctions WHERE auctions.item_id = items.id)",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
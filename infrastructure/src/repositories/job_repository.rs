@@ -0,0 +1,177 @@
+use crate::models::job::JobModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::entities::job::Job;
+use domain::id::Id;
+use domain::interfaces::i_job_repository::IJobRepository;
+use log::error;
+use sqlx::types::Uuid;
+use sqlx::PgConnection;
+
+/// Jobs left running longer than this are assumed to belong to a crashed worker and are claimed
+/// again. Workers time out their jobs well before that.
+const LOCK_TIMEOUT_SECONDS: i32 = 5 * 60;
+
+/// Enqueues jobs on an open connection, so other repositories can do it inside their own transaction.
+pub(crate) async fn enqueue_jobs(
+    connection: &mut PgConnection,
+    jobs: Vec<Job>,
+) -> anyhow::Result<()> {
+    for job in jobs {
+        insert_job(&mut *connection, job).await?;
+    }
+
+    Ok(())
+}
+
+async fn insert_job(connection: &mut PgConnection, job: Job) -> anyhow::Result<Option<JobModel>> {
+    let job = JobModel::try_from(job)?;
+
+    sqlx::query_as::<_, JobModel>(
+        "INSERT INTO jobs (id, kind, payload, status, attempts, max_attempts, run_at, last_error, created_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING *",
+    )
+    .bind(job.id)
+    .bind(job.kind)
+    .bind(job.payload)
+    .bind(job.status)
+    .bind(job.attempts)
+    .bind(job.max_attempts)
+    .bind(job.run_at)
+    .bind(job.last_error)
+    .bind(job.created_at)
+    .fetch_optional(connection)
+    .await
+    .map_err(|e| {
+        error!("{:?}", e);
+        anyhow!("{:?}", e)
+    })
+}
+
+#[async_trait]
+impl IJobRepository for DatabaseRepositoryImpl<Job> {
+    async fn enqueue(&self, job: Job) -> anyhow::Result<Option<Job>> {
+        let pool = self.pool.0.clone();
+        let mut connection = pool.acquire().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match insert_job(&mut connection, job).await? {
+            Some(job) => Ok(Some(Job::try_from(job)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn claim(&self, kinds: Vec<String>, limit: i64) -> anyhow::Result<Vec<Job>> {
+        let pool = self.pool.0.clone();
+
+        // SKIP LOCKED lets several workers claim disjoint batches without waiting on each other
+        let result = sqlx::query_as::<_, JobModel>(
+            "UPDATE jobs SET status = 'running', attempts = attempts + 1, locked_at = now() \
+            WHERE id IN ( \
+                SELECT id FROM jobs \
+                WHERE kind = ANY($1) \
+                AND ((status = 'pending' AND run_at <= now()) \
+                    OR (status = 'running' AND locked_at < now() - make_interval(secs => $3))) \
+                ORDER BY run_at \
+                LIMIT $2 \
+                FOR UPDATE SKIP LOCKED \
+            ) RETURNING *",
+        )
+        .bind(kinds)
+        .bind(limit)
+        .bind(LOCK_TIMEOUT_SECONDS)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result.into_iter().map(Job::try_from).collect()
+    }
+
+    async fn complete(&self, id: Id<Job>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        sqlx::query(
+            "UPDATE jobs SET status = 'completed', locked_at = NULL, payload = '{}'::jsonb \
+            WHERE id = $1",
+        )
+        .bind(id)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn retry(
+        &self,
+        id: Id<Job>,
+        last_error: String,
+        run_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        sqlx::query(
+            "UPDATE jobs SET status = 'pending', locked_at = NULL, last_error = $2, run_at = $3 \
+            WHERE id = $1",
+        )
+        .bind(id)
+        .bind(last_error)
+        .bind(run_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn dead_letter(&self, id: Id<Job>, last_error: String) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        sqlx::query(
+            "UPDATE jobs SET status = 'dead', locked_at = NULL, last_error = $2 WHERE id = $1",
+        )
+        .bind(id)
+        .bind(last_error)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn purge_finished(&self, before: DateTime<Utc>) -> anyhow::Result<u64> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query(
+            "DELETE FROM jobs WHERE status IN ('completed', 'dead') AND created_at < $1",
+        )
+        .bind(before)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::models::login_throttle::LoginThrottleModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::entities::login_throttle::LoginThrottle;
+use domain::interfaces::i_login_throttle_repository::ILoginThrottleRepository;
+use log::error;
+
+#[async_trait]
+impl ILoginThrottleRepository for DatabaseRepositoryImpl<LoginThrottle> {
+    async fn find_all(&self, keys: Vec<String>) -> anyhow::Result<Vec<LoginThrottle>> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query_as::<_, LoginThrottleModel>(
+            "SELECT * FROM login_throttles WHERE key = ANY($1)",
+        )
+        .bind(keys)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result.into_iter().map(LoginThrottle::try_from).collect()
+    }
+
+    async fn record_failure(
+        &self,
+        key: String,
+        forget_before: DateTime<Utc>,
+    ) -> anyhow::Result<LoginThrottle> {
+        let pool = self.pool.0.clone();
+
+        // a single upsert, so concurrent failures are all counted
+        let result = sqlx::query_as::<_, LoginThrottleModel>(
+            "INSERT INTO login_throttles (key, failed_attempts, last_failed_at) VALUES ($1, 1, now()) \
+            ON CONFLICT (key) DO UPDATE SET \
+            failed_attempts = CASE WHEN login_throttles.last_failed_at < $2 THEN 1 \
+            ELSE login_throttles.failed_attempts + 1 END, \
+            last_failed_at = now() \
+            RETURNING *",
+        )
+        .bind(key)
+        .bind(forget_before)
+        .fetch_one(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        LoginThrottle::try_from(result)
+    }
+
+    async fn lock(&self, key: String, locked_until: DateTime<Utc>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+
+        sqlx::query("UPDATE login_throttles SET locked_until = $2 WHERE key = $1")
+            .bind(key)
+            .bind(locked_until)
+            .execute(pool.as_ref())
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        Ok(())
+    }
+
+    async fn clear(&self, key: String) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+
+        sqlx::query("DELETE FROM login_throttles WHERE key = $1")
+            .bind(key)
+            .execute(pool.as_ref())
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        Ok(())
+    }
+}
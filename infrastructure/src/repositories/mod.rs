@@ -1,6 +1,22 @@
+pub mod account_repository;
+pub mod api_key_repository;
 mod auction_repository;
+pub mod audit_log_repository;
+pub mod email_verification_repository;
+pub mod external_identity_repository;
+pub mod item_image_repository;
 pub mod item_repository;
+pub mod job_repository;
+pub mod login_throttle_repository;
+pub mod notification_repository;
+pub mod password_reset_repository;
+pub mod rating_repository;
+pub mod session_repository;
+pub mod two_factor_repository;
+pub mod user_event_repository;
+pub mod user_profile_repository;
 pub mod user_repository;
+pub mod webhook_repository;
 
 use crate::db::Db;
 use sqlx::PgPool;
@@ -0,0 +1,168 @@
+use crate::models::notification::NotificationModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::notification::Notification;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_notification_repository::INotificationRepository;
+use log::error;
+use sqlx::types::Uuid;
+use sqlx::PgConnection;
+
+/// Stores notifications on an open connection, so they are written in the transaction of the
+/// change they tell about.
+pub(crate) async fn insert_notifications(
+    connection: &mut PgConnection,
+    notifications: Vec<Notification>,
+) -> anyhow::Result<()> {
+    for notification in notifications {
+        insert_notification(&mut *connection, notification).await?;
+    }
+
+    Ok(())
+}
+
+async fn insert_notification(
+    connection: &mut PgConnection,
+    notification: Notification,
+) -> anyhow::Result<Option<NotificationModel>> {
+    let notification = NotificationModel::try_from(notification)?;
+
+    sqlx::query_as::<_, NotificationModel>(
+        "INSERT INTO notifications (id, user_id, kind, message, auction_id, item_id, is_read, created_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING *",
+    )
+    .bind(notification.id)
+    .bind(notification.user_id)
+    .bind(notification.kind)
+    .bind(notification.message)
+    .bind(notification.auction_id)
+    .bind(notification.item_id)
+    .bind(notification.is_read)
+    .bind(notification.created_at)
+    .fetch_optional(connection)
+    .await
+    .map_err(|e| {
+        error!("{:?}", e);
+        anyhow!("{:?}", e)
+    })
+}
+
+#[async_trait]
+impl INotificationRepository for DatabaseRepositoryImpl<Notification> {
+    async fn insert(&self, notification: Notification) -> anyhow::Result<Option<Notification>> {
+        let pool = self.pool.0.clone();
+        let mut connection = pool.acquire().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match insert_notification(&mut connection, notification).await? {
+            Some(notification) => Ok(Some(Notification::try_from(notification)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: Id<User>,
+        before: Option<Id<Notification>>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<Notification>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let before = before
+            .map(|id| Uuid::parse_str(id.value.to_string().as_str()))
+            .transpose()
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        // ids break ties between notifications created at the same time
+        let result = sqlx::query_as::<_, NotificationModel>(
+            "SELECT * FROM notifications \
+            WHERE user_id = $1 AND ($2::uuid IS NULL OR (created_at, id) < ( \
+                SELECT created_at, id FROM notifications WHERE id = $2 AND user_id = $1 \
+            )) \
+            ORDER BY created_at DESC, id DESC \
+            LIMIT $3",
+        )
+        .bind(user_id)
+        .bind(before)
+        .bind(limit)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result.into_iter().map(Notification::try_from).collect()
+    }
+
+    async fn mark_read(
+        &self,
+        id: Id<Notification>,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Option<Notification>> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, NotificationModel>(
+            "UPDATE notifications SET is_read = true WHERE id = $1 AND user_id = $2 RETURNING *",
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(notification) => Ok(Some(Notification::try_from(notification)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn mark_all_read(&self, user_id: Id<User>) -> anyhow::Result<u64> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query(
+            "UPDATE notifications SET is_read = true WHERE user_id = $1 AND is_read = false",
+        )
+        .bind(user_id)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn count_unread(&self, user_id: Id<User>) -> anyhow::Result<i64> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND is_read = false",
+        )
+        .bind(user_id)
+        .fetch_one(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.0)
+    }
+}
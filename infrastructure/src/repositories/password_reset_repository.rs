@@ -0,0 +1,120 @@
+use crate::models::password_reset::PasswordResetTokenModel;
+use crate::repositories::job_repository::enqueue_jobs;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::job::Job;
+use domain::entities::password_reset::PasswordResetToken;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_password_reset_repository::IPasswordResetRepository;
+use log::error;
+use sqlx::types::Uuid;
+
+#[async_trait]
+impl IPasswordResetRepository for DatabaseRepositoryImpl<PasswordResetToken> {
+    async fn insert(&self, token: PasswordResetToken, jobs: Vec<Job>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let token = PasswordResetTokenModel::try_from(token)?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        sqlx::query(
+            "INSERT INTO password_reset_tokens (id, user_id, token_hash, expires_at, used_at, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(token.id)
+        .bind(token.user_id)
+        .bind(token.token_hash)
+        .bind(token.expires_at)
+        .bind(token.used_at)
+        .bind(token.created_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        enqueue_jobs(&mut transaction, jobs).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_latest_by_user_id(
+        &self,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Option<PasswordResetToken>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, PasswordResetTokenModel>(
+            "SELECT * FROM password_reset_tokens WHERE user_id = $1 ORDER BY created_at DESC LIMIT 1",
+        )
+        .bind(user_id)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(token) => Ok(Some(PasswordResetToken::try_from(token)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn consume(
+        &self,
+        token_hash: String,
+        password: String,
+    ) -> anyhow::Result<Option<Id<User>>> {
+        let pool = self.pool.0.clone();
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        let user_id = sqlx::query_scalar::<_, Uuid>(
+            "UPDATE password_reset_tokens SET used_at = now() \
+            WHERE token_hash = $1 AND used_at IS NULL AND expires_at > now() \
+            RETURNING user_id",
+        )
+        .bind(token_hash)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        let Some(user_id) = user_id else {
+            return Ok(None);
+        };
+
+        sqlx::query("UPDATE users SET password = $2 WHERE id = $1")
+            .bind(user_id)
+            .bind(password)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(Some(user_id.to_string().try_into()?))
+    }
+}
@@ -0,0 +1,129 @@
+use crate::models::rating::{RatingModel, RatingWithRaterModel, ReputationModel, SaleModel};
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::auction::Auction;
+use domain::entities::rating::{Rating, RatingWithRater, Sale};
+use domain::entities::reputation::Reputation;
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_rating_repository::IRatingRepository;
+use log::error;
+use sqlx::types::Uuid;
+use std::collections::HashMap;
+
+#[async_trait]
+impl IRatingRepository for DatabaseRepositoryImpl<Rating> {
+    async fn find_sale_by_auction_id(
+        &self,
+        auction_id: Id<Auction>,
+    ) -> anyhow::Result<Option<Sale>> {
+        let pool = self.pool.0.clone();
+        let auction_id = Uuid::parse_str(auction_id.value.to_string().as_str())
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, SaleModel>("SELECT * FROM sales WHERE auction_id = $1")
+            .bind(auction_id)
+            .fetch_optional(pool.as_ref())
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        match result {
+            Some(sale) => Ok(Some(Sale::try_from(sale)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn insert(&self, rating: Rating) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let rating = RatingModel::try_from(rating)?;
+
+        let result = sqlx::query(
+            "INSERT INTO ratings (id, sale_id, rater_id, ratee_id, ratee_role, score, comment, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+            ON CONFLICT (sale_id, rater_id) DO NOTHING",
+        )
+        .bind(rating.id)
+        .bind(rating.sale_id)
+        .bind(rating.rater_id)
+        .bind(rating.ratee_id)
+        .bind(rating.ratee_role)
+        .bind(rating.score)
+        .bind(rating.comment)
+        .bind(rating.created_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_all_by_ratee_id(
+        &self,
+        ratee_id: Id<User>,
+    ) -> anyhow::Result<Vec<RatingWithRater>> {
+        let pool = self.pool.0.clone();
+        let ratee_id =
+            Uuid::parse_str(ratee_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, RatingWithRaterModel>(
+            "SELECT ratings.*, users.username AS rater_name \
+            FROM ratings INNER JOIN users ON ratings.rater_id = users.id \
+            WHERE ratings.ratee_id = $1 ORDER BY ratings.created_at DESC",
+        )
+        .bind(ratee_id)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result
+            .into_iter()
+            .map(RatingWithRater::try_from)
+            .collect::<anyhow::Result<Vec<RatingWithRater>>>()
+    }
+
+    async fn find_reputations(
+        &self,
+        user_ids: Vec<Id<User>>,
+    ) -> anyhow::Result<HashMap<Id<User>, Reputation>> {
+        let pool = self.pool.0.clone();
+        let user_ids = user_ids
+            .iter()
+            .map(|user_id| Uuid::parse_str(user_id.value.to_string().as_str()))
+            .collect::<Result<Vec<Uuid>, _>>()
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, ReputationModel>(
+            "SELECT \
+                users.id AS user_id, \
+                (SELECT COUNT(*) FROM sales WHERE sales.seller_id = users.id) AS completed_sales, \
+                (SELECT COUNT(*) FROM ratings WHERE ratings.ratee_id = users.id) AS rating_count, \
+                (SELECT AVG(score)::float8 FROM ratings WHERE ratings.ratee_id = users.id) AS average_score \
+            FROM users WHERE users.id = ANY($1)",
+        )
+        .bind(user_ids)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result
+            .into_iter()
+            .map(|reputation| {
+                let user_id = Id::<User>::try_from(reputation.user_id.to_string())?;
+                Ok((user_id, Reputation::from(reputation)))
+            })
+            .collect::<anyhow::Result<HashMap<Id<User>, Reputation>>>()
+    }
+}
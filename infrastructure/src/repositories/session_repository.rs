@@ -0,0 +1,237 @@
+use crate::models::session::{RefreshTokenModel, SessionModel};
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::session::{RefreshToken, Session};
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_session_repository::ISessionRepository;
+use log::error;
+use sqlx::types::Uuid;
+use sqlx::PgConnection;
+
+async fn insert_refresh_token(
+    connection: &mut PgConnection,
+    refresh_token: RefreshToken,
+) -> anyhow::Result<()> {
+    let refresh_token = RefreshTokenModel::try_from(refresh_token)?;
+
+    sqlx::query(
+        "INSERT INTO refresh_tokens (id, session_id, token_hash, expires_at, used_at, created_at) \
+        VALUES ($1, $2, $3, $4, $5, $6)",
+    )
+    .bind(refresh_token.id)
+    .bind(refresh_token.session_id)
+    .bind(refresh_token.token_hash)
+    .bind(refresh_token.expires_at)
+    .bind(refresh_token.used_at)
+    .bind(refresh_token.created_at)
+    .execute(connection)
+    .await
+    .map_err(|e| {
+        error!("{:?}", e);
+        anyhow!("{:?}", e)
+    })?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl ISessionRepository for DatabaseRepositoryImpl<Session> {
+    async fn insert(&self, session: Session, refresh_token: RefreshToken) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let session = SessionModel::try_from(session)?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        sqlx::query(
+            "INSERT INTO sessions (id, user_id, user_agent, ip_address, created_at, last_seen_at, revoked_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(session.id)
+        .bind(session.user_id)
+        .bind(session.user_agent)
+        .bind(session.ip_address)
+        .bind(session.created_at)
+        .bind(session.last_seen_at)
+        .bind(session.revoked_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        insert_refresh_token(&mut transaction, refresh_token).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Id<Session>) -> anyhow::Result<Option<Session>> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, SessionModel>("SELECT * FROM sessions WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool.as_ref())
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        match result {
+            Some(session) => Ok(Some(Session::try_from(session)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_active_by_user_id(&self, user_id: Id<User>) -> anyhow::Result<Vec<Session>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, SessionModel>(
+            "SELECT * FROM sessions WHERE user_id = $1 AND revoked_at IS NULL ORDER BY last_seen_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result
+            .into_iter()
+            .map(Session::try_from)
+            .collect::<anyhow::Result<Vec<Session>>>()
+    }
+
+    async fn find_refresh_token(&self, token_hash: String) -> anyhow::Result<Option<RefreshToken>> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query_as::<_, RefreshTokenModel>(
+            "SELECT * FROM refresh_tokens WHERE token_hash = $1",
+        )
+        .bind(token_hash)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(refresh_token) => Ok(Some(RefreshToken::try_from(refresh_token)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn rotate_refresh_token(
+        &self,
+        used: Id<RefreshToken>,
+        next: RefreshToken,
+    ) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let used =
+            Uuid::parse_str(used.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET used_at = now() WHERE id = $1 AND used_at IS NULL",
+        )
+        .bind(used)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        insert_refresh_token(&mut transaction, next).await?;
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(true)
+    }
+
+    async fn revoke(&self, id: Id<Session>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        sqlx::query("UPDATE sessions SET revoked_at = now() WHERE id = $1 AND revoked_at IS NULL")
+            .bind(id)
+            .execute(pool.as_ref())
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: Id<User>,
+        except: Option<Id<Session>>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let except = except
+            .map(|id| Uuid::parse_str(id.value.to_string().as_str()))
+            .transpose()
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        sqlx::query(
+            "UPDATE sessions SET revoked_at = now() \
+            WHERE user_id = $1 AND revoked_at IS NULL AND ($2::uuid IS NULL OR id <> $2)",
+        )
+        .bind(user_id)
+        .bind(except)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn touch(&self, id: Id<Session>, ip_address: Option<String>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        sqlx::query(
+            "UPDATE sessions SET last_seen_at = now(), ip_address = COALESCE($2, ip_address) WHERE id = $1",
+        )
+        .bind(id)
+        .bind(ip_address)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+use crate::models::two_factor::{LoginChallengeModel, RecoveryCodeModel, TwoFactorModel};
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::two_factor::{LoginChallenge, RecoveryCode, TwoFactor};
+use domain::entities::user::User;
+use domain::id::Id;
+use domain::interfaces::i_two_factor_repository::ITwoFactorRepository;
+use log::error;
+use sqlx::types::Uuid;
+
+#[async_trait]
+impl ITwoFactorRepository for DatabaseRepositoryImpl<TwoFactor> {
+    async fn find_by_user_id(&self, user_id: Id<User>) -> anyhow::Result<Option<TwoFactor>> {
+        let pool = self.pool.0.clone();
+
+        let result =
+            sqlx::query_as::<_, TwoFactorModel>("SELECT * FROM user_two_factor WHERE user_id = $1")
+                .bind(Uuid::parse_str(&user_id.to_string())?)
+                .fetch_optional(pool.as_ref())
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+
+        result.map(TwoFactor::try_from).transpose()
+    }
+
+    async fn insert_pending(&self, two_factor: TwoFactor) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let two_factor = TwoFactorModel::try_from(two_factor)?;
+
+        // an unconfirmed secret is replaced, an enabled one is left alone
+        let result = sqlx::query(
+            "INSERT INTO user_two_factor (user_id, secret, enabled_at, last_used_step, created_at) \
+            VALUES ($1, $2, $3, $4, $5) \
+            ON CONFLICT (user_id) DO UPDATE SET \
+            secret = EXCLUDED.secret, last_used_step = NULL, created_at = EXCLUDED.created_at \
+            WHERE user_two_factor.enabled_at IS NULL",
+        )
+        .bind(two_factor.user_id)
+        .bind(two_factor.secret)
+        .bind(two_factor.enabled_at)
+        .bind(two_factor.last_used_step)
+        .bind(two_factor.created_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn enable(
+        &self,
+        user_id: Id<User>,
+        recovery_codes: Vec<RecoveryCode>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let user_id = Uuid::parse_str(&user_id.to_string())?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        sqlx::query("UPDATE user_two_factor SET enabled_at = now() WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        sqlx::query("DELETE FROM recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+
+        for code in recovery_codes {
+            let code = RecoveryCodeModel::try_from(code)?;
+            sqlx::query(
+                "INSERT INTO recovery_codes (id, user_id, code_hash, used_at) VALUES ($1, $2, $3, $4)",
+            )
+            .bind(code.id)
+            .bind(code.user_id)
+            .bind(code.code_hash)
+            .bind(code.used_at)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                anyhow!("{:?}", e)
+            })?;
+        }
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn disable(&self, user_id: Id<User>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let user_id = Uuid::parse_str(&user_id.to_string())?;
+        let mut transaction = pool.begin().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        for query in [
+            "DELETE FROM recovery_codes WHERE user_id = $1",
+            "DELETE FROM user_two_factor WHERE user_id = $1",
+        ] {
+            sqlx::query(query)
+                .bind(user_id)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+        }
+
+        transaction.commit().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn record_used_step(&self, user_id: Id<User>, step: i64) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query(
+            "UPDATE user_two_factor SET last_used_step = $2 \
+            WHERE user_id = $1 AND (last_used_step IS NULL OR last_used_step < $2)",
+        )
+        .bind(Uuid::parse_str(&user_id.to_string())?)
+        .bind(step)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn use_recovery_code(
+        &self,
+        user_id: Id<User>,
+        code_hash: String,
+    ) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query(
+            "UPDATE recovery_codes SET used_at = now() \
+            WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL",
+        )
+        .bind(Uuid::parse_str(&user_id.to_string())?)
+        .bind(code_hash)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn insert_challenge(&self, challenge: LoginChallenge) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let challenge = LoginChallengeModel::try_from(challenge)?;
+
+        sqlx::query(
+            "INSERT INTO login_challenges (id, user_id, token_hash, failed_attempts, expires_at, used_at, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(challenge.id)
+        .bind(challenge.user_id)
+        .bind(challenge.token_hash)
+        .bind(challenge.failed_attempts)
+        .bind(challenge.expires_at)
+        .bind(challenge.used_at)
+        .bind(challenge.created_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_challenge(&self, token_hash: String) -> anyhow::Result<Option<LoginChallenge>> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query_as::<_, LoginChallengeModel>(
+            "SELECT * FROM login_challenges WHERE token_hash = $1",
+        )
+        .bind(token_hash)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result.map(LoginChallenge::try_from).transpose()
+    }
+
+    async fn record_challenge_failure(&self, id: Id<LoginChallenge>) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+
+        sqlx::query(
+            "UPDATE login_challenges SET failed_attempts = failed_attempts + 1 WHERE id = $1",
+        )
+        .bind(Uuid::parse_str(&id.to_string())?)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn consume_challenge(&self, id: Id<LoginChallenge>) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query(
+            "UPDATE login_challenges SET used_at = now() WHERE id = $1 AND used_at IS NULL",
+        )
+        .bind(Uuid::parse_str(&id.to_string())?)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::models::user_event::UserEventModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::user::User;
+use domain::entities::user_event::{UserEvent, UserEventBatch, UserEventCursor};
+use domain::id::Id;
+use domain::interfaces::i_user_event_repository::IUserEventRepository;
+use log::error;
+use sqlx::types::Uuid;
+use sqlx::{FromRow, PgConnection};
+
+#[derive(FromRow)]
+struct FeedEventModel {
+    #[sqlx(flatten)]
+    event: UserEventModel,
+    released: bool,
+}
+
+/// Records events on an open connection, so they are written in the transaction of the change
+/// they tell about.
+pub(crate) async fn insert_user_events(
+    connection: &mut PgConnection,
+    events: Vec<UserEvent>,
+) -> anyhow::Result<()> {
+    for event in events {
+        insert_user_event(&mut *connection, event).await?;
+    }
+
+    Ok(())
+}
+
+async fn insert_user_event(
+    connection: &mut PgConnection,
+    event: UserEvent,
+) -> anyhow::Result<Option<UserEventModel>> {
+    let event = UserEventModel::try_from(event)?;
+
+    sqlx::query_as::<_, UserEventModel>(
+        "INSERT INTO user_events (id, user_id, kind, auction_id, item_id, value, created_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7) \
+        ON CONFLICT DO NOTHING \
+        RETURNING *",
+    )
+    .bind(event.id)
+    .bind(event.user_id)
+    .bind(event.kind)
+    .bind(event.auction_id)
+    .bind(event.item_id)
+    .bind(event.value)
+    .bind(event.created_at)
+    .fetch_optional(connection)
+    .await
+    .map_err(|e| {
+        error!("{:?}", e);
+        anyhow!("{:?}", e)
+    })
+}
+
+#[async_trait]
+impl IUserEventRepository for DatabaseRepositoryImpl<UserEvent> {
+    async fn insert(&self, event: UserEvent) -> anyhow::Result<Option<UserEvent>> {
+        let pool = self.pool.0.clone();
+        let mut connection = pool.acquire().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match insert_user_event(&mut connection, event).await? {
+            Some(event) => Ok(Some(UserEvent::try_from(event)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_after(
+        &self,
+        user_id: Id<User>,
+        after: UserEventCursor,
+        limit: i64,
+    ) -> anyhow::Result<UserEventBatch> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        // one statement sees one snapshot, so released rows and held back ones cannot overlap
+        let result = sqlx::query_as::<_, FeedEventModel>(
+            "SELECT *, xact_id < pg_snapshot_xmin(pg_current_snapshot())::text::bigint AS released \
+            FROM user_events \
+            WHERE user_id = $1 AND (xact_id, sequence) > ($2, $3) \
+            ORDER BY xact_id, sequence \
+            LIMIT $4",
+        )
+        .bind(user_id)
+        .bind(after.xact_id)
+        .bind(after.sequence)
+        .bind(limit)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        // released rows come first, their transactions are older than any open one
+        let held_back = result.iter().any(|row| !row.released);
+        let events = result
+            .into_iter()
+            .take_while(|row| row.released)
+            .map(|row| UserEvent::try_from(row.event))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(UserEventBatch { events, held_back })
+    }
+
+    async fn find_head(&self) -> anyhow::Result<UserEventCursor> {
+        let pool = self.pool.0.clone();
+
+        let result: (i64,) =
+            sqlx::query_as("SELECT pg_snapshot_xmin(pg_current_snapshot())::text::bigint")
+                .fetch_one(pool.as_ref())
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+
+        Ok(UserEventCursor {
+            xact_id: result.0 - 1,
+            sequence: i64::MAX,
+        })
+    }
+}
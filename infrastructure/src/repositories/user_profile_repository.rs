@@ -0,0 +1,67 @@
+use crate::models::user_profile::UserProfileModel;
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::user::User;
+use domain::entities::user_profile::UserProfile;
+use domain::id::Id;
+use domain::interfaces::i_user_profile_repository::IUserProfileRepository;
+use log::error;
+use sqlx::types::Uuid;
+
+#[async_trait]
+impl IUserProfileRepository for DatabaseRepositoryImpl<UserProfile> {
+    async fn find(&self, user_id: Id<User>) -> anyhow::Result<Option<UserProfile>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result =
+            sqlx::query_as::<_, UserProfileModel>("SELECT * FROM user_profiles WHERE user_id = $1")
+                .bind(user_id)
+                .fetch_optional(pool.as_ref())
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+
+        match result {
+            Some(profile) => Ok(Some(UserProfile::try_from(profile)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn upsert(&self, profile: UserProfile) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let profile = UserProfileModel::try_from(profile)?;
+
+        sqlx::query(
+            "INSERT INTO user_profiles \
+                (user_id, display_name, bio, location, avatar, avatar_content_type, updated_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7) \
+            ON CONFLICT (user_id) DO UPDATE SET \
+                display_name = EXCLUDED.display_name, \
+                bio = EXCLUDED.bio, \
+                location = EXCLUDED.location, \
+                avatar = EXCLUDED.avatar, \
+                avatar_content_type = EXCLUDED.avatar_content_type, \
+                updated_at = EXCLUDED.updated_at",
+        )
+        .bind(profile.user_id)
+        .bind(profile.display_name)
+        .bind(profile.bio)
+        .bind(profile.location)
+        .bind(profile.avatar)
+        .bind(profile.avatar_content_type)
+        .bind(profile.updated_at)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use crate::models::user::UserModel;
+use crate::repositories::job_repository::enqueue_jobs;
 use crate::repositories::DatabaseRepositoryImpl;
 use anyhow::anyhow;
 use async_trait::async_trait;
+use domain::entities::job::Job;
 use domain::entities::user::User;
 use domain::id::Id;
 use domain::interfaces::i_user_repository::IUserRepository;
@@ -53,23 +55,70 @@ impl IUserRepository for DatabaseRepositoryImpl<User> {
         }
     }
 
-    async fn insert(&self, user: User) -> anyhow::Result<Option<User>> {
+    async fn insert(&self, user: User, jobs: Vec<Job>) -> anyhow::Result<Option<User>> {
         let pool = self.pool.0.clone();
         let user = UserModel::try_from(user)?;
+        let mut transaction = pool.begin().await.map_err(|e| anyhow!("{:?}", e))?;
         let result = sqlx::query_as::<_, UserModel>(
-            "INSERT INTO users (id, username, email, password) VALUES ($1, $2, $3, $4) RETURNING *",
+            "INSERT INTO users (id, username, email, password, email_verified, role) \
+            VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
         )
         .bind(user.id)
         .bind(user.username)
         .bind(user.email)
         .bind(user.password)
-        .fetch_optional(pool.as_ref())
+        .bind(user.email_verified)
+        .bind(user.role)
+        .fetch_optional(&mut *transaction)
         .await
         .map_err(|e| anyhow!("{:?}", e))?;
 
+        enqueue_jobs(&mut transaction, jobs).await?;
+        transaction.commit().await.map_err(|e| anyhow!("{:?}", e))?;
+
         match result {
             Some(user) => Ok(Some(User::try_from(user)?)),
             None => Ok(None),
         }
     }
+
+    async fn update_password(&self, id: Id<User>, password: String) -> anyhow::Result<()> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        sqlx::query("UPDATE users SET password = $2 WHERE id = $1")
+            .bind(id)
+            .bind(password)
+            .execute(pool.as_ref())
+            .await
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        Ok(())
+    }
+
+    async fn find_all(&self) -> anyhow::Result<Vec<User>> {
+        let pool = self.pool.0.clone();
+
+        let result = sqlx::query_as::<_, UserModel>("SELECT * FROM users ORDER BY username")
+            .fetch_all(pool.as_ref())
+            .await
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        result.into_iter().map(User::try_from).collect()
+    }
+
+    async fn suspend(&self, id: Id<User>) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query(
+            "UPDATE users SET suspended_at = now() WHERE id = $1 AND suspended_at IS NULL",
+        )
+        .bind(id)
+        .execute(pool.as_ref())
+        .await
+        .map_err(|e| anyhow!("{:?}", e))?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
@@ -0,0 +1,198 @@
+use crate::models::webhook::{WebhookDeliveryModel, WebhookSubscriptionModel};
+use crate::repositories::DatabaseRepositoryImpl;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::user::User;
+use domain::entities::webhook::{WebhookDelivery, WebhookEventType, WebhookSubscription};
+use domain::id::Id;
+use domain::interfaces::i_webhook_repository::IWebhookRepository;
+use log::error;
+use sqlx::types::Uuid;
+
+#[async_trait]
+impl IWebhookRepository for DatabaseRepositoryImpl<WebhookSubscription> {
+    async fn insert(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> anyhow::Result<Option<WebhookSubscription>> {
+        let pool = self.pool.0.clone();
+        let subscription = WebhookSubscriptionModel::try_from(subscription)?;
+        let result = sqlx::query_as::<_, WebhookSubscriptionModel>(
+            "INSERT INTO webhook_subscriptions (id, user_id, url, event_types, secret, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+        )
+        .bind(subscription.id)
+        .bind(subscription.user_id)
+        .bind(subscription.url)
+        .bind(subscription.event_types)
+        .bind(subscription.secret)
+        .bind(subscription.created_at)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(subscription) => Ok(Some(WebhookSubscription::try_from(subscription)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Id<WebhookSubscription>,
+    ) -> anyhow::Result<Option<WebhookSubscription>> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, WebhookSubscriptionModel>(
+            "SELECT * FROM webhook_subscriptions WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(subscription) => Ok(Some(WebhookSubscription::try_from(subscription)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all_by_user_id(
+        &self,
+        user_id: Id<User>,
+    ) -> anyhow::Result<Vec<WebhookSubscription>> {
+        let pool = self.pool.0.clone();
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, WebhookSubscriptionModel>(
+            "SELECT * FROM webhook_subscriptions WHERE user_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result
+            .into_iter()
+            .map(WebhookSubscription::try_from)
+            .collect()
+    }
+
+    async fn find_subscribed(
+        &self,
+        user_ids: Vec<Id<User>>,
+        event_type: WebhookEventType,
+    ) -> anyhow::Result<Vec<WebhookSubscription>> {
+        let pool = self.pool.0.clone();
+        let user_ids = user_ids
+            .into_iter()
+            .map(|id| Uuid::parse_str(id.value.to_string().as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, WebhookSubscriptionModel>(
+            "SELECT * FROM webhook_subscriptions WHERE user_id = ANY($1) AND $2 = ANY(event_types)",
+        )
+        .bind(user_ids)
+        .bind(String::from(event_type))
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result
+            .into_iter()
+            .map(WebhookSubscription::try_from)
+            .collect()
+    }
+
+    async fn delete(&self, id: Id<WebhookSubscription>, user_id: Id<User>) -> anyhow::Result<bool> {
+        let pool = self.pool.0.clone();
+        let id = Uuid::parse_str(id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+        let user_id =
+            Uuid::parse_str(user_id.value.to_string().as_str()).map_err(|e| anyhow!("{:?}", e))?;
+
+        let result =
+            sqlx::query("DELETE FROM webhook_subscriptions WHERE id = $1 AND user_id = $2")
+                .bind(id)
+                .bind(user_id)
+                .execute(pool.as_ref())
+                .await
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    anyhow!("{:?}", e)
+                })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn insert_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> anyhow::Result<Option<WebhookDelivery>> {
+        let pool = self.pool.0.clone();
+        let delivery = WebhookDeliveryModel::try_from(delivery)?;
+        let result = sqlx::query_as::<_, WebhookDeliveryModel>(
+            "INSERT INTO webhook_deliveries (id, subscription_id, event_id, event_type, attempt, status_code, error, succeeded, created_at) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING *",
+        )
+        .bind(delivery.id)
+        .bind(delivery.subscription_id)
+        .bind(delivery.event_id)
+        .bind(delivery.event_type)
+        .bind(delivery.attempt)
+        .bind(delivery.status_code)
+        .bind(delivery.error)
+        .bind(delivery.succeeded)
+        .bind(delivery.created_at)
+        .fetch_optional(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        match result {
+            Some(delivery) => Ok(Some(WebhookDelivery::try_from(delivery)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_deliveries(
+        &self,
+        subscription_id: Id<WebhookSubscription>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<WebhookDelivery>> {
+        let pool = self.pool.0.clone();
+        let subscription_id = Uuid::parse_str(subscription_id.value.to_string().as_str())
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        let result = sqlx::query_as::<_, WebhookDeliveryModel>(
+            "SELECT * FROM webhook_deliveries WHERE subscription_id = $1 \
+            ORDER BY created_at DESC LIMIT $2",
+        )
+        .bind(subscription_id)
+        .bind(limit)
+        .fetch_all(pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        result.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+}
@@ -0,0 +1,260 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use domain::entities::webhook::is_public_address;
+use domain::interfaces::i_webhook_sender::IWebhookSender;
+use log::error;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::redirect::Policy;
+use std::error::Error;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Resolves receiver host names and refuses those pointing at internal addresses. Checking at
+/// connect time covers names re-pointed after the subscription was created.
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(resolve_public(name))
+    }
+}
+
+async fn resolve_public(name: Name) -> Result<Addrs, Box<dyn Error + Send + Sync>> {
+    let addresses = tokio::net::lookup_host((name.as_str(), 0))
+        .await?
+        .collect::<Vec<SocketAddr>>();
+    if let Some(address) = addresses
+        .iter()
+        .find(|address| !is_public_address(address.ip()))
+    {
+        return Err(format!(
+            "{} resolves to internal address {}",
+            name.as_str(),
+            address.ip()
+        )
+        .into());
+    }
+
+    Ok(Box::new(addresses.into_iter()))
+}
+
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+    allow_internal: bool,
+}
+
+impl HttpWebhookSender {
+    pub fn new() -> Self {
+        Self::build(false)
+    }
+
+    /// Lets tests deliver to receivers on the loopback interface.
+    #[cfg(test)]
+    fn allowing_internal() -> Self {
+        Self::build(true)
+    }
+
+    fn build(allow_internal: bool) -> Self {
+        // a redirect would lead past the address checks, and a proxy resolves names itself
+        let builder = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .redirect(Policy::none())
+            .no_proxy();
+        let builder = match allow_internal {
+            true => builder,
+            false => builder.dns_resolver(Arc::new(PublicAddressResolver)),
+        };
+        let client = builder
+            .build()
+            .expect("Failed to build webhook HTTP client");
+
+        Self {
+            client,
+            allow_internal,
+        }
+    }
+
+    /// Urls with an address instead of a host name are not resolved, so they are checked here.
+    fn check_address_literal(&self, url: &str) -> anyhow::Result<()> {
+        let url = reqwest::Url::parse(url)?;
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+
+        match host.parse::<IpAddr>() {
+            Ok(address) if !self.allow_internal && !is_public_address(address) => Err(anyhow!(
+                "Refusing to send webhook to internal address {}",
+                address
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for HttpWebhookSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl IWebhookSender for HttpWebhookSender {
+    async fn send(
+        &self,
+        url: String,
+        headers: Vec<(String, String)>,
+        body: String,
+    ) -> anyhow::Result<u16> {
+        self.check_address_literal(&url)?;
+
+        let request = headers
+            .into_iter()
+            .fold(self.client.post(url), |request, (name, value)| {
+                request.header(name, value)
+            })
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+
+        let response = request.send().await.map_err(|e| {
+            error!("{:?}", e);
+            anyhow!("{:?}", e)
+        })?;
+
+        Ok(response.status().as_u16())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::oneshot;
+
+    /// Accepts a single request, answers with `status` and `headers` and hands the raw request back.
+    async fn stub_receiver(status: u16, headers: &str) -> (String, oneshot::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/webhook", listener.local_addr().unwrap());
+        let (sender, receiver) = oneshot::channel();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 4096];
+            loop {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(headers_end) = text.find("\r\n\r\n") {
+                    let content_length = text[..headers_end]
+                        .lines()
+                        .find_map(|line| {
+                            line.to_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|value| value.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= headers_end + 4 + content_length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+            let response = format!(
+                "HTTP/1.1 {} Stub\r\n{}content-length: 0\r\n\r\n",
+                status, headers
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            let _ = sender.send(String::from_utf8_lossy(&request).to_string());
+        });
+
+        (url, receiver)
+    }
+
+    #[tokio::test]
+    async fn given_stub_receiver_when_sending_then_headers_and_body_are_delivered() {
+        // Arrange
+        let (url, received) = stub_receiver(200, "").await;
+        let sender = HttpWebhookSender::allowing_internal();
+
+        // Act
+        let result = sender
+            .send(
+                url,
+                vec![("X-Webhook-Signature".to_string(), "sha256=abc".to_string())],
+                "{\"type\":\"bid_placed\"}".to_string(),
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), 200);
+        let request = received.await.unwrap().to_lowercase();
+        assert!(request.starts_with("post /webhook"));
+        assert!(request.contains("x-webhook-signature: sha256=abc"));
+        assert!(request.contains("content-type: application/json"));
+        assert!(request.ends_with("{\"type\":\"bid_placed\"}"));
+    }
+
+    #[tokio::test]
+    async fn given_failing_receiver_when_sending_then_status_code_is_returned() {
+        // Arrange
+        let (url, _received) = stub_receiver(503, "").await;
+        let sender = HttpWebhookSender::allowing_internal();
+
+        // Act
+        let result = sender.send(url, vec![], "{}".to_string()).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), 503);
+    }
+
+    #[tokio::test]
+    async fn given_redirecting_receiver_when_sending_then_redirect_is_not_followed() {
+        // Arrange
+        let (url, _received) =
+            stub_receiver(302, "location: http://169.254.169.254/latest/meta-data\r\n").await;
+        let sender = HttpWebhookSender::allowing_internal();
+
+        // Act
+        let result = sender.send(url, vec![], "{}".to_string()).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), 302);
+    }
+
+    #[tokio::test]
+    async fn given_internal_address_when_sending_then_nothing_is_sent() {
+        // Arrange
+        let (url, mut received) = stub_receiver(200, "").await;
+        let sender = HttpWebhookSender::new();
+
+        // Act
+        let result = sender.send(url, vec![], "{}".to_string()).await;
+
+        // Assert
+        assert!(result.is_err());
+        assert!(received.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn given_host_name_of_internal_address_when_sending_then_nothing_is_sent() {
+        // Arrange
+        let (url, mut received) = stub_receiver(200, "").await;
+        let url = url.replace("127.0.0.1", "localhost");
+        let sender = HttpWebhookSender::new();
+
+        // Act
+        let result = sender.send(url, vec![], "{}".to_string()).await;
+
+        // Assert
+        assert!(result.is_err());
+        assert!(received.try_recv().is_err());
+    }
+}
@@ -0,0 +1,18 @@
+-- Add migration script here
+CREATE TABLE user_events (
+    id uuid PRIMARY KEY,
+    sequence BIGSERIAL NOT NULL UNIQUE,
+    user_id uuid NOT NULL,
+    kind TEXT NOT NULL,
+    auction_id uuid,
+    item_id uuid,
+    value real,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (user_id) REFERENCES users(id)
+);
+
+CREATE INDEX user_events_user_id_sequence_idx ON user_events (user_id, sequence);
+
+-- an auction asks its owner for confirmation only once, however often the expiry job sees it
+CREATE UNIQUE INDEX user_events_confirmation_required_idx ON user_events (user_id, auction_id)
+    WHERE kind = 'confirmation_required';
@@ -0,0 +1,14 @@
+-- Add migration script here
+CREATE TABLE notifications (
+    id uuid PRIMARY KEY,
+    user_id uuid NOT NULL,
+    kind TEXT NOT NULL,
+    message TEXT NOT NULL,
+    auction_id uuid,
+    item_id uuid,
+    is_read BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (user_id) REFERENCES users(id)
+);
+
+CREATE INDEX notifications_user_id_created_at_idx ON notifications (user_id, created_at DESC);
@@ -0,0 +1,14 @@
+-- Add migration script here
+CREATE TABLE email_outbox (
+    id uuid PRIMARY KEY,
+    recipient_id uuid NOT NULL,
+    subject TEXT NOT NULL,
+    body TEXT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (recipient_id) REFERENCES users(id)
+);
+
+CREATE INDEX email_outbox_pending_idx ON email_outbox (created_at) WHERE sent_at IS NULL;
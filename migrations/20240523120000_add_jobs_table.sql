@@ -0,0 +1,28 @@
+-- Add migration script here
+CREATE TABLE jobs (
+    id uuid PRIMARY KEY,
+    kind TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL DEFAULT 5,
+    run_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    locked_at TIMESTAMPTZ,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX jobs_runnable_idx ON jobs (run_at) WHERE status IN ('pending', 'running');
+
+-- emails still waiting in the outbox become send_email jobs
+INSERT INTO jobs (id, kind, payload, attempts, last_error, created_at)
+SELECT id,
+       'send_email',
+       jsonb_build_object('recipient_id', recipient_id::text, 'subject', subject, 'body', body),
+       attempts,
+       last_error,
+       created_at
+FROM email_outbox
+WHERE sent_at IS NULL AND attempts < 5;
+
+DROP TABLE email_outbox;
@@ -0,0 +1,4 @@
+-- Add migration script here
+ALTER TABLE auctions ADD COLUMN finalization_attempts INT NOT NULL DEFAULT 0;
+ALTER TABLE auctions ADD COLUMN last_finalization_error TEXT;
+ALTER TABLE auctions ADD COLUMN quarantined_at TIMESTAMPTZ;
@@ -0,0 +1,16 @@
+-- Add migration script here
+ALTER TABLE users ADD COLUMN email_verified BOOLEAN NOT NULL DEFAULT false;
+
+-- accounts created before verification existed keep working
+UPDATE users SET email_verified = true;
+
+CREATE TABLE email_verification_tokens (
+    id uuid PRIMARY KEY,
+    user_id uuid NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (user_id) REFERENCES users(id)
+);
+
+CREATE INDEX email_verification_tokens_user_id_idx ON email_verification_tokens (user_id, created_at DESC);
@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE password_reset_tokens (
+    id uuid PRIMARY KEY,
+    user_id uuid NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (user_id) REFERENCES users(id)
+);
@@ -0,0 +1,20 @@
+-- Add migration script here
+CREATE TABLE login_throttles (
+    key TEXT PRIMARY KEY,
+    failed_attempts INT NOT NULL,
+    locked_until TIMESTAMPTZ,
+    last_failed_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE audit_log (
+    id uuid PRIMARY KEY,
+    kind TEXT NOT NULL,
+    user_id uuid,
+    email TEXT,
+    ip_address TEXT,
+    details TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (user_id) REFERENCES users(id)
+);
+
+CREATE INDEX audit_log_created_at_idx ON audit_log (created_at DESC);
@@ -0,0 +1,26 @@
+-- Add migration script here
+ALTER TABLE users ALTER COLUMN password DROP NOT NULL;
+
+CREATE TABLE external_identities (
+    id uuid PRIMARY KEY,
+    user_id uuid NOT NULL,
+    provider TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    email TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (provider, subject),
+    FOREIGN KEY (user_id) REFERENCES users(id)
+);
+
+CREATE INDEX external_identities_user_id_idx ON external_identities (user_id);
+
+CREATE TABLE authorization_requests (
+    state TEXT PRIMARY KEY,
+    provider TEXT NOT NULL,
+    code_verifier TEXT NOT NULL,
+    nonce TEXT NOT NULL,
+    link_user_id uuid,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (link_user_id) REFERENCES users(id)
+);
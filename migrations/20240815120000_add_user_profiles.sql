@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE user_profiles (
+    user_id uuid PRIMARY KEY,
+    display_name TEXT,
+    bio TEXT,
+    location TEXT,
+    avatar BYTEA,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (user_id) REFERENCES users(id)
+);
@@ -0,0 +1,45 @@
+-- Add migration script here
+CREATE TABLE sales (
+    id uuid PRIMARY KEY,
+    auction_id uuid NOT NULL UNIQUE,
+    item_id uuid NOT NULL,
+    seller_id uuid NOT NULL,
+    buyer_id uuid NOT NULL,
+    completed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (seller_id) REFERENCES users(id),
+    FOREIGN KEY (buyer_id) REFERENCES users(id)
+);
+
+CREATE INDEX sales_seller_id_idx ON sales (seller_id);
+
+-- past sales only left events behind, they keep the time they were sold at, so those sold within
+-- the rating window can still be rated
+INSERT INTO sales (id, auction_id, item_id, seller_id, buyer_id, completed_at)
+SELECT DISTINCT ON (sold.auction_id)
+       gen_random_uuid(),
+       sold.auction_id,
+       sold.item_id,
+       sold.user_id,
+       won.user_id,
+       sold.created_at
+FROM user_events sold
+INNER JOIN user_events won ON won.auction_id = sold.auction_id AND won.kind = 'auction_won'
+WHERE sold.kind = 'item_sold' AND sold.auction_id IS NOT NULL AND sold.item_id IS NOT NULL
+ORDER BY sold.auction_id, sold.created_at;
+
+CREATE TABLE ratings (
+    id uuid PRIMARY KEY,
+    sale_id uuid NOT NULL,
+    rater_id uuid NOT NULL,
+    ratee_id uuid NOT NULL,
+    ratee_role TEXT NOT NULL,
+    score SMALLINT NOT NULL CHECK (score BETWEEN 1 AND 5),
+    comment TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (sale_id, rater_id),
+    FOREIGN KEY (sale_id) REFERENCES sales(id),
+    FOREIGN KEY (rater_id) REFERENCES users(id),
+    FOREIGN KEY (ratee_id) REFERENCES users(id)
+);
+
+CREATE INDEX ratings_ratee_id_created_at_idx ON ratings (ratee_id, created_at DESC);
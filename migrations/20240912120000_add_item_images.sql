@@ -0,0 +1,19 @@
+-- Add migration script here
+CREATE TABLE item_images (
+    id uuid PRIMARY KEY,
+    item_id uuid NOT NULL,
+    position INT NOT NULL,
+    data bytea NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (item_id) REFERENCES items(id),
+    -- checked at commit, so positions can be shifted with a single UPDATE
+    CONSTRAINT item_images_item_id_position_key UNIQUE (item_id, position) DEFERRABLE INITIALLY DEFERRED
+);
+
+-- the single picture of existing items becomes their first image
+INSERT INTO item_images (id, item_id, position, data)
+SELECT gen_random_uuid(), id, 0, picture
+FROM items
+WHERE picture IS NOT NULL AND length(picture) > 0;
+
+ALTER TABLE items DROP COLUMN picture;
@@ -0,0 +1,18 @@
+-- Add migration script here
+ALTER TABLE item_images ADD COLUMN content_type TEXT NOT NULL DEFAULT 'application/octet-stream';
+-- thumbnails are rows of their own at the position of their image, 0 marks the image itself
+ALTER TABLE item_images ADD COLUMN thumbnail_size INT NOT NULL DEFAULT 0;
+
+-- uploads were stored as sent, so their type is read from the magic bytes
+UPDATE item_images SET content_type = CASE
+    WHEN substring(data FROM 1 FOR 3) = '\xffd8ff'::bytea THEN 'image/jpeg'
+    WHEN substring(data FROM 1 FOR 8) = '\x89504e470d0a1a0a'::bytea THEN 'image/png'
+    WHEN substring(data FROM 1 FOR 4) = 'RIFF'::bytea AND substring(data FROM 9 FOR 4) = 'WEBP'::bytea THEN 'image/webp'
+    ELSE 'application/octet-stream'
+END;
+
+ALTER TABLE item_images ALTER COLUMN content_type DROP DEFAULT;
+
+ALTER TABLE item_images DROP CONSTRAINT item_images_item_id_position_key;
+ALTER TABLE item_images ADD CONSTRAINT item_images_item_id_position_thumbnail_size_key
+    UNIQUE (item_id, position, thumbnail_size) DEFERRABLE INITIALLY DEFERRED;
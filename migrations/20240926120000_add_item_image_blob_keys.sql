@@ -0,0 +1,8 @@
+-- Add migration script here
+-- image data moves to the blob store, rows keep the key it is stored under
+ALTER TABLE item_images ADD COLUMN blob_key TEXT;
+UPDATE item_images SET blob_key = 'item-images/' || item_id || '/' || id;
+ALTER TABLE item_images ALTER COLUMN blob_key SET NOT NULL;
+
+-- only rows written before keep their data here, until the migrate-blobs tool has moved it out
+ALTER TABLE item_images ALTER COLUMN data DROP NOT NULL;
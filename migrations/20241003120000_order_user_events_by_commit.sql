@@ -0,0 +1,18 @@
+-- sequences are drawn before commit, so feeds are ordered by the transaction that recorded an
+-- event and only pass it once every older transaction has ended
+ALTER TABLE user_events
+    ADD COLUMN xact_id BIGINT NOT NULL DEFAULT pg_current_xact_id()::text::bigint;
+
+CREATE INDEX user_events_user_id_xact_id_sequence_idx ON user_events (user_id, xact_id, sequence);
+DROP INDEX user_events_user_id_sequence_idx;
+
+-- wakes up the feeds of the user once the event is committed
+CREATE FUNCTION notify_user_event() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('user_events', NEW.user_id::text);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER user_events_notify AFTER INSERT ON user_events
+    FOR EACH ROW EXECUTE FUNCTION notify_user_event();
@@ -0,0 +1,2 @@
+-- sent emails carry verification and reset links, so payloads are dropped once a job is done
+UPDATE jobs SET payload = '{}'::jsonb WHERE status = 'completed';
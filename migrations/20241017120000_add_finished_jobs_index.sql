@@ -0,0 +1,2 @@
+-- finished jobs are purged by age
+CREATE INDEX jobs_finished_idx ON jobs (created_at) WHERE status IN ('completed', 'dead');
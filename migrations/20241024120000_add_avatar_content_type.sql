@@ -0,0 +1,13 @@
+ALTER TABLE user_profiles ADD COLUMN avatar_content_type TEXT;
+
+-- avatars uploaded so far were stored as sent, so they go by their magic bytes
+UPDATE user_profiles SET avatar_content_type = CASE
+    WHEN substring(avatar FROM 1 FOR 3) = '\xffd8ff'::bytea THEN 'image/jpeg'
+    WHEN substring(avatar FROM 1 FOR 8) = '\x89504e470d0a1a0a'::bytea THEN 'image/png'
+    WHEN substring(avatar FROM 1 FOR 4) = '\x52494646'::bytea
+        AND substring(avatar FROM 9 FOR 4) = '\x57454250'::bytea THEN 'image/webp'
+END
+WHERE avatar IS NOT NULL;
+
+-- anything else is no image and is not served any longer
+UPDATE user_profiles SET avatar = NULL WHERE avatar_content_type IS NULL;
@@ -0,0 +1,2 @@
+-- the latest reset of a user is looked up on every request to throttle them
+CREATE INDEX password_reset_tokens_user_id_created_at_idx ON password_reset_tokens (user_id, created_at DESC);